@@ -74,6 +74,14 @@ if [ -e $topdir/kclvm/target/release/libkclvm_cli_cdylib.$dll_extension ]; then
     cp $topdir/kclvm/target/release/libkclvm_cli_cdylib.$dll_extension $kclvm_install_dir/bin/libkclvm_cli_cdylib.$dll_extension
 fi
 
+# Build the KCLVM runtime static lib for the wasm32-wasi target if the target is installed.
+if rustup target list --installed 2>/dev/null | grep -q "^wasm32-wasi$"; then
+    cd $topdir/kclvm
+    cargo rustc -p kclvm-runtime --release --lib --target wasm32-wasi -- --crate-type staticlib -C panic=abort
+    mkdir -p $kclvm_install_dir/lib/wasm32-wasi
+    cp ./target/wasm32-wasi/release/libkclvm_runtime.a $kclvm_install_dir/lib/wasm32-wasi/libkclvm_runtime.a
+fi
+
 # Copy KCLVM C API header
 cd $topdir/kclvm/runtime
 cp src/_kclvm.h  $kclvm_install_dir/include/_kclvm.h
@@ -180,6 +180,9 @@ pub struct OverrideSpec {
     pub field_path: String,
     pub field_value: String,
     pub action: OverrideAction,
+    /// The config entry operator of the override value, e.g. `=`, `:` and `+=`.
+    #[serde(default)]
+    pub operation: ConfigEntryOperation,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -525,6 +528,7 @@ pub enum Expr {
     NameConstantLit(NameConstantLit),
     JoinedString(JoinedString),
     FormattedValue(FormattedValue),
+    Missing(MissingExpr),
 }
 
 /// Identifier, e.g.
@@ -767,9 +771,10 @@ pub struct ConfigExpr {
     pub items: Vec<NodeRef<ConfigEntry>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum ConfigEntryOperation {
     Union,
+    #[default]
     Override,
     Insert,
 }
@@ -1071,6 +1076,14 @@ pub struct FormattedValue {
     pub format_spec: Option<String>,
 }
 
+/// MissingExpr, the error node placeholder of an expression which can
+/// not be parsed, e.g. the value of
+/// ```kcl
+/// a =
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MissingExpr;
+
 /// Comment, e.g.
 /// ```kcl
 /// # This is a comment
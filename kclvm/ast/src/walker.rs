@@ -116,6 +116,7 @@ pub trait TypedResultWalker<'ctx>: Sized {
         -> Self::Result;
     fn walk_joined_string(&self, joined_string: &'ctx ast::JoinedString) -> Self::Result;
     fn walk_formatted_value(&self, formatted_value: &'ctx ast::FormattedValue) -> Self::Result;
+    fn walk_missing_expr(&self, missing_expr: &'ctx ast::MissingExpr) -> Self::Result;
     fn walk_comment(&self, comment: &'ctx ast::Comment) -> Self::Result;
 }
 
@@ -209,6 +210,7 @@ pub trait MutSelfTypedResultWalker<'ctx>: Sized {
             ast::Expr::FormattedValue(formatted_value) => {
                 self.walk_formatted_value(formatted_value)
             }
+            ast::Expr::Missing(missing_expr) => self.walk_missing_expr(missing_expr),
         }
     }
     fn walk_quant_expr(&mut self, quant_expr: &'ctx ast::QuantExpr) -> Self::Result;
@@ -249,6 +251,7 @@ pub trait MutSelfTypedResultWalker<'ctx>: Sized {
     ) -> Self::Result;
     fn walk_joined_string(&mut self, joined_string: &'ctx ast::JoinedString) -> Self::Result;
     fn walk_formatted_value(&mut self, formatted_value: &'ctx ast::FormattedValue) -> Self::Result;
+    fn walk_missing_expr(&mut self, missing_expr: &'ctx ast::MissingExpr) -> Self::Result;
     fn walk_comment(&mut self, comment: &'ctx ast::Comment) -> Self::Result;
 }
 
@@ -450,6 +453,10 @@ pub trait MutSelfMutWalker<'ctx> {
     fn walk_formatted_value(&mut self, formatted_value: &'ctx mut ast::FormattedValue) {
         self.walk_expr(&mut formatted_value.value.node);
     }
+    fn walk_missing_expr(&mut self, missing_expr: &'ctx mut ast::MissingExpr) {
+        // Nothing to do.
+        let _ = missing_expr;
+    }
     fn walk_comment(&mut self, comment: &'ctx mut ast::Comment) {
         // Nothing to do.
         let _ = comment;
@@ -512,6 +519,7 @@ pub trait MutSelfMutWalker<'ctx> {
             ast::Expr::FormattedValue(formatted_value) => {
                 self.walk_formatted_value(formatted_value)
             }
+            ast::Expr::Missing(missing_expr) => self.walk_missing_expr(missing_expr),
         }
     }
 }
@@ -643,6 +651,9 @@ pub trait Walker<'ctx>: TypedResultWalker<'ctx> {
     fn walk_formatted_value(&mut self, formatted_value: &'ctx ast::FormattedValue) {
         walk_formatted_value(self, formatted_value);
     }
+    fn walk_missing_expr(&mut self, missing_expr: &'ctx ast::MissingExpr) {
+        walk_missing_expr(self, missing_expr);
+    }
     fn walk_comment(&mut self, comment: &'ctx ast::Comment) {
         walk_comment(self, comment);
     }
@@ -693,6 +704,7 @@ pub fn walk_expr<'ctx, V: Walker<'ctx>>(walker: &mut V, expr: &'ctx ast::Expr) {
         }
         ast::Expr::JoinedString(joined_string) => walker.walk_joined_string(joined_string),
         ast::Expr::FormattedValue(formatted_value) => walker.walk_formatted_value(formatted_value),
+        ast::Expr::Missing(missing_expr) => walker.walk_missing_expr(missing_expr),
     }
 }
 
@@ -981,6 +993,15 @@ pub fn walk_formatted_value<'ctx, V: Walker<'ctx>>(
     walker.walk_expr(&formatted_value.value.node);
 }
 
+pub fn walk_missing_expr<'ctx, V: Walker<'ctx>>(
+    walker: &mut V,
+    missing_expr: &'ctx ast::MissingExpr,
+) {
+    // Nothing to do.
+    let _ = walker;
+    let _ = missing_expr;
+}
+
 pub fn walk_comment<'ctx, V: Walker<'ctx>>(walker: &mut V, comment: &'ctx ast::Comment) {
     // Nothing to do.
     let _ = walker;
@@ -1187,6 +1208,10 @@ pub trait MutSelfWalker {
     fn walk_formatted_value(&mut self, formatted_value: &ast::FormattedValue) {
         self.walk_expr(&formatted_value.value.node);
     }
+    fn walk_missing_expr(&mut self, missing_expr: &ast::MissingExpr) {
+        // Nothing to do.
+        let _ = missing_expr;
+    }
     fn walk_comment(&mut self, comment: &ast::Comment) {
         // Nothing to do.
         let _ = comment;
@@ -1249,6 +1274,7 @@ pub trait MutSelfWalker {
             ast::Expr::FormattedValue(formatted_value) => {
                 self.walk_formatted_value(formatted_value)
             }
+            ast::Expr::Missing(missing_expr) => self.walk_missing_expr(missing_expr),
         }
     }
 }
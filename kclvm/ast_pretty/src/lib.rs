@@ -4,7 +4,8 @@ use kclvm_ast::{
     token::TokenKind,
     walker::MutSelfTypedResultWalker,
 };
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
 mod node;
 
 #[cfg(test)]
@@ -13,6 +14,8 @@ mod tests;
 pub const WHITESPACE: &str = " ";
 pub const TAB: &str = "\t";
 pub const NEWLINE: &str = "\n";
+/// The separator between the code and the inline comment, e.g., `a = 1  # comment`.
+pub const INLINE_COMMENT_SEPARATOR: &str = "  ";
 
 #[derive(Debug, Clone)]
 pub enum Indentation {
@@ -24,13 +27,44 @@ pub enum Indentation {
     Fill = 5,
 }
 
+/// The quote style of the printed string literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Keep the quotes of the source.
+    Preserve,
+    /// Prefer double quotes unless more escapes are needed.
+    Double,
+    /// Prefer single quotes unless more escapes are needed.
+    Single,
+}
+
+impl FromStr for QuoteStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "preserve" => Ok(QuoteStyle::Preserve),
+            "double" => Ok(QuoteStyle::Double),
+            "single" => Ok(QuoteStyle::Single),
+            _ => Err(format!(
+                "Unsupported quote style '{}', expected one of preserve, double and single",
+                s
+            )),
+        }
+    }
+}
+
 /// Printer config
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Config {
     pub tab_len: usize,
     pub indent_len: usize,
     pub use_spaces: bool,
     pub write_comments: bool,
+    /// The max line width, the call arguments and config entries which exceed
+    /// it are wrapped into multiple lines. `0` denotes no limit.
+    pub max_width: usize,
+    pub quote_style: QuoteStyle,
 }
 
 impl Default for Config {
@@ -40,6 +74,8 @@ impl Default for Config {
             indent_len: 4,
             use_spaces: true,
             write_comments: true,
+            max_width: 0,
+            quote_style: QuoteStyle::Preserve,
         }
     }
 }
@@ -69,6 +105,16 @@ pub struct Printer<'p> {
     pub comments: VecDeque<ast::NodeRef<ast::Comment>>,
     pub import_spec: IndexMap<String, String>,
     pub hook: &'p (dyn PrinterHook + 'p),
+    /// The count of the nodes wrapped into multiple lines for the max width.
+    wrapped_count: usize,
+    /// Whether the nodes are wrapped into multiple lines for the max width, keyed by
+    /// the node address, the start column and the indent, which decide the wrapping.
+    wrapped_nodes: HashMap<(usize, usize, usize), bool>,
+    /// The end lines of the nodes being printed from the outer to the inner.
+    node_end_lines: Vec<u64>,
+    /// The last source line of the printed code, the end positions of statements
+    /// are not used because they are at the start of the next tokens.
+    code_end_line: u64,
 }
 
 impl Default for Printer<'_> {
@@ -81,6 +127,10 @@ impl Default for Printer<'_> {
             comments: Default::default(),
             import_spec: Default::default(),
             hook: &NoHook,
+            wrapped_count: 0,
+            wrapped_nodes: HashMap::default(),
+            node_end_lines: vec![],
+            code_end_line: 0,
         }
     }
 }
@@ -95,6 +145,10 @@ impl<'p> Printer<'p> {
             comments: VecDeque::default(),
             import_spec: IndexMap::default(),
             hook,
+            wrapped_count: 0,
+            wrapped_nodes: HashMap::default(),
+            node_end_lines: vec![],
+            code_end_line: 0,
         }
     }
 
@@ -168,6 +222,55 @@ impl<'p> Printer<'p> {
         self.write_string(NEWLINE);
     }
 
+    /// Print with the `one_line` writer, and print with the `multi_line` writer
+    /// instead when the printed lines exceed the max width or any inner node is
+    /// wrapped, so that the outer nodes are wrapped before the inner ones.
+    ///
+    /// The decision is memoized for the node at `key` because the inner nodes are
+    /// printed again in the `multi_line` writer, which is exponential in the depth
+    /// of the nested nodes without the memoization.
+    pub fn write_within_max_width(
+        &mut self,
+        key: usize,
+        one_line: impl Fn(&mut Self),
+        multi_line: impl Fn(&mut Self),
+    ) {
+        if self.cfg.max_width == 0 {
+            one_line(self);
+            return;
+        }
+        let out_len = self.out.len();
+        let line_start = self.out.rfind(NEWLINE).map_or(0, |i| i + 1);
+        let column = self.out[line_start..].chars().count();
+        let wrapped_key = (key, column, self.indent);
+        let wrapped_count = self.wrapped_count;
+        let is_wrapped = match self.wrapped_nodes.get(&wrapped_key) {
+            Some(is_wrapped) => *is_wrapped,
+            None => {
+                let (last_ast_line, comments) = (self.last_ast_line, self.comments.clone());
+                one_line(self);
+                let exceeded = self.out[line_start..]
+                    .split(NEWLINE)
+                    .any(|line| line.chars().count() > self.cfg.max_width);
+                let is_wrapped = exceeded || self.wrapped_count > wrapped_count;
+                self.wrapped_nodes.insert(wrapped_key, is_wrapped);
+                if !is_wrapped {
+                    return;
+                }
+                self.out.truncate(out_len);
+                self.last_ast_line = last_ast_line;
+                self.comments = comments;
+                true
+            }
+        };
+        if is_wrapped {
+            self.wrapped_count = wrapped_count + 1;
+            multi_line(self);
+        } else {
+            one_line(self);
+        }
+    }
+
     /// Print value
     #[inline]
     pub fn write_value<T: std::fmt::Display>(&mut self, value: T) {
@@ -209,7 +312,11 @@ impl<'p> Printer<'p> {
             self.last_ast_line = node.line;
             let mut index = None;
             for (i, comment) in self.comments.iter().enumerate() {
-                if comment.line <= node.line {
+                // The comments after the node start on the same line are the inline
+                // comments of the node or its children, e.g., `a = 1  # comment`.
+                if comment.line < node.line
+                    || (comment.line == node.line && comment.column < node.column)
+                {
                     index = Some(i);
                 } else {
                     break;
@@ -230,6 +337,85 @@ impl<'p> Printer<'p> {
         }
     }
 
+    /// Print the inline comments on the last source line of the printed code after
+    /// the last node on the line, e.g., `a = 1  # comment`, and the newlines after the
+    /// node are kept after the comments.
+    pub fn write_inline_comments(&mut self) {
+        if !self.cfg.write_comments {
+            return;
+        }
+        let mut texts = vec![];
+        while let Some(comment) = self.comments.front() {
+            if comment.line != self.code_end_line {
+                break;
+            }
+            texts.extend(
+                self.comments
+                    .pop_front()
+                    .map(|comment| comment.node.text.clone()),
+            );
+        }
+        if texts.is_empty() {
+            return;
+        }
+        let newlines = self.out.split_off(self.out.trim_end_matches(NEWLINE).len());
+        for text in texts {
+            self.write(INLINE_COMMENT_SEPARATOR);
+            self.write(&text);
+        }
+        self.write(&newlines);
+    }
+
+    /// Print the trailing comments of a block of statements e.g., the schema body and
+    /// the if body, which are on the lines right after the printed code of the block
+    /// and indented at least the statements of the block.
+    pub fn write_block_trailing_comments(&mut self, stmts: &[ast::NodeRef<ast::Stmt>]) {
+        let column = match stmts.first() {
+            Some(first) if self.cfg.write_comments => first.column,
+            _ => return,
+        };
+        while let Some(comment) = self.comments.front() {
+            if comment.line != self.code_end_line + 1 || comment.column < column {
+                break;
+            }
+            self.code_end_line = comment.line;
+            if let Some(comment) = self.comments.pop_front() {
+                self.fill(&comment.node.text);
+                self.write_newline_without_fill();
+            }
+        }
+    }
+
+    /// Returns whether there are comments to print before the end line of the
+    /// node being printed, which can't be printed in one line with the node.
+    pub fn has_comments_in_node(&self) -> bool {
+        match (self.comments.front(), self.node_end_lines.last()) {
+            (Some(comment), Some(end_line)) => self.cfg.write_comments && comment.line < *end_line,
+            _ => false,
+        }
+    }
+
+    /// Pops the comments before the end line of the node being printed, e.g., the
+    /// trailing comments before the closing bracket of a config or a lambda.
+    pub fn pop_trailing_comments(&mut self) -> Vec<ast::NodeRef<ast::Comment>> {
+        let mut comments = vec![];
+        while self.has_comments_in_node() {
+            comments.extend(self.comments.pop_front());
+        }
+        comments
+    }
+
+    /// Print the trailing comments of a multi-line config or list on separate lines,
+    /// `has_items` denotes whether the items have been printed before them.
+    pub fn write_trailing_comments(&mut self, has_items: bool) {
+        for (i, comment) in self.pop_trailing_comments().iter().enumerate() {
+            if has_items || i > 0 {
+                self.write_newline();
+            }
+            self.write(&comment.node.text);
+        }
+    }
+
     // --------------------------
     // Indent and scope functions
     // --------------------------
@@ -252,6 +438,13 @@ pub fn print_ast_module(module: &Module) -> String {
     printer.out
 }
 
+/// Print AST to string with the printer config.
+pub fn print_ast_module_with_config(module: &Module, cfg: Config) -> String {
+    let mut printer = Printer::new(cfg, &NoHook);
+    printer.write_module(module);
+    printer.out
+}
+
 /// Print AST to string
 pub fn print_ast_node(node: ASTNode) -> String {
     let mut printer = Printer::default();
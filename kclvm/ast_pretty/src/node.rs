@@ -7,7 +7,7 @@ use kclvm_ast::{
 };
 use kclvm_error::bug;
 
-use super::{Indentation, Printer};
+use super::{Indentation, Printer, QuoteStyle};
 
 type ParameterType<'a> = (
     (
@@ -118,6 +118,7 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
         self.write_newline_without_fill();
         self.write_indentation(Indentation::Indent);
         self.stmts(&if_stmt.body);
+        self.write_block_trailing_comments(&if_stmt.body);
         self.write_indentation(Indentation::Dedent);
         if !if_stmt.orelse.is_empty() {
             if let ast::Stmt::If(elif_stmt) = &if_stmt.orelse[0].node {
@@ -132,6 +133,7 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
                 self.write_newline_without_fill();
                 self.write_indentation(Indentation::Indent);
                 self.stmts(&if_stmt.orelse);
+                self.write_block_trailing_comments(&if_stmt.orelse);
                 self.write_indentation(Indentation::Dedent);
             }
         } else {
@@ -229,6 +231,7 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             self.write_newline_without_fill();
         }
         self.stmts(&schema_stmt.body);
+        self.write_block_trailing_comments(&schema_stmt.body);
         self.write_newline_without_fill();
         if !schema_stmt.checks.is_empty() {
             self.fill("check:");
@@ -401,9 +404,7 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
 
     fn walk_call_expr(&mut self, call_expr: &'ctx ast::CallExpr) -> Self::Result {
         self.expr(&call_expr.func);
-        self.write("(");
-        self.write_args_and_kwargs(&call_expr.args, &call_expr.keywords);
-        self.write(")");
+        self.write_args_and_kwargs_in_parens(&call_expr.args, &call_expr.keywords);
     }
 
     fn walk_subscript(&mut self, subscript: &'ctx ast::Subscript) -> Self::Result {
@@ -442,12 +443,12 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             .iter()
             .map(|e| e.line)
             .collect::<HashSet<u64>>();
-        let mut in_one_line = line_set.len() <= 1;
-        if let Some(elt) = list_expr.elts.first() {
-            if let ast::Expr::ListIfItem(_) = &elt.node {
-                in_one_line = false;
-            }
-        }
+        let in_one_line = line_set.len() <= 1
+            && !list_expr
+                .elts
+                .iter()
+                .any(|elt| matches!(elt.node, ast::Expr::ListIfItem(_)))
+            && !self.has_comments_in_node();
         self.write_token(TokenKind::OpenDelim(DelimToken::Bracket));
         if !in_one_line {
             self.write_indentation(Indentation::IndentWithNewline);
@@ -458,10 +459,16 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             } else {
                 self.write_newline();
             },
-            |elt| self.expr(elt),
+            |elt: &ast::NodeRef<ast::Expr>| {
+                self.expr(elt);
+                if !in_one_line {
+                    self.write_inline_comments();
+                }
+            },
             list_expr.elts
         );
         if !in_one_line {
+            self.write_trailing_comments(!list_expr.elts.is_empty());
             self.write_indentation(Indentation::DedentWithNewline);
         }
         self.write_token(TokenKind::CloseDelim(DelimToken::Bracket));
@@ -489,8 +496,9 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             |expr| self.expr(expr),
             list_if_item_expr.exprs
         );
-        self.write_indentation(Indentation::DedentWithNewline);
+        self.write_indentation(Indentation::Dedent);
         if let Some(orelse) = &list_if_item_expr.orelse {
+            self.write_newline();
             match &orelse.node {
                 ast::Expr::List(list_expr) => {
                     self.write("else:");
@@ -527,6 +535,7 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
         }
         self.write(dict_comp.entry.operation.symbol());
         self.write_space();
+        self.expr(&dict_comp.entry.value);
         for gen in &dict_comp.generators {
             self.walk_comp_clause(&gen.node);
         }
@@ -546,8 +555,9 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             |entry: &ast::NodeRef<ast::ConfigEntry>| self.write_entry(entry),
             config_if_entry_expr.items
         );
-        self.write_indentation(Indentation::DedentWithNewline);
+        self.write_indentation(Indentation::Dedent);
         if let Some(orelse) = &config_if_entry_expr.orelse {
+            self.write_newline();
             match &orelse.node {
                 ast::Expr::Config(config_expr) => {
                     self.write("else:");
@@ -586,9 +596,7 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
     fn walk_schema_expr(&mut self, schema_expr: &'ctx ast::SchemaExpr) -> Self::Result {
         self.walk_identifier(&schema_expr.name.node);
         if !schema_expr.args.is_empty() || !schema_expr.kwargs.is_empty() {
-            self.write_token(TokenKind::OpenDelim(DelimToken::Paren));
-            self.write_args_and_kwargs(&schema_expr.args, &schema_expr.kwargs);
-            self.write_token(TokenKind::CloseDelim(DelimToken::Paren));
+            self.write_args_and_kwargs_in_parens(&schema_expr.args, &schema_expr.kwargs);
         }
         self.write_space();
         self.expr(&schema_expr.config)
@@ -596,31 +604,21 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
 
     fn walk_config_expr(&mut self, config_expr: &'ctx ast::ConfigExpr) -> Self::Result {
         let line_set: HashSet<u64> = config_expr.items.iter().map(|item| item.line).collect();
-        let mut in_one_line = line_set.len() <= 1;
+        let mut in_one_line = line_set.len() <= 1 && !self.has_comments_in_node();
         if let Some(item) = config_expr.items.first() {
             if let ast::Expr::ConfigIfEntry(_) = &item.node.value.node {
                 in_one_line = false;
             }
         }
-        self.write_token(TokenKind::OpenDelim(DelimToken::Brace));
-        if !config_expr.items.is_empty() {
-            if !in_one_line {
-                self.write_indentation(Indentation::IndentWithNewline);
-            }
-            interleave!(
-                || if in_one_line {
-                    self.write(COMMA_WHITESPACE);
-                } else {
-                    self.write_newline();
-                },
-                |entry: &ast::NodeRef<ast::ConfigEntry>| self.write_entry(entry),
-                config_expr.items
+        if in_one_line && !config_expr.items.is_empty() {
+            self.write_within_max_width(
+                config_expr as *const ast::ConfigExpr as usize,
+                |printer| printer.write_config_items(&config_expr.items, true),
+                |printer| printer.write_config_items(&config_expr.items, false),
             );
-            if !in_one_line {
-                self.write_indentation(Indentation::DedentWithNewline);
-            }
+        } else {
+            self.write_config_items(&config_expr.items, in_one_line);
         }
-        self.write_token(TokenKind::CloseDelim(DelimToken::Brace));
     }
 
     fn walk_check_expr(&mut self, check_expr: &'ctx ast::CheckExpr) -> Self::Result {
@@ -649,14 +647,18 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
         }
         self.write_space();
         self.write_token(TokenKind::OpenDelim(DelimToken::Brace));
-        self.write_newline();
+        self.write_newline_without_fill();
         self.write_indentation(Indentation::Indent);
 
         // lambda body
         self.stmts(&lambda_expr.body);
+        for comment in self.pop_trailing_comments() {
+            self.fill(&comment.node.text);
+            self.write_newline_without_fill();
+        }
 
         self.write_indentation(Indentation::Dedent);
-        self.write_newline();
+        self.fill("");
         self.write_token(TokenKind::CloseDelim(DelimToken::Brace));
     }
 
@@ -710,13 +712,17 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
     fn walk_number_lit(&mut self, number_lit: &'ctx ast::NumberLit) -> Self::Result {
         match number_lit.value {
             ast::NumberLitValue::Int(int_val) => self.write(&int_val.to_string()),
-            ast::NumberLitValue::Float(float_val) => self.write(&float_val.to_string()),
+            // Use the debug format to keep the decimal point and the exponent of floats.
+            ast::NumberLitValue::Float(float_val) => self.write(&format!("{:?}", float_val)),
+        }
+        if let Some(binary_suffix) = &number_lit.binary_suffix {
+            self.write(&binary_suffix.value());
         }
     }
 
     fn walk_string_lit(&mut self, string_lit: &'ctx ast::StringLit) -> Self::Result {
         if !string_lit.raw_value.is_empty() {
-            self.write(&string_lit.raw_value)
+            self.write_string_raw_value(&string_lit.raw_value);
         } else {
             self.write(&if string_lit.is_long_string {
                 format!("\"\"\"{}\"\"\"", string_lit.value.replace('\"', "\\\""))
@@ -735,21 +741,40 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
     }
 
     fn walk_joined_string(&mut self, joined_string: &'ctx ast::JoinedString) -> Self::Result {
+        // Keep the escapes of the parsed string such as `$$` and `\n`.
+        if !joined_string.raw_value.is_empty() {
+            self.write_string_raw_value(&joined_string.raw_value);
+            return;
+        }
+        // Use single quotes only when no single quote needs to be escaped.
+        let quote = if self.cfg.quote_style == QuoteStyle::Single
+            && joined_string.values.iter().all(|value| match &value.node {
+                ast::Expr::StringLit(string_lit) => !string_lit.value.contains('\''),
+                _ => true,
+            }) {
+            '\''
+        } else {
+            '"'
+        };
         let quote_str = if joined_string.is_long_string {
-            "\"\"\""
+            quote.to_string().repeat(3)
         } else {
-            "\""
+            quote.to_string()
         };
-        self.write(quote_str);
+        self.write(&quote_str);
         for value in &joined_string.values {
             match &value.node {
                 ast::Expr::StringLit(string_lit) => {
-                    self.write(&string_lit.value.replace('\"', "\\\""));
+                    if quote == '"' {
+                        self.write(&string_lit.value.replace('\"', "\\\""));
+                    } else {
+                        self.write(&string_lit.value);
+                    }
                 }
                 _ => self.expr(value),
             }
         }
-        self.write(quote_str);
+        self.write(&quote_str);
     }
 
     fn walk_formatted_value(&mut self, formatted_value: &'ctx ast::FormattedValue) -> Self::Result {
@@ -761,6 +786,10 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
         self.write("}");
     }
 
+    fn walk_missing_expr(&mut self, _missing_expr: &'ctx ast::MissingExpr) -> Self::Result {
+        // Nothing to do.
+    }
+
     fn walk_comment(&mut self, comment: &'ctx ast::Comment) -> Self::Result {
         self.writeln(&comment.text);
         self.fill("");
@@ -768,6 +797,59 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
 }
 
 impl<'p> Printer<'p> {
+    /// Writes the string literal source with the quote style of the config.
+    fn write_string_raw_value(&mut self, raw_value: &str) {
+        let requoted = match self.cfg.quote_style {
+            QuoteStyle::Preserve => None,
+            QuoteStyle::Double => requote_string(raw_value, '"'),
+            QuoteStyle::Single => requote_string(raw_value, '\''),
+        };
+        self.write(requoted.as_deref().unwrap_or(raw_value));
+    }
+
+    /// Print the call arguments in parentheses, which are wrapped into multiple
+    /// lines with trailing commas when they exceed the max width.
+    pub fn write_args_and_kwargs_in_parens(
+        &mut self,
+        args: &[ast::NodeRef<ast::Expr>],
+        kwargs: &[ast::NodeRef<ast::Keyword>],
+    ) {
+        self.write_token(TokenKind::OpenDelim(DelimToken::Paren));
+        if args.is_empty() && kwargs.is_empty() {
+            self.write_token(TokenKind::CloseDelim(DelimToken::Paren));
+            return;
+        }
+        // The address of the first argument identifies the arguments.
+        let key = match args.first() {
+            Some(arg) => arg as *const ast::NodeRef<ast::Expr> as usize,
+            None => kwargs.as_ptr() as usize,
+        };
+        self.write_within_max_width(
+            key,
+            |printer| {
+                printer.write_args_and_kwargs(args, kwargs);
+                printer.write_token(TokenKind::CloseDelim(DelimToken::Paren));
+            },
+            |printer| {
+                printer.write_indentation(Indentation::Indent);
+                for arg in args {
+                    printer.write_newline();
+                    printer.expr(arg);
+                    printer.write_token(TokenKind::Comma);
+                    printer.write_inline_comments();
+                }
+                for kwarg in kwargs {
+                    printer.write_newline();
+                    printer.walk_keyword(&kwarg.node);
+                    printer.write_token(TokenKind::Comma);
+                    printer.write_inline_comments();
+                }
+                printer.write_indentation(Indentation::DedentWithNewline);
+                printer.write_token(TokenKind::CloseDelim(DelimToken::Paren));
+            },
+        );
+    }
+
     pub fn write_args_and_kwargs(
         &mut self,
         args: &[ast::NodeRef<ast::Expr>],
@@ -784,6 +866,37 @@ impl<'p> Printer<'p> {
         );
     }
 
+    /// Print the config entries in braces in one line or multiple lines.
+    pub fn write_config_items(
+        &mut self,
+        items: &[ast::NodeRef<ast::ConfigEntry>],
+        in_one_line: bool,
+    ) {
+        self.write_token(TokenKind::OpenDelim(DelimToken::Brace));
+        if !in_one_line {
+            self.write_indentation(Indentation::IndentWithNewline);
+        }
+        interleave!(
+            || if in_one_line {
+                self.write(COMMA_WHITESPACE);
+            } else {
+                self.write_newline();
+            },
+            |entry: &ast::NodeRef<ast::ConfigEntry>| {
+                self.write_entry(entry);
+                if !in_one_line {
+                    self.write_inline_comments();
+                }
+            },
+            items
+        );
+        if !in_one_line {
+            self.write_trailing_comments(!items.is_empty());
+            self.write_indentation(Indentation::DedentWithNewline);
+        }
+        self.write_token(TokenKind::CloseDelim(DelimToken::Brace));
+    }
+
     pub fn write_entry(&mut self, item: &ast::NodeRef<ast::ConfigEntry>) {
         match &item.node.key {
             Some(key) => {
@@ -850,7 +963,10 @@ impl<'p> Printer<'p> {
     pub fn expr(&mut self, expr: &ast::NodeRef<ast::Expr>) {
         self.hook.pre(self, super::ASTNode::Expr(expr));
         self.write_ast_comments(expr);
+        self.node_end_lines.push(expr.end_line);
         self.walk_expr(&expr.node);
+        self.code_end_line = self.code_end_line.max(expr.end_line);
+        self.node_end_lines.pop();
         self.hook.post(self, super::ASTNode::Expr(expr));
     }
 
@@ -858,7 +974,11 @@ impl<'p> Printer<'p> {
         self.hook.pre(self, super::ASTNode::Stmt(stmt));
         self.fill("");
         self.write_ast_comments(stmt);
+        self.node_end_lines.push(stmt.end_line);
+        self.code_end_line = self.code_end_line.max(stmt.line);
         self.walk_stmt(&stmt.node);
+        self.write_inline_comments();
+        self.node_end_lines.pop();
         self.hook.post(self, super::ASTNode::Stmt(stmt));
     }
 
@@ -874,3 +994,48 @@ impl<'p> Printer<'p> {
         }
     }
 }
+
+/// Returns the string literal source quoted with `quote`, or `None` when the
+/// quotes are unchanged or can't be changed without adding escapes.
+fn requote_string(raw_value: &str, quote: char) -> Option<String> {
+    let quote_index = raw_value.find(['"', '\''])?;
+    let (prefix, quoted) = raw_value.split_at(quote_index);
+    let old_quote = quoted.chars().next()?;
+    if old_quote == quote {
+        return None;
+    }
+    let is_raw = prefix.to_lowercase().contains('r');
+    let quote_len = if quoted.len() >= 6 && quoted.starts_with(&old_quote.to_string().repeat(3)) {
+        3
+    } else {
+        1
+    };
+    let body = quoted.get(quote_len..quoted.len().checked_sub(quote_len)?)?;
+    if body.contains(quote) {
+        return None;
+    }
+    // Unescape the old quotes, which are not needed in the new quotes.
+    let mut new_body = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some(next) if next == old_quote => {
+                    if is_raw {
+                        return None;
+                    }
+                    new_body.push(next);
+                }
+                Some(next) => {
+                    new_body.push(c);
+                    new_body.push(next);
+                }
+                None => new_body.push(c),
+            }
+        } else {
+            new_body.push(c);
+        }
+    }
+    let quotes = quote.to_string().repeat(quote_len);
+    Some(format!("{}{}{}{}", prefix, quotes, new_body, quotes))
+}
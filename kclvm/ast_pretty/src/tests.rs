@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use super::print_ast_module;
+use super::{print_ast_module, print_ast_module_with_config, Config, QuoteStyle};
 use kclvm_parser::parse_file;
 use pretty_assertions::assert_eq;
 
@@ -50,3 +50,47 @@ fn test_ast_printer() {
         assert_eq!(data_input, data_output, "Test failed on {}", case);
     }
 }
+
+#[test]
+fn test_ast_printer_with_config() {
+    let module = parse_file(
+        "",
+        Some("a = \"It's\"\nb = r\"\\d\"\nc = func(1111, 2222, x=3333)\n".to_string()),
+    )
+    .unwrap();
+    let cfg = Config {
+        max_width: 20,
+        quote_style: QuoteStyle::Single,
+        ..Default::default()
+    };
+    assert_eq!(
+        print_ast_module_with_config(&module, cfg),
+        "a = \"It's\"\nb = r'\\d'\nc = func(\n    1111,\n    2222,\n    x=3333,\n)\n"
+    );
+}
+
+#[test]
+fn test_ast_printer_with_max_width_deep_nesting() {
+    // The nested nodes are wrapped from the outer to the inner, which must not take
+    // exponential time in the nesting depth.
+    let depth = 32;
+    let code = format!("a = {}1{}\n", "f(".repeat(depth), ")".repeat(depth));
+    let module = parse_file("", Some(code)).unwrap();
+    let cfg = Config {
+        max_width: 40,
+        ..Default::default()
+    };
+    // The innermost call exceeds the max width at its indentation, so all the outer
+    // calls are wrapped.
+    let indent = |level: usize| "    ".repeat(level);
+    let mut expected = "a = f(\n".to_string();
+    for level in 1..depth {
+        expected.push_str(&format!("{}f(\n", indent(level)));
+    }
+    expected.push_str(&format!("{}1,\n", indent(depth)));
+    for level in (1..depth).rev() {
+        expected.push_str(&format!("{}),\n", indent(level)));
+    }
+    expected.push_str(")\n");
+    assert_eq!(print_ast_module_with_config(&module, cfg), expected);
+}
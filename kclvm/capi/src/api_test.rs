@@ -1,4 +1,4 @@
-use protobuf::Message;
+use protobuf::{Message, MessageFull};
 
 use crate::model::gpyrpc::*;
 use crate::service::api::*;
@@ -43,6 +43,30 @@ fn test_c_api_call_exec_program() {
     kclvm_service_free_string(result_ptr);
 }
 
+#[test]
+fn test_c_api_call_exec_program_fail() {
+    let (result, except_result) = test_c_api::<ExecProgram_Args, ExecProgram_Result>(
+        "KclvmService.ExecProgram",
+        "exec-program-fail.json",
+        "exec-program-fail.response.json",
+    );
+    assert_eq!(result.json_result, except_result.json_result);
+    assert_eq!(result.yaml_result, except_result.yaml_result);
+    assert!(result.err_message.contains(&except_result.err_message));
+    assert!(!result.diagnostics.is_empty());
+}
+
+#[test]
+fn test_c_api_call_exec_program_with_schema_type_path() {
+    let (result, except_result) = test_c_api::<ExecProgram_Args, ExecProgram_Result>(
+        "KclvmService.ExecProgram",
+        "exec-program-with-schema-type-path.json",
+        "exec-program-with-schema-type-path.response.json",
+    );
+    assert_eq!(result.json_result, except_result.json_result);
+    assert_eq!(result.yaml_result, except_result.yaml_result);
+}
+
 #[test]
 fn test_c_api_call_override_file() {
     let serv = kclvm_service_new(0);
@@ -77,3 +101,223 @@ fn test_c_api_call_override_file() {
     kclvm_service_delete(serv);
     kclvm_service_free_string(result_ptr);
 }
+
+#[test]
+fn test_c_api_call_get_field_values() {
+    let (result, except_result) = test_c_api::<GetFieldValues_Args, GetFieldValues_Result>(
+        "KclvmService.GetFieldValues",
+        "get-field-values.json",
+        "get-field-values.response.json",
+    );
+    // The file names of the positions are absolute paths and only the values,
+    // operators and the line and column numbers are compared.
+    let positions = |result: &GetFieldValues_Result| {
+        result
+            .field_values
+            .iter()
+            .map(|v| {
+                let pos = |p: &Position| (p.line, p.column);
+                (
+                    v.value.clone(),
+                    v.operation.clone(),
+                    pos(&v.start),
+                    pos(&v.end),
+                    pos(&v.value_start),
+                    pos(&v.value_end),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(positions(&result), positions(&except_result));
+}
+
+#[test]
+fn test_c_api_call_get_schema_type() {
+    let (result, except_result) = test_c_api::<GetSchemaType_Args, GetSchemaType_Result>(
+        "KclvmService.GetSchemaType",
+        "get-schema-type.json",
+        "get-schema-type.response.json",
+    );
+    // The schema file names are absolute paths and they are not compared.
+    let schema_types = |result: &GetSchemaType_Result| {
+        result
+            .schema_type_list
+            .iter()
+            .map(|ty| {
+                let mut properties = ty
+                    .properties
+                    .iter()
+                    .map(|(name, p)| {
+                        (
+                            name.clone(),
+                            p.type_.clone(),
+                            p.type_str.clone(),
+                            p.default.clone(),
+                            p.description.clone(),
+                            p.line,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                properties.sort();
+                (
+                    ty.schema_name.clone(),
+                    ty.schema_doc.clone(),
+                    ty.pkg_path.clone(),
+                    ty.line,
+                    properties,
+                    ty.required.clone(),
+                    ty.checks.clone(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(schema_types(&result), schema_types(&except_result));
+}
+
+#[test]
+fn test_c_api_call_format_code() {
+    let (result, except_result) = test_c_api::<FormatCode_Args, FormatCode_Result>(
+        "KclvmService.FormatCode",
+        "format-code.json",
+        "format-code.response.json",
+    );
+    assert_eq!(result.formatted, except_result.formatted);
+}
+
+#[test]
+fn test_c_api_call_lint_path() {
+    let (result, except_result) = test_c_api::<LintPath_Args, LintPath_Result>(
+        "KclvmService.LintPath",
+        "lint-path.json",
+        "lint-path.response.json",
+    );
+    assert_eq!(result.results, except_result.results);
+}
+
+#[test]
+fn test_c_api_call_validate_code() {
+    let (result, except_result) = test_c_api::<ValidateCode_Args, ValidateCode_Result>(
+        "KclvmService.ValidateCode",
+        "validate-code.json",
+        "validate-code.response.json",
+    );
+    assert_eq!(result.success, except_result.success);
+}
+
+#[test]
+fn test_c_api_call_validate_code_fail() {
+    for (input, output) in [
+        (
+            "validate-code-fail.json",
+            "validate-code-fail.response.json",
+        ),
+        (
+            "validate-code-invalid-data.json",
+            "validate-code-invalid-data.response.json",
+        ),
+    ] {
+        let (result, except_result) = test_c_api::<ValidateCode_Args, ValidateCode_Result>(
+            "KclvmService.ValidateCode",
+            input,
+            output,
+        );
+        assert_eq!(result.success, except_result.success, "{}", input);
+        assert!(!result.err_message.is_empty(), "{}", input);
+    }
+}
+
+#[test]
+fn test_c_api_call_parse_file_ast() {
+    let serv = kclvm_service_new(0);
+    let args = unsafe {
+        CString::from_vec_unchecked(
+            ParseFile_AST_Args {
+                filename: Path::new(TEST_DATA_PATH)
+                    .join("hello.k")
+                    .to_str()
+                    .unwrap()
+                    .to_string(),
+                ..Default::default()
+            }
+            .write_to_bytes()
+            .unwrap(),
+        )
+    };
+    let call = CString::new("KclvmService.ParseFile_AST").unwrap();
+    let result_ptr = kclvm_service_call(serv, call.as_ptr(), args.as_ptr()) as *mut i8;
+    let result = unsafe { CStr::from_ptr(result_ptr) };
+
+    let result = parse_message_from_protobuf::<ParseFile_AST_Result>(result.to_bytes()).unwrap();
+    let module: kclvm_ast::ast::Module = serde_json::from_str(&result.ast_json).unwrap();
+    assert_eq!(module.body.len(), 1);
+
+    kclvm_service_delete(serv);
+    kclvm_service_free_string(result_ptr);
+}
+
+#[test]
+fn test_c_api_call_parse_program_ast() {
+    let (result, _) = test_c_api::<ParseProgram_AST_Args, ParseProgram_AST_Result>(
+        "KclvmService.ParseProgram_AST",
+        "parse-program-ast.json",
+        "parse-program-ast.response.json",
+    );
+    let program: kclvm_ast::ast::Program = serde_json::from_str(&result.ast_json).unwrap();
+    assert_eq!(program.main, kclvm_ast::MAIN_PKG);
+    let modules = &program.pkgs[kclvm_ast::MAIN_PKG];
+    assert_eq!(modules.len(), 1);
+    assert_eq!(modules[0].body.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "not_found.k")]
+fn test_c_api_call_parse_program_ast_fail() {
+    test_c_api::<ParseProgram_AST_Args, ParseProgram_AST_Result>(
+        "KclvmService.ParseProgram_AST",
+        "parse-program-ast-fail.json",
+        "parse-program-ast.response.json",
+    );
+}
+
+/// Call the service `svc_name` with the json args in the file `input` and
+/// return the call result and the expected result in the file `output`, it
+/// panics with the error message when the call fails.
+fn test_c_api<A, R>(svc_name: &str, input: &str, output: &str) -> (R, R)
+where
+    A: MessageFull,
+    R: MessageFull,
+{
+    let serv = kclvm_service_new(0);
+    let input_path = Path::new(TEST_DATA_PATH).join(input);
+    let input = fs::read_to_string(&input_path)
+        .expect(format!("Something went wrong reading {}", input_path.display()).as_str());
+    let args = unsafe {
+        CString::from_vec_unchecked(
+            parse_message_from_json::<A>(&input)
+                .unwrap()
+                .write_to_bytes()
+                .unwrap(),
+        )
+    };
+    let call = CString::new(svc_name).unwrap();
+    let result_ptr = kclvm_service_call(serv, call.as_ptr(), args.as_ptr()) as *mut i8;
+    let result = unsafe { CStr::from_ptr(result_ptr) };
+    if result.to_bytes().starts_with(b"KCLVM_CAPI_CALL_ERROR:") {
+        panic!("{}", result.to_string_lossy());
+    }
+
+    let result = parse_message_from_protobuf::<R>(result.to_bytes()).unwrap();
+    let except_result_path = Path::new(TEST_DATA_PATH).join(output);
+    let except_result_json = fs::read_to_string(&except_result_path).expect(
+        format!(
+            "Something went wrong reading {}",
+            except_result_path.display()
+        )
+        .as_str(),
+    );
+    let except_result = parse_message_from_json::<R>(&except_result_json).unwrap();
+
+    kclvm_service_delete(serv);
+    kclvm_service_free_string(result_ptr);
+    (result, except_result)
+}
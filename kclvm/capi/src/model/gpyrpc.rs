@@ -2175,6 +2175,8 @@ pub struct ExecProgram_Args {
     pub sort_keys: bool,
     // @@protoc_insertion_point(field:gpyrpc.ExecProgram_Args.include_schema_type_path)
     pub include_schema_type_path: bool,
+    // @@protoc_insertion_point(field:gpyrpc.ExecProgram_Args.path_selector)
+    pub path_selector: ::std::vec::Vec<::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:gpyrpc.ExecProgram_Args.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -2192,7 +2194,7 @@ impl ExecProgram_Args {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(13);
+        let mut fields = ::std::vec::Vec::with_capacity(14);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "work_dir",
@@ -2259,6 +2261,11 @@ impl ExecProgram_Args {
             |m: &ExecProgram_Args| { &m.include_schema_type_path },
             |m: &mut ExecProgram_Args| { &mut m.include_schema_type_path },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "path_selector",
+            |m: &ExecProgram_Args| { &m.path_selector },
+            |m: &mut ExecProgram_Args| { &mut m.path_selector },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ExecProgram_Args>(
             "ExecProgram_Args",
             fields,
@@ -2316,6 +2323,9 @@ impl ::protobuf::Message for ExecProgram_Args {
                 104 => {
                     self.include_schema_type_path = is.read_bool()?;
                 },
+                114 => {
+                    self.path_selector.push(is.read_string()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -2369,6 +2379,9 @@ impl ::protobuf::Message for ExecProgram_Args {
         if self.include_schema_type_path != false {
             my_size += 1 + 1;
         }
+        for value in &self.path_selector {
+            my_size += ::protobuf::rt::string_size(14, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -2414,6 +2427,9 @@ impl ::protobuf::Message for ExecProgram_Args {
         if self.include_schema_type_path != false {
             os.write_bool(13, self.include_schema_type_path)?;
         }
+        for v in &self.path_selector {
+            os.write_string(14, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2444,6 +2460,7 @@ impl ::protobuf::Message for ExecProgram_Args {
         self.debug = 0;
         self.sort_keys = false;
         self.include_schema_type_path = false;
+        self.path_selector.clear();
         self.special_fields.clear();
     }
 
@@ -2462,6 +2479,7 @@ impl ::protobuf::Message for ExecProgram_Args {
             debug: 0,
             sort_keys: false,
             include_schema_type_path: false,
+            path_selector: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -2493,6 +2511,10 @@ pub struct ExecProgram_Result {
     pub json_result: ::std::string::String,
     // @@protoc_insertion_point(field:gpyrpc.ExecProgram_Result.yaml_result)
     pub yaml_result: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.ExecProgram_Result.diagnostics)
+    pub diagnostics: ::std::vec::Vec<KclError>,
+    // @@protoc_insertion_point(field:gpyrpc.ExecProgram_Result.err_message)
+    pub err_message: ::std::string::String,
     // @@protoc_insertion_point(field:gpyrpc.ExecProgram_Result.escaped_time)
     pub escaped_time: ::std::string::String,
     // special fields
@@ -2512,7 +2534,7 @@ impl ExecProgram_Result {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "json_result",
@@ -2524,6 +2546,16 @@ impl ExecProgram_Result {
             |m: &ExecProgram_Result| { &m.yaml_result },
             |m: &mut ExecProgram_Result| { &mut m.yaml_result },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "diagnostics",
+            |m: &ExecProgram_Result| { &m.diagnostics },
+            |m: &mut ExecProgram_Result| { &mut m.diagnostics },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "err_message",
+            |m: &ExecProgram_Result| { &m.err_message },
+            |m: &mut ExecProgram_Result| { &mut m.err_message },
+        ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "escaped_time",
             |m: &ExecProgram_Result| { &m.escaped_time },
@@ -2553,6 +2585,12 @@ impl ::protobuf::Message for ExecProgram_Result {
                 18 => {
                     self.yaml_result = is.read_string()?;
                 },
+                26 => {
+                    self.diagnostics.push(is.read_message()?);
+                },
+                34 => {
+                    self.err_message = is.read_string()?;
+                },
                 810 => {
                     self.escaped_time = is.read_string()?;
                 },
@@ -2574,6 +2612,13 @@ impl ::protobuf::Message for ExecProgram_Result {
         if !self.yaml_result.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.yaml_result);
         }
+        for value in &self.diagnostics {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.err_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.err_message);
+        }
         if !self.escaped_time.is_empty() {
             my_size += ::protobuf::rt::string_size(101, &self.escaped_time);
         }
@@ -2589,6 +2634,12 @@ impl ::protobuf::Message for ExecProgram_Result {
         if !self.yaml_result.is_empty() {
             os.write_string(2, &self.yaml_result)?;
         }
+        for v in &self.diagnostics {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        if !self.err_message.is_empty() {
+            os.write_string(4, &self.err_message)?;
+        }
         if !self.escaped_time.is_empty() {
             os.write_string(101, &self.escaped_time)?;
         }
@@ -2611,6 +2662,8 @@ impl ::protobuf::Message for ExecProgram_Result {
     fn clear(&mut self) {
         self.json_result.clear();
         self.yaml_result.clear();
+        self.diagnostics.clear();
+        self.err_message.clear();
         self.escaped_time.clear();
         self.special_fields.clear();
     }
@@ -2619,6 +2672,8 @@ impl ::protobuf::Message for ExecProgram_Result {
         static instance: ExecProgram_Result = ExecProgram_Result {
             json_result: ::std::string::String::new(),
             yaml_result: ::std::string::String::new(),
+            diagnostics: ::std::vec::Vec::new(),
+            err_message: ::std::string::String::new(),
             escaped_time: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -3744,60 +3799,498 @@ impl ::protobuf::Message for OverrideFile_Args {
 impl ::protobuf::MessageFull for OverrideFile_Args {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("OverrideFile_Args").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("OverrideFile_Args").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for OverrideFile_Args {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OverrideFile_Args {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.OverrideFile_Result)
+pub struct OverrideFile_Result {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.OverrideFile_Result.result)
+    pub result: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.OverrideFile_Result.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a OverrideFile_Result {
+    fn default() -> &'a OverrideFile_Result {
+        <OverrideFile_Result as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OverrideFile_Result {
+    pub fn new() -> OverrideFile_Result {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "result",
+            |m: &OverrideFile_Result| { &m.result },
+            |m: &mut OverrideFile_Result| { &mut m.result },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OverrideFile_Result>(
+            "OverrideFile_Result",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for OverrideFile_Result {
+    const NAME: &'static str = "OverrideFile_Result";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.result = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.result != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.result != false {
+            os.write_bool(1, self.result)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> OverrideFile_Result {
+        OverrideFile_Result::new()
+    }
+
+    fn clear(&mut self) {
+        self.result = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static OverrideFile_Result {
+        static instance: OverrideFile_Result = OverrideFile_Result {
+            result: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for OverrideFile_Result {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("OverrideFile_Result").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for OverrideFile_Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OverrideFile_Result {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.GetFieldValues_Args)
+pub struct GetFieldValues_Args {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.GetFieldValues_Args.file)
+    pub file: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.GetFieldValues_Args.code)
+    pub code: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.GetFieldValues_Args.field_path)
+    pub field_path: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.GetFieldValues_Args.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetFieldValues_Args {
+    fn default() -> &'a GetFieldValues_Args {
+        <GetFieldValues_Args as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetFieldValues_Args {
+    pub fn new() -> GetFieldValues_Args {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "file",
+            |m: &GetFieldValues_Args| { &m.file },
+            |m: &mut GetFieldValues_Args| { &mut m.file },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &GetFieldValues_Args| { &m.code },
+            |m: &mut GetFieldValues_Args| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "field_path",
+            |m: &GetFieldValues_Args| { &m.field_path },
+            |m: &mut GetFieldValues_Args| { &mut m.field_path },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetFieldValues_Args>(
+            "GetFieldValues_Args",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetFieldValues_Args {
+    const NAME: &'static str = "GetFieldValues_Args";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.file = is.read_string()?;
+                },
+                18 => {
+                    self.code = is.read_string()?;
+                },
+                26 => {
+                    self.field_path = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.file.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.file);
+        }
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.code);
+        }
+        if !self.field_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.field_path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.file.is_empty() {
+            os.write_string(1, &self.file)?;
+        }
+        if !self.code.is_empty() {
+            os.write_string(2, &self.code)?;
+        }
+        if !self.field_path.is_empty() {
+            os.write_string(3, &self.field_path)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetFieldValues_Args {
+        GetFieldValues_Args::new()
+    }
+
+    fn clear(&mut self) {
+        self.file.clear();
+        self.code.clear();
+        self.field_path.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetFieldValues_Args {
+        static instance: GetFieldValues_Args = GetFieldValues_Args {
+            file: ::std::string::String::new(),
+            code: ::std::string::String::new(),
+            field_path: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetFieldValues_Args {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetFieldValues_Args").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetFieldValues_Args {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetFieldValues_Args {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.GetFieldValues_Result)
+pub struct GetFieldValues_Result {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.GetFieldValues_Result.field_values)
+    pub field_values: ::std::vec::Vec<FieldValue>,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.GetFieldValues_Result.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetFieldValues_Result {
+    fn default() -> &'a GetFieldValues_Result {
+        <GetFieldValues_Result as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetFieldValues_Result {
+    pub fn new() -> GetFieldValues_Result {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "field_values",
+            |m: &GetFieldValues_Result| { &m.field_values },
+            |m: &mut GetFieldValues_Result| { &mut m.field_values },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetFieldValues_Result>(
+            "GetFieldValues_Result",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetFieldValues_Result {
+    const NAME: &'static str = "GetFieldValues_Result";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.field_values.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.field_values {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.field_values {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetFieldValues_Result {
+        GetFieldValues_Result::new()
+    }
+
+    fn clear(&mut self) {
+        self.field_values.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetFieldValues_Result {
+        static instance: GetFieldValues_Result = GetFieldValues_Result {
+            field_values: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetFieldValues_Result {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetFieldValues_Result").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for OverrideFile_Args {
+impl ::std::fmt::Display for GetFieldValues_Result {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OverrideFile_Args {
+impl ::protobuf::reflect::ProtobufValue for GetFieldValues_Result {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:gpyrpc.OverrideFile_Result)
-pub struct OverrideFile_Result {
+// @@protoc_insertion_point(message:gpyrpc.FieldValue)
+pub struct FieldValue {
     // message fields
-    // @@protoc_insertion_point(field:gpyrpc.OverrideFile_Result.result)
-    pub result: bool,
+    // @@protoc_insertion_point(field:gpyrpc.FieldValue.value)
+    pub value: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.FieldValue.operation)
+    pub operation: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.FieldValue.start)
+    pub start: ::protobuf::MessageField<Position>,
+    // @@protoc_insertion_point(field:gpyrpc.FieldValue.end)
+    pub end: ::protobuf::MessageField<Position>,
+    // @@protoc_insertion_point(field:gpyrpc.FieldValue.value_start)
+    pub value_start: ::protobuf::MessageField<Position>,
+    // @@protoc_insertion_point(field:gpyrpc.FieldValue.value_end)
+    pub value_end: ::protobuf::MessageField<Position>,
     // special fields
-    // @@protoc_insertion_point(special_field:gpyrpc.OverrideFile_Result.special_fields)
+    // @@protoc_insertion_point(special_field:gpyrpc.FieldValue.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a OverrideFile_Result {
-    fn default() -> &'a OverrideFile_Result {
-        <OverrideFile_Result as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a FieldValue {
+    fn default() -> &'a FieldValue {
+        <FieldValue as ::protobuf::Message>::default_instance()
     }
 }
 
-impl OverrideFile_Result {
-    pub fn new() -> OverrideFile_Result {
+impl FieldValue {
+    pub fn new() -> FieldValue {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(6);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "result",
-            |m: &OverrideFile_Result| { &m.result },
-            |m: &mut OverrideFile_Result| { &mut m.result },
+            "value",
+            |m: &FieldValue| { &m.value },
+            |m: &mut FieldValue| { &mut m.value },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OverrideFile_Result>(
-            "OverrideFile_Result",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "operation",
+            |m: &FieldValue| { &m.operation },
+            |m: &mut FieldValue| { &mut m.operation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
+            "start",
+            |m: &FieldValue| { &m.start },
+            |m: &mut FieldValue| { &mut m.start },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
+            "end",
+            |m: &FieldValue| { &m.end },
+            |m: &mut FieldValue| { &mut m.end },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
+            "value_start",
+            |m: &FieldValue| { &m.value_start },
+            |m: &mut FieldValue| { &mut m.value_start },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Position>(
+            "value_end",
+            |m: &FieldValue| { &m.value_end },
+            |m: &mut FieldValue| { &mut m.value_end },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FieldValue>(
+            "FieldValue",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for OverrideFile_Result {
-    const NAME: &'static str = "OverrideFile_Result";
+impl ::protobuf::Message for FieldValue {
+    const NAME: &'static str = "FieldValue";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3806,8 +4299,23 @@ impl ::protobuf::Message for OverrideFile_Result {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.result = is.read_bool()?;
+                10 => {
+                    self.value = is.read_string()?;
+                },
+                18 => {
+                    self.operation = is.read_string()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.start)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.end)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.value_start)?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.value_end)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3821,8 +4329,27 @@ impl ::protobuf::Message for OverrideFile_Result {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.result != false {
-            my_size += 1 + 1;
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.value);
+        }
+        if !self.operation.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.operation);
+        }
+        if let Some(v) = self.start.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.end.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.value_start.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.value_end.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -3830,8 +4357,23 @@ impl ::protobuf::Message for OverrideFile_Result {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.result != false {
-            os.write_bool(1, self.result)?;
+        if !self.value.is_empty() {
+            os.write_string(1, &self.value)?;
+        }
+        if !self.operation.is_empty() {
+            os.write_string(2, &self.operation)?;
+        }
+        if let Some(v) = self.start.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.end.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.value_start.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        if let Some(v) = self.value_end.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3845,38 +4387,48 @@ impl ::protobuf::Message for OverrideFile_Result {
         &mut self.special_fields
     }
 
-    fn new() -> OverrideFile_Result {
-        OverrideFile_Result::new()
+    fn new() -> FieldValue {
+        FieldValue::new()
     }
 
     fn clear(&mut self) {
-        self.result = false;
+        self.value.clear();
+        self.operation.clear();
+        self.start.clear();
+        self.end.clear();
+        self.value_start.clear();
+        self.value_end.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static OverrideFile_Result {
-        static instance: OverrideFile_Result = OverrideFile_Result {
-            result: false,
+    fn default_instance() -> &'static FieldValue {
+        static instance: FieldValue = FieldValue {
+            value: ::std::string::String::new(),
+            operation: ::std::string::String::new(),
+            start: ::protobuf::MessageField::none(),
+            end: ::protobuf::MessageField::none(),
+            value_start: ::protobuf::MessageField::none(),
+            value_end: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for OverrideFile_Result {
+impl ::protobuf::MessageFull for FieldValue {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("OverrideFile_Result").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FieldValue").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for OverrideFile_Result {
+impl ::std::fmt::Display for FieldValue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OverrideFile_Result {
+impl ::protobuf::reflect::ProtobufValue for FieldValue {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -7625,6 +8177,22 @@ pub struct KclType {
     pub line: i32,
     // @@protoc_insertion_point(field:gpyrpc.KclType.decorators)
     pub decorators: ::std::vec::Vec<Decorator>,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.type_str)
+    pub type_str: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.pkg_path)
+    pub pkg_path: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.filename)
+    pub filename: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.description)
+    pub description: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.base_schema)
+    pub base_schema: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.mixins)
+    pub mixins: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.index_signature)
+    pub index_signature: ::protobuf::MessageField<IndexSignature>,
+    // @@protoc_insertion_point(field:gpyrpc.KclType.checks)
+    pub checks: ::std::vec::Vec<::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:gpyrpc.KclType.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -7642,7 +8210,7 @@ impl KclType {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(11);
+        let mut fields = ::std::vec::Vec::with_capacity(19);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "type",
@@ -7699,6 +8267,46 @@ impl KclType {
             |m: &KclType| { &m.decorators },
             |m: &mut KclType| { &mut m.decorators },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type_str",
+            |m: &KclType| { &m.type_str },
+            |m: &mut KclType| { &mut m.type_str },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pkg_path",
+            |m: &KclType| { &m.pkg_path },
+            |m: &mut KclType| { &mut m.pkg_path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "filename",
+            |m: &KclType| { &m.filename },
+            |m: &mut KclType| { &mut m.filename },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "description",
+            |m: &KclType| { &m.description },
+            |m: &mut KclType| { &mut m.description },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "base_schema",
+            |m: &KclType| { &m.base_schema },
+            |m: &mut KclType| { &mut m.base_schema },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "mixins",
+            |m: &KclType| { &m.mixins },
+            |m: &mut KclType| { &mut m.mixins },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, IndexSignature>(
+            "index_signature",
+            |m: &KclType| { &m.index_signature },
+            |m: &mut KclType| { &mut m.index_signature },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "checks",
+            |m: &KclType| { &m.checks },
+            |m: &mut KclType| { &mut m.checks },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KclType>(
             "KclType",
             fields,
@@ -7762,6 +8370,30 @@ impl ::protobuf::Message for KclType {
                 90 => {
                     self.decorators.push(is.read_message()?);
                 },
+                98 => {
+                    self.type_str = is.read_string()?;
+                },
+                106 => {
+                    self.pkg_path = is.read_string()?;
+                },
+                114 => {
+                    self.filename = is.read_string()?;
+                },
+                122 => {
+                    self.description = is.read_string()?;
+                },
+                130 => {
+                    self.base_schema = is.read_string()?;
+                },
+                138 => {
+                    self.mixins.push(is.read_string()?);
+                },
+                146 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.index_signature)?;
+                },
+                154 => {
+                    self.checks.push(is.read_string()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -7815,6 +8447,31 @@ impl ::protobuf::Message for KclType {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         };
+        if !self.type_str.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.type_str);
+        }
+        if !self.pkg_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(13, &self.pkg_path);
+        }
+        if !self.filename.is_empty() {
+            my_size += ::protobuf::rt::string_size(14, &self.filename);
+        }
+        if !self.description.is_empty() {
+            my_size += ::protobuf::rt::string_size(15, &self.description);
+        }
+        if !self.base_schema.is_empty() {
+            my_size += ::protobuf::rt::string_size(16, &self.base_schema);
+        }
+        for value in &self.mixins {
+            my_size += ::protobuf::rt::string_size(17, &value);
+        };
+        if let Some(v) = self.index_signature.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.checks {
+            my_size += ::protobuf::rt::string_size(19, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -7861,6 +8518,30 @@ impl ::protobuf::Message for KclType {
         for v in &self.decorators {
             ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
         };
+        if !self.type_str.is_empty() {
+            os.write_string(12, &self.type_str)?;
+        }
+        if !self.pkg_path.is_empty() {
+            os.write_string(13, &self.pkg_path)?;
+        }
+        if !self.filename.is_empty() {
+            os.write_string(14, &self.filename)?;
+        }
+        if !self.description.is_empty() {
+            os.write_string(15, &self.description)?;
+        }
+        if !self.base_schema.is_empty() {
+            os.write_string(16, &self.base_schema)?;
+        }
+        for v in &self.mixins {
+            os.write_string(17, &v)?;
+        };
+        if let Some(v) = self.index_signature.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(18, v, os)?;
+        }
+        for v in &self.checks {
+            os.write_string(19, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -7889,6 +8570,14 @@ impl ::protobuf::Message for KclType {
         self.item.clear();
         self.line = 0;
         self.decorators.clear();
+        self.type_str.clear();
+        self.pkg_path.clear();
+        self.filename.clear();
+        self.description.clear();
+        self.base_schema.clear();
+        self.mixins.clear();
+        self.index_signature.clear();
+        self.checks.clear();
         self.special_fields.clear();
     }
 
@@ -7915,6 +8604,184 @@ impl ::protobuf::reflect::ProtobufValue for KclType {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.IndexSignature)
+pub struct IndexSignature {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.IndexSignature.key_name)
+    pub key_name: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.IndexSignature.key)
+    pub key: ::protobuf::MessageField<KclType>,
+    // @@protoc_insertion_point(field:gpyrpc.IndexSignature.val)
+    pub val: ::protobuf::MessageField<KclType>,
+    // @@protoc_insertion_point(field:gpyrpc.IndexSignature.any_other)
+    pub any_other: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.IndexSignature.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a IndexSignature {
+    fn default() -> &'a IndexSignature {
+        <IndexSignature as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl IndexSignature {
+    pub fn new() -> IndexSignature {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key_name",
+            |m: &IndexSignature| { &m.key_name },
+            |m: &mut IndexSignature| { &mut m.key_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, KclType>(
+            "key",
+            |m: &IndexSignature| { &m.key },
+            |m: &mut IndexSignature| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, KclType>(
+            "val",
+            |m: &IndexSignature| { &m.val },
+            |m: &mut IndexSignature| { &mut m.val },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "any_other",
+            |m: &IndexSignature| { &m.any_other },
+            |m: &mut IndexSignature| { &mut m.any_other },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<IndexSignature>(
+            "IndexSignature",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for IndexSignature {
+    const NAME: &'static str = "IndexSignature";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.key_name = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.key)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.val)?;
+                },
+                32 => {
+                    self.any_other = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.key_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key_name);
+        }
+        if let Some(v) = self.key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.val.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.any_other != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.key_name.is_empty() {
+            os.write_string(1, &self.key_name)?;
+        }
+        if let Some(v) = self.key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.val.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.any_other != false {
+            os.write_bool(4, self.any_other)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> IndexSignature {
+        IndexSignature::new()
+    }
+
+    fn clear(&mut self) {
+        self.key_name.clear();
+        self.key.clear();
+        self.val.clear();
+        self.any_other = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static IndexSignature {
+        static instance: IndexSignature = IndexSignature {
+            key_name: ::std::string::String::new(),
+            key: ::protobuf::MessageField::none(),
+            val: ::protobuf::MessageField::none(),
+            any_other: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for IndexSignature {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("IndexSignature").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for IndexSignature {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IndexSignature {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:gpyrpc.Decorator)
 pub struct Decorator {
@@ -8121,7 +8988,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x08ast_json\x18\x01\x20\x01(\tR\x07astJson\"?\n\x15ParseProgram_AST_A\
     rgs\x12&\n\x0fk_filename_list\x18\x01\x20\x03(\tR\rkFilenameList\"4\n\
     \x17ParseProgram_AST_Result\x12\x19\n\x08ast_json\x18\x01\x20\x01(\tR\
-    \x07astJson\"\x89\x04\n\x10ExecProgram_Args\x12\x19\n\x08work_dir\x18\
+    \x07astJson\"\xae\x04\n\x10ExecProgram_Args\x12\x19\n\x08work_dir\x18\
     \x01\x20\x01(\tR\x07workDir\x12&\n\x0fk_filename_list\x18\x02\x20\x03(\t\
     R\rkFilenameList\x12\x1e\n\x0bk_code_list\x18\x03\x20\x03(\tR\tkCodeList\
     \x12&\n\x04args\x18\x04\x20\x03(\x0b2\x12.gpyrpc.CmdArgSpecR\x04args\x12\
@@ -8133,119 +9000,142 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x07verbose\x18\n\x20\x01(\x05R\x07verbose\x12\x14\n\x05debug\x18\x0b\
     \x20\x01(\x05R\x05debug\x12\x1b\n\tsort_keys\x18\x0c\x20\x01(\x08R\x08so\
     rtKeys\x127\n\x18include_schema_type_path\x18\r\x20\x01(\x08R\x15include\
-    SchemaTypePath\"y\n\x12ExecProgram_Result\x12\x1f\n\x0bjson_result\x18\
-    \x01\x20\x01(\tR\njsonResult\x12\x1f\n\x0byaml_result\x18\x02\x20\x01(\t\
-    R\nyamlResult\x12!\n\x0cescaped_time\x18e\x20\x01(\tR\x0bescapedTime\"3\
-    \n\x10ResetPlugin_Args\x12\x1f\n\x0bplugin_root\x18\x01\x20\x01(\tR\nplu\
-    ginRoot\"\x14\n\x12ResetPlugin_Result\")\n\x0fFormatCode_Args\x12\x16\n\
-    \x06source\x18\x01\x20\x01(\tR\x06source\"1\n\x11FormatCode_Result\x12\
-    \x1c\n\tformatted\x18\x01\x20\x01(\x0cR\tformatted\"%\n\x0fFormatPath_Ar\
-    gs\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\"7\n\x11FormatPath_Resu\
-    lt\x12\"\n\x0cchangedPaths\x18\x01\x20\x03(\tR\x0cchangedPaths\"#\n\rLin\
-    tPath_Args\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\"+\n\x0fLintPat\
-    h_Result\x12\x18\n\x07results\x18\x01\x20\x03(\tR\x07results\"`\n\x11Ove\
-    rrideFile_Args\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\x12\x14\n\
-    \x05specs\x18\x02\x20\x03(\tR\x05specs\x12!\n\x0cimport_paths\x18\x03\
-    \x20\x03(\tR\x0bimportPaths\"-\n\x13OverrideFile_Result\x12\x16\n\x06res\
-    ult\x18\x01\x20\x01(\x08R\x06result\"#\n\rEvalCode_Args\x12\x12\n\x04cod\
-    e\x18\x01\x20\x01(\tR\x04code\"2\n\x0fEvalCode_Result\x12\x1f\n\x0bjson_\
-    result\x18\x02\x20\x01(\tR\njsonResult\"&\n\x10ResolveCode_Args\x12\x12\
-    \n\x04code\x18\x01\x20\x01(\tR\x04code\".\n\x12ResolveCode_Result\x12\
-    \x18\n\x07success\x18\x01\x20\x01(\x08R\x07success\"]\n\x12GetSchemaType\
-    _Args\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\x12\x12\n\x04code\
-    \x18\x02\x20\x01(\tR\x04code\x12\x1f\n\x0bschema_name\x18\x03\x20\x01(\t\
-    R\nschemaName\"Q\n\x14GetSchemaType_Result\x129\n\x10schema_type_list\
-    \x18\x01\x20\x03(\x0b2\x0f.gpyrpc.KclTypeR\x0eschemaTypeList\"\x92\x01\n\
-    \x11ValidateCode_Args\x12\x12\n\x04data\x18\x01\x20\x01(\tR\x04data\x12\
-    \x12\n\x04code\x18\x02\x20\x01(\tR\x04code\x12\x16\n\x06schema\x18\x03\
-    \x20\x01(\tR\x06schema\x12%\n\x0eattribute_name\x18\x04\x20\x01(\tR\ratt\
-    ributeName\x12\x16\n\x06format\x18\x05\x20\x01(\tR\x06format\"P\n\x13Val\
-    idateCode_Result\x12\x18\n\x07success\x18\x01\x20\x01(\x08R\x07success\
-    \x12\x1f\n\x0berr_message\x18\x02\x20\x01(\tR\nerrMessage\"9\n\x0bCodeSn\
-    ippet\x12\x16\n\x06schema\x18\x01\x20\x01(\tR\x06schema\x12\x12\n\x04rul\
-    e\x18\x02\x20\x01(\tR\x04rule\"J\n\x0fSpliceCode_Args\x127\n\x0ccodeSnip\
-    pets\x18\x01\x20\x03(\x0b2\x13.gpyrpc.CodeSnippetR\x0ccodeSnippets\"3\n\
-    \x11SpliceCode_Result\x12\x1e\n\nspliceCode\x18\x01\x20\x01(\tR\nspliceC\
-    ode\"R\n\x08Position\x12\x12\n\x04line\x18\x01\x20\x01(\x03R\x04line\x12\
-    \x16\n\x06column\x18\x02\x20\x01(\x03R\x06column\x12\x1a\n\x08filename\
-    \x18\x03\x20\x01(\tR\x08filename\"[\n\rComplete_Args\x12\"\n\x03pos\x18\
-    \x01\x20\x01(\x0b2\x10.gpyrpc.PositionR\x03pos\x12\x12\n\x04name\x18\x02\
-    \x20\x01(\tR\x04name\x12\x12\n\x04code\x18\x03\x20\x01(\tR\x04code\"7\n\
-    \x0fComplete_Result\x12$\n\rcompleteItems\x18\x01\x20\x01(\tR\rcompleteI\
-    tems\"F\n\x0cGoToDef_Args\x12\"\n\x03pos\x18\x01\x20\x01(\x0b2\x10.gpyrp\
-    c.PositionR\x03pos\x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04code\".\n\
-    \x0eGoToDef_Result\x12\x1c\n\tlocations\x18\x01\x20\x01(\tR\tlocations\"\
-    =\n\x13DocumentSymbol_Args\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\
-    \x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04code\"/\n\x15DocumentSymbol_Re\
-    sult\x12\x16\n\x06symbol\x18\x01\x20\x01(\tR\x06symbol\"D\n\nHover_Args\
-    \x12\"\n\x03pos\x18\x01\x20\x01(\x0b2\x10.gpyrpc.PositionR\x03pos\x12\
-    \x12\n\x04code\x18\x02\x20\x01(\tR\x04code\"0\n\x0cHover_Result\x12\x20\
-    \n\x0bhoverResult\x18\x01\x20\x01(\tR\x0bhoverResult\"\x99\x01\n\x11List\
-    DepFiles_Args\x12\x19\n\x08work_dir\x18\x01\x20\x01(\tR\x07workDir\x12\
-    \x20\n\x0cuse_abs_path\x18\x02\x20\x01(\x08R\nuseAbsPath\x12\x1f\n\x0bin\
-    clude_all\x18\x03\x20\x01(\x08R\nincludeAll\x12&\n\x0fuse_fast_parser\
-    \x18\x04\x20\x01(\x08R\ruseFastParser\"_\n\x13ListDepFiles_Result\x12\
-    \x18\n\x07pkgroot\x18\x01\x20\x01(\tR\x07pkgroot\x12\x18\n\x07pkgpath\
-    \x18\x02\x20\x01(\tR\x07pkgpath\x12\x14\n\x05files\x18\x03\x20\x03(\tR\
-    \x05files\"I\n\x16LoadSettingsFiles_Args\x12\x19\n\x08work_dir\x18\x01\
-    \x20\x01(\tR\x07workDir\x12\x14\n\x05files\x18\x02\x20\x03(\tR\x05files\
-    \"\x8c\x01\n\x18LoadSettingsFiles_Result\x129\n\x0fkcl_cli_configs\x18\
-    \x01\x20\x01(\x0b2\x11.gpyrpc.CliConfigR\rkclCliConfigs\x125\n\x0bkcl_op\
-    tions\x18\x02\x20\x03(\x0b2\x14.gpyrpc.KeyValuePairR\nkclOptions\"\xfd\
-    \x01\n\tCliConfig\x12\x14\n\x05files\x18\x01\x20\x03(\tR\x05files\x12\
-    \x16\n\x06output\x18\x02\x20\x01(\tR\x06output\x12\x1c\n\toverrides\x18\
-    \x03\x20\x03(\tR\toverrides\x12#\n\rpath_selector\x18\x04\x20\x03(\tR\
-    \x0cpathSelector\x12,\n\x12strict_range_check\x18\x05\x20\x01(\x08R\x10s\
-    trictRangeCheck\x12!\n\x0cdisable_none\x18\x06\x20\x01(\x08R\x0bdisableN\
-    one\x12\x18\n\x07verbose\x18\x07\x20\x01(\x03R\x07verbose\x12\x14\n\x05d\
-    ebug\x18\x08\x20\x01(\x08R\x05debug\"6\n\x0cKeyValuePair\x12\x10\n\x03ke\
-    y\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05v\
-    alue\"\xe5\x03\n\x07KclType\x12\x12\n\x04type\x18\x01\x20\x01(\tR\x04typ\
-    e\x120\n\x0bunion_types\x18\x02\x20\x03(\x0b2\x0f.gpyrpc.KclTypeR\nunion\
-    Types\x12\x18\n\x07default\x18\x03\x20\x01(\tR\x07default\x12\x1f\n\x0bs\
-    chema_name\x18\x04\x20\x01(\tR\nschemaName\x12\x1d\n\nschema_doc\x18\x05\
-    \x20\x01(\tR\tschemaDoc\x12?\n\nproperties\x18\x06\x20\x03(\x0b2\x1f.gpy\
-    rpc.KclType.PropertiesEntryR\nproperties\x12\x1a\n\x08required\x18\x07\
-    \x20\x03(\tR\x08required\x12!\n\x03key\x18\x08\x20\x01(\x0b2\x0f.gpyrpc.\
-    KclTypeR\x03key\x12#\n\x04item\x18\t\x20\x01(\x0b2\x0f.gpyrpc.KclTypeR\
-    \x04item\x12\x12\n\x04line\x18\n\x20\x01(\x05R\x04line\x121\n\ndecorator\
-    s\x18\x0b\x20\x03(\x0b2\x11.gpyrpc.DecoratorR\ndecorators\x1aN\n\x0fProp\
-    ertiesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12%\n\x05value\
-    \x18\x02\x20\x01(\x0b2\x0f.gpyrpc.KclTypeR\x05value:\x028\x01\"\xb7\x01\
-    \n\tDecorator\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x1c\n\ta\
-    rguments\x18\x02\x20\x03(\tR\targuments\x12;\n\x08keywords\x18\x03\x20\
-    \x03(\x0b2\x1f.gpyrpc.Decorator.KeywordsEntryR\x08keywords\x1a;\n\rKeywo\
-    rdsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\
-    \x18\x02\x20\x01(\tR\x05value:\x028\x012\x82\x01\n\x0eBuiltinService\x12\
-    .\n\x04Ping\x12\x11.gpyrpc.Ping_Args\x1a\x13.gpyrpc.Ping_Result\x12@\n\n\
-    ListMethod\x12\x17.gpyrpc.ListMethod_Args\x1a\x19.gpyrpc.ListMethod_Resu\
-    lt2\xb4\x0b\n\x0cKclvmService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\
-    \x1a\x13.gpyrpc.Ping_Result\x12X\n\x12ParseFile_LarkTree\x12\x1f.gpyrpc.\
-    ParseFile_LarkTree_Args\x1a!.gpyrpc.ParseFile_LarkTree_Result\x12I\n\rPa\
-    rseFile_AST\x12\x1a.gpyrpc.ParseFile_AST_Args\x1a\x1c.gpyrpc.ParseFile_A\
-    ST_Result\x12R\n\x10ParseProgram_AST\x12\x1d.gpyrpc.ParseProgram_AST_Arg\
-    s\x1a\x1f.gpyrpc.ParseProgram_AST_Result\x12C\n\x0bExecProgram\x12\x18.g\
-    pyrpc.ExecProgram_Args\x1a\x1a.gpyrpc.ExecProgram_Result\x12C\n\x0bReset\
-    Plugin\x12\x18.gpyrpc.ResetPlugin_Args\x1a\x1a.gpyrpc.ResetPlugin_Result\
-    \x12@\n\nFormatCode\x12\x17.gpyrpc.FormatCode_Args\x1a\x19.gpyrpc.Format\
-    Code_Result\x12@\n\nFormatPath\x12\x17.gpyrpc.FormatPath_Args\x1a\x19.gp\
-    yrpc.FormatPath_Result\x12:\n\x08LintPath\x12\x15.gpyrpc.LintPath_Args\
-    \x1a\x17.gpyrpc.LintPath_Result\x12F\n\x0cOverrideFile\x12\x19.gpyrpc.Ov\
-    errideFile_Args\x1a\x1b.gpyrpc.OverrideFile_Result\x12:\n\x08EvalCode\
-    \x12\x15.gpyrpc.EvalCode_Args\x1a\x17.gpyrpc.EvalCode_Result\x12C\n\x0bR\
-    esolveCode\x12\x18.gpyrpc.ResolveCode_Args\x1a\x1a.gpyrpc.ResolveCode_Re\
-    sult\x12I\n\rGetSchemaType\x12\x1a.gpyrpc.GetSchemaType_Args\x1a\x1c.gpy\
-    rpc.GetSchemaType_Result\x12F\n\x0cValidateCode\x12\x19.gpyrpc.ValidateC\
-    ode_Args\x1a\x1b.gpyrpc.ValidateCode_Result\x12@\n\nSpliceCode\x12\x17.g\
-    pyrpc.SpliceCode_Args\x1a\x19.gpyrpc.SpliceCode_Result\x12:\n\x08Complet\
-    e\x12\x15.gpyrpc.Complete_Args\x1a\x17.gpyrpc.Complete_Result\x127\n\x07\
-    GoToDef\x12\x14.gpyrpc.GoToDef_Args\x1a\x16.gpyrpc.GoToDef_Result\x12L\n\
-    \x0eDocumentSymbol\x12\x1b.gpyrpc.DocumentSymbol_Args\x1a\x1d.gpyrpc.Doc\
-    umentSymbol_Result\x121\n\x05Hover\x12\x12.gpyrpc.Hover_Args\x1a\x14.gpy\
-    rpc.Hover_Result\x12F\n\x0cListDepFiles\x12\x19.gpyrpc.ListDepFiles_Args\
-    \x1a\x1b.gpyrpc.ListDepFiles_Result\x12U\n\x11LoadSettingsFiles\x12\x1e.\
-    gpyrpc.LoadSettingsFiles_Args\x1a\x20.gpyrpc.LoadSettingsFiles_Resultb\
-    \x06proto3\
+    SchemaTypePath\x12#\n\rpath_selector\x18\x0e\x20\x03(\tR\x0cpathSelector\
+    \"\xce\x01\n\x12ExecProgram_Result\x12\x1f\n\x0bjson_result\x18\x01\x20\
+    \x01(\tR\njsonResult\x12\x1f\n\x0byaml_result\x18\x02\x20\x01(\tR\nyamlR\
+    esult\x122\n\x0bdiagnostics\x18\x03\x20\x03(\x0b2\x10.gpyrpc.KclErrorR\
+    \x0bdiagnostics\x12\x1f\n\x0berr_message\x18\x04\x20\x01(\tR\nerrMessage\
+    \x12!\n\x0cescaped_time\x18e\x20\x01(\tR\x0bescapedTime\"3\n\x10ResetPlu\
+    gin_Args\x12\x1f\n\x0bplugin_root\x18\x01\x20\x01(\tR\npluginRoot\"\x14\
+    \n\x12ResetPlugin_Result\")\n\x0fFormatCode_Args\x12\x16\n\x06source\x18\
+    \x01\x20\x01(\tR\x06source\"1\n\x11FormatCode_Result\x12\x1c\n\tformatte\
+    d\x18\x01\x20\x01(\x0cR\tformatted\"%\n\x0fFormatPath_Args\x12\x12\n\x04\
+    path\x18\x01\x20\x01(\tR\x04path\"7\n\x11FormatPath_Result\x12\"\n\x0cch\
+    angedPaths\x18\x01\x20\x03(\tR\x0cchangedPaths\"#\n\rLintPath_Args\x12\
+    \x12\n\x04path\x18\x01\x20\x01(\tR\x04path\"+\n\x0fLintPath_Result\x12\
+    \x18\n\x07results\x18\x01\x20\x03(\tR\x07results\"`\n\x11OverrideFile_Ar\
+    gs\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\x12\x14\n\x05specs\x18\
+    \x02\x20\x03(\tR\x05specs\x12!\n\x0cimport_paths\x18\x03\x20\x03(\tR\x0b\
+    importPaths\"-\n\x13OverrideFile_Result\x12\x16\n\x06result\x18\x01\x20\
+    \x01(\x08R\x06result\"\\\n\x13GetFieldValues_Args\x12\x12\n\x04file\x18\
+    \x01\x20\x01(\tR\x04file\x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04code\
+    \x12\x1d\n\nfield_path\x18\x03\x20\x01(\tR\tfieldPath\"N\n\x15GetFieldVa\
+    lues_Result\x125\n\x0cfield_values\x18\x01\x20\x03(\x0b2\x12.gpyrpc.Fiel\
+    dValueR\x0bfieldValues\"\xee\x01\n\nFieldValue\x12\x14\n\x05value\x18\
+    \x01\x20\x01(\tR\x05value\x12\x1c\n\toperation\x18\x02\x20\x01(\tR\toper\
+    ation\x12&\n\x05start\x18\x03\x20\x01(\x0b2\x10.gpyrpc.PositionR\x05star\
+    t\x12\"\n\x03end\x18\x04\x20\x01(\x0b2\x10.gpyrpc.PositionR\x03end\x121\
+    \n\x0bvalue_start\x18\x05\x20\x01(\x0b2\x10.gpyrpc.PositionR\nvalueStart\
+    \x12-\n\tvalue_end\x18\x06\x20\x01(\x0b2\x10.gpyrpc.PositionR\x08valueEn\
+    d\"#\n\rEvalCode_Args\x12\x12\n\x04code\x18\x01\x20\x01(\tR\x04code\"2\n\
+    \x0fEvalCode_Result\x12\x1f\n\x0bjson_result\x18\x02\x20\x01(\tR\njsonRe\
+    sult\"&\n\x10ResolveCode_Args\x12\x12\n\x04code\x18\x01\x20\x01(\tR\x04c\
+    ode\".\n\x12ResolveCode_Result\x12\x18\n\x07success\x18\x01\x20\x01(\x08\
+    R\x07success\"]\n\x12GetSchemaType_Args\x12\x12\n\x04file\x18\x01\x20\
+    \x01(\tR\x04file\x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04code\x12\x1f\n\
+    \x0bschema_name\x18\x03\x20\x01(\tR\nschemaName\"Q\n\x14GetSchemaType_Re\
+    sult\x129\n\x10schema_type_list\x18\x01\x20\x03(\x0b2\x0f.gpyrpc.KclType\
+    R\x0eschemaTypeList\"\x92\x01\n\x11ValidateCode_Args\x12\x12\n\x04data\
+    \x18\x01\x20\x01(\tR\x04data\x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04co\
+    de\x12\x16\n\x06schema\x18\x03\x20\x01(\tR\x06schema\x12%\n\x0eattribute\
+    _name\x18\x04\x20\x01(\tR\rattributeName\x12\x16\n\x06format\x18\x05\x20\
+    \x01(\tR\x06format\"P\n\x13ValidateCode_Result\x12\x18\n\x07success\x18\
+    \x01\x20\x01(\x08R\x07success\x12\x1f\n\x0berr_message\x18\x02\x20\x01(\
+    \tR\nerrMessage\"9\n\x0bCodeSnippet\x12\x16\n\x06schema\x18\x01\x20\x01(\
+    \tR\x06schema\x12\x12\n\x04rule\x18\x02\x20\x01(\tR\x04rule\"J\n\x0fSpli\
+    ceCode_Args\x127\n\x0ccodeSnippets\x18\x01\x20\x03(\x0b2\x13.gpyrpc.Code\
+    SnippetR\x0ccodeSnippets\"3\n\x11SpliceCode_Result\x12\x1e\n\nspliceCode\
+    \x18\x01\x20\x01(\tR\nspliceCode\"R\n\x08Position\x12\x12\n\x04line\x18\
+    \x01\x20\x01(\x03R\x04line\x12\x16\n\x06column\x18\x02\x20\x01(\x03R\x06\
+    column\x12\x1a\n\x08filename\x18\x03\x20\x01(\tR\x08filename\"[\n\rCompl\
+    ete_Args\x12\"\n\x03pos\x18\x01\x20\x01(\x0b2\x10.gpyrpc.PositionR\x03po\
+    s\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04code\x18\
+    \x03\x20\x01(\tR\x04code\"7\n\x0fComplete_Result\x12$\n\rcompleteItems\
+    \x18\x01\x20\x01(\tR\rcompleteItems\"F\n\x0cGoToDef_Args\x12\"\n\x03pos\
+    \x18\x01\x20\x01(\x0b2\x10.gpyrpc.PositionR\x03pos\x12\x12\n\x04code\x18\
+    \x02\x20\x01(\tR\x04code\".\n\x0eGoToDef_Result\x12\x1c\n\tlocations\x18\
+    \x01\x20\x01(\tR\tlocations\"=\n\x13DocumentSymbol_Args\x12\x12\n\x04fil\
+    e\x18\x01\x20\x01(\tR\x04file\x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04c\
+    ode\"/\n\x15DocumentSymbol_Result\x12\x16\n\x06symbol\x18\x01\x20\x01(\t\
+    R\x06symbol\"D\n\nHover_Args\x12\"\n\x03pos\x18\x01\x20\x01(\x0b2\x10.gp\
+    yrpc.PositionR\x03pos\x12\x12\n\x04code\x18\x02\x20\x01(\tR\x04code\"0\n\
+    \x0cHover_Result\x12\x20\n\x0bhoverResult\x18\x01\x20\x01(\tR\x0bhoverRe\
+    sult\"\x99\x01\n\x11ListDepFiles_Args\x12\x19\n\x08work_dir\x18\x01\x20\
+    \x01(\tR\x07workDir\x12\x20\n\x0cuse_abs_path\x18\x02\x20\x01(\x08R\nuse\
+    AbsPath\x12\x1f\n\x0binclude_all\x18\x03\x20\x01(\x08R\nincludeAll\x12&\
+    \n\x0fuse_fast_parser\x18\x04\x20\x01(\x08R\ruseFastParser\"_\n\x13ListD\
+    epFiles_Result\x12\x18\n\x07pkgroot\x18\x01\x20\x01(\tR\x07pkgroot\x12\
+    \x18\n\x07pkgpath\x18\x02\x20\x01(\tR\x07pkgpath\x12\x14\n\x05files\x18\
+    \x03\x20\x03(\tR\x05files\"I\n\x16LoadSettingsFiles_Args\x12\x19\n\x08wo\
+    rk_dir\x18\x01\x20\x01(\tR\x07workDir\x12\x14\n\x05files\x18\x02\x20\x03\
+    (\tR\x05files\"\x8c\x01\n\x18LoadSettingsFiles_Result\x129\n\x0fkcl_cli_\
+    configs\x18\x01\x20\x01(\x0b2\x11.gpyrpc.CliConfigR\rkclCliConfigs\x125\
+    \n\x0bkcl_options\x18\x02\x20\x03(\x0b2\x14.gpyrpc.KeyValuePairR\nkclOpt\
+    ions\"\xfd\x01\n\tCliConfig\x12\x14\n\x05files\x18\x01\x20\x03(\tR\x05fi\
+    les\x12\x16\n\x06output\x18\x02\x20\x01(\tR\x06output\x12\x1c\n\toverrid\
+    es\x18\x03\x20\x03(\tR\toverrides\x12#\n\rpath_selector\x18\x04\x20\x03(\
+    \tR\x0cpathSelector\x12,\n\x12strict_range_check\x18\x05\x20\x01(\x08R\
+    \x10strictRangeCheck\x12!\n\x0cdisable_none\x18\x06\x20\x01(\x08R\x0bdis\
+    ableNone\x12\x18\n\x07verbose\x18\x07\x20\x01(\x03R\x07verbose\x12\x14\n\
+    \x05debug\x18\x08\x20\x01(\x08R\x05debug\"6\n\x0cKeyValuePair\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
+    R\x05value\"\xeb\x05\n\x07KclType\x12\x12\n\x04type\x18\x01\x20\x01(\tR\
+    \x04type\x120\n\x0bunion_types\x18\x02\x20\x03(\x0b2\x0f.gpyrpc.KclTypeR\
+    \nunionTypes\x12\x18\n\x07default\x18\x03\x20\x01(\tR\x07default\x12\x1f\
+    \n\x0bschema_name\x18\x04\x20\x01(\tR\nschemaName\x12\x1d\n\nschema_doc\
+    \x18\x05\x20\x01(\tR\tschemaDoc\x12?\n\nproperties\x18\x06\x20\x03(\x0b2\
+    \x1f.gpyrpc.KclType.PropertiesEntryR\nproperties\x12\x1a\n\x08required\
+    \x18\x07\x20\x03(\tR\x08required\x12!\n\x03key\x18\x08\x20\x01(\x0b2\x0f\
+    .gpyrpc.KclTypeR\x03key\x12#\n\x04item\x18\t\x20\x01(\x0b2\x0f.gpyrpc.Kc\
+    lTypeR\x04item\x12\x12\n\x04line\x18\n\x20\x01(\x05R\x04line\x121\n\ndec\
+    orators\x18\x0b\x20\x03(\x0b2\x11.gpyrpc.DecoratorR\ndecorators\x12\x19\
+    \n\x08type_str\x18\x0c\x20\x01(\tR\x07typeStr\x12\x19\n\x08pkg_path\x18\
+    \r\x20\x01(\tR\x07pkgPath\x12\x1a\n\x08filename\x18\x0e\x20\x01(\tR\x08f\
+    ilename\x12\x20\n\x0bdescription\x18\x0f\x20\x01(\tR\x0bdescription\x12\
+    \x1f\n\x0bbase_schema\x18\x10\x20\x01(\tR\nbaseSchema\x12\x16\n\x06mixin\
+    s\x18\x11\x20\x03(\tR\x06mixins\x12?\n\x0findex_signature\x18\x12\x20\
+    \x01(\x0b2\x16.gpyrpc.IndexSignatureR\x0eindexSignature\x12\x16\n\x06che\
+    cks\x18\x13\x20\x03(\tR\x06checks\x1aN\n\x0fPropertiesEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12%\n\x05value\x18\x02\x20\x01(\x0b2\
+    \x0f.gpyrpc.KclTypeR\x05value:\x028\x01\"\x8e\x01\n\x0eIndexSignature\
+    \x12\x19\n\x08key_name\x18\x01\x20\x01(\tR\x07keyName\x12!\n\x03key\x18\
+    \x02\x20\x01(\x0b2\x0f.gpyrpc.KclTypeR\x03key\x12!\n\x03val\x18\x03\x20\
+    \x01(\x0b2\x0f.gpyrpc.KclTypeR\x03val\x12\x1b\n\tany_other\x18\x04\x20\
+    \x01(\x08R\x08anyOther\"\xb7\x01\n\tDecorator\x12\x12\n\x04name\x18\x01\
+    \x20\x01(\tR\x04name\x12\x1c\n\targuments\x18\x02\x20\x03(\tR\targuments\
+    \x12;\n\x08keywords\x18\x03\x20\x03(\x0b2\x1f.gpyrpc.Decorator.KeywordsE\
+    ntryR\x08keywords\x1a;\n\rKeywordsEntry\x12\x10\n\x03key\x18\x01\x20\x01\
+    (\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x012\
+    \x82\x01\n\x0eBuiltinService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\x1a\
+    \x13.gpyrpc.Ping_Result\x12@\n\nListMethod\x12\x17.gpyrpc.ListMethod_Arg\
+    s\x1a\x19.gpyrpc.ListMethod_Result2\x82\x0c\n\x0cKclvmService\x12.\n\x04\
+    Ping\x12\x11.gpyrpc.Ping_Args\x1a\x13.gpyrpc.Ping_Result\x12X\n\x12Parse\
+    File_LarkTree\x12\x1f.gpyrpc.ParseFile_LarkTree_Args\x1a!.gpyrpc.ParseFi\
+    le_LarkTree_Result\x12I\n\rParseFile_AST\x12\x1a.gpyrpc.ParseFile_AST_Ar\
+    gs\x1a\x1c.gpyrpc.ParseFile_AST_Result\x12R\n\x10ParseProgram_AST\x12\
+    \x1d.gpyrpc.ParseProgram_AST_Args\x1a\x1f.gpyrpc.ParseProgram_AST_Result\
+    \x12C\n\x0bExecProgram\x12\x18.gpyrpc.ExecProgram_Args\x1a\x1a.gpyrpc.Ex\
+    ecProgram_Result\x12C\n\x0bResetPlugin\x12\x18.gpyrpc.ResetPlugin_Args\
+    \x1a\x1a.gpyrpc.ResetPlugin_Result\x12@\n\nFormatCode\x12\x17.gpyrpc.For\
+    matCode_Args\x1a\x19.gpyrpc.FormatCode_Result\x12@\n\nFormatPath\x12\x17\
+    .gpyrpc.FormatPath_Args\x1a\x19.gpyrpc.FormatPath_Result\x12:\n\x08LintP\
+    ath\x12\x15.gpyrpc.LintPath_Args\x1a\x17.gpyrpc.LintPath_Result\x12F\n\
+    \x0cOverrideFile\x12\x19.gpyrpc.OverrideFile_Args\x1a\x1b.gpyrpc.Overrid\
+    eFile_Result\x12L\n\x0eGetFieldValues\x12\x1b.gpyrpc.GetFieldValues_Args\
+    \x1a\x1d.gpyrpc.GetFieldValues_Result\x12:\n\x08EvalCode\x12\x15.gpyrpc.\
+    EvalCode_Args\x1a\x17.gpyrpc.EvalCode_Result\x12C\n\x0bResolveCode\x12\
+    \x18.gpyrpc.ResolveCode_Args\x1a\x1a.gpyrpc.ResolveCode_Result\x12I\n\rG\
+    etSchemaType\x12\x1a.gpyrpc.GetSchemaType_Args\x1a\x1c.gpyrpc.GetSchemaT\
+    ype_Result\x12F\n\x0cValidateCode\x12\x19.gpyrpc.ValidateCode_Args\x1a\
+    \x1b.gpyrpc.ValidateCode_Result\x12@\n\nSpliceCode\x12\x17.gpyrpc.Splice\
+    Code_Args\x1a\x19.gpyrpc.SpliceCode_Result\x12:\n\x08Complete\x12\x15.gp\
+    yrpc.Complete_Args\x1a\x17.gpyrpc.Complete_Result\x127\n\x07GoToDef\x12\
+    \x14.gpyrpc.GoToDef_Args\x1a\x16.gpyrpc.GoToDef_Result\x12L\n\x0eDocumen\
+    tSymbol\x12\x1b.gpyrpc.DocumentSymbol_Args\x1a\x1d.gpyrpc.DocumentSymbol\
+    _Result\x121\n\x05Hover\x12\x12.gpyrpc.Hover_Args\x1a\x14.gpyrpc.Hover_R\
+    esult\x12F\n\x0cListDepFiles\x12\x19.gpyrpc.ListDepFiles_Args\x1a\x1b.gp\
+    yrpc.ListDepFiles_Result\x12U\n\x11LoadSettingsFiles\x12\x1e.gpyrpc.Load\
+    SettingsFiles_Args\x1a\x20.gpyrpc.LoadSettingsFiles_Resultb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -8265,7 +9155,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             let mut deps = ::std::vec::Vec::with_capacity(2);
             deps.push(::protobuf::well_known_types::any::file_descriptor().clone());
             deps.push(::protobuf::descriptor::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(55);
+            let mut messages = ::std::vec::Vec::with_capacity(59);
             messages.push(CmdArgSpec::generated_message_descriptor_data());
             messages.push(CmdOverrideSpec::generated_message_descriptor_data());
             messages.push(RestResponse::generated_message_descriptor_data());
@@ -8293,6 +9183,9 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(LintPath_Result::generated_message_descriptor_data());
             messages.push(OverrideFile_Args::generated_message_descriptor_data());
             messages.push(OverrideFile_Result::generated_message_descriptor_data());
+            messages.push(GetFieldValues_Args::generated_message_descriptor_data());
+            messages.push(GetFieldValues_Result::generated_message_descriptor_data());
+            messages.push(FieldValue::generated_message_descriptor_data());
             messages.push(EvalCode_Args::generated_message_descriptor_data());
             messages.push(EvalCode_Result::generated_message_descriptor_data());
             messages.push(ResolveCode_Args::generated_message_descriptor_data());
@@ -8320,6 +9213,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(CliConfig::generated_message_descriptor_data());
             messages.push(KeyValuePair::generated_message_descriptor_data());
             messages.push(KclType::generated_message_descriptor_data());
+            messages.push(IndexSignature::generated_message_descriptor_data());
             messages.push(Decorator::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(0);
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
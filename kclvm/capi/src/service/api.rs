@@ -2,6 +2,7 @@ use protobuf::Message;
 
 use crate::model::gpyrpc::*;
 use crate::service::service::KclvmService;
+use crate::service::util::transform_str_to_kcl_error_json;
 use kclvm_runtime::utils::*;
 use std::ffi::CString;
 use std::os::raw::c_char;
@@ -95,6 +96,14 @@ pub(crate) fn _kclvm_get_service_fn_ptr_by_name(name: &str) -> u64 {
         "KclvmService.Ping" => ping as *const () as u64,
         "KclvmService.ExecProgram" => exec_program as *const () as u64,
         "KclvmService.OverrideFile" => override_file as *const () as u64,
+        "KclvmService.GetFieldValues" => get_field_values as *const () as u64,
+        "KclvmService.GetSchemaType" => get_schema_type as *const () as u64,
+        "KclvmService.ParseFile_AST" => parse_file_ast as *const () as u64,
+        "KclvmService.ParseProgram_AST" => parse_program_ast as *const () as u64,
+        "KclvmService.FormatCode" => format_code as *const () as u64,
+        "KclvmService.FormatPath" => format_path as *const () as u64,
+        "KclvmService.LintPath" => lint_path as *const () as u64,
+        "KclvmService.ValidateCode" => validate_code as *const () as u64,
         _ => panic!("unknown method name : {}", name),
     }
 }
@@ -169,3 +178,205 @@ pub(crate) fn override_file(serv: &mut KclvmService, args: &[u8]) -> *const c_ch
     };
     CString::new(result_byte).unwrap().into_raw()
 }
+
+/// get_field_values provides users with the ability to read the source values and
+/// positions of a field path in a KCL file without evaluating it
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     kcl file, kcl code and field path selected by the user
+///     serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn get_field_values(serv: &mut KclvmService, args: &[u8]) -> *const c_char {
+    let serv_ref = mut_ptr_as_ref(serv);
+    let args = GetFieldValues_Args::parse_from_bytes(args).unwrap();
+    let res = serv_ref.get_field_values(&args);
+    let result_byte = match res {
+        Ok(res) => match res.write_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{}", err.to_string()),
+        },
+        Err(err) => panic!("{}", err.clone()),
+    };
+    CString::new(result_byte).unwrap().into_raw()
+}
+
+/// get_schema_type provides users with the ability to get the schema types with the
+/// attributes, defaults, decorators and documents in a KCL file
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     kcl file, kcl code and schema name selected by the user
+///     serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn get_schema_type(serv: &mut KclvmService, args: &[u8]) -> *const c_char {
+    let serv_ref = mut_ptr_as_ref(serv);
+    let args = GetSchemaType_Args::parse_from_bytes(args).unwrap();
+    let res = serv_ref.get_schema_type(&args);
+    let result_byte = match res {
+        Ok(res) => match res.write_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{}", err.to_string()),
+        },
+        Err(err) => panic!("{}", err.clone()),
+    };
+    CString::new(result_byte).unwrap().into_raw()
+}
+
+/// parse_file_ast provides users with the ability to parse a KCL file into the AST json
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the filename and the optional source code of the KCL file
+///     serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn parse_file_ast(serv: *mut KclvmService, args: &[u8]) -> *const c_char {
+    call_with_kcl_error(serv, args, KclvmService::parse_file_ast)
+}
+
+/// parse_program_ast provides users with the ability to parse a KCL program into the AST json
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the KCL file list of the program serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn parse_program_ast(serv: *mut KclvmService, args: &[u8]) -> *const c_char {
+    call_with_kcl_error(serv, args, KclvmService::parse_program_ast)
+}
+
+/// format_code provides users with the ability to format KCL source code
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the KCL source code serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn format_code(serv: *mut KclvmService, args: &[u8]) -> *const c_char {
+    call_with_kcl_error(serv, args, KclvmService::format_code)
+}
+
+/// format_path provides users with the ability to format KCL files in a path
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the KCL file or directory path serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn format_path(serv: *mut KclvmService, args: &[u8]) -> *const c_char {
+    call_with_kcl_error(serv, args, KclvmService::format_path)
+}
+
+/// lint_path provides users with the ability to lint KCL files
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the KCL file path serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn lint_path(serv: *mut KclvmService, args: &[u8]) -> *const c_char {
+    call_with_kcl_error(serv, args, KclvmService::lint_path)
+}
+
+/// validate_code provides users with the ability to validate Json/Yaml data with KCL schemas
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the validated data, the KCL code, the schema name, the attribute name
+///     and the data format serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub(crate) fn validate_code(serv: *mut KclvmService, args: &[u8]) -> *const c_char {
+    call_with_kcl_error(serv, args, KclvmService::validate_code)
+}
+
+/// Parse the protobuf `args`, call the service method `f` and return the result
+/// serialized as protobuf byte sequence. The service error is transformed into
+/// a [KclError] json string and raised as the call error.
+fn call_with_kcl_error<A, R>(
+    serv: *mut KclvmService,
+    args: &[u8],
+    f: fn(&KclvmService, &A) -> Result<R, String>,
+) -> *const c_char
+where
+    A: Message,
+    R: Message,
+{
+    let serv_ref = mut_ptr_as_ref(serv);
+    let args = A::parse_from_bytes(args).unwrap();
+    let result_byte = match f(serv_ref, &args) {
+        Ok(res) => match res.write_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{}", err.to_string()),
+        },
+        Err(err) => panic!("{}", transform_str_to_kcl_error_json(&err)),
+    };
+    CString::new(result_byte).unwrap().into_raw()
+}
@@ -1,3 +1,4 @@
 pub mod api;
 pub mod service;
+mod ty;
 pub mod util;
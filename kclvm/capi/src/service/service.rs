@@ -1,11 +1,18 @@
-use std::{path::Path, string::String, time::SystemTime};
+use std::{path::Path, string::String};
 
 use crate::model::gpyrpc::*;
+use crate::service::ty::kcl_schema_ty_to_pb_ty;
+use crate::service::util::transform_diagnostic_to_kcl_error;
 
-use kclvm_parser::load_program;
-use kclvm_query::apply_overrides;
-use kclvm_query::override_file;
-use kclvm_runtime::ValueRef;
+use kclvm_ast::MAIN_PKG;
+use kclvm_parser::parse_file;
+use kclvm_parser::{load_program, LoadProgramOptions};
+use kclvm_query::{get_file_field_values, get_program_schema_types, override_file};
+use kclvm_tools::format::{format, format_code, FormatOptions};
+use kclvm_tools::lint::lint_files;
+use kclvm_tools::util::loader::LoaderKind;
+use kclvm_tools::vet::validator::{validate, ValidateOption};
+use protobuf::MessageField;
 use protobuf_json_mapping::print_to_string_with_options;
 use protobuf_json_mapping::PrintOptions;
 
@@ -71,60 +78,41 @@ impl KclvmService {
         )
         .unwrap();
         // parse native_args from json string
-        let native_args = kclvm_runner::ExecProgramArgs::from_str(args_json.as_str());
-        let opts = native_args.get_load_program_options();
-        let k_files = &native_args.k_filename_list;
-        let mut kcl_paths = Vec::<String>::new();
-        // join work_path with k_fiel_path
-        for (_, file) in k_files.into_iter().enumerate() {
-            match Path::new(args.work_dir.as_str()).join(file).to_str() {
-                Some(str) => kcl_paths.push(String::from(str)),
-                None => (),
-            }
-        }
-
-        let kcl_paths_str = kcl_paths.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
-        let mut result = ExecProgram_Result::default();
-        let mut program = load_program(&kcl_paths_str.as_slice(), Some(opts))?;
-
-        if let Err(err) = apply_overrides(
-            &mut program,
-            &native_args.overrides,
-            &[],
-            native_args.print_override_ast,
-        ) {
-            return Err(err.to_string());
-        }
+        let mut native_args = kclvm_runner::ExecProgramArgs::from_str(args_json.as_str());
+        // join work_path with k_file_path
+        native_args.k_filename_list = args
+            .k_filename_list
+            .iter()
+            .map(|file| {
+                let path = Path::new(args.work_dir.as_str()).join(file);
+                path.canonicalize().unwrap_or(path).display().to_string()
+            })
+            .collect();
 
-        let start_time = SystemTime::now();
-        let exec_result = kclvm_runner::execute(program, self.plugin_agent, &native_args);
-        let escape_time = match SystemTime::now().duration_since(start_time) {
-            Ok(dur) => dur.as_secs_f32(),
-            Err(err) => return Err(err.to_string()),
-        };
-        result.escaped_time = escape_time.to_string();
-        let json_result = match exec_result {
-            Ok(res) => res,
-            Err(res) => {
-                if res.is_empty() {
-                    return Ok(result);
-                } else {
-                    return Err(res);
-                }
-            }
-        };
-        let kcl_val = ValueRef::from_json(&json_result).unwrap();
-        if let Some(val) = kcl_val.get_by_key("__kcl_PanicInfo__") {
-            if val.is_truthy() {
-                return Err(json_result);
-            }
-        }
-        let (json_result, yaml_result) = kcl_val.plan();
-        result.json_result = json_result;
-        if !args.disable_yaml_result {
-            result.yaml_result = yaml_result;
+        match kclvm_runner::exec_program(&native_args, self.plugin_agent) {
+            Ok(exec_result) => Ok(ExecProgram_Result {
+                json_result: exec_result.json_result,
+                yaml_result: exec_result.yaml_result,
+                diagnostics: exec_result
+                    .warnings
+                    .iter()
+                    .map(transform_diagnostic_to_kcl_error)
+                    .collect(),
+                escaped_time: exec_result.escaped_time,
+                ..Default::default()
+            }),
+            // The diagnostics of all the phases are returned in the result,
+            // and the error message is set to denote the failure.
+            Err(err) => Ok(ExecProgram_Result {
+                diagnostics: err
+                    .diagnostics
+                    .iter()
+                    .map(transform_diagnostic_to_kcl_error)
+                    .collect(),
+                err_message: err.to_string(),
+                ..Default::default()
+            }),
         }
-        Ok(result)
     }
 
     /// Override KCL file with args
@@ -162,4 +150,333 @@ impl KclvmService {
                 ..Default::default()
             })
     }
+
+    /// Get the source values and positions of a field path in a KCL file with args
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &GetFieldValues_Args {
+    ///     file: "main.k".to_string(),
+    ///     code: "app = {replicas = 2}".to_string(),
+    ///     field_path: "app.replicas".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let result = serv.get_field_values(args).unwrap();
+    /// assert_eq!(result.field_values[0].value, "2");
+    /// assert_eq!(result.field_values[0].operation, "=");
+    /// ```
+    pub fn get_field_values(
+        &self,
+        args: &GetFieldValues_Args,
+    ) -> Result<GetFieldValues_Result, String> {
+        let code = if args.code.is_empty() {
+            None
+        } else {
+            Some(args.code.clone())
+        };
+        let position = |filename: &str, line: u64, column: u64| Position {
+            line: line as i64,
+            column: column as i64,
+            filename: filename.to_string(),
+            ..Default::default()
+        };
+        let values = get_file_field_values(&args.file, code, &args.field_path)
+            .map_err(|err| err.to_string())?;
+        Ok(GetFieldValues_Result {
+            field_values: values
+                .iter()
+                .map(|v| FieldValue {
+                    value: v.value.clone(),
+                    operation: v
+                        .operation
+                        .as_ref()
+                        .map(|op| op.symbol().to_string())
+                        .unwrap_or_default(),
+                    start: MessageField::some(position(&v.filename, v.range.0, v.range.1)),
+                    end: MessageField::some(position(&v.filename, v.range.2, v.range.3)),
+                    value_start: MessageField::some(position(
+                        &v.filename,
+                        v.value_range.0,
+                        v.value_range.1,
+                    )),
+                    value_end: MessageField::some(position(
+                        &v.filename,
+                        v.value_range.2,
+                        v.value_range.3,
+                    )),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Get the schema types with the attributes, defaults and documents in a KCL file with args,
+    /// all the schema types in the main package are returned when the schema name is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &GetSchemaType_Args {
+    ///     file: "main.k".to_string(),
+    ///     code: "schema Person:\n    name: str = \"Alice\"\n    age?: int\n".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let result = serv.get_schema_type(args).unwrap();
+    /// let person = &result.schema_type_list[0];
+    /// assert_eq!(person.schema_name, "Person");
+    /// assert_eq!(person.properties["name"].default, "\"Alice\"");
+    /// assert_eq!(person.required, vec!["name".to_string()]);
+    /// ```
+    pub fn get_schema_type(
+        &self,
+        args: &GetSchemaType_Args,
+    ) -> Result<GetSchemaType_Result, String> {
+        let opts = if args.code.is_empty() {
+            None
+        } else {
+            Some(LoadProgramOptions {
+                k_code_list: vec![args.code.clone()],
+                ..Default::default()
+            })
+        };
+        let program = load_program(&[args.file.as_str()], opts)?;
+        let schema_types = get_program_schema_types(&program);
+        Ok(GetSchemaType_Result {
+            schema_type_list: schema_types
+                .iter()
+                .filter(|ty| ty.pkgpath == MAIN_PKG)
+                .filter(|ty| args.schema_name.is_empty() || ty.name == args.schema_name)
+                .map(|ty| kcl_schema_ty_to_pb_ty(ty, &schema_types))
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Parse KCL file with args and return the AST json string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &ParseFile_AST_Args {
+    ///     filename: "./src/testdata/test.k".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let parse_result = serv.parse_file_ast(args).unwrap();
+    /// assert!(!parse_result.ast_json.is_empty());
+    /// ```
+    pub fn parse_file_ast(
+        &self,
+        args: &ParseFile_AST_Args,
+    ) -> Result<ParseFile_AST_Result, String> {
+        let code = if args.source_code.is_empty() {
+            None
+        } else {
+            Some(args.source_code.clone())
+        };
+        let module = parse_file(&args.filename, code)?;
+        let ast_json = serde_json::to_string(&module).map_err(|err| err.to_string())?;
+        Ok(ParseFile_AST_Result {
+            ast_json,
+            ..Default::default()
+        })
+    }
+
+    /// Parse KCL program with args and return the AST json string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &ParseProgram_AST_Args {
+    ///     k_filename_list: vec!["./src/testdata/test.k".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// let parse_result = serv.parse_program_ast(args).unwrap();
+    /// assert!(!parse_result.ast_json.is_empty());
+    /// ```
+    pub fn parse_program_ast(
+        &self,
+        args: &ParseProgram_AST_Args,
+    ) -> Result<ParseProgram_AST_Result, String> {
+        let k_files = args
+            .k_filename_list
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<&str>>();
+        let program = load_program(&k_files, None)?;
+        let ast_json = serde_json::to_string(&program).map_err(|err| err.to_string())?;
+        Ok(ParseProgram_AST_Result {
+            ast_json,
+            ..Default::default()
+        })
+    }
+
+    /// Format KCL source code with args and return the formatted code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &FormatCode_Args {
+    ///     source: "a  =  1".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let format_result = serv.format_code(args).unwrap();
+    /// assert_eq!(format_result.formatted, "a = 1\n".as_bytes().to_vec());
+    /// ```
+    pub fn format_code(&self, args: &FormatCode_Args) -> Result<FormatCode_Result, String> {
+        let formatted = format_code(&args.source).map_err(|err| err.to_string())?;
+        Ok(FormatCode_Result {
+            formatted: formatted.as_bytes().to_vec(),
+            ..Default::default()
+        })
+    }
+
+    /// Format KCL file or directory path with args and return the changed paths.
+    /// When the path ends with `...`, all KCL files in the directory will be
+    /// formatted recursively.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &FormatPath_Args {
+    ///     path: "./src/testdata/test.k".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let format_result = serv.format_path(args).unwrap();
+    /// assert!(format_result.changedPaths.is_empty());
+    /// ```
+    pub fn format_path(&self, args: &FormatPath_Args) -> Result<FormatPath_Result, String> {
+        let (path, recursively) = match args.path.strip_suffix("...") {
+            Some(path) => (path, true),
+            None => (args.path.as_str(), false),
+        };
+        let changed_paths = format(
+            path,
+            &FormatOptions {
+                recursively,
+                is_stdout: false,
+                ..Default::default()
+            },
+        )
+        .map_err(|err| err.to_string())?;
+        Ok(FormatPath_Result {
+            changedPaths: changed_paths,
+            ..Default::default()
+        })
+    }
+
+    /// Lint KCL file with args and return the error and warning messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &LintPath_Args {
+    ///     path: "./src/testdata/test-lint.k".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let lint_result = serv.lint_path(args).unwrap();
+    /// assert_eq!(lint_result.results, vec!["Module 'math' imported but unused".to_string()]);
+    /// ```
+    pub fn lint_path(&self, args: &LintPath_Args) -> Result<LintPath_Result, String> {
+        let (errs, warnings) = lint_files(&[args.path.as_str()], None);
+        let results = errs
+            .iter()
+            .chain(warnings.iter())
+            .flat_map(|diag| diag.messages.iter().map(|msg| msg.message.clone()))
+            .collect();
+        Ok(LintPath_Result {
+            results,
+            ..Default::default()
+        })
+    }
+
+    /// Validate the Json/Yaml data with the KCL schema code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use kclvm_capi::service::service::KclvmService;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let code = r#"
+    /// schema Person:
+    ///     name: str
+    ///     age: int
+    ///
+    ///     check:
+    ///         0 < age < 120
+    /// "#;
+    /// let data = r#"{"name": "Alice", "age": 10}"#;
+    /// let args = &ValidateCode_Args {
+    ///     code: code.to_string(),
+    ///     data: data.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let validate_result = serv.validate_code(args).unwrap();
+    /// assert!(validate_result.success);
+    /// ```
+    pub fn validate_code(&self, args: &ValidateCode_Args) -> Result<ValidateCode_Result, String> {
+        let kind = match args.format.to_lowercase().as_str() {
+            "" | "json" => LoaderKind::JSON,
+            "yaml" | "yml" => LoaderKind::YAML,
+            "toml" => LoaderKind::TOML,
+            _ => return Err(format!("Unsupported data format '{}'", args.format)),
+        };
+        let schema_name = if args.schema.is_empty() {
+            None
+        } else {
+            Some(args.schema.clone())
+        };
+        let attribute_name = if args.attribute_name.is_empty() {
+            "value".to_string()
+        } else {
+            args.attribute_name.clone()
+        };
+        let opt = ValidateOption::new_with_data(
+            schema_name,
+            attribute_name,
+            args.data.clone(),
+            kind,
+            None,
+            Some(args.code.clone()),
+        );
+        let (success, err_message) = match validate(opt) {
+            Ok(errors) => (
+                errors.is_empty(),
+                errors
+                    .iter()
+                    .map(|err| err.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            Err(err) => (false, err),
+        };
+        Ok(ValidateCode_Result {
+            success,
+            err_message,
+            ..Default::default()
+        })
+    }
 }
@@ -0,0 +1,133 @@
+use crate::model::gpyrpc::{Decorator, IndexSignature, KclType};
+use kclvm_query::{DecoratorInfo, SchemaTypeInfo};
+use kclvm_sema::ty::{SchemaType, Type, TypeKind};
+use protobuf::MessageField;
+
+/// Convert the schema type info into the protobuf KclType, `schema_types` are the schema types
+/// of the whole program used to convert the schema types referenced by the attributes.
+pub(crate) fn kcl_schema_ty_to_pb_ty(
+    schema_ty: &SchemaTypeInfo,
+    schema_types: &[SchemaTypeInfo],
+) -> KclType {
+    TypeConverter {
+        schema_types,
+        visiting: vec![],
+    }
+    .schema_ty_info_to_pb_ty(schema_ty)
+}
+
+struct TypeConverter<'a> {
+    schema_types: &'a [SchemaTypeInfo],
+    /// The schema types being converted, which are used to stop the recursive schema conversion.
+    visiting: Vec<(String, String)>,
+}
+
+impl<'a> TypeConverter<'a> {
+    fn schema_ty_info_to_pb_ty(&mut self, schema_ty: &SchemaTypeInfo) -> KclType {
+        let key = (schema_ty.pkgpath.clone(), schema_ty.name.clone());
+        let mut ty = KclType {
+            type_: "schema".to_string(),
+            type_str: schema_ty.name.clone(),
+            schema_name: schema_ty.name.clone(),
+            schema_doc: schema_ty.doc.clone(),
+            pkg_path: schema_ty.pkgpath.clone(),
+            filename: schema_ty.filename.clone(),
+            line: schema_ty.line as i32,
+            base_schema: schema_ty.parent.clone().unwrap_or_default(),
+            mixins: schema_ty.mixins.clone(),
+            decorators: schema_ty.decorators.iter().map(pb_decorator).collect(),
+            checks: schema_ty.checks.clone(),
+            ..Default::default()
+        };
+        if self.visiting.contains(&key) {
+            return ty;
+        }
+        self.visiting.push(key);
+        for (name, attr) in &schema_ty.attrs {
+            let mut attr_ty = self.ty_to_pb_ty(&attr.ty);
+            attr_ty.default = attr.default.clone().unwrap_or_default();
+            attr_ty.description = attr.doc.clone();
+            attr_ty.line = attr.line as i32;
+            attr_ty.decorators = attr.decorators.iter().map(pb_decorator).collect();
+            ty.properties.insert(name.clone(), attr_ty);
+            if !attr.is_optional {
+                ty.required.push(name.clone());
+            }
+        }
+        if let Some(index_signature) = &schema_ty.index_signature {
+            ty.index_signature = MessageField::some(IndexSignature {
+                key_name: index_signature.key_name.clone().unwrap_or_default(),
+                key: MessageField::some(KclType {
+                    type_: index_signature.key_type.clone(),
+                    type_str: index_signature.key_type.clone(),
+                    ..Default::default()
+                }),
+                val: MessageField::some(self.ty_to_pb_ty(&index_signature.value_ty)),
+                any_other: index_signature.any_other,
+                ..Default::default()
+            });
+        }
+        self.visiting.pop();
+        ty
+    }
+
+    fn schema_ty_to_pb_ty(&mut self, schema_ty: &SchemaType) -> KclType {
+        let schema_types = self.schema_types;
+        match schema_types
+            .iter()
+            .find(|ty| ty.pkgpath == schema_ty.pkgpath && ty.name == schema_ty.name)
+        {
+            Some(schema_ty) => self.schema_ty_info_to_pb_ty(schema_ty),
+            None => KclType {
+                type_: "schema".to_string(),
+                type_str: schema_ty.name.clone(),
+                schema_name: schema_ty.name.clone(),
+                schema_doc: schema_ty.doc.clone(),
+                pkg_path: schema_ty.pkgpath.clone(),
+                filename: schema_ty.filename.clone(),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn ty_to_pb_ty(&mut self, ty: &Type) -> KclType {
+        let mut pb_ty = match &ty.kind {
+            TypeKind::List(item_ty) => KclType {
+                type_: "list".to_string(),
+                item: MessageField::some(self.ty_to_pb_ty(item_ty)),
+                ..Default::default()
+            },
+            TypeKind::Dict(key_ty, val_ty) => KclType {
+                type_: "dict".to_string(),
+                key: MessageField::some(self.ty_to_pb_ty(key_ty)),
+                item: MessageField::some(self.ty_to_pb_ty(val_ty)),
+                ..Default::default()
+            },
+            TypeKind::Union(types) => KclType {
+                type_: "union".to_string(),
+                union_types: types.iter().map(|ty| self.ty_to_pb_ty(ty)).collect(),
+                ..Default::default()
+            },
+            TypeKind::Schema(schema_ty) => self.schema_ty_to_pb_ty(schema_ty),
+            _ => KclType {
+                type_: ty.ty_str(),
+                ..Default::default()
+            },
+        };
+        pb_ty.type_str = ty.ty_str();
+        pb_ty
+    }
+}
+
+fn pb_decorator(decorator: &DecoratorInfo) -> Decorator {
+    Decorator {
+        name: decorator.name.clone(),
+        arguments: decorator.arguments.clone(),
+        keywords: decorator
+            .keywords
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+        ..Default::default()
+    }
+}
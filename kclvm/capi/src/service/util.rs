@@ -1,3 +1,6 @@
+use crate::model::gpyrpc::{KclError, KclErrorInfo};
+use kclvm_error::{Diagnostic, DiagnosticId};
+use kclvm_runtime::PanicInfo;
 use protobuf::MessageFull;
 use protobuf_json_mapping::PrintOptions;
 
@@ -28,3 +31,100 @@ pub fn transform_protobuf_to_json<M: MessageFull>(
         },
     )
 }
+
+/// Transform an error message into [KclError]. The message is parsed as
+/// a [PanicInfo] json string when possible, otherwise it is used as the
+/// error message directly.
+pub fn transform_str_to_kcl_error(err: &str) -> KclError {
+    let panic_info = match serde_json::from_str::<PanicInfo>(err) {
+        Ok(panic_info) if panic_info.__kcl_PanicInfo__ => panic_info,
+        _ => {
+            return KclError {
+                msg: err.to_string(),
+                ..Default::default()
+            }
+        }
+    };
+    let err_level = if panic_info.is_warning {
+        "warning"
+    } else {
+        "error"
+    };
+    let mut error_infos = vec![KclErrorInfo {
+        err_level: err_level.to_string(),
+        arg_msg: panic_info.kcl_arg_msg.clone(),
+        filename: panic_info.kcl_file.clone(),
+        line_no: panic_info.kcl_line.to_string(),
+        col_no: panic_info.kcl_col.to_string(),
+        ..Default::default()
+    }];
+    if !panic_info.kcl_config_meta_file.is_empty() {
+        error_infos.push(KclErrorInfo {
+            err_level: err_level.to_string(),
+            arg_msg: panic_info.kcl_config_meta_arg_msg.clone(),
+            filename: panic_info.kcl_config_meta_file.clone(),
+            line_no: panic_info.kcl_config_meta_line.to_string(),
+            col_no: panic_info.kcl_config_meta_col.to_string(),
+            ..Default::default()
+        });
+    }
+    KclError {
+        ewcode: panic_info.err_type_code.to_string(),
+        msg: if panic_info.message.is_empty() {
+            panic_info.kcl_arg_msg
+        } else {
+            panic_info.message
+        },
+        error_infos,
+        ..Default::default()
+    }
+}
+
+/// Transform a [Diagnostic] into [KclError], the positions of the diagnostic
+/// messages are the error infos.
+pub fn transform_diagnostic_to_kcl_error(diag: &Diagnostic) -> KclError {
+    let (ewcode, name) = match &diag.code {
+        Some(DiagnosticId::Error(kind)) => {
+            (kind.code().unwrap_or_default().to_string(), kind.name())
+        }
+        Some(DiagnosticId::Warning(kind)) => (kind.code().to_string(), kind.name()),
+        None => ("".to_string(), "".to_string()),
+    };
+    let err_level = diag.level.to_str().to_lowercase();
+    KclError {
+        ewcode,
+        name,
+        msg: diag
+            .messages
+            .first()
+            .map(|msg| msg.message.clone())
+            .unwrap_or_default(),
+        error_infos: diag
+            .messages
+            .iter()
+            .map(|msg| KclErrorInfo {
+                err_level: err_level.clone(),
+                arg_msg: msg.note.clone().unwrap_or_else(|| msg.message.clone()),
+                filename: msg.pos.filename.clone(),
+                line_no: msg.pos.line.to_string(),
+                col_no: msg.pos.column.unwrap_or_default().to_string(),
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Transform an error message into a [KclError] json string.
+pub fn transform_str_to_kcl_error_json(err: &str) -> String {
+    protobuf_json_mapping::print_to_string_with_options(
+        &transform_str_to_kcl_error(err),
+        &PrintOptions {
+            enum_values_int: true,
+            proto_field_name: true,
+            always_output_default_values: true,
+            _future_options: (),
+        },
+    )
+    .unwrap_or_else(|_| err.to_string())
+}
@@ -11,6 +11,7 @@ pub(crate) const INTERNAL_ERROR_MSG: &str = "Internal error, please report a bug
 pub(crate) const CODE_GEN_ERROR_MSG: &str = "Code gen error";
 pub(crate) const INVALID_OPERATOR_MSG: &str = "Invalid operator";
 pub(crate) const INVALID_JOINED_STR_MSG: &str = "Invalid AST JoinedString value";
+pub(crate) const MISSING_EXPR_MSG: &str = "Missing expression in the AST, the code has syntax errors";
 pub(crate) const INVALID_STR_INTERPOLATION_SPEC_MSG: &str =
     "Invalid string interpolation format specification";
 
@@ -1230,7 +1230,7 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
                     .expect(kcl_error::COMPILE_ERROR_MSG);
             }
             // Get the JSON string including all global variables
-            let json_str_value = self.globals_to_json_str();
+            let json_str_value = self.globals_to_json_str(opt.pkg_vars);
             // Build a return in the current block
             self.pop_function();
             self.builder
@@ -1248,25 +1248,31 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
                     };
                     let path = std::path::Path::new(&path);
                     // Build LLVM module to a `.o` object file.
-                    self.build_object_file(&module.borrow(), path)?;
+                    self.build_object_file(&module.borrow(), path, opt.target)?;
                 }
             } else {
                 // Build LLVM module to a `.o` object file.
-                self.build_object_file(&self.module, path)?;
+                self.build_object_file(&self.module, path, opt.target)?;
             }
         }
         Ok(())
     }
 
-    /// Build LLVM module to a `.o` object file.
+    /// Build LLVM module to a `.o` object file for the target triple `target`,
+    /// and the host target is used if it is not set.
     ///
-    /// TODO: WASM and cross platform build.
+    /// TODO: cross platform build except the WASM target.
     fn build_object_file(
         self: &LLVMCodeGenContext<'ctx>,
         module: &Module,
         path: &Path,
+        target: Option<&str>,
     ) -> Result<(), LLVMString> {
-        let triple = inkwell::targets::TargetMachine::get_default_triple();
+        let triple = match target {
+            Some(target) => inkwell::targets::TargetTriple::create(target),
+            None => inkwell::targets::TargetMachine::get_default_triple(),
+        };
+        let is_wasm = triple.as_str().to_string_lossy().starts_with("wasm");
         let target = inkwell::targets::Target::from_triple(&triple)?;
         // Convert LLVM module to ll file.
         module.print_to_file(path)?;
@@ -1281,10 +1287,19 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
                 // We do not enable any optimization, so that
                 // the sum of compile time and run time is as small as possible
                 inkwell::OptimizationLevel::None,
-                RelocMode::PIC,
+                // WebAssembly has no position independent code of the native shared library.
+                if is_wasm {
+                    RelocMode::Static
+                } else {
+                    RelocMode::PIC
+                },
                 CodeModel::Default,
             )
             .expect(kcl_error::CODE_GEN_ERROR_MSG);
+        // The runtime module has no target information, so the pointer width
+        // of the target e.g., 32-bit of `wasm32` comes from the target machine.
+        module.set_triple(&triple);
+        module.set_data_layout(&target_machine.get_target_data().get_data_layout());
         target_machine.write_to_file(&module, FileType::Object, path)
     }
 }
@@ -1487,6 +1502,30 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
         self.get_variable_in_pkgpath(name, &current_pkgpath)
     }
 
+    /// Whether the variable `name` is defined in the local scopes of lambda functions
+    /// e.g., the lambda arguments, which shadow the schema attributes with the same name.
+    pub(crate) fn is_local_var_in_lambda(&self, name: &str) -> bool {
+        let pkgpath = self.current_pkgpath();
+        let pkgpath = if !pkgpath.starts_with(PKG_PATH_PREFIX) && pkgpath != MAIN_PKG_PATH {
+            format!("{}{}", PKG_PATH_PREFIX, pkgpath)
+        } else {
+            pkgpath
+        };
+        let pkg_scopes = self.pkg_scopes.borrow();
+        let scopes = pkg_scopes
+            .get(&pkgpath)
+            .unwrap_or_else(|| panic!("package {} is not found", pkgpath));
+        match scopes
+            .iter()
+            .position(|scope| scope.variables.borrow().contains_key(value::LAMBDA_CLOSURE))
+        {
+            Some(index) => scopes[index..]
+                .iter()
+                .any(|scope| scope.variables.borrow().contains_key(name)),
+            None => false,
+        }
+    }
+
     /// Get the variable value named `name` from the scope, return Err when not found
     pub fn get_variable_in_schema(&self, name: &str) -> CompileResult<'ctx> {
         let schema_value = self
@@ -1651,32 +1690,36 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
                 .unwrap_or_else(|| panic!("package {} is not found", pkgpath));
             // Scopes 0 is builtin scope, Scopes 1 is the global scope, Scopes 2~ are the local scopes
             let scopes_len = scopes.len();
-            let last_scopes = scopes.last().expect(kcl_error::INTERNAL_ERROR_MSG);
-            let mut closures_mut = last_scopes.closures.borrow_mut();
+            // The scope of the innermost lambda function, the local variables defined
+            // outside the lambda function are loaded from the lambda closure map.
+            let lambda_index = scopes
+                .iter()
+                .rposition(|scope| scope.variables.borrow().contains_key(value::LAMBDA_CLOSURE));
             for i in 0..scopes_len {
                 let index = scopes_len - i - 1;
                 let variables_mut = scopes[index].variables.borrow_mut();
                 if let Some(var) = variables_mut.get(&name.to_string()) {
-                    // Closure vars, 2 denotes the builtin scope and the global scope
-                    let value = if i >= 1 && i < scopes_len - 2 {
-                        closures_mut.insert(name.to_string(), *var);
-                        let variables = last_scopes.variables.borrow();
-                        let ptr = variables.get(value::LAMBDA_CLOSURE);
-                        // Lambda closure
-                        match ptr {
-                            Some(ptr) => {
-                                let closure_map = self.builder.build_load(*ptr, "");
-                                let string_ptr_value = self.native_global_string(name, "").into();
-                                self.build_call(
-                                    &ApiFunc::kclvm_dict_get_value.name(),
-                                    &[closure_map, string_ptr_value],
-                                )
-                            }
-                            // Nest comp for
-                            None => self.builder.build_load(*var, name),
+                    let value = match lambda_index {
+                        // Closure vars, 2 denotes the builtin scope and the global scope
+                        Some(lambda_index) if index >= 2 && index < lambda_index => {
+                            let lambda_scope = &scopes[lambda_index];
+                            lambda_scope
+                                .closures
+                                .borrow_mut()
+                                .insert(name.to_string(), *var);
+                            let ptr = *lambda_scope
+                                .variables
+                                .borrow()
+                                .get(value::LAMBDA_CLOSURE)
+                                .expect(kcl_error::INTERNAL_ERROR_MSG);
+                            let closure_map = self.builder.build_load(ptr, "");
+                            let string_ptr_value = self.native_global_string(name, "").into();
+                            self.build_call(
+                                &ApiFunc::kclvm_dict_get_value.name(),
+                                &[closure_map, string_ptr_value],
+                            )
                         }
-                    } else {
-                        self.builder.build_load(*var, name)
+                        _ => self.builder.build_load(*var, name),
                     };
                     result = Ok(value);
                     break;
@@ -1753,44 +1796,36 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
         Ok(value)
     }
 
-    /// Get closure dict in the current scope.
-    pub(crate) fn get_closure_dict_in_current_scope(&self) -> BasicValueEnum<'ctx> {
-        let is_in_schema = self.schema_stack.borrow().len() > 0;
-        // Get closures in the current scope
-        let dict_value = self.dict_value();
-        {
+    /// Leave the lambda function scope and get the closure map of the lambda function,
+    /// which holds the values of all the variables captured by the lambda body. The values
+    /// are loaded in the enclosing function, thus the nested lambda closures are captured
+    /// by the enclosing lambda function as well.
+    pub(crate) fn leave_lambda_scope(&self) -> BasicValueEnum<'ctx> {
+        let names: Vec<String> = {
             let pkgpath = self.current_pkgpath();
             let pkgpath = if !pkgpath.starts_with(PKG_PATH_PREFIX) && pkgpath != MAIN_PKG_PATH {
                 format!("{}{}", PKG_PATH_PREFIX, pkgpath)
             } else {
                 pkgpath
             };
-            let pkg_scopes = self.pkg_scopes.borrow_mut();
+            let pkg_scopes = self.pkg_scopes.borrow();
             let scopes = pkg_scopes
                 .get(&pkgpath)
                 .unwrap_or_else(|| panic!("package {} is not found", pkgpath));
-            let closures_mut = scopes
+            let closures = scopes
                 .last()
                 .expect(kcl_error::INTERNAL_ERROR_MSG)
                 .closures
-                .borrow_mut();
-            let variables_mut = scopes
-                .last()
-                .expect(kcl_error::INTERNAL_ERROR_MSG)
-                .variables
-                .borrow_mut();
-            for (key, ptr) in &*closures_mut {
-                if variables_mut.contains_key(key) {
-                    let value = self.builder.build_load(*ptr, "");
-                    self.dict_insert_override_item(dict_value, key.as_str(), value);
-                }
-            }
-        }
-        if is_in_schema {
-            let schema_value = self
-                .get_variable(value::SCHEMA_SELF_NAME)
+                .borrow();
+            closures.keys().cloned().collect()
+        };
+        self.leave_scope();
+        let dict_value = self.dict_value();
+        for name in &names {
+            let value = self
+                .get_variable(name)
                 .expect(kcl_error::INTERNAL_ERROR_MSG);
-            self.dict_insert_override_item(dict_value, value::SCHEMA_SELF_NAME, schema_value);
+            self.dict_insert_override_item(dict_value, name, value);
         }
         dict_value
     }
@@ -1817,25 +1852,39 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
             .expect(kcl_error::INTERNAL_ERROR_MSG)
     }
 
-    /// Plan globals to a json string
-    pub fn globals_to_json_str(&self) -> BasicValueEnum<'ctx> {
-        let current_pkgpath = self.current_pkgpath();
-        let mut pkg_scopes = self.pkg_scopes.borrow_mut();
-        let msg = format!("pkgpath {} is not found", current_pkgpath);
-        let scopes = pkg_scopes.get_mut(&current_pkgpath).expect(&msg);
-        let globals = scopes
-            .last_mut()
-            .expect(kcl_error::INTERNAL_ERROR_MSG)
-            .variables
-            .borrow_mut();
+    /// Plan globals and the variables of other packages in `pkg_vars` to a json string,
+    /// the package variables are keyed by [kclvm_runtime::pkg_var_key].
+    pub fn globals_to_json_str(&self, pkg_vars: &[(String, String)]) -> BasicValueEnum<'ctx> {
         let global_dict = self.dict_value();
-        for (name, ptr) in globals.iter() {
-            // Omit private variables and function variables
-            if name.starts_with(kclvm_runtime::KCL_PRIVATE_VAR_PREFIX) {
-                continue;
+        {
+            let current_pkgpath = self.current_pkgpath();
+            let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+            let msg = format!("pkgpath {} is not found", current_pkgpath);
+            let scopes = pkg_scopes.get_mut(&current_pkgpath).expect(&msg);
+            let globals = scopes
+                .last_mut()
+                .expect(kcl_error::INTERNAL_ERROR_MSG)
+                .variables
+                .borrow_mut();
+            for (name, ptr) in globals.iter() {
+                // Omit private variables and function variables
+                if name.starts_with(kclvm_runtime::KCL_PRIVATE_VAR_PREFIX) {
+                    continue;
+                }
+                let value = self.builder.build_load(*ptr, "");
+                self.dict_safe_insert(global_dict, name.as_str(), value, 0, -1);
             }
-            let value = self.builder.build_load(*ptr, "");
-            self.dict_safe_insert(global_dict, name.as_str(), value, 0, -1);
+        }
+        // The package variables are selected by name, so private variables are not omitted.
+        for (pkgpath, name) in pkg_vars {
+            let value = if self.no_link {
+                self.get_external_variable_in_pkgpath(name, pkgpath)
+            } else {
+                self.get_variable_in_pkgpath(name, pkgpath)
+            }
+            .expect(kcl_error::INTERNAL_ERROR_MSG);
+            let key = kclvm_runtime::pkg_var_key(pkgpath, name);
+            self.dict_safe_insert(global_dict, &key, value, 0, -1);
         }
         self.build_call(&ApiFunc::kclvm_value_plan_to_json.name(), &[global_dict])
     }
@@ -1231,6 +1231,7 @@ impl<'ctx> TypedResultWalker<'ctx> for LLVMCodeGenContext<'ctx> {
             ast::Expr::FormattedValue(formatted_value) => {
                 self.walk_formatted_value(formatted_value)
             }
+            ast::Expr::Missing(missing_expr) => self.walk_missing_expr(missing_expr),
         }
     }
 
@@ -2078,7 +2079,6 @@ impl<'ctx> TypedResultWalker<'ctx> for LLVMCodeGenContext<'ctx> {
     fn walk_lambda_expr(&self, lambda_expr: &'ctx ast::LambdaExpr) -> Self::Result {
         check_backtrack_stop!(self);
         let pkgpath = &self.current_pkgpath();
-        let is_in_schema = self.schema_stack.borrow().len() > 0;
         let func_before_block = self.append_block("");
         self.br(func_before_block);
         // Use "pkgpath"+"kclvm_lambda" to name 'function' to prevent conflicts between lambdas with the same name in different packages
@@ -2105,33 +2105,22 @@ impl<'ctx> TypedResultWalker<'ctx> for LLVMCodeGenContext<'ctx> {
         let var = self.builder.build_alloca(tpe, value::LAMBDA_CLOSURE);
         self.builder.build_store(var, closure_map);
         self.add_variable(value::LAMBDA_CLOSURE, var);
-        if is_in_schema {
-            let string_ptr_value = self
-                .native_global_string(value::SCHEMA_SELF_NAME, "")
-                .into();
-            let schema_value = self.build_call(
-                &ApiFunc::kclvm_dict_get_value.name(),
-                &[closure_map, string_ptr_value],
-            );
-            let value_ptr_type = self.value_ptr_type();
-            let var = self
-                .builder
-                .build_alloca(value_ptr_type, value::SCHEMA_SELF_NAME);
-            self.builder.build_store(var, schema_value);
-            self.add_variable(value::SCHEMA_SELF_NAME, var);
-        }
         self.walk_arguments(&lambda_expr.args, args, kwargs);
-        let val = self
+        let mut val = self
             .walk_stmts(&lambda_expr.body)
             .expect(kcl_error::COMPILE_ERROR_MSG);
+        // Check and convert the return value with the return type annotation
+        if let Some(return_type_str) = &lambda_expr.return_type_str {
+            let return_type = self.string_value(return_type_str);
+            val = self.build_call(&ApiFunc::kclvm_value_as.name(), &[val, return_type]);
+        }
         self.builder.build_return(Some(&val));
         // Exist the function
         self.builder.position_at_end(func_before_block);
+        let dict_value = self.leave_lambda_scope();
         let closure = self.list_value();
-        let dict_value = self.get_closure_dict_in_current_scope();
         self.list_append(closure, dict_value);
         let function = self.closure_value(function, closure);
-        self.leave_scope();
         self.pop_function();
         self.lambda_stack.borrow_mut().pop();
         Ok(function)
@@ -2302,6 +2291,11 @@ impl<'ctx> TypedResultWalker<'ctx> for LLVMCodeGenContext<'ctx> {
         Ok(self.build_call(&fn_name.name(), &[formatted_expr_value]))
     }
 
+    fn walk_missing_expr(&self, _missing_expr: &'ctx ast::MissingExpr) -> Self::Result {
+        // Programs with syntax errors are rejected before the code generation.
+        Err(kcl_error::KCLError::new(kcl_error::MISSING_EXPR_MSG))
+    }
+
     fn walk_comment(&self, _comment: &'ctx ast::Comment) -> Self::Result {
         // Nothing to do
         self.ok_result()
@@ -2534,6 +2528,8 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
                     let local_vars = self.local_vars.borrow_mut();
                     local_vars.contains(name)
                 };
+                let is_local_var =
+                    is_local_var || (is_in_schema && self.is_local_var_in_lambda(name));
                 if identifier.names.len() == 1 {
                     if is_in_schema && !is_local_var {
                         self.get_variable_in_schema(name)
@@ -2652,6 +2648,10 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
         ))
     }
 
+    /// Bind the arguments of a function call to the parameters in the function body.
+    /// The default values are evaluated in the function scope at each call before the
+    /// positional and keyword arguments are bound, so the defaults of lambdas see the
+    /// captured closure variables and need no extra handling.
     pub fn walk_arguments(
         &self,
         arguments: &'ctx Option<ast::NodeRef<ast::Arguments>>,
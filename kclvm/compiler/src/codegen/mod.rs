@@ -38,6 +38,11 @@ pub struct EmitOptions<'a> {
     pub emit_path: Option<&'a str>,
     /// no_link indicates whether to link the generated code of different KCL packages to the same module.
     pub no_link: bool,
+    /// LLVM target triple of the emitted object files e.g., `wasm32-wasi`, the host target is used if not set.
+    pub target: Option<&'a str>,
+    /// Variables of other packages in the form of `(pkgpath, name)`, which are returned together
+    /// with the global variables of the main package with the keys `@pkgpath.name`.
+    pub pkg_vars: &'a [(String, String)],
 }
 
 /// Emit code with the options using CodeGenContext.
@@ -74,12 +74,12 @@ where
         return;
     }
     let dst_filename = get_cache_filename(root, target, pkgpath, Some(&option.cache_dir));
+    let cache_dir = get_cache_dir(root, Some(&option.cache_dir));
+    create_dir_all(Path::new(&cache_dir).join(target)).unwrap();
     let real_path = get_pkg_realpath_from_pkgpath(root, pkgpath);
     if Path::new(&real_path).exists() {
         write_info_cache(root, target, Some(&option.cache_dir), &real_path).unwrap();
     }
-    let cache_dir = get_cache_dir(root, Some(&option.cache_dir));
-    create_dir_all(&cache_dir).unwrap();
     let tmp_filename = temp_file(&cache_dir, pkgpath);
     save_data_to_file(&dst_filename, &tmp_filename, data)
 }
@@ -149,7 +149,7 @@ pub fn write_info_cache(
     let dst_filename = get_cache_info_filename(root, target, cache_name);
     let cache_dir = get_cache_dir(root, cache_name);
     let path = Path::new(&cache_dir);
-    create_dir_all(path).unwrap();
+    create_dir_all(path.join(target)).unwrap();
     let relative_path = filepath.replacen(root, ".", 1);
     let cache_info = get_cache_info(filepath);
     let tmp_filename = temp_file(&cache_dir, "");
@@ -166,7 +166,7 @@ pub fn write_info_cache(
 }
 
 /// Return the information used to check if a file or path is already changed or not.
-fn get_cache_info(path_str: &str) -> CacheInfo {
+pub fn get_cache_info(path_str: &str) -> CacheInfo {
     let path = Path::new(path_str);
     let mut md5 = Md5::new();
     if path.is_file() {
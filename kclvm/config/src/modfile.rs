@@ -1,6 +1,7 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::io::Read;
 use toml;
 
@@ -15,6 +16,19 @@ pub struct KCLModFile {
     pub root_pkg: Option<String>,
     pub build: Option<KCLModFileBuildSection>,
     pub expected: Option<KCLModFileExpectedSection>,
+    pub lint: Option<KCLModFileLintSection>,
+    pub format: Option<KCLModFileFormatSection>,
+}
+
+impl KCLModFile {
+    /// Whether to cache the parsed and resolved packages, which is set by
+    /// `enable_pkg_cache` in the build section and disabled by default.
+    pub fn enable_pkg_cache(&self) -> bool {
+        self.build
+            .as_ref()
+            .and_then(|build| build.enable_pkg_cache)
+            .unwrap_or(false)
+    }
 }
 
 #[allow(dead_code)]
@@ -35,12 +49,48 @@ pub struct KCLModFileExpectedSection {
     pub global_version: Option<String>,
 }
 
+/// The lint section of `kcl.mod`, lints are referred by their codes e.g., `W0411`
+/// or their names e.g., `unused_import`.
+///
+/// ```toml
+/// [lint]
+/// enable = ["naming_convention"]
+/// disable = ["W0411"]
+///
+/// [lint.level]
+/// reimport = "error"
+/// ```
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+pub struct KCLModFileLintSection {
+    pub enable: Option<Vec<String>>,
+    pub disable: Option<Vec<String>>,
+    pub level: Option<HashMap<String, String>>,
+}
+
+/// The format section of `kcl.mod`, the quote style is one of `preserve`,
+/// `double` and `single`.
+///
+/// ```toml
+/// [format]
+/// indent_width = 2
+/// max_width = 100
+/// quote_style = "double"
+/// ```
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+pub struct KCLModFileFormatSection {
+    pub indent_width: Option<usize>,
+    pub max_width: Option<usize>,
+    pub quote_style: Option<String>,
+}
+
 pub fn get_pkg_root_from_paths(file_paths: &[String]) -> Result<String, String> {
     if file_paths.is_empty() {
         return Err("No input KCL files or paths".to_string());
     }
 
-    let mut m = std::collections::HashMap::<String, String>::new();
+    let mut m = HashMap::<String, String>::new();
     let mut last_root = "".to_string();
     for s in file_paths {
         if s.contains(KCL_MOD_PATH_ENV) {
@@ -142,6 +192,8 @@ mod modfile_test {
             kcl_mod.build.as_ref().unwrap().enable_pkg_cache.unwrap(),
             true
         );
+        assert!(kcl_mod.enable_pkg_cache());
+        assert!(!KCLModFile::default().enable_pkg_cache());
         assert_eq!(
             kcl_mod
                 .build
@@ -172,5 +224,13 @@ mod modfile_test {
                 .unwrap(),
             "v0.2.0"
         );
+        let lint = kcl_mod.lint.as_ref().unwrap();
+        assert_eq!(lint.enable.as_ref().unwrap(), &["naming_convention"]);
+        assert_eq!(lint.disable.as_ref().unwrap(), &["W0411", "reimport"]);
+        assert_eq!(lint.level.as_ref().unwrap()["W0404"], "error");
+        let format = kcl_mod.format.as_ref().unwrap();
+        assert_eq!(format.indent_width, Some(2));
+        assert_eq!(format.max_width, Some(100));
+        assert_eq!(format.quote_style.as_deref(), Some("double"));
     }
 }
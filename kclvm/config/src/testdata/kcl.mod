@@ -4,3 +4,12 @@ cached_pkg_prefix="pkg.path"
 [expected]
 kclvm_version="v0.3.0"
 kcl_plugin_version="v0.2.0"
+[lint]
+enable=["naming_convention"]
+disable=["W0411", "reimport"]
+[lint.level]
+W0404="error"
+[format]
+indent_width=2
+max_width=100
+quote_style="double"
@@ -3,16 +3,20 @@ use std::hash::Hash;
 
 use kclvm_span::Loc;
 use rustc_span::Pos;
+use serde::{Deserialize, Serialize};
 use termcolor::{Color, ColorSpec};
 
 use crate::{ErrorKind, WarningKind};
 
 /// Diagnostic structure.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Diagnostic {
     pub level: Level,
     pub messages: Vec<Message>,
     pub code: Option<DiagnosticId>,
+    /// Machine-applicable suggestions which fix the diagnostic.
+    #[serde(default)]
+    pub suggestions: Vec<Suggestion>,
 }
 
 /// Position describes an arbitrary source position including the filename,
@@ -20,7 +24,7 @@ pub struct Diagnostic {
 ///
 /// A Position is valid if the line number is > 0.
 /// The line and column are both 1 based.
-#[derive(PartialEq, Clone, Eq, Hash, Debug, Default)]
+#[derive(PartialEq, Clone, Eq, Hash, Debug, Default, Serialize, Deserialize)]
 pub struct Position {
     pub filename: String,
     pub line: u64,
@@ -37,6 +41,16 @@ impl Position {
         }
     }
 
+    /// The position of the start of the line.
+    #[inline]
+    pub fn line_start(filename: &str, line: u64) -> Self {
+        Position {
+            filename: filename.to_string(),
+            line,
+            column: None,
+        }
+    }
+
     #[inline]
     pub fn is_valid(&self) -> bool {
         self.line > 0
@@ -114,6 +128,7 @@ impl Diagnostic {
                 note: None,
             }],
             code,
+            suggestions: vec![],
         }
     }
 
@@ -123,7 +138,7 @@ impl Diagnostic {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Message {
     pub pos: Position,
     pub style: Style,
@@ -131,13 +146,72 @@ pub struct Message {
     pub note: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Suggestion is a machine-applicable fix of a diagnostic, all the replacements
+/// of a suggestion must be applied together.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub message: String,
+    pub replacements: Vec<Replacement>,
+}
+
+/// Replacement replaces the source text in the range `[start, end)` with `text`.
+///
+/// The line is 1 based and the column is the 0 based character offset in the
+/// line, a `None` column denotes the start of the line. Thus an empty range
+/// inserts the text and an empty text deletes the range.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Replacement {
+    pub start: Position,
+    pub end: Position,
+    pub text: String,
+}
+
+impl Replacement {
+    /// Replacement which removes the whole lines from `start_line` to `end_line`.
+    pub fn remove_lines(filename: &str, start_line: u64, end_line: u64) -> Self {
+        Self {
+            start: Position::line_start(filename, start_line),
+            end: Position::line_start(filename, end_line + 1),
+            text: "".to_string(),
+        }
+    }
+
+    /// Replacement which inserts the text before the line.
+    pub fn insert_before_line(filename: &str, line: u64, text: &str) -> Self {
+        let pos = Position::line_start(filename, line);
+        Self {
+            start: pos.clone(),
+            end: pos,
+            text: text.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DiagnosticId {
     Error(ErrorKind),
     Warning(WarningKind),
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+impl DiagnosticId {
+    /// Get the error code or the lint code of the diagnostic.
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            DiagnosticId::Error(kind) => kind.code(),
+            DiagnosticId::Warning(kind) => Some(kind.code()),
+        }
+    }
+
+    /// Get the error kind name or the lint name of the diagnostic.
+    pub fn name(&self) -> String {
+        match self {
+            DiagnosticId::Error(kind) => kind.name(),
+            DiagnosticId::Warning(kind) => kind.lint_name().to_string(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Level {
     Error,
     Warning,
@@ -179,7 +253,7 @@ impl fmt::Display for Level {
 /// Style indicates the style of error message:
 /// - `LineAndColumn` is <filename>:<line>:<column>
 /// - `Line` is <filename>:<line>
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Style {
     Empty,
     LineAndColumn,
@@ -1,6 +1,8 @@
 //! This module is used to gather all error codes into one place,
 //! the goal being to make their maintenance easier.
 
+use serde::{Deserialize, Serialize};
+
 macro_rules! register_errors {
     ($($ecode:ident: $kind:expr, $message:expr,)*) => (
         pub static ERRORS: &[(&str, Error)] = &[
@@ -38,7 +40,7 @@ pub struct Error {
     pub message: Option<&'static str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ErrorKind {
     InvalidSyntax,
     TabError,
@@ -86,6 +88,14 @@ impl ErrorKind {
     pub fn name(&self) -> String {
         return format!("{:?}", self);
     }
+
+    /// Get the registered error code of the error kind e.g., `E2G22`.
+    pub fn code(&self) -> Option<&'static str> {
+        ERRORS
+            .iter()
+            .find(|(_, err)| err.kind == *self)
+            .map(|(code, _)| *code)
+    }
 }
 
 /// Warning information of KCL. Usually something that does not conform to the specification but does not cause an error.
@@ -97,11 +107,18 @@ pub struct Warning {
 }
 
 // Kind of KCL warning.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WarningKind {
     UnusedImportWarning,
     ReimportWarning,
     ImportPositionWarning,
+    UnusedVariableWarning,
+    UnusedPrivateAttrWarning,
+    ShadowedNameWarning,
+    NamingConventionWarning,
+    PrivateAccessWarning,
+    UnreachableBranchWarning,
+    RedundantTypeAnnotationWarning,
 }
 
 /// Test warning `fmt`
@@ -157,4 +174,37 @@ impl WarningKind {
     pub fn name(&self) -> String {
         return format!("{:?}", self);
     }
+
+    /// Get the code of the lint which reports the warning e.g., `W0411`,
+    /// which is also the code of the lint definition.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            WarningKind::UnusedImportWarning => "W0411",
+            WarningKind::ReimportWarning => "W0404",
+            WarningKind::ImportPositionWarning => "W0413",
+            WarningKind::UnusedVariableWarning => "W0612",
+            WarningKind::UnusedPrivateAttrWarning => "W0238",
+            WarningKind::ShadowedNameWarning => "W0621",
+            WarningKind::NamingConventionWarning => "C0103",
+            WarningKind::PrivateAccessWarning => "W0212",
+            WarningKind::UnreachableBranchWarning => "W0125",
+            WarningKind::RedundantTypeAnnotationWarning => "W0107",
+        }
+    }
+
+    /// Get the name of the lint which reports the warning e.g., `unused_import`.
+    pub const fn lint_name(&self) -> &'static str {
+        match self {
+            WarningKind::UnusedImportWarning => "unused_import",
+            WarningKind::ReimportWarning => "reimport",
+            WarningKind::ImportPositionWarning => "import_position",
+            WarningKind::UnusedVariableWarning => "unused_variable",
+            WarningKind::UnusedPrivateAttrWarning => "unused_private_attr",
+            WarningKind::ShadowedNameWarning => "shadowed_name",
+            WarningKind::NamingConventionWarning => "naming_convention",
+            WarningKind::PrivateAccessWarning => "private_access",
+            WarningKind::UnreachableBranchWarning => "unreachable_branch",
+            WarningKind::RedundantTypeAnnotationWarning => "redundant_type_annotation",
+        }
+    }
 }
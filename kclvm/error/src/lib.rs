@@ -10,16 +10,22 @@ pub mod bug;
 mod diagnostic;
 mod emitter;
 mod error;
+mod output;
 #[cfg(test)]
 mod tests;
 
 use std::sync::Arc;
 
-pub use diagnostic::{Diagnostic, DiagnosticId, Level, Message, Position, Style};
+pub use diagnostic::{
+    Diagnostic, DiagnosticId, Level, Message, Position, Replacement, Style, Suggestion,
+};
 pub use emitter::{Emitter, EmitterWriter};
 pub use error::*;
 use indexmap::IndexSet;
 use kclvm_span::SourceMap;
+pub use output::{
+    DiagnosticRecord, DiagnosticsEmitter, JUnitEmitter, JsonEmitter, OutputFormat, SarifEmitter,
+};
 
 /// A handler deals with errors and other compiler output.
 /// Certain errors (error, bug) may cause immediate exit,
@@ -194,17 +200,7 @@ impl Handler {
 
     /// Put a runtime panic info the handler diagnostic buffer.
     pub fn add_panic_info(&mut self, panic_info: &PanicInfo) -> &mut Self {
-        let diag = Diagnostic::new_with_code(
-            Level::Error,
-            &panic_info.message,
-            Position {
-                filename: panic_info.kcl_file.clone(),
-                line: panic_info.kcl_line as u64,
-                column: Some(panic_info.kcl_col as u64),
-            },
-            Some(DiagnosticId::Error(E2L23.kind)),
-        );
-        self.add_diagnostic(diag);
+        self.add_diagnostic(panic_info.clone().into());
 
         self
     }
@@ -227,6 +223,7 @@ impl Handler {
             level: Level::Error,
             messages: msgs.to_owned(),
             code: Some(DiagnosticId::Error(err)),
+            suggestions: vec![],
         };
         self.add_diagnostic(diag);
 
@@ -247,10 +244,37 @@ impl Handler {
     /// );
     /// ```
     pub fn add_warning(&mut self, warning: WarningKind, msgs: &[Message]) -> &mut Self {
+        self.add_warning_with_suggestions(warning, msgs, vec![])
+    }
+
+    /// Add an warning with the machine-applicable suggestions into the handler
+    /// ```
+    /// use kclvm_error::*;
+    /// let mut handler = Handler::default();
+    /// handler.add_warning_with_suggestions(WarningKind::UnusedImportWarning, &[
+    ///     Message {
+    ///         pos: Position::dummy_pos(),
+    ///         style: Style::Line,
+    ///         message: "Module 'a' imported but unused.".to_string(),
+    ///         note: None,
+    ///     }],
+    ///     vec![Suggestion {
+    ///         message: "Remove the unused import".to_string(),
+    ///         replacements: vec![Replacement::remove_lines("main.k", 1, 1)],
+    ///     }],
+    /// );
+    /// ```
+    pub fn add_warning_with_suggestions(
+        &mut self,
+        warning: WarningKind,
+        msgs: &[Message],
+        suggestions: Vec<Suggestion>,
+    ) -> &mut Self {
         let diag = Diagnostic {
             level: Level::Warning,
             messages: msgs.to_owned(),
             code: Some(DiagnosticId::Warning(warning)),
+            suggestions,
         };
         self.add_diagnostic(diag);
 
@@ -266,6 +290,182 @@ impl Handler {
     }
 }
 
+/// The error kinds of the runtime error types in [`PanicInfo`].
+static ERR_TYPE_KINDS: &[(ErrType, ErrorKind)] = &[
+    (ErrType::InvalidSyntax_TYPE, ErrorKind::InvalidSyntax),
+    (ErrType::TabError_TYPE, ErrorKind::TabError),
+    (ErrType::IndentationError_TYPE, ErrorKind::Indentation),
+    (ErrType::CannotFindModule_TYPE, ErrorKind::CannotFindModule),
+    (ErrType::FailedLoadModule_TYPE, ErrorKind::CannotFindModule),
+    (ErrType::CompileError_TYPE, ErrorKind::CompileError),
+    (ErrType::EvaluationError_TYPE, ErrorKind::EvaluationError),
+    (ErrType::RecursiveLoad_TYPE, ErrorKind::RecursiveLoad),
+    (ErrType::FloatOverflow_TYPE, ErrorKind::FloatOverflow),
+    (ErrType::FloatUnderflow_TYPE, ErrorKind::FloatUnderflow),
+    (ErrType::IntOverflow_TYPE, ErrorKind::IntOverflow),
+    (ErrType::InvalidDocstring_TYPE, ErrorKind::InvalidDocstring),
+    (ErrType::Deprecated_TYPE, ErrorKind::Deprecated),
+    (ErrType::Deprecated_Warning_TYPE, ErrorKind::Deprecated),
+    (ErrType::UnKnownDecorator_TYPE, ErrorKind::UnKnownDecorator),
+    (
+        ErrType::InvalidDecoratorTarget_TYPE,
+        ErrorKind::InvalidDecoratorTarget,
+    ),
+    (
+        ErrType::InvalidFormatSpec_TYPE,
+        ErrorKind::InvalidFormatSpec,
+    ),
+    (
+        ErrType::SchemaCheckFailure_TYPE,
+        ErrorKind::SchemaCheckFailure,
+    ),
+    (
+        ErrType::IndexSignatureError_TYPE,
+        ErrorKind::IndexSignatureError,
+    ),
+    (ErrType::TypeError_Runtime_TYPE, ErrorKind::TypeError),
+    (ErrType::TypeError_Compile_TYPE, ErrorKind::TypeError),
+    (ErrType::NameError_TYPE, ErrorKind::NameError),
+    (ErrType::ValueError_TYPE, ErrorKind::ValueError),
+    (ErrType::KeyError_TYPE, ErrorKind::KeyError),
+    (ErrType::UniqueKeyError_TYPE, ErrorKind::UniqueKeyError),
+    (ErrType::AttributeError_TYPE, ErrorKind::AttributeError),
+    (
+        ErrType::AttributeError_Runtime_TYPE,
+        ErrorKind::AttributeError,
+    ),
+    (ErrType::AssertionError_TYPE, ErrorKind::AssertionError),
+    (
+        ErrType::ImmutableCompileError_TYPE,
+        ErrorKind::ImmutableError,
+    ),
+    (
+        ErrType::ImmutableRuntimeError_TYPE,
+        ErrorKind::ImmutableError,
+    ),
+    (
+        ErrType::MultiInheritError_TYPE,
+        ErrorKind::MultiInheritError,
+    ),
+    (
+        ErrType::CycleInheritError_TYPE,
+        ErrorKind::CycleInheritError,
+    ),
+    (
+        ErrType::IllegalInheritError_TYPE,
+        ErrorKind::IllegalInheritError,
+    ),
+    (
+        ErrType::IllegalAttributeError_TYPE,
+        ErrorKind::IllegalAttributeError,
+    ),
+    (
+        ErrType::IllegalArgumentError_TYPE,
+        ErrorKind::IllegalParameterError,
+    ),
+    (
+        ErrType::IllegalArgumentError_Complie_TYPE,
+        ErrorKind::IllegalParameterError,
+    ),
+    (
+        ErrType::IllegalArgumentError_Syntax_TYPE,
+        ErrorKind::IllegalParameterError,
+    ),
+    (ErrType::RecursionError_TYPE, ErrorKind::RecursionError),
+    (ErrType::PlanError_TYPE, ErrorKind::PlanError),
+    (ErrType::CannotAddMembers_TYPE, ErrorKind::CannotAddMembers),
+    (
+        ErrType::CannotAddMembers_Runtime_TYPE,
+        ErrorKind::CannotAddMembers,
+    ),
+];
+
+/// Convert a runtime panic info into a diagnostic. The position of the panic
+/// is the primary message, and the position of the config which fails the
+/// schema check, if any, is the secondary message.
+impl From<PanicInfo> for Diagnostic {
+    fn from(panic_info: PanicInfo) -> Self {
+        let kind = ERR_TYPE_KINDS
+            .iter()
+            .find(|(err_type, _)| *err_type as i32 == panic_info.err_type_code)
+            .map(|(_, kind)| kind.clone())
+            .unwrap_or(ErrorKind::EvaluationError);
+        let message = if panic_info.message.is_empty() {
+            panic_info.kcl_arg_msg.clone()
+        } else {
+            panic_info.message.clone()
+        };
+        let mut messages = vec![Message {
+            pos: Position {
+                filename: panic_info.kcl_file.clone(),
+                line: panic_info.kcl_line as u64,
+                column: Some(panic_info.kcl_col as u64),
+            },
+            style: Style::LineAndColumn,
+            message,
+            note: if panic_info.kcl_arg_msg.is_empty() || panic_info.message.is_empty() {
+                None
+            } else {
+                Some(panic_info.kcl_arg_msg.clone())
+            },
+        }];
+        if !panic_info.kcl_config_meta_file.is_empty() {
+            messages.push(Message {
+                pos: Position {
+                    filename: panic_info.kcl_config_meta_file.clone(),
+                    line: panic_info.kcl_config_meta_line as u64,
+                    column: Some(panic_info.kcl_config_meta_col as u64),
+                },
+                style: Style::LineAndColumn,
+                message: panic_info.kcl_config_meta_arg_msg.clone(),
+                note: None,
+            });
+        }
+        Diagnostic {
+            level: if panic_info.is_warning {
+                Level::Warning
+            } else {
+                Level::Error
+            },
+            messages,
+            code: Some(DiagnosticId::Error(kind)),
+            suggestions: vec![],
+        }
+    }
+}
+
+/// Convert a diagnostic into a runtime panic info, which is the reverse of
+/// `From<PanicInfo> for Diagnostic` and keeps the legacy panic info JSON
+/// output of the error.
+impl From<&Diagnostic> for PanicInfo {
+    fn from(diag: &Diagnostic) -> Self {
+        let mut panic_info = PanicInfo {
+            __kcl_PanicInfo__: true,
+            is_warning: diag.level == Level::Warning,
+            ..Default::default()
+        };
+        if let Some(DiagnosticId::Error(kind)) = &diag.code {
+            if let Some((err_type, _)) = ERR_TYPE_KINDS.iter().find(|(_, k)| k == kind) {
+                panic_info.err_type_code = *err_type as i32;
+            }
+        }
+        if let Some(msg) = diag.messages.first() {
+            panic_info.message = msg.message.clone();
+            panic_info.kcl_arg_msg = msg.note.clone().unwrap_or_default();
+            panic_info.kcl_file = msg.pos.filename.clone();
+            panic_info.kcl_line = msg.pos.line as i32;
+            panic_info.kcl_col = msg.pos.column.unwrap_or_default() as i32;
+        }
+        if let Some(msg) = diag.messages.get(1) {
+            panic_info.kcl_config_meta_file = msg.pos.filename.clone();
+            panic_info.kcl_config_meta_line = msg.pos.line as i32;
+            panic_info.kcl_config_meta_col = msg.pos.column.unwrap_or_default() as i32;
+            panic_info.kcl_config_meta_arg_msg = msg.message.clone();
+        }
+        panic_info
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ParseError {
     UnexpectedToken { expected: Vec<String>, got: String },
@@ -0,0 +1,287 @@
+//! Machine-readable diagnostic emitters, which render all the diagnostics of
+//! a check such as `kcl lint` into one JSON, SARIF or JUnit XML document.
+
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::Diagnostic;
+
+/// The SARIF schema version of [SarifEmitter].
+pub const SARIF_VERSION: &str = "2.1.0";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The machine-readable diagnostic output formats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Sarif,
+    JUnit,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(OutputFormat::Json),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "junit" => Ok(OutputFormat::JUnit),
+            _ => Err(format!(
+                "Unsupported output format '{}', expected one of json, sarif and junit",
+                s
+            )),
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Get the emitter of the output format, and `tool` is the name of the tool
+    /// which reports the diagnostics e.g., `kcl-lint`.
+    pub fn emitter(self, tool: &str) -> Box<dyn DiagnosticsEmitter> {
+        match self {
+            OutputFormat::Json => Box::new(JsonEmitter),
+            OutputFormat::Sarif => Box::new(SarifEmitter::new(tool)),
+            OutputFormat::JUnit => Box::new(JUnitEmitter::new(tool)),
+        }
+    }
+}
+
+/// DiagnosticsEmitter renders all the diagnostics into one document.
+pub trait DiagnosticsEmitter {
+    /// Render the diagnostics into a document string.
+    fn render(&self, diags: &[Diagnostic]) -> String;
+
+    /// Write the rendered document into `dst`.
+    fn emit_diagnostics(&self, dst: &mut dyn Write, diags: &[Diagnostic]) -> io::Result<()> {
+        writeln!(dst, "{}", self.render(diags))?;
+        dst.flush()
+    }
+}
+
+/// DiagnosticRecord is the flat form of a diagnostic with stable fields for
+/// machine-readable outputs. The position is the position of the first message
+/// of the diagnostic, and the line and column are both 1 based.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticRecord {
+    /// `error`, `warning` or `note`.
+    pub level: String,
+    /// The error code or the lint code e.g., `E2G22` and `W0411`.
+    pub code: Option<String>,
+    /// The error kind name or the lint name e.g., `TypeError` and `unused_import`.
+    pub name: Option<String>,
+    pub file: String,
+    pub line: u64,
+    pub column: Option<u64>,
+    pub message: String,
+    pub note: Option<String>,
+}
+
+impl From<&Diagnostic> for DiagnosticRecord {
+    fn from(diag: &Diagnostic) -> Self {
+        let msg = diag.messages.first();
+        Self {
+            level: diag.level.to_str().to_lowercase(),
+            code: diag
+                .code
+                .as_ref()
+                .and_then(|code| code.code())
+                .map(|code| code.to_string()),
+            name: diag.code.as_ref().map(|code| code.name()),
+            file: msg.map(|msg| msg.pos.filename.clone()).unwrap_or_default(),
+            line: msg.map(|msg| msg.pos.line).unwrap_or_default(),
+            // The column of the diagnostic position is 0 based.
+            column: msg.and_then(|msg| msg.pos.column).map(|column| column + 1),
+            message: msg.map(|msg| msg.message.clone()).unwrap_or_default(),
+            note: msg.and_then(|msg| msg.note.clone()),
+        }
+    }
+}
+
+/// JsonEmitter renders the diagnostics into a JSON array of [DiagnosticRecord].
+#[derive(Debug, Default)]
+pub struct JsonEmitter;
+
+impl DiagnosticsEmitter for JsonEmitter {
+    fn render(&self, diags: &[Diagnostic]) -> String {
+        let records: Vec<DiagnosticRecord> = diags.iter().map(DiagnosticRecord::from).collect();
+        serde_json::to_string_pretty(&records).unwrap()
+    }
+}
+
+/// SarifEmitter renders the diagnostics into a SARIF 2.1.0 log with one run,
+/// the codes of the diagnostics are the rule ids.
+#[derive(Debug)]
+pub struct SarifEmitter {
+    tool: String,
+}
+
+impl SarifEmitter {
+    pub fn new(tool: &str) -> Self {
+        Self {
+            tool: tool.to_string(),
+        }
+    }
+}
+
+impl DiagnosticsEmitter for SarifEmitter {
+    fn render(&self, diags: &[Diagnostic]) -> String {
+        let records: Vec<DiagnosticRecord> = diags.iter().map(DiagnosticRecord::from).collect();
+        let mut rules = IndexMap::new();
+        for record in &records {
+            if let Some(code) = &record.code {
+                rules.entry(code.clone()).or_insert_with(|| {
+                    json!({
+                        "id": code,
+                        // The rule name is required to be a string, use the
+                        // code as the name when the diagnostic has no name.
+                        "name": record.name.as_ref().unwrap_or(code),
+                    })
+                });
+            }
+        }
+        let results: Vec<serde_json::Value> = records
+            .iter()
+            .map(|record| {
+                let mut result = json!({
+                    "level": record.level,
+                    "message": {
+                        "text": match &record.note {
+                            Some(note) => format!("{}\n{}", record.message, note),
+                            None => record.message.clone(),
+                        },
+                    },
+                });
+                if let Some(code) = &record.code {
+                    result["ruleId"] = json!(code);
+                }
+                if !record.file.is_empty() {
+                    let mut location = json!({
+                        "physicalLocation": {
+                            "artifactLocation": {
+                                "uri": record.file,
+                            },
+                        },
+                    });
+                    if record.line > 0 {
+                        let mut region = json!({ "startLine": record.line });
+                        if let Some(column) = record.column {
+                            region["startColumn"] = json!(column);
+                        }
+                        location["physicalLocation"]["region"] = region;
+                    }
+                    result["locations"] = json!([location]);
+                }
+                result
+            })
+            .collect();
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": self.tool,
+                        "rules": rules.into_values().collect::<Vec<serde_json::Value>>(),
+                    },
+                },
+                "results": results,
+            }],
+        });
+        serde_json::to_string_pretty(&log).unwrap()
+    }
+}
+
+/// JUnitEmitter renders the diagnostics into a JUnit XML report. Each file is a
+/// test suite and each diagnostic is a failed test case of the file.
+#[derive(Debug)]
+pub struct JUnitEmitter {
+    tool: String,
+}
+
+impl JUnitEmitter {
+    pub fn new(tool: &str) -> Self {
+        Self {
+            tool: tool.to_string(),
+        }
+    }
+}
+
+impl DiagnosticsEmitter for JUnitEmitter {
+    fn render(&self, diags: &[Diagnostic]) -> String {
+        let mut suites: IndexMap<String, Vec<DiagnosticRecord>> = IndexMap::new();
+        for diag in diags {
+            let record = DiagnosticRecord::from(diag);
+            suites.entry(record.file.clone()).or_default().push(record);
+        }
+        let mut xml = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+            format!(
+                r#"<testsuites name="{}" tests="{}" failures="{}">"#,
+                xml_escape(&self.tool),
+                diags.len(),
+                diags.len()
+            ),
+        ];
+        for (file, records) in &suites {
+            xml.push(format!(
+                r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0">"#,
+                xml_escape(file),
+                records.len(),
+                records.len()
+            ));
+            for record in records {
+                let mut pos = format!("{}:{}", file, record.line);
+                if let Some(column) = record.column {
+                    pos += &format!(":{}", column);
+                }
+                let name = match (&record.code, &record.name) {
+                    (Some(code), Some(name)) => format!("{} {}", code, name),
+                    (None, Some(name)) => name.clone(),
+                    _ => record.level.clone(),
+                };
+                let mut text = format!("{}: {}", pos, record.message);
+                if let Some(note) = &record.note {
+                    text += &format!("\n{}", note);
+                }
+                xml.push(format!(
+                    r#"    <testcase name="{} at {}" classname="{}" file="{}" line="{}">"#,
+                    xml_escape(&name),
+                    xml_escape(&pos),
+                    xml_escape(file),
+                    xml_escape(file),
+                    record.line
+                ));
+                xml.push(format!(
+                    r#"      <failure type="{}" message="{}">{}</failure>"#,
+                    record.level,
+                    xml_escape(&record.message),
+                    xml_escape(&text)
+                ));
+                xml.push("    </testcase>".to_string());
+            }
+            xml.push("  </testsuite>".to_string());
+        }
+        xml.push("</testsuites>".to_string());
+        xml.join("\n")
+    }
+}
+
+/// Escape the XML special characters in the text and the attribute values.
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
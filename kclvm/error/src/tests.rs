@@ -15,3 +15,107 @@ fn test_bug_macro() {
     });
     assert!(result.is_err());
 }
+
+fn lint_diagnostics() -> Vec<Diagnostic> {
+    vec![
+        Diagnostic::new_with_code(
+            Level::Error,
+            "expected int, got str",
+            Position {
+                filename: "main.k".to_string(),
+                line: 2,
+                column: Some(4),
+            },
+            Some(DiagnosticId::Error(ErrorKind::TypeError)),
+        ),
+        Diagnostic::new_with_code(
+            Level::Warning,
+            "Module 'math' imported but unused",
+            Position {
+                filename: "main.k".to_string(),
+                line: 1,
+                column: None,
+            },
+            Some(DiagnosticId::Warning(WarningKind::UnusedImportWarning)),
+        ),
+    ]
+}
+
+#[test]
+fn test_json_emitter() {
+    let output = JsonEmitter.render(&lint_diagnostics());
+    let records: Vec<DiagnosticRecord> = serde_json::from_str(&output).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].level, "error");
+    assert_eq!(
+        records[0].code,
+        ErrorKind::TypeError.code().map(|c| c.to_string())
+    );
+    assert_eq!(records[0].name.as_deref(), Some("TypeError"));
+    assert_eq!(records[0].file, "main.k");
+    assert_eq!(records[0].line, 2);
+    assert_eq!(records[0].column, Some(5));
+    assert_eq!(records[1].level, "warning");
+    assert_eq!(records[1].code.as_deref(), Some("W0411"));
+    assert_eq!(records[1].name.as_deref(), Some("unused_import"));
+    assert_eq!(records[1].column, None);
+}
+
+#[test]
+fn test_sarif_emitter() {
+    let output = SarifEmitter::new("kcl-lint").render(&lint_diagnostics());
+    let log: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "kcl-lint");
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert!(rules.iter().all(|rule| rule["name"].is_string()));
+    assert_eq!(rules[1]["name"], "unused_import");
+    let result = &run["results"][1];
+    assert_eq!(result["ruleId"], "W0411");
+    assert_eq!(result["level"], "warning");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "main.k");
+    assert_eq!(location["region"]["startLine"], 1);
+    assert!(location["region"].get("startColumn").is_none());
+}
+
+#[test]
+fn test_junit_emitter() {
+    let output = JUnitEmitter::new("kcl-lint").render(&lint_diagnostics());
+    assert!(output.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+    assert!(output.contains(r#"<testsuites name="kcl-lint" tests="2" failures="2">"#));
+    assert_eq!(output.matches("<testsuite ").count(), 1);
+    assert!(output.contains(r#"message="Module &apos;math&apos; imported but unused""#));
+    assert!(output.contains("main.k:2:5: expected int, got str"));
+}
+
+#[test]
+fn test_output_format_from_str() {
+    assert_eq!("json".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+    assert_eq!("SARIF".parse::<OutputFormat>(), Ok(OutputFormat::Sarif));
+    assert_eq!("junit".parse::<OutputFormat>(), Ok(OutputFormat::JUnit));
+    assert!("xml".parse::<OutputFormat>().is_err());
+}
+
+#[test]
+fn test_panic_info_diagnostic_round_trip() {
+    let panic_info = PanicInfo {
+        __kcl_PanicInfo__: true,
+        kcl_file: "main.k".to_string(),
+        kcl_line: 8,
+        kcl_col: 4,
+        kcl_arg_msg: "Check failed on the condition".to_string(),
+        kcl_config_meta_file: "main.k".to_string(),
+        kcl_config_meta_line: 12,
+        kcl_config_meta_col: 1,
+        kcl_config_meta_arg_msg: "Instance check failed".to_string(),
+        message: "age must be positive".to_string(),
+        err_type_code: ErrType::SchemaCheckFailure_TYPE as i32,
+        ..Default::default()
+    };
+    let diag: Diagnostic = panic_info.clone().into();
+    assert_eq!(diag.messages.len(), 2);
+    assert_eq!(PanicInfo::from(&diag), panic_info);
+}
@@ -0,0 +1,255 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use std::rc::Rc;
+
+use kclvm_ast::ast;
+use kclvm_runtime::{type_pack_and_check, ValueRef};
+
+use crate::schema::BacktrackMeta;
+use crate::scope::Scope;
+use crate::{Evaluator, LAMBDA_CLOSURE, SCHEMA_SELF_NAME};
+
+/// The function body of a proxy function value, which records the definition
+/// and the package it is defined in.
+pub(crate) enum FunctionProxy<'ctx> {
+    Lambda {
+        expr: &'ctx ast::LambdaExpr,
+        pkgpath: String,
+        filename: String,
+        /// The schemas where the lambda is defined.
+        schema_stack: Vec<String>,
+    },
+    Schema {
+        stmt: &'ctx ast::SchemaStmt,
+        pkgpath: String,
+        filename: String,
+        runtime_type: String,
+    },
+    Rule {
+        stmt: &'ctx ast::RuleStmt,
+        pkgpath: String,
+        filename: String,
+        runtime_type: String,
+    },
+    SchemaAttr {
+        schema_stmt: &'ctx ast::SchemaStmt,
+        stmt: &'ctx ast::Node<ast::Stmt>,
+        name: String,
+        if_level: usize,
+        pkgpath: String,
+        filename: String,
+        runtime_type: String,
+    },
+}
+
+impl<'ctx> FunctionProxy<'ctx> {
+    /// The package path and the filename where the function is defined.
+    fn location(&self) -> (&str, &str) {
+        match self {
+            FunctionProxy::Lambda {
+                pkgpath, filename, ..
+            }
+            | FunctionProxy::Schema {
+                pkgpath, filename, ..
+            }
+            | FunctionProxy::Rule {
+                pkgpath, filename, ..
+            }
+            | FunctionProxy::SchemaAttr {
+                pkgpath, filename, ..
+            } => (pkgpath, filename),
+        }
+    }
+}
+
+/// The evaluation states saved when entering a function frame.
+struct Frame {
+    scopes: Vec<Scope>,
+    local_vars: std::collections::HashSet<String>,
+    schema_stack: Vec<String>,
+    lambda_stack: Vec<bool>,
+    target_vars: Vec<String>,
+    backtrack_meta: Option<BacktrackMeta>,
+}
+
+/// The proxy function handler registered in the runtime context, which calls
+/// the function numbered `proxy` in the evaluator at the address `data`.
+pub(crate) fn proxy_func_handler(
+    data: u64,
+    proxy: u64,
+    args: &ValueRef,
+    kwargs: &ValueRef,
+) -> ValueRef {
+    // Safety: the address is set by `Evaluator::main` and only used while the
+    // evaluator is running the main function of the runtime context.
+    let evaluator = unsafe { &*(data as usize as *const Evaluator) };
+    let function = evaluator
+        .functions
+        .borrow()
+        .get(proxy as usize)
+        .cloned()
+        .unwrap_or_else(|| panic!("proxy function {} is not found", proxy));
+    evaluator.call_proxy(&function, args, kwargs)
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// Call the proxy function `function` with the arguments.
+    pub(crate) fn call_proxy(
+        &self,
+        function: &Rc<FunctionProxy<'ctx>>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        match function.as_ref() {
+            FunctionProxy::Lambda {
+                expr, schema_stack, ..
+            } => self.with_frame(function, schema_stack.clone(), true, || {
+                self.lambda_body(expr, !schema_stack.is_empty(), args, kwargs)
+            }),
+            FunctionProxy::Schema {
+                stmt, runtime_type, ..
+            } => self.with_frame(function, vec![], false, || {
+                self.schema_body(stmt, runtime_type, args, kwargs)
+            }),
+            FunctionProxy::Rule {
+                stmt, runtime_type, ..
+            } => self.with_frame(function, vec![], false, || {
+                self.rule_body(stmt, runtime_type, args, kwargs)
+            }),
+            FunctionProxy::SchemaAttr {
+                schema_stmt,
+                stmt,
+                name,
+                if_level,
+                runtime_type,
+                ..
+            } => self.with_frame(function, vec![], false, || {
+                self.schema_attr_body(
+                    schema_stmt,
+                    stmt,
+                    name,
+                    *if_level,
+                    runtime_type,
+                    args,
+                    kwargs,
+                )
+            }),
+        }
+    }
+
+    /// Get the proxy function of the function value `func`.
+    fn function_proxy(&self, func: &ValueRef) -> Rc<FunctionProxy<'ctx>> {
+        let proxy = if func.is_func() {
+            func.as_function().proxy
+        } else {
+            None
+        };
+        match proxy {
+            Some(proxy) => self
+                .functions
+                .borrow()
+                .get(proxy as usize)
+                .cloned()
+                .unwrap_or_else(|| panic!("proxy function {} is not found", proxy)),
+            None => panic!("{} is not a schema or rule type", func.type_str()),
+        }
+    }
+
+    /// Call the schema or rule body function of the type function value `func`.
+    pub(crate) fn call_schema_body(
+        &self,
+        func: &ValueRef,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        let function = self.function_proxy(func);
+        self.call_proxy(&function, args, kwargs)
+    }
+
+    /// Call the schema or rule check function of the type function value `func`.
+    pub(crate) fn call_schema_check(
+        &self,
+        func: &ValueRef,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        let function = self.function_proxy(func);
+        match function.as_ref() {
+            FunctionProxy::Schema {
+                stmt, runtime_type, ..
+            } => self.with_frame(&function, vec![], false, || {
+                self.schema_check(stmt, runtime_type, args, kwargs)
+            }),
+            FunctionProxy::Rule {
+                stmt, runtime_type, ..
+            } => self.with_frame(&function, vec![], false, || {
+                self.rule_check(stmt, runtime_type, args, kwargs)
+            }),
+            _ => panic!("{} is not a schema or rule type", func.type_str()),
+        }
+    }
+
+    /// The lambda function body.
+    fn lambda_body(
+        &self,
+        lambda_expr: &'ctx ast::LambdaExpr,
+        is_in_schema: bool,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        let mut args = args.clone();
+        let closure_map = args
+            .list_pop_first()
+            .unwrap_or_else(|| ValueRef::dict(None));
+        self.add_variable(LAMBDA_CLOSURE, closure_map.clone());
+        if is_in_schema {
+            let schema_value = closure_map
+                .dict_get_value(SCHEMA_SELF_NAME)
+                .unwrap_or_else(ValueRef::undefined);
+            self.add_variable(SCHEMA_SELF_NAME, schema_value);
+        }
+        self.walk_arguments(&lambda_expr.args, &args, kwargs);
+        let value = self.walk_stmts(&lambda_expr.body);
+        // Check and convert the return value with the return type annotation
+        match &lambda_expr.return_type_str {
+            Some(return_type) => type_pack_and_check(&value, vec![return_type]),
+            None => value,
+        }
+    }
+
+    /// Run `f` in a new function frame of `function`, which only sees the builtin
+    /// and global scopes of the package where the function is defined.
+    fn with_frame<R>(
+        &self,
+        function: &FunctionProxy<'ctx>,
+        schema_stack: Vec<String>,
+        is_lambda: bool,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        let (pkgpath, filename) = function.location();
+        self.pkgpath_stack.borrow_mut().push(pkgpath.to_string());
+        self.filename_stack.borrow_mut().push(filename.to_string());
+        let frame = Frame {
+            scopes: self.with_current_scopes(|scopes| scopes.split_off(2.min(scopes.len()))),
+            local_vars: self.local_vars.take(),
+            schema_stack: self.schema_stack.replace(schema_stack),
+            lambda_stack: self.lambda_stack.replace(vec![is_lambda]),
+            target_vars: self.target_vars.take(),
+            backtrack_meta: self.backtrack_meta.take(),
+        };
+        self.enter_scope();
+        let result = f();
+        self.with_current_scopes(|scopes| {
+            scopes.truncate(2);
+            scopes.extend(frame.scopes);
+        });
+        self.local_vars.replace(frame.local_vars);
+        self.schema_stack.replace(frame.schema_stack);
+        self.lambda_stack.replace(frame.lambda_stack);
+        self.target_vars.replace(frame.target_vars);
+        self.backtrack_meta.replace(frame.backtrack_meta);
+        self.filename_stack.borrow_mut().pop();
+        self.pkgpath_stack.borrow_mut().pop();
+        result
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+//! The KCL AST evaluator is a tree-walking interpreter backend that runs a
+//! resolved [`ast::Program`] directly on the KCL runtime values and functions,
+//! as an alternative to compiling it into a native library with LLVM.
+//!
+//! The evaluator keeps the same execution model as the LLVM code generator:
+//! lambdas, schemas, schema attributes and rules are all represented as
+//! runtime function values, but their bodies are proxy functions numbered in
+//! the evaluator and called back through `Context::proxy_func_handler`.
+
+mod function;
+mod node;
+mod schema;
+mod scope;
+mod value;
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_ast::walker::TypedResultWalker;
+use kclvm_runtime::{Context, ValueRef, MAIN_PKG_PATH, PKG_PATH_PREFIX};
+
+use crate::function::FunctionProxy;
+use crate::schema::BacktrackMeta;
+use crate::scope::Scope;
+
+/// Scope level of the global scope, 0 denotes the builtin scope.
+pub(crate) const GLOBAL_LEVEL: usize = 1;
+/// Scope level of the schema, rule and lambda function body.
+pub(crate) const SCHEMA_LEVEL: usize = 2;
+
+/// Magic variable names used by lambdas, schemas and rules.
+pub(crate) const LAMBDA_CLOSURE: &str = "$lambda_closure";
+pub(crate) const SCHEMA_SELF_NAME: &str = "$schema_self";
+pub(crate) const SCHEMA_CONFIG_NAME: &str = "$schema_config";
+pub(crate) const SCHEMA_CONFIG_META_NAME: &str = "$schema_config_meta";
+pub(crate) const SCHEMA_CAL_MAP: &str = "$schema_cal_map";
+pub(crate) const SCHEMA_ARGS: &str = "$schema_args";
+pub(crate) const SCHEMA_KWARGS: &str = "$schema_kwargs";
+pub(crate) const SCHEMA_RUNTIME_TYPE: &str = "$schema_runtime_type";
+pub(crate) const BACKTRACK_LEVEL_MAP: &str = "$backtrack_level_map";
+pub(crate) const BACKTRACK_CACHE: &str = "$backtrack_cache";
+
+/// Evaluator is the AST evaluator context including the program, the package
+/// scopes and all the evaluation states corresponding to the compile states
+/// of the LLVM code generator.
+pub struct Evaluator<'ctx> {
+    pub program: &'ctx ast::Program,
+    /// Import names mapping of each filename, e.g., {"main.k": {"pkg": "path.to.pkg"}}
+    pub import_names: IndexMap<String, IndexMap<String, String>>,
+    /// Variables of other packages in the form of `(pkgpath, name)`, which are returned
+    /// together with the global variables of the main package with the keys
+    /// `@pkgpath.name`, see [kclvm_runtime::pkg_var_key].
+    pub pkg_vars: Vec<(String, String)>,
+    pub(crate) pkgpath_stack: RefCell<Vec<String>>,
+    pub(crate) filename_stack: RefCell<Vec<String>>,
+    pub(crate) pkg_scopes: RefCell<HashMap<String, Vec<Scope>>>,
+    pub(crate) imported: RefCell<HashSet<String>>,
+    pub(crate) local_vars: RefCell<HashSet<String>>,
+    /// Runtime types of the schemas being evaluated.
+    pub(crate) schema_stack: RefCell<Vec<String>>,
+    pub(crate) lambda_stack: RefCell<Vec<bool>>,
+    pub(crate) target_vars: RefCell<Vec<String>>,
+    pub(crate) backtrack_meta: RefCell<Option<BacktrackMeta>>,
+    pub(crate) current_line: RefCell<u64>,
+    /// All proxy functions, the function value proxy is the index in the list.
+    pub(crate) functions: RefCell<Vec<Rc<FunctionProxy<'ctx>>>>,
+    /// Proxy function indexes of each function definition, which makes a
+    /// function defined many times (e.g. in a schema body) share one proxy.
+    pub(crate) function_indexes: RefCell<HashMap<(usize, String, usize), u64>>,
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// New an evaluator using the AST program and the import names mapping.
+    pub fn new(
+        program: &'ctx ast::Program,
+        import_names: IndexMap<String, IndexMap<String, String>>,
+    ) -> Self {
+        Self {
+            program,
+            import_names,
+            pkg_vars: vec![],
+            pkgpath_stack: RefCell::new(vec![MAIN_PKG_PATH.to_string()]),
+            filename_stack: RefCell::new(vec![]),
+            pkg_scopes: RefCell::new(HashMap::new()),
+            imported: RefCell::new(HashSet::new()),
+            local_vars: RefCell::new(HashSet::new()),
+            schema_stack: RefCell::new(vec![]),
+            lambda_stack: RefCell::new(vec![false]),
+            target_vars: RefCell::new(vec![]),
+            backtrack_meta: RefCell::new(None),
+            current_line: RefCell::new(0),
+            functions: RefCell::new(vec![]),
+            function_indexes: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Evaluate the main package in the current runtime context and return
+    /// the JSON string of all the global variables.
+    pub fn run(&self) -> String {
+        let ctx = Context::current_context_mut();
+        for (filename, names) in &self.import_names {
+            let names = names
+                .iter()
+                .map(|(pkgname, pkgpath)| {
+                    (
+                        pkgname.to_string(),
+                        format!("{}{}", PKG_PATH_PREFIX, pkgpath),
+                    )
+                })
+                .collect();
+            ctx.import_names.insert(filename.to_string(), names);
+        }
+        self.init_scope(MAIN_PKG_PATH);
+        let modules = self
+            .program
+            .pkgs
+            .get(MAIN_PKG_PATH)
+            .unwrap_or_else(|| panic!("pkgpath {} is not found", MAIN_PKG_PATH));
+        for module in modules {
+            self.filename_stack
+                .borrow_mut()
+                .push(module.filename.clone());
+            self.walk_module(module);
+        }
+        self.globals_to_json_str()
+    }
+
+    /// The main function called by the runtime entry e.g.,
+    /// `kclvm_runtime::kcl_run_with_main`, which registers the evaluator as the
+    /// proxy function handler of the runtime context `ctx`, evaluates the
+    /// program and returns the JSON string value of all the global variables.
+    pub fn main(&self, ctx: &mut Context) -> ValueRef {
+        let handler: kclvm_runtime::ProxyFuncHandler = function::proxy_func_handler;
+        ctx.proxy_func_handler = handler as usize as u64;
+        ctx.proxy_func_data = self as *const Self as usize as u64;
+        let result = ValueRef::str(&self.run());
+        ctx.proxy_func_handler = 0;
+        ctx.proxy_func_data = 0;
+        result
+    }
+}
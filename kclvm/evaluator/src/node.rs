@@ -0,0 +1,1298 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use kclvm_ast::ast;
+use kclvm_ast::walker::TypedResultWalker;
+use kclvm_runtime::{Context, ValueRef, PKG_PATH_PREFIX};
+use kclvm_sema::{builtin, plugin};
+
+use crate::function::FunctionProxy;
+use crate::value::*;
+use crate::{
+    Evaluator, BACKTRACK_CACHE, GLOBAL_LEVEL, SCHEMA_CAL_MAP, SCHEMA_CONFIG_META_NAME,
+    SCHEMA_CONFIG_NAME, SCHEMA_LEVEL, SCHEMA_RUNTIME_TYPE, SCHEMA_SELF_NAME,
+};
+
+/// Return the none value when the schema attribute backtracking stops, which
+/// corresponds to the code not generated by the LLVM code generator.
+macro_rules! check_backtrack_stop {
+    ($evaluator: expr) => {
+        if $evaluator.is_backtrack_stopped() {
+            return ValueRef::none();
+        }
+    };
+}
+
+/// Impl TypedResultWalker for Evaluator to visit AST nodes to evaluate the result.
+impl<'ctx> TypedResultWalker<'ctx> for Evaluator<'ctx> {
+    type Result = ValueRef;
+
+    /*
+     * Stmt
+     */
+
+    fn walk_stmt(&self, stmt: &'ctx ast::Node<ast::Stmt>) -> Self::Result {
+        check_backtrack_stop!(self);
+        if self.backtrack_skip_stmt(stmt) {
+            return ValueRef::none();
+        }
+        self.update_ctx_filename(stmt);
+        self.update_ctx_line_col(stmt);
+        self.target_vars.borrow_mut().clear();
+        self.target_vars.borrow_mut().push("".to_string());
+        match &stmt.node {
+            ast::Stmt::TypeAlias(type_alias) => self.walk_type_alias_stmt(type_alias),
+            ast::Stmt::Expr(expr_stmt) => self.walk_expr_stmt(expr_stmt),
+            ast::Stmt::Unification(unification_stmt) => {
+                self.walk_unification_stmt(unification_stmt)
+            }
+            ast::Stmt::Assign(assign_stmt) => self.walk_assign_stmt(assign_stmt),
+            ast::Stmt::AugAssign(aug_assign_stmt) => self.walk_aug_assign_stmt(aug_assign_stmt),
+            ast::Stmt::Assert(assert_stmt) => self.walk_assert_stmt(assert_stmt),
+            ast::Stmt::If(if_stmt) => self.walk_if_stmt(if_stmt),
+            ast::Stmt::Import(import_stmt) => self.walk_import_stmt(import_stmt),
+            ast::Stmt::SchemaAttr(schema_attr) => self.walk_schema_attr(schema_attr),
+            ast::Stmt::Schema(schema_stmt) => self.walk_schema_stmt(schema_stmt),
+            ast::Stmt::Rule(rule_stmt) => self.walk_rule_stmt(rule_stmt),
+        }
+    }
+
+    fn walk_expr_stmt(&self, expr_stmt: &'ctx ast::ExprStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut result = ValueRef::none();
+        for expr in &expr_stmt.exprs {
+            // Ignore the doc string
+            if !matches!(&expr.node, ast::Expr::StringLit(..)) {
+                result = self.walk_expr(expr);
+            }
+        }
+        result
+    }
+
+    fn walk_unification_stmt(&self, unification_stmt: &'ctx ast::UnificationStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.local_vars.borrow_mut().clear();
+        let name = &unification_stmt.target.node.names[0];
+        self.target_vars.borrow_mut().push(name.clone());
+        // The right value of the unification_stmt is a schema_expr.
+        let value = self.walk_schema_expr(&unification_stmt.value.node);
+        let target = &unification_stmt.target.node;
+        if self.scope_level() == GLOBAL_LEVEL || self.is_in_lambda() {
+            if self.resolve_variable(name) {
+                let org_value =
+                    self.walk_identifier_with_ctx(target, &ast::ExprContext::Load, None);
+                let value = aug(kclvm_runtime::kclvm_value_op_aug_bit_or, &org_value, &value);
+                // Store the identifier value
+                self.walk_identifier_with_ctx(
+                    target,
+                    &ast::ExprContext::Store,
+                    Some(value.clone()),
+                );
+                value
+            } else {
+                self.walk_identifier_with_ctx(target, &target.ctx, Some(value.clone()));
+                value
+            }
+        // Local variables including schema/rule/lambda
+        } else if self.is_in_schema() {
+            // Load the identifier value
+            let org_value = self.walk_identifier_with_ctx(target, &ast::ExprContext::Load, None);
+            let value = binary(kclvm_runtime::kclvm_value_op_bit_or, &org_value, &value);
+            // Store the identifier value
+            self.walk_identifier_with_ctx(target, &ast::ExprContext::Store, Some(value.clone()));
+            value
+        } else {
+            value
+        }
+    }
+
+    fn walk_type_alias_stmt(&self, _type_alias_stmt: &'ctx ast::TypeAliasStmt) -> Self::Result {
+        // Nothing to do, because all type aliases have been replaced at compile time
+        ValueRef::none()
+    }
+
+    fn walk_assign_stmt(&self, assign_stmt: &'ctx ast::AssignStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.local_vars.borrow_mut().clear();
+        for name in &assign_stmt.targets {
+            self.target_vars
+                .borrow_mut()
+                .push(name.node.names[0].clone());
+        }
+        // Load the right value
+        let mut value = self.walk_expr(&assign_stmt.value);
+        if let Some(type_annotation) = &assign_stmt.type_annotation {
+            value = convert_collection_value(&value, &type_annotation.node);
+        }
+        if assign_stmt.targets.len() == 1 {
+            let name = &assign_stmt.targets[0];
+            self.walk_identifier_with_ctx(&name.node, &name.node.ctx, Some(value.clone()));
+        } else {
+            // Store targets
+            for name in &assign_stmt.targets {
+                if self.is_backtrack_stopped() {
+                    break;
+                }
+                self.walk_identifier_with_ctx(&name.node, &name.node.ctx, Some(value.deep_copy()));
+            }
+        }
+        value
+    }
+
+    fn walk_aug_assign_stmt(&self, aug_assign_stmt: &'ctx ast::AugAssignStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.target_vars
+            .borrow_mut()
+            .push(aug_assign_stmt.target.node.names[0].clone());
+        // Load the right value
+        let right_value = self.walk_expr(&aug_assign_stmt.value);
+        // Load the identifier value
+        let org_value = self.walk_identifier_with_ctx(
+            &aug_assign_stmt.target.node,
+            &ast::ExprContext::Load,
+            None,
+        );
+        let value = aug_assign(&aug_assign_stmt.op, &org_value, &right_value);
+        // Store the identifier value
+        self.walk_identifier_with_ctx(
+            &aug_assign_stmt.target.node,
+            &ast::ExprContext::Store,
+            Some(value.clone()),
+        );
+        value
+    }
+
+    fn walk_assert_stmt(&self, assert_stmt: &'ctx ast::AssertStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        if let Some(if_cond) = &assert_stmt.if_cond {
+            if !self.walk_expr(if_cond).is_truthy() {
+                return ValueRef::none();
+            }
+        }
+        let assert_result = self.walk_expr(&assert_stmt.test);
+        let msg = match &assert_stmt.msg {
+            Some(msg) => self.walk_expr(msg),
+            None => ValueRef::str(""),
+        };
+        kclvm_runtime::kclvm_assert(&assert_result, &msg);
+        ValueRef::none()
+    }
+
+    fn walk_if_stmt(&self, if_stmt: &'ctx ast::IfStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let cond = self.walk_expr(&if_stmt.cond);
+        if cond.is_truthy() {
+            self.walk_stmts(&if_stmt.body);
+        } else {
+            self.walk_stmts(&if_stmt.orelse);
+        }
+        ValueRef::none()
+    }
+
+    fn walk_import_stmt(&self, import_stmt: &'ctx ast::ImportStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let pkgpath = import_stmt.path.as_str();
+        if self.imported.borrow().contains(pkgpath) {
+            return ValueRef::none();
+        }
+        // Nothing to do on the builtin system module import because the check has been done.
+        if !builtin::STANDARD_SYSTEM_MODULES.contains(&pkgpath)
+            && !pkgpath.starts_with(plugin::PLUGIN_MODULE_PREFIX)
+        {
+            let pkgpath_with_prefix = format!("{}{}", PKG_PATH_PREFIX, pkgpath);
+            self.pkgpath_stack
+                .borrow_mut()
+                .push(pkgpath_with_prefix.clone());
+            if let Some(modules) = self.program.pkgs.get(pkgpath) {
+                // Init all builtin functions.
+                self.init_scope(&pkgpath_with_prefix);
+                // Evaluate the ast module in the pkgpath.
+                for module in modules {
+                    self.filename_stack
+                        .borrow_mut()
+                        .push(module.filename.clone());
+                    self.compile_module_import_and_types(module);
+                    self.filename_stack.borrow_mut().pop();
+                }
+                for module in modules {
+                    self.filename_stack
+                        .borrow_mut()
+                        .push(module.filename.clone());
+                    self.walk_stmts_except_import(&module.body);
+                    self.filename_stack.borrow_mut().pop();
+                }
+            }
+            self.pkgpath_stack.borrow_mut().pop();
+        }
+        self.imported.borrow_mut().insert(pkgpath.to_string());
+        ValueRef::none()
+    }
+
+    fn walk_schema_stmt(&self, schema_stmt: &'ctx ast::SchemaStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let name = &schema_stmt.name.node;
+        let pkgpath = self.current_pkgpath();
+        let runtime_type = kclvm_runtime::schema_runtime_type(name, &pkgpath);
+        let proxy = self.proxy_function(
+            schema_stmt as *const ast::SchemaStmt as usize,
+            "",
+            0,
+            || FunctionProxy::Schema {
+                stmt: schema_stmt,
+                pkgpath: pkgpath.clone(),
+                filename: self.current_filename(),
+                runtime_type: runtime_type.clone(),
+            },
+        );
+        let function = self.schema_function_value(proxy, &runtime_type);
+        // Store or add the variable in the scope
+        if !self.store_variable(name, function.clone()) {
+            self.add_variable(name, function.clone());
+        }
+        function
+    }
+
+    fn walk_rule_stmt(&self, rule_stmt: &'ctx ast::RuleStmt) -> Self::Result {
+        check_backtrack_stop!(self);
+        let name = &rule_stmt.name.node;
+        let pkgpath = self.current_pkgpath();
+        let runtime_type = kclvm_runtime::schema_runtime_type(name, &pkgpath);
+        let proxy = self.proxy_function(rule_stmt as *const ast::RuleStmt as usize, "", 0, || {
+            FunctionProxy::Rule {
+                stmt: rule_stmt,
+                pkgpath: pkgpath.clone(),
+                filename: self.current_filename(),
+                runtime_type: runtime_type.clone(),
+            }
+        });
+        let function = self.schema_function_value(proxy, &runtime_type);
+        // Store or add the variable in the scope
+        if !self.store_variable(name, function.clone()) {
+            self.add_variable(name, function.clone());
+        }
+        function
+    }
+
+    /*
+     * Expr
+     */
+
+    fn walk_expr(&self, expr: &'ctx ast::Node<ast::Expr>) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.update_ctx_filename(expr);
+        self.update_ctx_line_col(expr);
+        match &expr.node {
+            ast::Expr::Identifier(identifier) => self.walk_identifier(identifier),
+            ast::Expr::Unary(unary_expr) => self.walk_unary_expr(unary_expr),
+            ast::Expr::Binary(binary_expr) => self.walk_binary_expr(binary_expr),
+            ast::Expr::If(if_expr) => self.walk_if_expr(if_expr),
+            ast::Expr::Selector(selector_expr) => self.walk_selector_expr(selector_expr),
+            ast::Expr::Call(call_expr) => self.walk_call_expr(call_expr),
+            ast::Expr::Paren(paren_expr) => self.walk_paren_expr(paren_expr),
+            ast::Expr::Quant(quant_expr) => self.walk_quant_expr(quant_expr),
+            ast::Expr::List(list_expr) => self.walk_list_expr(list_expr),
+            ast::Expr::ListIfItem(list_if_item_expr) => {
+                self.walk_list_if_item_expr(list_if_item_expr)
+            }
+            ast::Expr::ListComp(list_comp) => self.walk_list_comp(list_comp),
+            ast::Expr::Starred(starred_expr) => self.walk_starred_expr(starred_expr),
+            ast::Expr::DictComp(dict_comp) => self.walk_dict_comp(dict_comp),
+            ast::Expr::ConfigIfEntry(config_if_entry_expr) => {
+                self.walk_config_if_entry_expr(config_if_entry_expr)
+            }
+            ast::Expr::CompClause(comp_clause) => self.walk_comp_clause(comp_clause),
+            ast::Expr::Schema(schema_expr) => self.walk_schema_expr(schema_expr),
+            ast::Expr::Config(config_expr) => self.walk_config_expr(config_expr),
+            ast::Expr::Check(check) => self.walk_check_expr(check),
+            ast::Expr::Lambda(lambda) => self.walk_lambda_expr(lambda),
+            ast::Expr::Subscript(subscript) => self.walk_subscript(subscript),
+            ast::Expr::Keyword(keyword) => self.walk_keyword(keyword),
+            ast::Expr::Arguments(..) => ValueRef::none(),
+            ast::Expr::Compare(compare) => self.walk_compare(compare),
+            ast::Expr::NumberLit(number_lit) => self.walk_number_lit(number_lit),
+            ast::Expr::StringLit(string_lit) => self.walk_string_lit(string_lit),
+            ast::Expr::NameConstantLit(name_constant_lit) => {
+                self.walk_name_constant_lit(name_constant_lit)
+            }
+            ast::Expr::JoinedString(joined_string) => self.walk_joined_string(joined_string),
+            ast::Expr::FormattedValue(formatted_value) => {
+                self.walk_formatted_value(formatted_value)
+            }
+            ast::Expr::Missing(missing_expr) => self.walk_missing_expr(missing_expr),
+        }
+    }
+
+    fn walk_quant_expr(&self, quant_expr: &'ctx ast::QuantExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let result = match quant_expr.op {
+            ast::QuantOperation::All => ValueRef::bool(true),
+            ast::QuantOperation::Any => ValueRef::bool(false),
+            ast::QuantOperation::Map => ValueRef::list(None),
+            ast::QuantOperation::Filter => self.walk_expr(&quant_expr.target).deep_copy(),
+        };
+        // Iterator
+        let iter_host_value = if let ast::QuantOperation::Filter = quant_expr.op {
+            result.deep_copy()
+        } else {
+            self.walk_expr(&quant_expr.target)
+        };
+        let mut iter = iter_host_value.iter();
+        self.enter_scope();
+        let variables = &quant_expr.variables;
+        let mut quant_result = None;
+        while !iter.is_end() {
+            let next_value = match iter.next(&iter_host_value) {
+                Some(value) => value.clone(),
+                None => break,
+            };
+            {
+                let mut local_vars = self.local_vars.borrow_mut();
+                for v in variables {
+                    local_vars.insert(v.node.names[0].clone());
+                }
+            }
+            if variables.len() == 1 {
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &variables[0].node,
+                    &ast::ExprContext::Store,
+                    Some(next_value.clone()),
+                );
+            } else if variables.len() == 2 {
+                let key = iter.key().cloned().unwrap_or_else(ValueRef::none);
+                let value = iter.value().cloned().unwrap_or_else(ValueRef::none);
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &variables[0].node,
+                    &ast::ExprContext::Store,
+                    Some(key),
+                );
+                self.walk_identifier_with_ctx(
+                    &variables[1].node,
+                    &ast::ExprContext::Store,
+                    Some(value),
+                );
+            } else {
+                panic!(
+                    "the number of loop variables is {}, which can only be 1 or 2",
+                    variables.len()
+                )
+            }
+            if let Some(if_expr) = &quant_expr.if_cond {
+                if !self.walk_expr(if_expr).is_truthy() {
+                    continue;
+                }
+            }
+            // Body block
+            let value = self.walk_expr(&quant_expr.test);
+            let is_truth = value.is_truthy();
+            match quant_expr.op {
+                ast::QuantOperation::All => {
+                    if !is_truth {
+                        quant_result = Some(ValueRef::bool(false));
+                        break;
+                    }
+                }
+                ast::QuantOperation::Any => {
+                    if is_truth {
+                        quant_result = Some(ValueRef::bool(true));
+                        break;
+                    }
+                }
+                ast::QuantOperation::Filter => {
+                    if !is_truth {
+                        remove_item(&result, &next_value);
+                    }
+                }
+                ast::QuantOperation::Map => {
+                    let mut result = result.clone();
+                    result.list_append(&value);
+                }
+            }
+        }
+        self.leave_scope();
+        self.local_vars.borrow_mut().clear();
+        quant_result.unwrap_or(result)
+    }
+
+    fn walk_schema_attr(&self, schema_attr: &'ctx ast::SchemaAttr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.local_vars.borrow_mut().clear();
+        let name = schema_attr.name.node.as_str();
+        self.target_vars.borrow_mut().push(name.to_string());
+        for decorator in &schema_attr.decorators {
+            self.walk_decorator_with_name(&decorator.node, Some(name), false);
+        }
+        let value = match &schema_attr.value {
+            Some(value) => self.walk_expr(value),
+            None => ValueRef::undefined(),
+        };
+        let config_value = self.get_variable(SCHEMA_CONFIG_NAME);
+        let schema_value = self.get_variable(SCHEMA_SELF_NAME);
+        config_attr_map(&schema_value, name, &schema_attr.type_str.node);
+        if let Some(op) = &schema_attr.op {
+            match op {
+                // Union
+                ast::BinOrAugOp::Aug(ast::AugOp::BitOr) => {
+                    let org_value = schema_value
+                        .dict_get_value(name)
+                        .unwrap_or_else(ValueRef::undefined);
+                    let value = binary(kclvm_runtime::kclvm_value_op_bit_or, &org_value, &value);
+                    dict_merge(&schema_value, name, &value, 1, -1);
+                }
+                // Assign
+                _ => dict_merge(&schema_value, name, &value, 1, -1),
+            }
+        }
+        if config_value.has_key(name) {
+            let config_attr_value = config_value
+                .dict_get_entry(name)
+                .unwrap_or_else(ValueRef::undefined);
+            value_union(&schema_value, &config_attr_value);
+            let cal_map = self.get_variable(SCHEMA_CAL_MAP);
+            let backtrack_cache = self.get_variable(BACKTRACK_CACHE);
+            let runtime_type = self.get_variable(SCHEMA_RUNTIME_TYPE);
+            schema_backtrack_cache(
+                &schema_value,
+                &backtrack_cache,
+                &cal_map,
+                name,
+                &runtime_type,
+            );
+        }
+        // Update backtrack meta
+        self.update_backtrack_meta(name);
+        schema_value
+    }
+
+    fn walk_if_expr(&self, if_expr: &'ctx ast::IfExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let cond = self.walk_expr(&if_expr.cond);
+        if cond.is_truthy() {
+            self.walk_expr(&if_expr.body)
+        } else {
+            self.walk_expr(&if_expr.orelse)
+        }
+    }
+
+    fn walk_unary_expr(&self, unary_expr: &'ctx ast::UnaryExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let value = self.walk_expr(&unary_expr.operand);
+        unary(unary_fn(&unary_expr.op), &value)
+    }
+
+    fn walk_binary_expr(&self, binary_expr: &'ctx ast::BinaryExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        match &binary_expr.op {
+            // Logic operators with the short-circuit evaluation
+            ast::BinOrCmpOp::Bin(ast::BinOp::And) | ast::BinOrCmpOp::Bin(ast::BinOp::Or) => {
+                let jump_if_false = matches!(binary_expr.op, ast::BinOrCmpOp::Bin(ast::BinOp::And));
+                let left_value = self.walk_expr(&binary_expr.left);
+                if left_value.is_truthy() != jump_if_false {
+                    left_value
+                } else {
+                    self.walk_expr(&binary_expr.right)
+                }
+            }
+            ast::BinOrCmpOp::Bin(op) => {
+                let left_value = self.walk_expr(&binary_expr.left);
+                let right_value = if matches!(op, ast::BinOp::As) {
+                    match &binary_expr.right.node {
+                        ast::Expr::Identifier(id) => ValueRef::str(&id.names.join(".")),
+                        _ => ValueRef::none(),
+                    }
+                } else {
+                    self.walk_expr(&binary_expr.right)
+                };
+                binary(binary_fn(op), &left_value, &right_value)
+            }
+            ast::BinOrCmpOp::Cmp(op) => {
+                let left_value = self.walk_expr(&binary_expr.left);
+                let right_value = self.walk_expr(&binary_expr.right);
+                binary(compare_fn(op), &left_value, &right_value)
+            }
+        }
+    }
+
+    fn walk_selector_expr(&self, selector_expr: &'ctx ast::SelectorExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut value = self.walk_expr(&selector_expr.value);
+        let names = &selector_expr.attr.node.names;
+        value = load_attr(&value, &names[0], selector_expr.has_question);
+        for name in &names[1..] {
+            value = load_attr(&value, name, false);
+        }
+        value
+    }
+
+    fn walk_call_expr(&self, call_expr: &'ctx ast::CallExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let func = self.walk_expr(&call_expr.func);
+        // args
+        let mut list_value = ValueRef::list(None);
+        for arg in &call_expr.args {
+            let value = self.walk_expr(arg);
+            list_value.list_append(&value);
+        }
+        // kwargs
+        let dict_value = ValueRef::dict(None);
+        for keyword in &call_expr.keywords {
+            let name = &keyword.node.arg.node.names[0];
+            let value = match &keyword.node.value {
+                Some(value) => self.walk_expr(value),
+                None => ValueRef::none(),
+            };
+            dict_insert(&dict_value, name, &value, 0, -1);
+        }
+        function_invoke(&func, &list_value, &dict_value, &self.current_pkgpath())
+    }
+
+    fn walk_subscript(&self, subscript: &'ctx ast::Subscript) -> Self::Result {
+        check_backtrack_stop!(self);
+        let value = self.walk_expr(&subscript.value);
+        if let Some(index) = &subscript.index {
+            // index
+            let index = self.walk_expr(index);
+            let f = if subscript.has_question {
+                kclvm_runtime::kclvm_value_subscr_option
+            } else {
+                kclvm_runtime::kclvm_value_subscr
+            };
+            binary(f, &value, &index)
+        } else {
+            let walk_or_none = |expr: &'ctx Option<ast::NodeRef<ast::Expr>>| match expr {
+                Some(expr) => self.walk_expr(expr),
+                None => ValueRef::none(),
+            };
+            let lower = walk_or_none(&subscript.lower);
+            let upper = walk_or_none(&subscript.upper);
+            let step = walk_or_none(&subscript.step);
+            let f = if subscript.has_question {
+                kclvm_runtime::kclvm_value_slice_option
+            } else {
+                kclvm_runtime::kclvm_value_slice
+            };
+            from_ptr(f(&value, &lower, &upper, &step))
+        }
+    }
+
+    fn walk_paren_expr(&self, paren_expr: &'ctx ast::ParenExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_expr(&paren_expr.expr)
+    }
+
+    fn walk_list_expr(&self, list_expr: &'ctx ast::ListExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_list_items(&list_expr.elts)
+    }
+
+    fn walk_list_if_item_expr(&self, list_if_item_expr: &'ctx ast::ListIfItemExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let cond = self.walk_expr(&list_if_item_expr.if_cond);
+        if cond.is_truthy() {
+            self.walk_list_items(&list_if_item_expr.exprs)
+        } else {
+            match &list_if_item_expr.orelse {
+                Some(orelse) => self.walk_expr(orelse),
+                None => ValueRef::none(),
+            }
+        }
+    }
+
+    fn walk_starred_expr(&self, starred_expr: &'ctx ast::StarredExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_expr(&starred_expr.value)
+    }
+
+    fn walk_list_comp(&self, list_comp: &'ctx ast::ListComp) -> Self::Result {
+        check_backtrack_stop!(self);
+        let collection_value = ValueRef::list(None);
+        self.enter_scope();
+        self.walk_generator(
+            &list_comp.generators,
+            &list_comp.elt,
+            None,
+            None,
+            0,
+            &collection_value,
+            &ast::CompType::List,
+        );
+        self.leave_scope();
+        collection_value
+    }
+
+    fn walk_dict_comp(&self, dict_comp: &'ctx ast::DictComp) -> Self::Result {
+        check_backtrack_stop!(self);
+        let collection_value = ValueRef::dict(None);
+        self.enter_scope();
+        let key = dict_comp
+            .entry
+            .key
+            .as_ref()
+            .expect("the key of the dict comprehension is not found");
+        self.walk_generator(
+            &dict_comp.generators,
+            key,
+            Some(&dict_comp.entry.value),
+            Some(&dict_comp.entry.operation),
+            0,
+            &collection_value,
+            &ast::CompType::Dict,
+        );
+        self.leave_scope();
+        collection_value
+    }
+
+    fn walk_config_if_entry_expr(
+        &self,
+        config_if_entry_expr: &'ctx ast::ConfigIfEntryExpr,
+    ) -> Self::Result {
+        check_backtrack_stop!(self);
+        let cond = self.walk_expr(&config_if_entry_expr.if_cond);
+        if cond.is_truthy() {
+            self.walk_config_entries(&config_if_entry_expr.items)
+        } else {
+            match &config_if_entry_expr.orelse {
+                Some(orelse) => self.walk_expr(orelse),
+                None => ValueRef::none(),
+            }
+        }
+    }
+
+    fn walk_comp_clause(&self, _comp_clause: &'ctx ast::CompClause) -> Self::Result {
+        // Nothing to do on this AST node
+        ValueRef::none()
+    }
+
+    fn walk_schema_expr(&self, schema_expr: &'ctx ast::SchemaExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let config_value = self.walk_expr(&schema_expr.config);
+        let schema_type =
+            self.walk_identifier_with_ctx(&schema_expr.name.node, &schema_expr.name.node.ctx, None);
+        let config_expr = match &schema_expr.config.node {
+            ast::Expr::Config(config_expr) => config_expr,
+            _ => panic!("invalid schema config expr"),
+        };
+        let config_meta = self.get_schema_config_meta(Some(&schema_expr.name), config_expr);
+        let mut list_value = ValueRef::list(None);
+        for arg in &schema_expr.args {
+            let value = self.walk_expr(arg);
+            list_value.list_append(&value);
+        }
+        let dict_value = ValueRef::dict(None);
+        for keyword in &schema_expr.kwargs {
+            let name = &keyword.node.arg.node.names[0];
+            let value = match &keyword.node.value {
+                Some(value) => self.walk_expr(value),
+                None => ValueRef::none(),
+            };
+            dict_insert(&dict_value, name, &value, 0, -1);
+        }
+        let schema = schema_value_new(
+            &list_value,
+            &dict_value,
+            &schema_type,
+            &config_value,
+            &config_meta,
+            &self.current_pkgpath(),
+        );
+        self.update_ctx_filename(&schema_expr.config);
+        schema
+    }
+
+    fn walk_config_expr(&self, config_expr: &'ctx ast::ConfigExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_config_entries(&config_expr.items)
+    }
+
+    fn walk_check_expr(&self, check_expr: &'ctx ast::CheckExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        if let Some(if_cond) = &check_expr.if_cond {
+            if !self.walk_expr(if_cond).is_truthy() {
+                return ValueRef::none();
+            }
+        }
+        let check_result = self.walk_expr(&check_expr.test);
+        let msg = match &check_expr.msg {
+            Some(msg) => self.walk_expr(msg),
+            None => ValueRef::str(""),
+        };
+        let schema_config_meta = self.get_variable(SCHEMA_CONFIG_META_NAME);
+        let current_line = *self.current_line.borrow();
+        Context::current_context_mut().set_kcl_line_col(current_line as i32, 0);
+        kclvm_runtime::kclvm_schema_assert(&check_result, &msg, &schema_config_meta);
+        ValueRef::none()
+    }
+
+    fn walk_lambda_expr(&self, lambda_expr: &'ctx ast::LambdaExpr) -> Self::Result {
+        check_backtrack_stop!(self);
+        let proxy = self.proxy_function(
+            lambda_expr as *const ast::LambdaExpr as usize,
+            "",
+            0,
+            || FunctionProxy::Lambda {
+                expr: lambda_expr,
+                pkgpath: self.current_pkgpath(),
+                filename: self.current_filename(),
+                schema_stack: self.schema_stack.borrow().clone(),
+            },
+        );
+        let mut closure = ValueRef::list(None);
+        closure.list_append(&self.get_closure_dict_in_current_scope());
+        ValueRef::proxy_func(proxy, closure, "")
+    }
+
+    fn walk_keyword(&self, _keyword: &'ctx ast::Keyword) -> Self::Result {
+        // Nothing to do
+        ValueRef::none()
+    }
+
+    fn walk_arguments(&self, _arguments: &'ctx ast::Arguments) -> Self::Result {
+        // Nothing to do
+        ValueRef::none()
+    }
+
+    fn walk_compare(&self, compare: &'ctx ast::Compare) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut left_value = self.walk_expr(&compare.left);
+        let mut result = ValueRef::none();
+        for (op, comparator) in compare.ops.iter().zip(&compare.comparators) {
+            let right_value = self.walk_expr(comparator);
+            result = binary(compare_fn(op), &left_value, &right_value);
+            // Stop the comparison chain at the first false result.
+            if !result.is_truthy() {
+                break;
+            }
+            left_value = right_value;
+        }
+        result
+    }
+
+    fn walk_identifier(&self, identifier: &'ctx ast::Identifier) -> Self::Result {
+        check_backtrack_stop!(self);
+        self.walk_identifier_with_ctx(identifier, &identifier.ctx, None)
+    }
+
+    fn walk_number_lit(&self, number_lit: &'ctx ast::NumberLit) -> Self::Result {
+        check_backtrack_stop!(self);
+        match number_lit.value {
+            ast::NumberLitValue::Int(int_value) => match &number_lit.binary_suffix {
+                Some(binary_suffix) => {
+                    let unit = binary_suffix.value();
+                    let value = kclvm_runtime::cal_num(int_value, unit.as_str());
+                    ValueRef::unit(value, int_value, &unit)
+                }
+                None => ValueRef::int(int_value),
+            },
+            ast::NumberLitValue::Float(float_value) => ValueRef::float(float_value),
+        }
+    }
+
+    fn walk_string_lit(&self, string_lit: &'ctx ast::StringLit) -> Self::Result {
+        check_backtrack_stop!(self);
+        ValueRef::str(string_lit.value.as_str())
+    }
+
+    fn walk_name_constant_lit(
+        &self,
+        name_constant_lit: &'ctx ast::NameConstantLit,
+    ) -> Self::Result {
+        check_backtrack_stop!(self);
+        match name_constant_lit.value {
+            ast::NameConstant::True => ValueRef::bool(true),
+            ast::NameConstant::False => ValueRef::bool(false),
+            ast::NameConstant::None => ValueRef::none(),
+            ast::NameConstant::Undefined => ValueRef::undefined(),
+        }
+    }
+
+    fn walk_joined_string(&self, joined_string: &'ctx ast::JoinedString) -> Self::Result {
+        check_backtrack_stop!(self);
+        let mut result_value = ValueRef::str("");
+        for value in &joined_string.values {
+            let value = match &value.node {
+                ast::Expr::FormattedValue(formatted_value) => {
+                    self.walk_formatted_value(formatted_value)
+                }
+                ast::Expr::StringLit(string_lit) => self.walk_string_lit(string_lit),
+                _ => panic!("invalid joined string"),
+            };
+            result_value = binary(kclvm_runtime::kclvm_value_op_add, &result_value, &value);
+        }
+        result_value
+    }
+
+    fn walk_formatted_value(&self, formatted_value: &'ctx ast::FormattedValue) -> Self::Result {
+        check_backtrack_stop!(self);
+        let formatted_expr_value = self.walk_expr(&formatted_value.value);
+        let f: UnaryFn = match &formatted_value.format_spec {
+            Some(spec) => match spec.to_lowercase().as_str() {
+                "#json" => kclvm_runtime::kclvm_value_to_json_value,
+                "#yaml" => kclvm_runtime::kclvm_value_to_yaml_value,
+                _ => panic!("invalid string interpolation format specification"),
+            },
+            None => kclvm_runtime::kclvm_value_to_str_value,
+        };
+        unary(f, &formatted_expr_value)
+    }
+
+    fn walk_missing_expr(&self, _missing_expr: &'ctx ast::MissingExpr) -> Self::Result {
+        // Programs with syntax errors are rejected before the evaluation.
+        panic!("missing expression found in the program")
+    }
+
+    fn walk_comment(&self, _comment: &'ctx ast::Comment) -> Self::Result {
+        // Nothing to do
+        ValueRef::none()
+    }
+
+    fn walk_module(&self, module: &'ctx ast::Module) -> Self::Result {
+        check_backtrack_stop!(self);
+        if !module.body.is_empty() {
+            self.update_ctx_filename(&module.body[0]);
+        }
+        // Evaluate all schema and rule firstly
+        self.compile_module_import_and_types(module);
+        // Evaluate all statements of the module
+        self.walk_stmts_except_import(&module.body)
+    }
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    pub(crate) fn walk_stmts_except_import(
+        &self,
+        stmts: &'ctx [Box<ast::Node<ast::Stmt>>],
+    ) -> ValueRef {
+        check_backtrack_stop!(self);
+        let mut result = ValueRef::none();
+        for stmt in stmts {
+            if !matches!(&stmt.node, ast::Stmt::Import(..)) {
+                result = self.walk_stmt(stmt);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn walk_stmts(&self, stmts: &'ctx [Box<ast::Node<ast::Stmt>>]) -> ValueRef {
+        check_backtrack_stop!(self);
+        // Empty statements return None value
+        let mut result = ValueRef::none();
+        for stmt in stmts {
+            result = self.walk_stmt(stmt);
+        }
+        result
+    }
+
+    /// Evaluate the import statements and define all the schemas and rules in the module.
+    pub(crate) fn compile_module_import_and_types(&self, module: &'ctx ast::Module) {
+        self.predefine_global_vars(module);
+        for stmt in &module.body {
+            match &stmt.node {
+                ast::Stmt::Import(import_stmt) => {
+                    self.walk_import_stmt(import_stmt);
+                }
+                ast::Stmt::Schema(schema_stmt) => {
+                    self.predefine_global_types(&schema_stmt.name.node);
+                    self.walk_schema_stmt(schema_stmt);
+                }
+                ast::Stmt::Rule(rule_stmt) => {
+                    self.predefine_global_types(&rule_stmt.name.node);
+                    self.walk_rule_stmt(rule_stmt);
+                }
+                _ => {}
+            };
+        }
+    }
+
+    fn predefine_global_types(&self, name: &str) {
+        // Store or add the variable in the scope
+        if !self.store_variable(name, ValueRef::undefined()) {
+            self.add_variable(name, ValueRef::undefined());
+        }
+    }
+
+    /// Predefine all global variables, which keeps the order of the output.
+    fn predefine_global_vars(&self, module: &'ctx ast::Module) {
+        for stmt in &module.body {
+            if let ast::Stmt::Assign(assign_stmt) = &stmt.node {
+                for target in &assign_stmt.targets {
+                    let names = &target.node.names;
+                    if names.len() == 1 {
+                        self.add_or_update_global_variable(&names[0], ValueRef::undefined());
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn walk_identifier_with_ctx(
+        &self,
+        identifier: &'ctx ast::Identifier,
+        identifier_ctx: &ast::ExprContext,
+        right_value: Option<ValueRef>,
+    ) -> ValueRef {
+        check_backtrack_stop!(self);
+        let is_in_schema = self.is_in_schema();
+        let names = &identifier.names;
+        let name = names[0].as_str();
+        let is_local_var = self.local_vars.borrow().contains(name);
+        match identifier_ctx {
+            ast::ExprContext::Store => {
+                let right_value = right_value.expect("the right value of the assignment is empty");
+                if names.len() == 1 {
+                    // Global variables
+                    if self.scope_level() == GLOBAL_LEVEL {
+                        self.add_or_update_global_variable(name, right_value.clone());
+                    // Local variables including schema/rule/lambda
+                    } else if self.is_in_lambda() {
+                        // If variable exists in the scope and update it, if not, add it to the scope.
+                        if !self.store_variable_in_current_scope(name, right_value.clone()) {
+                            self.add_variable(name, right_value.clone());
+                        }
+                    } else {
+                        if is_in_schema && self.scope_level() >= SCHEMA_LEVEL && !is_local_var {
+                            let schema_value = self.get_variable(SCHEMA_SELF_NAME);
+                            let config_value = self.get_variable(SCHEMA_CONFIG_NAME);
+                            let config_entry = if config_value.has_key(name) {
+                                config_value
+                                    .dict_get_entry(name)
+                                    .unwrap_or_else(ValueRef::undefined)
+                            } else {
+                                ValueRef::none()
+                            };
+                            dict_merge(&schema_value, name, &right_value, 1, -1);
+                            value_union(&schema_value, &config_entry);
+                            let cal_map = self.get_variable(SCHEMA_CAL_MAP);
+                            let backtrack_cache = self.get_variable(BACKTRACK_CACHE);
+                            let runtime_type = self.get_variable(SCHEMA_RUNTIME_TYPE);
+                            schema_backtrack_cache(
+                                &schema_value,
+                                &backtrack_cache,
+                                &cal_map,
+                                name,
+                                &runtime_type,
+                            );
+                            // Update backtrack meta
+                            if self.update_backtrack_meta(name) {
+                                return schema_value;
+                            }
+                        }
+                        if is_local_var || !is_in_schema {
+                            self.add_variable(name, right_value.clone());
+                        }
+                    }
+                } else {
+                    let mut value = if is_in_schema {
+                        self.get_variable_in_schema(name)
+                    } else {
+                        self.get_variable(name)
+                    };
+                    for i in 0..names.len() - 1 {
+                        let attr = names[i + 1].as_str();
+                        if i != names.len() - 2 && names.len() > 2 {
+                            value = load_attr(&value, attr, false);
+                        } else {
+                            dict_set_value(&value, attr, &right_value);
+                        }
+                    }
+                }
+                right_value
+            }
+            ast::ExprContext::Load => {
+                if names.len() == 1 {
+                    if is_in_schema && !is_local_var {
+                        self.get_variable_in_schema(name)
+                    } else {
+                        self.get_variable(name)
+                    }
+                } else {
+                    let mut value = if identifier.pkgpath.is_empty() {
+                        if is_in_schema && !is_local_var {
+                            self.get_variable_in_schema(name)
+                        } else {
+                            self.get_variable(name)
+                        }
+                    } else {
+                        ValueRef::none()
+                    };
+                    for (i, attr) in names[1..].iter().enumerate() {
+                        value = if i == 0 && !identifier.pkgpath.is_empty() {
+                            self.get_variable_in_pkgpath(attr, &identifier.pkgpath)
+                        } else {
+                            load_attr(&value, attr, false)
+                        };
+                    }
+                    value
+                }
+            }
+        }
+    }
+
+    pub(crate) fn walk_decorator_with_name(
+        &self,
+        decorator: &'ctx ast::CallExpr,
+        attr_name: Option<&str>,
+        is_schema_target: bool,
+    ) -> ValueRef {
+        check_backtrack_stop!(self);
+        let mut list_value = ValueRef::list(None);
+        let dict_value = ValueRef::dict(None);
+        let schema_config_meta = self.get_variable(SCHEMA_CONFIG_META_NAME);
+        let schema_config_value = self.get_variable(SCHEMA_CONFIG_NAME);
+        for arg in &decorator.args {
+            let value = self.walk_expr(arg);
+            list_value.list_append(&value);
+        }
+        for keyword in &decorator.keywords {
+            let name = &keyword.node.arg.node.names[0];
+            let value = match &keyword.node.value {
+                Some(value) => self.walk_expr(value),
+                None => ValueRef::none(),
+            };
+            dict_insert(&dict_value, name, &value, 0, -1);
+        }
+        let name = match &decorator.func.node {
+            ast::Expr::Identifier(ident) if ident.names.len() == 1 => ident.names[0].clone(),
+            _ => panic!("invalid decorator name, expect single identifier"),
+        };
+        crate::value::decorator(
+            &name,
+            &list_value,
+            &dict_value,
+            &schema_config_meta,
+            attr_name.unwrap_or_default(),
+            &schema_config_value,
+            is_schema_target,
+        );
+        ValueRef::none()
+    }
+
+    /// Store the function arguments into the current scope.
+    pub(crate) fn walk_arguments(
+        &self,
+        arguments: &'ctx Option<ast::NodeRef<ast::Arguments>>,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) {
+        // Arguments names and defaults
+        let (arg_names, arg_defaults) = match &arguments {
+            Some(args) => (
+                args.node
+                    .args
+                    .iter()
+                    .map(|identifier| &identifier.node)
+                    .collect(),
+                args.node.defaults.iter().collect(),
+            ),
+            None => (vec![], vec![]),
+        };
+        // Default parameter values
+        for (arg_name, value) in arg_names.iter().zip(arg_defaults.iter()) {
+            let arg_value = match value {
+                Some(value) => self.walk_expr(value),
+                None => ValueRef::none(),
+            };
+            self.walk_identifier_with_ctx(arg_name, &ast::ExprContext::Store, Some(arg_value));
+        }
+        // Positional arguments
+        let argument_len = args.len();
+        for (i, arg_name) in arg_names.iter().enumerate() {
+            if i >= argument_len {
+                break;
+            }
+            let arg_value = args
+                .list_get_option(i as isize)
+                .unwrap_or_else(ValueRef::undefined);
+            self.store_variable(&arg_name.names[0], arg_value);
+        }
+        // Keyword arguments
+        for arg_name in arg_names.iter() {
+            let name = &arg_name.names[0];
+            if let Some(arg) = kwargs.dict_get_value(name) {
+                // Find argument name in the scope
+                self.store_variable(&arg_name.names[0], arg);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn walk_generator(
+        &self,
+        generators: &'ctx [Box<ast::Node<ast::CompClause>>],
+        elt: &'ctx ast::Node<ast::Expr>,
+        val: Option<&'ctx ast::Node<ast::Expr>>,
+        op: Option<&'ctx ast::ConfigEntryOperation>,
+        gen_index: usize,
+        collection_value: &ValueRef,
+        comp_type: &ast::CompType,
+    ) {
+        let generator = &generators[gen_index];
+        let iter_host_value = self.walk_expr(&generator.node.iter);
+        let mut iter = iter_host_value.iter();
+        let targets = &generator.node.targets;
+        while !iter.is_end() {
+            let next_value = match iter.next(&iter_host_value) {
+                Some(value) => value.clone(),
+                None => break,
+            };
+            {
+                let mut local_vars = self.local_vars.borrow_mut();
+                for v in targets {
+                    local_vars.insert(v.node.names[0].clone());
+                }
+            }
+            if targets.len() == 1 {
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &targets[0].node,
+                    &ast::ExprContext::Store,
+                    Some(next_value),
+                );
+            } else if targets.len() == 2 {
+                let key = iter.key().cloned().unwrap_or_else(ValueRef::none);
+                let value = iter.value().cloned().unwrap_or_else(ValueRef::none);
+                // Store the target
+                self.walk_identifier_with_ctx(
+                    &targets[0].node,
+                    &ast::ExprContext::Store,
+                    Some(key),
+                );
+                self.walk_identifier_with_ctx(
+                    &targets[1].node,
+                    &ast::ExprContext::Store,
+                    Some(value),
+                );
+            } else {
+                panic!(
+                    "the number of loop variables is {}, which can only be 1 or 2",
+                    targets.len()
+                )
+            }
+            if !generator
+                .node
+                .ifs
+                .iter()
+                .all(|if_expr| self.walk_expr(if_expr).is_truthy())
+            {
+                continue;
+            }
+            let next_gen_index = gen_index + 1;
+            if next_gen_index >= generators.len() {
+                match comp_type {
+                    ast::CompType::List => {
+                        let item = self.walk_expr(elt);
+                        let mut list_value = collection_value.clone();
+                        list_value.list_append(&item);
+                    }
+                    ast::CompType::Dict => {
+                        let value =
+                            self.walk_expr(val.expect("the dict comprehension value is empty"));
+                        let key = self.walk_expr(elt);
+                        let op = op.expect("the dict comprehension operation is empty");
+                        dict_insert_value(collection_value, &key, &value, op.value(), -1);
+                    }
+                }
+            } else {
+                self.walk_generator(
+                    generators,
+                    elt,
+                    val,
+                    op,
+                    next_gen_index,
+                    collection_value,
+                    comp_type,
+                );
+            }
+        }
+        self.local_vars.borrow_mut().clear();
+    }
+
+    /// Evaluate the list items, the starred and list if items are unpacked.
+    fn walk_list_items(&self, items: &'ctx [ast::NodeRef<ast::Expr>]) -> ValueRef {
+        let mut list_value = ValueRef::list(None);
+        for item in items {
+            let value = self.walk_expr(item);
+            match &item.node {
+                ast::Expr::Starred(_) | ast::Expr::ListIfItem(_) => {
+                    list_append_unpack(&list_value, &value)
+                }
+                _ => list_value.list_append(&value),
+            };
+        }
+        list_value
+    }
+
+    /// Evaluate the config entries into a dict value.
+    fn walk_config_entries(&self, items: &'ctx [ast::NodeRef<ast::ConfigEntry>]) -> ValueRef {
+        let config_value = ValueRef::dict(None);
+        for item in items {
+            let value = self.walk_expr(&item.node.value);
+            match &item.node.key {
+                Some(key) => {
+                    let mut insert_index = -1;
+                    let key = match &key.node {
+                        ast::Expr::Identifier(identifier) => ValueRef::str(&identifier.names[0]),
+                        ast::Expr::StringLit(string_lit) => ValueRef::str(&string_lit.value),
+                        ast::Expr::Subscript(subscript) => match (
+                            &subscript.value.node,
+                            subscript.index.as_ref().map(|index| &index.node),
+                        ) {
+                            (
+                                ast::Expr::Identifier(identifier),
+                                Some(ast::Expr::NumberLit(ast::NumberLit {
+                                    value: ast::NumberLitValue::Int(v),
+                                    ..
+                                })),
+                            ) => {
+                                insert_index = *v as i32;
+                                ValueRef::str(&identifier.names[0])
+                            }
+                            _ => self.walk_expr(key),
+                        },
+                        _ => self.walk_expr(key),
+                    };
+                    dict_insert_value(
+                        &config_value,
+                        &key,
+                        &value,
+                        item.node.operation.value(),
+                        insert_index,
+                    );
+                }
+                None => dict_insert_unpack(&config_value, &value),
+            }
+        }
+        config_value
+    }
+
+    /// Whether the evaluator is in a schema or rule body.
+    #[inline]
+    pub(crate) fn is_in_schema(&self) -> bool {
+        !self.schema_stack.borrow().is_empty()
+    }
+
+    /// Whether the evaluator is in a lambda body.
+    #[inline]
+    pub(crate) fn is_in_lambda(&self) -> bool {
+        *self
+            .lambda_stack
+            .borrow()
+            .last()
+            .expect("the lambda stack is empty")
+    }
+
+    /// Update runtime context filename
+    pub(crate) fn update_ctx_filename<T>(&self, node: &ast::Node<T>) {
+        if !node.filename.is_empty() {
+            Context::current_context_mut().set_kcl_filename(&node.filename);
+        }
+    }
+
+    /// Update runtime context line and column
+    pub(crate) fn update_ctx_line_col<T>(&self, node: &ast::Node<T>) {
+        let mut current_line = self.current_line.borrow_mut();
+        if node.line != *current_line {
+            *current_line = node.line;
+            Context::current_context_mut().set_kcl_line_col(node.line as i32, 0);
+        }
+    }
+}
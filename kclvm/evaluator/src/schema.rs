@@ -0,0 +1,872 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_ast::walker::TypedResultWalker;
+use kclvm_runtime::{Context, ValueRef, MAIN_PKG_PATH};
+
+use crate::function::FunctionProxy;
+use crate::value::*;
+use crate::{
+    Evaluator, BACKTRACK_CACHE, BACKTRACK_LEVEL_MAP, SCHEMA_ARGS, SCHEMA_CAL_MAP,
+    SCHEMA_CONFIG_META_NAME, SCHEMA_CONFIG_NAME, SCHEMA_KWARGS, SCHEMA_RUNTIME_TYPE,
+    SCHEMA_SELF_NAME,
+};
+
+/// The backtrack meta of the schema attribute function defined in an if statement.
+///
+/// The LLVM code generator stops generating the attribute function code once it
+/// meets the `level`-th store of the `target` attribute in the if statement, thus
+/// the evaluator computes the same stop point before walking the statement and
+/// skips all the code after it.
+#[derive(Debug, Default)]
+pub(crate) struct BacktrackMeta {
+    pub target: String,
+    pub level: usize,
+    pub count: usize,
+    pub stop: bool,
+    /// Preorder indexes of all the statements in the if statement.
+    stmt_indexes: HashMap<usize, usize>,
+    /// Index of the statement which contains the `level`-th target store.
+    stop_index: Option<usize>,
+    /// Number of target stores in the stop statement to reach the `level`.
+    needed: usize,
+    /// Index of the statement being walked.
+    current_index: Option<usize>,
+}
+
+impl BacktrackMeta {
+    /// New a backtrack meta of the attribute `target` with the if level `level`
+    /// defined in the statement `stmt`.
+    pub(crate) fn new(target: &str, level: usize, stmt: &ast::Node<ast::Stmt>) -> Self {
+        let mut meta = BacktrackMeta {
+            target: target.to_string(),
+            level,
+            ..Default::default()
+        };
+        let mut seen = 0;
+        meta.index_stmt(stmt, &mut seen);
+        meta
+    }
+
+    fn index_stmt(&mut self, stmt: &ast::Node<ast::Stmt>, seen: &mut usize) {
+        let index = self.stmt_indexes.len();
+        self.stmt_indexes
+            .insert(stmt as *const ast::Node<ast::Stmt> as usize, index);
+        if let ast::Stmt::If(if_stmt) = &stmt.node {
+            for stmt in if_stmt.body.iter().chain(&if_stmt.orelse) {
+                self.index_stmt(stmt, seen);
+            }
+        } else if self.stop_index.is_none() {
+            let count = self.target_store_count(&stmt.node);
+            if *seen + count >= self.level {
+                self.stop_index = Some(index);
+                self.needed = self.level - *seen;
+            } else {
+                *seen += count;
+            }
+        }
+    }
+
+    /// Count the target attribute stores in the statement.
+    fn target_store_count(&self, stmt: &ast::Stmt) -> usize {
+        let is_target = |identifier: &ast::Identifier| {
+            identifier.names.len() == 1 && identifier.names[0] == self.target
+        };
+        match stmt {
+            ast::Stmt::SchemaAttr(schema_attr) => (schema_attr.name.node == self.target) as usize,
+            ast::Stmt::Assign(assign_stmt) => assign_stmt
+                .targets
+                .iter()
+                .filter(|target| is_target(&target.node))
+                .count(),
+            ast::Stmt::AugAssign(aug_assign_stmt) => {
+                is_target(&aug_assign_stmt.target.node) as usize
+            }
+            ast::Stmt::Unification(unification_stmt) => {
+                is_target(&unification_stmt.target.node) as usize
+            }
+            _ => 0,
+        }
+    }
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// Whether the schema attribute backtracking stops.
+    #[inline]
+    pub(crate) fn is_backtrack_stopped(&self) -> bool {
+        match self.backtrack_meta.borrow().as_ref() {
+            Some(backtrack_meta) => backtrack_meta.stop,
+            None => false,
+        }
+    }
+
+    /// Returns true and stops the backtracking when the statement is after the stop point.
+    pub(crate) fn backtrack_skip_stmt(&self, stmt: &'ctx ast::Node<ast::Stmt>) -> bool {
+        if let Some(backtrack_meta) = self.backtrack_meta.borrow_mut().as_mut() {
+            if let Some(index) = backtrack_meta
+                .stmt_indexes
+                .get(&(stmt as *const ast::Node<ast::Stmt> as usize))
+                .cloned()
+            {
+                if matches!(backtrack_meta.stop_index, Some(stop_index) if index > stop_index) {
+                    backtrack_meta.stop = true;
+                    return true;
+                }
+                backtrack_meta.current_index = Some(index);
+            }
+        }
+        false
+    }
+
+    /// Update the backtrack meta on the attribute `name` store, and returns true
+    /// when the backtracking stops.
+    pub(crate) fn update_backtrack_meta(&self, name: &str) -> bool {
+        if let Some(backtrack_meta) = self.backtrack_meta.borrow_mut().as_mut() {
+            if name == backtrack_meta.target
+                && backtrack_meta.stop_index.is_some()
+                && backtrack_meta.current_index == backtrack_meta.stop_index
+            {
+                backtrack_meta.count += 1;
+                if backtrack_meta.count >= backtrack_meta.needed {
+                    backtrack_meta.stop = true;
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Get the proxy function index of the definition denoted by `key`, `name` and `index`,
+    /// the function `f` is used to new the proxy function when it is not found.
+    pub(crate) fn proxy_function(
+        &self,
+        key: usize,
+        name: &str,
+        index: usize,
+        f: impl FnOnce() -> FunctionProxy<'ctx>,
+    ) -> u64 {
+        let function_key = (key, name.to_string(), index);
+        if let Some(proxy) = self.function_indexes.borrow().get(&function_key) {
+            return *proxy;
+        }
+        let proxy = {
+            let mut functions = self.functions.borrow_mut();
+            functions.push(std::rc::Rc::new(f()));
+            (functions.len() - 1) as u64
+        };
+        self.function_indexes
+            .borrow_mut()
+            .insert(function_key, proxy);
+        proxy
+    }
+
+    /// New a schema or rule type function value with the proxy function `proxy`
+    /// and record it in the runtime context.
+    pub(crate) fn schema_function_value(&self, proxy: u64, runtime_type: &str) -> ValueRef {
+        let mut closure = ValueRef::list(None);
+        for value in [
+            // is_sub_schema
+            ValueRef::bool(false),
+            // config_meta
+            ValueRef::dict(None),
+            // config
+            ValueRef::dict(None),
+            // schema
+            ValueRef::dict(None),
+            // optional_mapping
+            ValueRef::dict(None),
+            // cal_map
+            ValueRef::dict(None),
+            // backtrack_level_map
+            ValueRef::dict(None),
+            // backtrack_cache
+            ValueRef::dict(None),
+            // record_instance
+            ValueRef::bool(false),
+            // instance_pkgpath
+            ValueRef::str(MAIN_PKG_PATH),
+        ] {
+            closure.list_append(&value);
+        }
+        let function = ValueRef::proxy_func(proxy, closure, runtime_type);
+        Context::current_context_mut()
+            .all_schemas
+            .borrow_mut()
+            .insert(runtime_type.to_string(), function.clone());
+        function
+    }
+
+    /// Emit all the left identifiers of the schema body into the schema value and the
+    /// `cal_map`, each identifier is evaluated by a schema attribute function on demand.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn emit_schema_left_identifiers(
+        &self,
+        schema_stmt: &'ctx ast::SchemaStmt,
+        body: &'ctx [Box<ast::Node<ast::Stmt>>],
+        cal_map: &ValueRef,
+        runtime_type: &str,
+        is_in_if: bool,
+        body_map: &mut IndexMap<String, Vec<&'ctx ast::Node<ast::Stmt>>>,
+        in_if_names: &mut Vec<String>,
+    ) {
+        let schema_value = self.get_variable(SCHEMA_SELF_NAME);
+        let value = ValueRef::undefined();
+        let add_stmt =
+            |name: &str,
+             stmt: &'ctx ast::Node<ast::Stmt>,
+             body_map: &mut IndexMap<String, Vec<&'ctx ast::Node<ast::Stmt>>>| {
+                let stmts = body_map.entry(name.to_string()).or_default();
+                stmts.push(stmt);
+                let index = stmts.len() - 1;
+                // The if level of the attribute statement, which increases on the
+                // continuous if statements and resets on the others.
+                let mut if_level = 0;
+                for stmt in stmts.iter() {
+                    if matches!(&stmt.node, ast::Stmt::If(..)) {
+                        if_level += 1;
+                    } else {
+                        if_level = 0;
+                    }
+                }
+                let proxy = self.proxy_function(
+                    stmt as *const ast::Node<ast::Stmt> as usize,
+                    name,
+                    index,
+                    || FunctionProxy::SchemaAttr {
+                        schema_stmt,
+                        stmt,
+                        name: name.to_string(),
+                        if_level,
+                        pkgpath: self.current_pkgpath(),
+                        filename: self.current_filename(),
+                        runtime_type: runtime_type.to_string(),
+                    },
+                );
+                default_collection_insert_value(
+                    cal_map,
+                    name,
+                    &ValueRef::proxy_func(proxy, ValueRef::none(), ""),
+                );
+                default_collection_insert_value(
+                    cal_map,
+                    &format!("{}_{}", name, kclvm_runtime::CAL_MAP_RUNTIME_TYPE),
+                    &ValueRef::str(runtime_type),
+                );
+                default_collection_insert_value(
+                    cal_map,
+                    &format!("{}_{}", name, kclvm_runtime::CAL_MAP_META_LINE),
+                    &ValueRef::int(stmt.line as i64),
+                );
+            };
+        let emit_name = |name: &str,
+                         stmt: &'ctx ast::Node<ast::Stmt>,
+                         body_map: &mut IndexMap<String, Vec<&'ctx ast::Node<ast::Stmt>>>,
+                         in_if_names: &mut Vec<String>| {
+            dict_merge(&schema_value, name, &value, 0, -1);
+            if is_in_if {
+                in_if_names.push(name.to_string());
+            } else {
+                add_stmt(name, stmt, body_map);
+            }
+        };
+        for stmt in body {
+            match &stmt.node {
+                ast::Stmt::Unification(unification_stmt) => {
+                    let name = &unification_stmt.target.node.names[0];
+                    emit_name(name, stmt, body_map, in_if_names);
+                }
+                ast::Stmt::Assign(assign_stmt) => {
+                    for target in &assign_stmt.targets {
+                        let name = &target.node.names[0];
+                        emit_name(name, stmt, body_map, in_if_names);
+                    }
+                }
+                ast::Stmt::AugAssign(aug_assign_stmt) => {
+                    let name = &aug_assign_stmt.target.node.names[0];
+                    emit_name(name, stmt, body_map, in_if_names);
+                }
+                ast::Stmt::SchemaAttr(schema_attr) => {
+                    let name = schema_attr.name.node.as_str();
+                    emit_name(name, stmt, body_map, in_if_names);
+                }
+                ast::Stmt::If(if_stmt) => {
+                    for body in [&if_stmt.body, &if_stmt.orelse] {
+                        let mut names: Vec<String> = vec![];
+                        self.emit_schema_left_identifiers(
+                            schema_stmt,
+                            body,
+                            cal_map,
+                            runtime_type,
+                            true,
+                            body_map,
+                            &mut names,
+                        );
+                        if is_in_if {
+                            in_if_names.extend(names);
+                        } else {
+                            for name in &names {
+                                add_stmt(name, stmt, body_map);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Get the config meta including the filename, line and column of the
+    /// schema name and all the config keys.
+    pub(crate) fn get_schema_config_meta(
+        &self,
+        n: Option<&'ctx ast::Node<ast::Identifier>>,
+        t: &'ctx ast::ConfigExpr,
+    ) -> ValueRef {
+        let config_meta = ValueRef::dict(None);
+        if let Some(n) = n {
+            let value = ValueRef::str(&n.filename);
+            dict_insert(
+                &config_meta,
+                kclvm_runtime::CONFIG_META_FILENAME,
+                &value,
+                1,
+                -1,
+            );
+            let value = ValueRef::int(n.line as i64);
+            dict_insert(&config_meta, kclvm_runtime::CONFIG_META_LINE, &value, 1, -1);
+            let value = ValueRef::int(n.column as i64);
+            dict_insert(
+                &config_meta,
+                kclvm_runtime::CONFIG_META_COLUMN,
+                &value,
+                1,
+                -1,
+            );
+        }
+        for item in &t.items {
+            if let Some(key) = &item.node.key {
+                let name = match &key.node {
+                    ast::Expr::Identifier(t) => t.names[0].clone(),
+                    ast::Expr::NumberLit(t) => match t.value {
+                        ast::NumberLitValue::Int(i) => i.to_string(),
+                        ast::NumberLitValue::Float(f) => f.to_string(),
+                    },
+                    ast::Expr::StringLit(t) => t.value.clone(),
+                    ast::Expr::NameConstantLit(t) => match t.value {
+                        ast::NameConstant::True => {
+                            kclvm_runtime::KCL_NAME_CONSTANT_TRUE.to_string()
+                        }
+                        ast::NameConstant::False => {
+                            kclvm_runtime::KCL_NAME_CONSTANT_FALSE.to_string()
+                        }
+                        ast::NameConstant::None => {
+                            kclvm_runtime::KCL_NAME_CONSTANT_NONE.to_string()
+                        }
+                        ast::NameConstant::Undefined => {
+                            kclvm_runtime::KCL_NAME_CONSTANT_UNDEFINED.to_string()
+                        }
+                    },
+                    _ => format!("{:?}", key.node),
+                };
+                let config_item_meta = ValueRef::dict(None);
+                let value = ValueRef::str(&key.filename);
+                dict_insert(
+                    &config_item_meta,
+                    kclvm_runtime::CONFIG_ITEM_META_FILENAME,
+                    &value,
+                    1,
+                    -1,
+                );
+                let value = ValueRef::int(key.line as i64);
+                dict_insert(
+                    &config_item_meta,
+                    kclvm_runtime::CONFIG_ITEM_META_LINE,
+                    &value,
+                    1,
+                    -1,
+                );
+                let value = ValueRef::int(key.column as i64);
+                dict_insert(
+                    &config_item_meta,
+                    kclvm_runtime::CONFIG_ITEM_META_COLUMN,
+                    &value,
+                    1,
+                    -1,
+                );
+                let value = match &item.node.value.node {
+                    ast::Expr::Config(config_expr) => {
+                        self.get_schema_config_meta(None, config_expr)
+                    }
+                    _ => ValueRef::dict(None),
+                };
+                dict_insert(
+                    &config_item_meta,
+                    kclvm_runtime::CONFIG_ITEM_META,
+                    &value,
+                    1,
+                    -1,
+                );
+                dict_insert(&config_meta, &name, &config_item_meta, 1, -1);
+            }
+        }
+        config_meta
+    }
+
+    /// Add the schema magic variables popped from the schema check or attribute
+    /// function arguments `args` into the current scope.
+    fn add_schema_check_variables(
+        &self,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+        runtime_type: &str,
+    ) -> ValueRef {
+        let mut args = args.clone();
+        let mut pop = || args.list_pop().unwrap_or_else(ValueRef::undefined);
+        let backtrack_cache = pop();
+        let backtrack_level_map = pop();
+        let cal_map = pop();
+        let schema_value = pop();
+        let schema_config = pop();
+        let schema_config_meta = pop();
+        self.add_variable(BACKTRACK_CACHE, backtrack_cache);
+        self.add_variable(BACKTRACK_LEVEL_MAP, backtrack_level_map);
+        self.add_variable(SCHEMA_CAL_MAP, cal_map);
+        self.add_variable(SCHEMA_CONFIG_NAME, schema_config);
+        self.add_variable(SCHEMA_CONFIG_META_NAME, schema_config_meta);
+        self.add_variable(SCHEMA_SELF_NAME, schema_value.clone());
+        self.add_variable(SCHEMA_ARGS, args);
+        self.add_variable(SCHEMA_KWARGS, kwargs.clone());
+        self.add_variable(SCHEMA_RUNTIME_TYPE, ValueRef::str(runtime_type));
+        schema_value
+    }
+
+    /// The schema check function arguments including the schema function arguments
+    /// and the schema check function closure.
+    fn schema_check_args(&self, args: Option<&ValueRef>) -> ValueRef {
+        let mut list_value = match args {
+            Some(args) => args.deep_copy(),
+            None => ValueRef::list(None),
+        };
+        for name in [
+            SCHEMA_CONFIG_META_NAME,
+            SCHEMA_CONFIG_NAME,
+            SCHEMA_SELF_NAME,
+            SCHEMA_CAL_MAP,
+            BACKTRACK_LEVEL_MAP,
+            BACKTRACK_CACHE,
+        ] {
+            list_value.list_append(&self.get_variable(name));
+        }
+        list_value
+    }
+
+    /// The schema function arguments used to call the parent schema, the mixins
+    /// and the protocol of the rule.
+    fn schema_base_args(&self, closure: &[ValueRef]) -> ValueRef {
+        let mut list_value = ValueRef::list(None);
+        list_value.list_append(&ValueRef::bool(false));
+        for value in closure {
+            list_value.list_append(value);
+        }
+        list_value
+    }
+
+    /// The schema body function.
+    pub(crate) fn schema_body(
+        &self,
+        schema_stmt: &'ctx ast::SchemaStmt,
+        runtime_type: &str,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        let schema_name = &schema_stmt.name.node;
+        let filename = self.current_filename();
+        let pkgpath = self.current_pkgpath();
+        let ctx = Context::current_context_mut();
+        ctx.set_kcl_filename(&filename);
+        ctx.set_kcl_pkgpath(&pkgpath);
+        // Schema function closures
+        let mut args = args.clone();
+        let mut pop = || args.list_pop().unwrap_or_else(ValueRef::undefined);
+        let instance_pkgpath = pop();
+        let record_instance = pop();
+        let backtrack_cache = pop();
+        let backtrack_level_map = pop();
+        let cal_map = pop();
+        let attr_optional_mapping = pop();
+        let schema_value = pop();
+        let schema_config = pop();
+        let schema_config_meta = pop();
+        let is_sub_schema = pop();
+        self.add_variable(BACKTRACK_CACHE, backtrack_cache.clone());
+        self.add_variable(BACKTRACK_LEVEL_MAP, backtrack_level_map.clone());
+        self.add_variable(SCHEMA_CAL_MAP, cal_map.clone());
+        self.add_variable(SCHEMA_CONFIG_NAME, schema_config.clone());
+        self.add_variable(SCHEMA_CONFIG_META_NAME, schema_config_meta.clone());
+        self.add_variable(SCHEMA_ARGS, args.clone());
+        self.add_variable(SCHEMA_KWARGS, kwargs.clone());
+        self.add_variable(SCHEMA_RUNTIME_TYPE, ValueRef::str(runtime_type));
+        self.walk_arguments(&schema_stmt.args, &args, kwargs);
+        let closure = [
+            schema_config_meta.clone(),
+            schema_config.clone(),
+            schema_value.clone(),
+            attr_optional_mapping.clone(),
+            cal_map.clone(),
+            backtrack_level_map,
+            backtrack_cache,
+            record_instance.clone(),
+            instance_pkgpath.clone(),
+        ];
+        let schema_value = if let Some(parent_name) = &schema_stmt.parent_name {
+            let base_constructor_func =
+                self.walk_identifier_with_ctx(&parent_name.node, &ast::ExprContext::Load, None);
+            let value = self.call_schema_body(
+                &base_constructor_func,
+                &self.schema_base_args(&closure),
+                &ValueRef::dict(None),
+            );
+            Context::current_context_mut().set_kcl_filename(&filename);
+            value
+        } else {
+            schema_value
+        };
+        self.schema_stack
+            .borrow_mut()
+            .push(runtime_type.to_string());
+        self.add_variable(SCHEMA_SELF_NAME, schema_value.clone());
+        self.emit_schema_left_identifiers(
+            schema_stmt,
+            &schema_stmt.body,
+            &cal_map,
+            runtime_type,
+            false,
+            &mut IndexMap::new(),
+            &mut vec![],
+        );
+        if record_instance.is_truthy() {
+            // Run schema compiled function
+            for stmt in &schema_stmt.body {
+                self.walk_stmt(stmt);
+            }
+            // Schema decorators check
+            for decorator in &schema_stmt.decorators {
+                self.walk_decorator_with_name(&decorator.node, Some(schema_name), true);
+            }
+            // Append schema default settings
+            schema_default_settings(&schema_value, &schema_config, runtime_type);
+        }
+        // Schema mixin
+        for mixin in &schema_stmt.mixins {
+            let mixin_func =
+                self.walk_identifier_with_ctx(&mixin.node, &ast::ExprContext::Load, None);
+            let mut closure = closure.clone();
+            // The mixin uses the schema value derived from the parent schema.
+            closure[2] = schema_value.clone();
+            self.call_schema_body(
+                &mixin_func,
+                &self.schema_base_args(&closure),
+                &ValueRef::dict(None),
+            );
+            Context::current_context_mut().set_kcl_filename(&filename);
+        }
+        // Schema Attribute optional check
+        for stmt in &schema_stmt.body {
+            if let ast::Stmt::SchemaAttr(schema_attr) = &stmt.node {
+                dict_insert(
+                    &attr_optional_mapping,
+                    schema_attr.name.node.as_str(),
+                    &ValueRef::bool(schema_attr.is_optional),
+                    1,
+                    -1,
+                );
+            }
+        }
+        if is_sub_schema.is_truthy() {
+            // Schema runtime index signature and relaxed check
+            if let Some(index_signature) = &schema_stmt.index_signature {
+                let index_sign_value = match &index_signature.node.value {
+                    Some(value) => self.walk_expr(value),
+                    None => ValueRef::none(),
+                };
+                schema_value_check(
+                    &schema_value,
+                    &schema_config,
+                    &schema_config_meta,
+                    schema_name,
+                    &index_sign_value,
+                    index_signature.node.key_name.as_deref().unwrap_or_default(),
+                    index_signature.node.key_type.node.as_str(),
+                    index_signature.node.value_type.node.as_str(),
+                    index_signature.node.any_other,
+                );
+            } else {
+                schema_value_check(
+                    &schema_value,
+                    &schema_config,
+                    &schema_config_meta,
+                    schema_name,
+                    &ValueRef::none(),
+                    "",
+                    "",
+                    "",
+                    false,
+                );
+            }
+            schema_optional_check(
+                &schema_value,
+                &attr_optional_mapping,
+                schema_name,
+                &schema_config_meta,
+            );
+            let index_sign_key_name = match &schema_stmt.index_signature {
+                Some(index_signature) => {
+                    index_signature.node.key_name.as_deref().unwrap_or_default()
+                }
+                None => "",
+            };
+            let list_value = self.schema_check_args(Some(&args));
+            let dict_value = kwargs.deep_copy();
+            if index_sign_key_name.is_empty() {
+                // Call schema check block function
+                self.schema_check(schema_stmt, runtime_type, &list_value, &dict_value);
+            } else {
+                // Call schema check block function with index sign attribute name loop set
+                self.schema_check_with_index_sign_attr(
+                    schema_stmt,
+                    runtime_type,
+                    &list_value,
+                    &dict_value,
+                    index_sign_key_name,
+                );
+            }
+        }
+        // Build a schema value and record instance
+        schema_with_config(
+            &schema_value,
+            &schema_config,
+            schema_name,
+            &pkgpath,
+            &is_sub_schema,
+            &record_instance,
+            &instance_pkgpath,
+        )
+    }
+
+    /// The schema check function.
+    pub(crate) fn schema_check(
+        &self,
+        schema_stmt: &'ctx ast::SchemaStmt,
+        runtime_type: &str,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        self.enter_scope();
+        let filename = self.current_filename();
+        let schema_value = self.add_schema_check_variables(args, kwargs, runtime_type);
+        let schema_stack = self.schema_stack.replace(vec![]);
+        let args = self.get_variable(SCHEMA_ARGS);
+        self.walk_arguments(&schema_stmt.args, &args, kwargs);
+        self.schema_stack
+            .borrow_mut()
+            .push(runtime_type.to_string());
+        // Call base check function
+        if let Some(parent_name) = &schema_stmt.parent_name {
+            let base_constructor_func =
+                self.walk_identifier_with_ctx(&parent_name.node, &ast::ExprContext::Load, None);
+            self.call_schema_check(
+                &base_constructor_func,
+                &self.schema_check_args(None),
+                &ValueRef::dict(None),
+            );
+            Context::current_context_mut().set_kcl_filename(&filename);
+        }
+        // Call self check function
+        for check_expr in &schema_stmt.checks {
+            self.walk_check_expr(&check_expr.node);
+        }
+        // Call mixin check functions
+        for mixin in &schema_stmt.mixins {
+            let mixin_func =
+                self.walk_identifier_with_ctx(&mixin.node, &ast::ExprContext::Load, None);
+            self.call_schema_check(
+                &mixin_func,
+                &self.schema_check_args(None),
+                &ValueRef::dict(None),
+            );
+            Context::current_context_mut().set_kcl_filename(&filename);
+        }
+        self.schema_stack.replace(schema_stack);
+        self.leave_scope();
+        schema_value
+    }
+
+    /// Call the schema check function with each relaxed config key as the index
+    /// signature attribute named `attr_name`.
+    fn schema_check_with_index_sign_attr(
+        &self,
+        schema_stmt: &'ctx ast::SchemaStmt,
+        runtime_type: &str,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+        attr_name: &str,
+    ) {
+        // args: [..., config_meta, config, schema, cal_map, level_map, cache]
+        let n = args.len() as isize;
+        let config = args
+            .list_get_option(n - 5)
+            .unwrap_or_else(|| ValueRef::dict(None));
+        let mut schema = args
+            .list_get_option(n - 4)
+            .unwrap_or_else(|| ValueRef::dict(None));
+        let keys: Vec<String> = config.as_dict_ref().values.keys().cloned().collect();
+        for key in keys {
+            if schema.attr_map_get(&key).is_none() {
+                schema.dict_update_key_value(attr_name, ValueRef::str(&key));
+                self.schema_check(schema_stmt, runtime_type, &args.deep_copy(), kwargs);
+            }
+        }
+        schema.dict_remove(attr_name);
+    }
+
+    /// The schema attribute function, which evaluates the attribute in the
+    /// statement `stmt` on demand.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn schema_attr_body(
+        &self,
+        schema_stmt: &'ctx ast::SchemaStmt,
+        stmt: &'ctx ast::Node<ast::Stmt>,
+        name: &str,
+        if_level: usize,
+        runtime_type: &str,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        let schema_value = self.add_schema_check_variables(args, kwargs, runtime_type);
+        Context::current_context_mut().set_kcl_filename(&self.current_filename());
+        let args = self.get_variable(SCHEMA_ARGS);
+        self.walk_arguments(&schema_stmt.args, &args, kwargs);
+        self.schema_stack
+            .borrow_mut()
+            .push(runtime_type.to_string());
+        // Backtrack meta begin
+        if matches!(&stmt.node, ast::Stmt::If(..)) {
+            *self.backtrack_meta.borrow_mut() = Some(BacktrackMeta::new(name, if_level, stmt));
+        }
+        self.walk_stmt(stmt);
+        // Backtrack meta end
+        *self.backtrack_meta.borrow_mut() = None;
+        schema_value
+    }
+
+    /// The rule body function.
+    pub(crate) fn rule_body(
+        &self,
+        rule_stmt: &'ctx ast::RuleStmt,
+        runtime_type: &str,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        let name = &rule_stmt.name.node;
+        Context::current_context_mut().set_kcl_filename(&self.current_filename());
+        // Schema function closures
+        let mut args = args.clone();
+        let mut pop = || args.list_pop().unwrap_or_else(ValueRef::undefined);
+        let instance_pkgpath = pop();
+        let record_instance = pop();
+        let backtrack_cache = pop();
+        let backtrack_level_map = pop();
+        let cal_map = pop();
+        let attr_optional_mapping = pop();
+        let schema_value = pop();
+        let schema_config = pop();
+        let schema_config_meta = pop();
+        let is_sub_schema = pop();
+        self.add_variable(BACKTRACK_CACHE, backtrack_cache.clone());
+        self.add_variable(BACKTRACK_LEVEL_MAP, backtrack_level_map.clone());
+        self.add_variable(SCHEMA_CAL_MAP, cal_map.clone());
+        self.add_variable(SCHEMA_CONFIG_NAME, schema_config.clone());
+        self.add_variable(SCHEMA_CONFIG_META_NAME, schema_config_meta.clone());
+        self.add_variable(SCHEMA_ARGS, args.clone());
+        self.add_variable(SCHEMA_KWARGS, kwargs.clone());
+        self.add_variable(SCHEMA_RUNTIME_TYPE, ValueRef::str(runtime_type));
+        self.walk_arguments(&rule_stmt.args, &args, kwargs);
+        self.schema_stack
+            .borrow_mut()
+            .push(runtime_type.to_string());
+        self.add_variable(SCHEMA_SELF_NAME, schema_value.clone());
+        // Construct for protocol
+        let schema_value = if let Some(for_host_name) = &rule_stmt.for_host_name {
+            let base_constructor_func =
+                self.walk_identifier_with_ctx(&for_host_name.node, &ast::ExprContext::Load, None);
+            let closure = [
+                schema_config_meta,
+                schema_config,
+                schema_value,
+                attr_optional_mapping,
+                cal_map,
+                backtrack_level_map,
+                backtrack_cache,
+                record_instance.clone(),
+                instance_pkgpath,
+            ];
+            self.call_schema_body(
+                &base_constructor_func,
+                &self.schema_base_args(&closure),
+                &ValueRef::dict(None),
+            )
+        } else {
+            schema_value
+        };
+        if record_instance.is_truthy() {
+            // Rule decorators check
+            for decorator in &rule_stmt.decorators {
+                self.walk_decorator_with_name(&decorator.node, Some(name), true);
+            }
+        }
+        if is_sub_schema.is_truthy() {
+            // Call rule check block function
+            let list_value = self.schema_check_args(Some(&args));
+            self.rule_check(rule_stmt, runtime_type, &list_value, &kwargs.deep_copy());
+        }
+        schema_value
+    }
+
+    /// The rule check function.
+    pub(crate) fn rule_check(
+        &self,
+        rule_stmt: &'ctx ast::RuleStmt,
+        runtime_type: &str,
+        args: &ValueRef,
+        kwargs: &ValueRef,
+    ) -> ValueRef {
+        self.enter_scope();
+        let schema_value = self.add_schema_check_variables(args, kwargs, runtime_type);
+        let schema_stack = self.schema_stack.replace(vec![]);
+        let args = self.get_variable(SCHEMA_ARGS);
+        self.walk_arguments(&rule_stmt.args, &args, kwargs);
+        self.schema_stack
+            .borrow_mut()
+            .push(runtime_type.to_string());
+        // Call base check function
+        for parent_name in &rule_stmt.parent_rules {
+            let base_constructor_func =
+                self.walk_identifier_with_ctx(&parent_name.node, &ast::ExprContext::Load, None);
+            self.call_schema_check(
+                &base_constructor_func,
+                &self.schema_check_args(None),
+                &ValueRef::dict(None),
+            );
+        }
+        // Call self rule check expressions
+        for check_expr in &rule_stmt.checks {
+            self.walk_check_expr(&check_expr.node);
+        }
+        self.schema_stack.replace(schema_stack);
+        self.leave_scope();
+        schema_value
+    }
+}
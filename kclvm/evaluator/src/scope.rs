@@ -0,0 +1,319 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_runtime::{
+    f64_unit_value, pkg_var_key, u64_unit_value, ValueRef, KCL_PRIVATE_VAR_PREFIX, MAIN_PKG_PATH,
+    PKG_PATH_PREFIX,
+};
+use kclvm_sema::{builtin, plugin};
+
+use crate::value::{dict_safe_insert, function_value, plan_to_json, schema_get_value};
+use crate::{
+    Evaluator, BACKTRACK_CACHE, BACKTRACK_LEVEL_MAP, LAMBDA_CLOSURE, SCHEMA_ARGS, SCHEMA_CAL_MAP,
+    SCHEMA_CONFIG_META_NAME, SCHEMA_CONFIG_NAME, SCHEMA_KWARGS, SCHEMA_SELF_NAME,
+};
+
+/// The evaluator scope including all the variable values.
+#[derive(Debug, Default, Clone)]
+pub struct Scope {
+    pub variables: IndexMap<String, ValueRef>,
+}
+
+/// Get the scope key of `pkgpath`, the main package is `__main__` and the others
+/// are prefixed with `@`.
+#[inline]
+pub(crate) fn pkgpath_scope_key(pkgpath: &str) -> String {
+    if !pkgpath.starts_with(PKG_PATH_PREFIX) && pkgpath != MAIN_PKG_PATH {
+        format!("{}{}", PKG_PATH_PREFIX, pkgpath)
+    } else {
+        pkgpath.to_string()
+    }
+}
+
+impl<'ctx> Evaluator<'ctx> {
+    /// Current package path
+    pub(crate) fn current_pkgpath(&self) -> String {
+        self.pkgpath_stack
+            .borrow()
+            .last()
+            .expect("the package path stack is empty")
+            .to_string()
+    }
+
+    /// Current filename
+    pub(crate) fn current_filename(&self) -> String {
+        self.filename_stack
+            .borrow()
+            .last()
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Init a scope named `pkgpath` with all builtin functions
+    pub(crate) fn init_scope(&self, pkgpath: &str) {
+        {
+            let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+            if pkg_scopes.contains_key(pkgpath) {
+                return;
+            }
+            pkg_scopes.insert(pkgpath.to_string(), vec![Scope::default()]);
+        }
+        let modules: &Vec<ast::Module> = match self.program.pkgs.get(pkgpath) {
+            Some(modules) => modules,
+            None => match pkgpath.strip_prefix(PKG_PATH_PREFIX) {
+                Some(path) if self.program.pkgs.contains_key(path) => &self.program.pkgs[path],
+                _ => panic!("pkgpath {} not found", pkgpath),
+            },
+        };
+        // Init all global types including schema and rule in the builtin scope.
+        for module in modules {
+            for stmt in &module.body {
+                let name = match &stmt.node {
+                    ast::Stmt::Schema(schema_stmt) => &schema_stmt.name.node,
+                    ast::Stmt::Rule(rule_stmt) => &rule_stmt.name.node,
+                    _ => continue,
+                };
+                self.add_variable(name, ValueRef::undefined());
+            }
+        }
+        // Init all builtin functions
+        for symbol in builtin::BUILTIN_FUNCTION_NAMES {
+            let function_name =
+                format!("{}_{}", builtin::KCL_BUILTIN_FUNCTION_MANGLE_PREFIX, symbol);
+            self.add_variable(symbol, function_value(&function_name));
+        }
+        self.enter_scope();
+    }
+
+    /// Get the scope level, 0 denotes the builtin scope.
+    pub(crate) fn scope_level(&self) -> usize {
+        let pkgpath = self.current_pkgpath();
+        let pkg_scopes = self.pkg_scopes.borrow();
+        let scopes = pkg_scopes
+            .get(&pkgpath)
+            .unwrap_or_else(|| panic!("pkgpath {} is not found", pkgpath));
+        scopes.len() - 1
+    }
+
+    /// Enter scope
+    pub(crate) fn enter_scope(&self) {
+        self.with_current_scopes(|scopes| scopes.push(Scope::default()));
+    }
+
+    /// Leave scope
+    pub(crate) fn leave_scope(&self) {
+        self.with_current_scopes(|scopes| {
+            scopes.pop();
+        });
+    }
+
+    /// Call `f` with the scopes of the current package.
+    pub(crate) fn with_current_scopes<R>(&self, f: impl FnOnce(&mut Vec<Scope>) -> R) -> R {
+        let pkgpath = self.current_pkgpath();
+        let mut pkg_scopes = self.pkg_scopes.borrow_mut();
+        let scopes = pkg_scopes
+            .get_mut(&pkgpath)
+            .unwrap_or_else(|| panic!("pkgpath {} is not found", pkgpath));
+        f(scopes)
+    }
+
+    /// Append a variable or update the existed variable in the current scope.
+    pub(crate) fn add_variable(&self, name: &str, value: ValueRef) {
+        self.with_current_scopes(|scopes| {
+            if let Some(last) = scopes.last_mut() {
+                last.variables.insert(name.to_string(), value);
+            }
+        });
+    }
+
+    /// Store the variable named `name` with `value` from the current scope, return false when not found
+    pub(crate) fn store_variable_in_current_scope(&self, name: &str, value: ValueRef) -> bool {
+        self.with_current_scopes(|scopes| match scopes.last_mut() {
+            Some(last) => match last.variables.get_mut(name) {
+                Some(var) => {
+                    *var = value;
+                    true
+                }
+                None => false,
+            },
+            None => false,
+        })
+    }
+
+    /// Store the variable named `name` with `value` from the scope, return false when not found
+    pub(crate) fn store_variable(&self, name: &str, value: ValueRef) -> bool {
+        self.with_current_scopes(|scopes| {
+            for scope in scopes.iter_mut().rev() {
+                if let Some(var) = scope.variables.get_mut(name) {
+                    *var = value;
+                    return true;
+                }
+            }
+            false
+        })
+    }
+
+    /// Resolve variable in scope, return false when not found
+    pub(crate) fn resolve_variable(&self, name: &str) -> bool {
+        self.with_current_scopes(|scopes| {
+            scopes
+                .iter()
+                .rev()
+                .any(|scope| scope.variables.contains_key(name))
+        })
+    }
+
+    /// Append a variable or update the existed variable in the global scope.
+    #[inline]
+    pub(crate) fn add_or_update_global_variable(&self, name: &str, value: ValueRef) {
+        self.add_variable(name, value);
+    }
+
+    /// Get the variable value named `name` from the scope, panic when not found
+    pub(crate) fn get_variable(&self, name: &str) -> ValueRef {
+        let pkgpath = self.current_pkgpath();
+        self.get_variable_in_pkgpath(name, &pkgpath)
+    }
+
+    /// Get the variable value named `name` from the schema instance, the schema
+    /// attributes may be calculated in the schema `cal_map` on demand.
+    pub(crate) fn get_variable_in_schema(&self, name: &str) -> ValueRef {
+        let schema_value = self.get_variable(SCHEMA_SELF_NAME);
+        let cal_map = self.get_variable(SCHEMA_CAL_MAP);
+        if cal_map.has_key(name) || schema_value.has_key(name) {
+            let target_attr = self
+                .target_vars
+                .borrow()
+                .last()
+                .cloned()
+                .unwrap_or_default();
+            schema_get_value(
+                &schema_value,
+                name,
+                &self.get_variable(SCHEMA_CONFIG_NAME),
+                &self.get_variable(SCHEMA_CONFIG_META_NAME),
+                &cal_map,
+                &target_attr,
+                &self.get_variable(BACKTRACK_LEVEL_MAP),
+                &self.get_variable(BACKTRACK_CACHE),
+                &self.get_variable(SCHEMA_ARGS),
+                &self.get_variable(SCHEMA_KWARGS),
+            )
+        } else {
+            let pkgpath = self.current_pkgpath();
+            self.find_variable_in_pkgpath(name, &pkgpath)
+                .unwrap_or_else(ValueRef::undefined)
+        }
+    }
+
+    /// Get the variable value named `name` from the scope named `pkgpath`. The resolver
+    /// has checked all the names, so a variable not found here is defined in a branch
+    /// not executed e.g., `if False: v = 1`, and returns the undefined value.
+    pub(crate) fn get_variable_in_pkgpath(&self, name: &str, pkgpath: &str) -> ValueRef {
+        self.find_variable_in_pkgpath(name, pkgpath)
+            .unwrap_or_else(ValueRef::undefined)
+    }
+
+    /// Find the variable value named `name` from the scope named `pkgpath`.
+    pub(crate) fn find_variable_in_pkgpath(&self, name: &str, pkgpath: &str) -> Option<ValueRef> {
+        let pkgpath = pkgpath_scope_key(pkgpath);
+        // System module
+        if builtin::STANDARD_SYSTEM_MODULE_NAMES_WITH_AT.contains(&pkgpath.as_str()) {
+            let pkgpath = &pkgpath[1..];
+            let value = if pkgpath == builtin::system_module::UNITS
+                && builtin::system_module::UNITS_FIELD_NAMES.contains(&name)
+            {
+                let value_int = u64_unit_value(name);
+                if value_int != 1 {
+                    ValueRef::int(value_int as i64)
+                } else {
+                    ValueRef::float(f64_unit_value(name))
+                }
+            } else {
+                function_value(&format!(
+                    "{}{}_{}",
+                    builtin::KCL_SYSTEM_MODULE_MANGLE_PREFIX,
+                    pkgpath,
+                    name
+                ))
+            };
+            Some(value)
+        }
+        // Plugin pkgpath
+        else if pkgpath.starts_with(plugin::PLUGIN_PREFIX_WITH_AT) {
+            let name = format!("{}.{}", &pkgpath[1..], name);
+            Some(ValueRef::func(0, 0, ValueRef::none(), &name, ""))
+        }
+        // User pkgpath
+        else {
+            let pkg_scopes = self.pkg_scopes.borrow();
+            let scopes = pkg_scopes
+                .get(&pkgpath)
+                .unwrap_or_else(|| panic!("package {} is not found", pkgpath));
+            // Scopes 0 is builtin scope, Scopes 1 is the global scope, Scopes 2~ are the local scopes
+            for (level, scope) in scopes.iter().enumerate().rev() {
+                if let Some(value) = scope.variables.get(name) {
+                    return Some(value.clone());
+                }
+                // Lambda closure variables
+                if level > 1 {
+                    if let Some(closure) = scope.variables.get(LAMBDA_CLOSURE) {
+                        if let Some(value) = closure.dict_get_value(name) {
+                            return Some(value);
+                        }
+                    }
+                }
+            }
+            None
+        }
+    }
+
+    /// Get the closure dict including all the local variables visible in the
+    /// current scope, which is captured by the lambda defined here.
+    pub(crate) fn get_closure_dict_in_current_scope(&self) -> ValueRef {
+        let mut closure = ValueRef::dict(None);
+        self.with_current_scopes(|scopes| {
+            for scope in scopes.iter().skip(2) {
+                if let Some(outer) = scope.variables.get(LAMBDA_CLOSURE) {
+                    closure.dict_update(outer);
+                }
+                for (name, value) in &scope.variables {
+                    if name != LAMBDA_CLOSURE {
+                        closure.dict_update_key_value(name, value.clone());
+                    }
+                }
+            }
+        });
+        if !self.schema_stack.borrow().is_empty() {
+            let schema_value = self.get_variable(SCHEMA_SELF_NAME);
+            closure.dict_update_key_value(SCHEMA_SELF_NAME, schema_value);
+        }
+        closure
+    }
+
+    /// Plan globals and the variables of other packages in `pkg_vars` to a json string,
+    /// the package variables are keyed by [pkg_var_key].
+    pub(crate) fn globals_to_json_str(&self) -> String {
+        let global_dict = ValueRef::dict(None);
+        let globals = self.with_current_scopes(|scopes| {
+            scopes
+                .last()
+                .map(|scope| scope.variables.clone())
+                .unwrap_or_default()
+        });
+        for (name, value) in &globals {
+            // Omit private variables and function variables
+            if name.starts_with(KCL_PRIVATE_VAR_PREFIX) {
+                continue;
+            }
+            dict_safe_insert(&global_dict, name, value, 0, -1);
+        }
+        // The package variables are selected by name, so private variables are not omitted.
+        for (pkgpath, name) in &self.pkg_vars {
+            let value = self.get_variable_in_pkgpath(name, pkgpath);
+            dict_safe_insert(&global_dict, &pkg_var_key(pkgpath, name), &value, 0, -1);
+        }
+        plan_to_json(&global_dict)
+    }
+}
@@ -0,0 +1,217 @@
+use kclvm_ast::ast;
+use kclvm_parser::parse_file;
+use kclvm_runtime::ValueRef;
+use kclvm_sema::resolver::resolve_program;
+
+use crate::Evaluator;
+
+/// Evaluate the KCL `code` and return the YAML result or the error message.
+fn eval(code: &str) -> Result<String, String> {
+    let mut module = parse_file("main.k", Some(code.to_string())).unwrap();
+    module.pkg = kclvm_ast::MAIN_PKG.to_string();
+    let mut program = ast::Program {
+        root: ".".to_string(),
+        main: kclvm_ast::MAIN_PKG.to_string(),
+        pkgs: [(kclvm_ast::MAIN_PKG.to_string(), vec![module])]
+            .into_iter()
+            .collect(),
+        cmd_args: vec![],
+        cmd_overrides: vec![],
+    };
+    let scope = resolve_program(&mut program);
+    let evaluator = Evaluator::new(&program, scope.import_names);
+    run(|ctx| {
+        evaluator
+            .main(kclvm_runtime::mut_ptr_as_ref(ctx))
+            .into_raw()
+    })
+}
+
+/// Run the main function in a new runtime context and return the YAML result or the error
+/// message.
+fn run<F>(main: F) -> Result<String, String>
+where
+    F: FnOnce(*mut kclvm_runtime::Context) -> *mut ValueRef,
+{
+    let option_keys: Vec<*const i8> = vec![std::ptr::null()];
+    let mut result = vec![0i8; 1024 * 1024];
+    let mut warn = vec![0i8; 1024 * 1024];
+    let n = kclvm_runtime::kcl_run_with_main(
+        main,
+        0,
+        option_keys.as_ptr(),
+        option_keys.as_ptr(),
+        0,
+        0,
+        0,
+        0,
+        0,
+        result.len() as i32 - 1,
+        result.as_mut_ptr(),
+        warn.len() as i32 - 1,
+        warn.as_mut_ptr(),
+        0,
+        0,
+    );
+    let (buffer, len) = if n >= 0 {
+        (&result, n as usize)
+    } else {
+        (&warn, (-n) as usize)
+    };
+    let bytes = buffer[..len].iter().map(|c| *c as u8).collect::<Vec<u8>>();
+    let s = String::from_utf8(bytes).unwrap();
+    if n >= 0 {
+        Ok(ValueRef::from_yaml_stream(&s).unwrap().plan().1)
+    } else {
+        Err(s)
+    }
+}
+
+#[test]
+fn test_eval_assign_stmt() {
+    let result = eval(
+        r#"
+a = 1
+b = a + 1
+c = [b * i for i in range(3)]
+d = {k = v for k, v in {x = 1, y = 2} if v > 1}
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        "a: 1\nb: 2\nc:\n  - 0\n  - 2\n  - 4\nd:\n  y: 2".to_string()
+    );
+}
+
+#[test]
+fn test_eval_if_stmt() {
+    let result = eval(
+        r#"
+a = 1
+if a > 1:
+    b = 1
+elif a == 1:
+    c = 2
+else:
+    d = 3
+"#,
+    )
+    .unwrap();
+    assert_eq!(result, "a: 1\nc: 2".to_string());
+}
+
+#[test]
+fn test_eval_lambda_closure() {
+    let result = eval(
+        r#"
+x = 10
+add = lambda a: int, b: int = 1 {
+    a + b + x
+}
+a = add(1)
+b = add(1, b=2)
+"#,
+    )
+    .unwrap();
+    assert_eq!(result, "x: 10\na: 12\nb: 13".to_string());
+}
+
+#[test]
+fn test_eval_schema_stmt() {
+    let result = eval(
+        r#"
+schema Base:
+    name: str
+    labels: {str:str} = {name = name}
+
+schema Person(Base):
+    age: int = 1
+    fullName = "${name} ${age}"
+
+    check:
+        age >= 0
+
+alice = Person {name = "alice", age = 18}
+bob = Person {name = "bob"} | {age = 20}
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        r#"alice:
+  name: alice
+  labels:
+    name: alice
+  age: 18
+  fullName: alice 18
+bob:
+  name: bob
+  labels:
+    name: bob
+  age: 20
+  fullName: bob 20"#
+            .to_string()
+    );
+}
+
+#[test]
+fn test_eval_in_threads() {
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            std::thread::spawn(move || {
+                eval(&format!(
+                    r#"
+schema Person:
+    name: str
+    age: int = {i}
+
+person = Person {{name = "p{i}"}}
+"#
+                ))
+            })
+        })
+        .collect();
+    for (i, thread) in threads.into_iter().enumerate() {
+        assert_eq!(
+            thread.join().unwrap().unwrap(),
+            format!("person:\n  name: p{}\n  age: {}", i, i)
+        );
+    }
+}
+
+/// The panics of the overlapped runs on different threads are reported by their own runs.
+#[test]
+fn test_run_panics_in_threads() {
+    let n = 8;
+    let barrier = std::sync::Arc::new(std::sync::Barrier::new(n));
+    let threads: Vec<_> = (0..n)
+        .map(|i| {
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                run(|_| {
+                    // All the runs are started before any of them ends.
+                    barrier.wait();
+                    if i % 2 == 0 {
+                        ValueRef::str(&format!("{{\"a\": {}}}", i)).into_raw()
+                    } else {
+                        std::thread::sleep(std::time::Duration::from_millis(20));
+                        panic!("failed in thread {}", i)
+                    }
+                })
+            })
+        })
+        .collect();
+    for (i, thread) in threads.into_iter().enumerate() {
+        let result = thread.join().unwrap().unwrap();
+        if i % 2 == 0 {
+            assert_eq!(result, format!("a: {}", i));
+        } else {
+            assert!(
+                result.contains(&format!("failed in thread {}\n", i)),
+                "{}",
+                result
+            );
+        }
+    }
+}
@@ -0,0 +1,452 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+//! Value helpers of the evaluator, which call the KCL runtime API functions
+//! used by the LLVM code generator to keep the same semantics.
+
+use std::ffi::CString;
+
+use kclvm_ast::ast;
+use kclvm_runtime::*;
+
+#[allow(non_camel_case_types)]
+type kclvm_char_t = i8;
+
+/// Binary runtime API function type e.g., `kclvm_value_op_add`.
+pub(crate) type BinaryFn = extern "C" fn(*const ValueRef, *const ValueRef) -> *mut ValueRef;
+/// Augmented assignment runtime API function type e.g., `kclvm_value_op_aug_add`.
+pub(crate) type AugFn = extern "C" fn(*mut ValueRef, *const ValueRef) -> *const ValueRef;
+/// Unary runtime API function type e.g., `kclvm_value_unary_plus`.
+pub(crate) type UnaryFn = extern "C" fn(*const ValueRef) -> *mut ValueRef;
+
+/// Get the value from the value pointer returned by the runtime API.
+#[inline]
+pub(crate) fn from_ptr(p: *const ValueRef) -> ValueRef {
+    ptr_as_ref(p).clone()
+}
+
+/// New a C string used as the runtime API argument.
+#[inline]
+pub(crate) fn c_str(s: &str) -> CString {
+    CString::new(s).unwrap_or_else(|_| panic!("invalid string '{}' with a nul byte", s))
+}
+
+/// Construct a native runtime function value e.g., `kclvm_builtin_len` and `kclvm_math_log`.
+pub(crate) fn function_value(name: &str) -> ValueRef {
+    ValueRef::func(_kclvm_get_fn_ptr_by_name(name), 0, ValueRef::none(), "", "")
+}
+
+/// Get the runtime API function of the binary operator.
+pub(crate) fn binary_fn(op: &ast::BinOp) -> BinaryFn {
+    match op {
+        ast::BinOp::Add => kclvm_value_op_add,
+        ast::BinOp::Sub => kclvm_value_op_sub,
+        ast::BinOp::Mul => kclvm_value_op_mul,
+        ast::BinOp::Div => kclvm_value_op_div,
+        ast::BinOp::FloorDiv => kclvm_value_op_floor_div,
+        ast::BinOp::Mod => kclvm_value_op_mod,
+        ast::BinOp::Pow => kclvm_value_op_pow,
+        ast::BinOp::LShift => kclvm_value_op_bit_lshift,
+        ast::BinOp::RShift => kclvm_value_op_bit_rshift,
+        ast::BinOp::BitAnd => kclvm_value_op_bit_and,
+        ast::BinOp::BitOr => kclvm_value_op_bit_or,
+        ast::BinOp::BitXor => kclvm_value_op_bit_xor,
+        ast::BinOp::And => kclvm_value_logic_and,
+        ast::BinOp::Or => kclvm_value_logic_or,
+        ast::BinOp::As => kclvm_value_as,
+    }
+}
+
+/// Get the runtime API function of the compare operator.
+pub(crate) fn compare_fn(op: &ast::CmpOp) -> BinaryFn {
+    match op {
+        ast::CmpOp::Eq => kclvm_value_cmp_equal_to,
+        ast::CmpOp::NotEq => kclvm_value_cmp_not_equal_to,
+        ast::CmpOp::Gt => kclvm_value_cmp_greater_than,
+        ast::CmpOp::GtE => kclvm_value_cmp_greater_than_or_equal,
+        ast::CmpOp::Lt => kclvm_value_cmp_less_than,
+        ast::CmpOp::LtE => kclvm_value_cmp_less_than_or_equal,
+        ast::CmpOp::Is => kclvm_value_is,
+        ast::CmpOp::IsNot => kclvm_value_is_not,
+        ast::CmpOp::Not => kclvm_value_is_not,
+        ast::CmpOp::NotIn => kclvm_value_not_in,
+        ast::CmpOp::In => kclvm_value_in,
+    }
+}
+
+/// Get the runtime API function of the unary operator.
+pub(crate) fn unary_fn(op: &ast::UnaryOp) -> UnaryFn {
+    match op {
+        ast::UnaryOp::UAdd => kclvm_value_unary_plus,
+        ast::UnaryOp::USub => kclvm_value_unary_minus,
+        ast::UnaryOp::Invert => kclvm_value_unary_not,
+        ast::UnaryOp::Not => kclvm_value_unary_l_not,
+    }
+}
+
+/// Call the binary runtime API function `f`.
+#[inline]
+pub(crate) fn binary(f: BinaryFn, left: &ValueRef, right: &ValueRef) -> ValueRef {
+    from_ptr(f(left, right))
+}
+
+/// Call the unary runtime API function `f`.
+#[inline]
+pub(crate) fn unary(f: UnaryFn, value: &ValueRef) -> ValueRef {
+    from_ptr(f(value))
+}
+
+/// Calculate the augmented assignment value, the union operator `|=` does not
+/// modify the original value in place.
+pub(crate) fn aug_assign(op: &ast::AugOp, org: &ValueRef, right: &ValueRef) -> ValueRef {
+    let f: AugFn = match op {
+        ast::AugOp::Add => kclvm_value_op_aug_add,
+        ast::AugOp::Sub => kclvm_value_op_aug_sub,
+        ast::AugOp::Mul => kclvm_value_op_aug_mul,
+        ast::AugOp::Div => kclvm_value_op_aug_div,
+        ast::AugOp::Mod => kclvm_value_op_aug_mod,
+        ast::AugOp::Pow => kclvm_value_op_aug_pow,
+        ast::AugOp::LShift => kclvm_value_op_aug_bit_lshift,
+        ast::AugOp::RShift => kclvm_value_op_aug_bit_rshift,
+        ast::AugOp::BitOr => return binary(kclvm_value_op_bit_or, org, right),
+        ast::AugOp::BitXor => kclvm_value_op_aug_bit_xor,
+        ast::AugOp::BitAnd => kclvm_value_op_aug_bit_and,
+        ast::AugOp::FloorDiv => kclvm_value_op_aug_floor_div,
+        ast::AugOp::Assign => panic!("invalid augmented assignment operator"),
+    };
+    aug(f, org, right)
+}
+
+/// Call the augmented assignment runtime API function `f`, which modifies the
+/// original value in place and returns it.
+#[inline]
+pub(crate) fn aug(f: AugFn, org: &ValueRef, right: &ValueRef) -> ValueRef {
+    let mut org = org.clone();
+    from_ptr(f(&mut org, right))
+}
+
+/// Load the attribute named `name` of the value.
+pub(crate) fn load_attr(value: &ValueRef, name: &str, has_question: bool) -> ValueRef {
+    let name = c_str(name);
+    let f = if has_question {
+        kclvm_value_load_attr_option
+    } else {
+        kclvm_value_load_attr
+    };
+    from_ptr(f(value, name.as_ptr() as *const kclvm_char_t))
+}
+
+/// Insert a dict entry including key, value, op and insert_index into the dict.
+pub(crate) fn dict_insert(dict: &ValueRef, key: &str, value: &ValueRef, op: i32, index: i32) {
+    let key = c_str(key);
+    let mut dict = dict.clone();
+    kclvm_dict_insert(
+        &mut dict,
+        key.as_ptr() as *const kclvm_char_t,
+        value,
+        op,
+        index,
+    );
+}
+
+/// Insert a dict entry with the key value into the dict.
+pub(crate) fn dict_insert_value(
+    dict: &ValueRef,
+    key: &ValueRef,
+    value: &ValueRef,
+    op: i32,
+    index: i32,
+) {
+    let mut dict = dict.clone();
+    kclvm_dict_insert_value(&mut dict, key, value, op, index);
+}
+
+/// Insert a dict entry into the dict only if the key, value and dict are valid.
+pub(crate) fn dict_safe_insert(dict: &ValueRef, key: &str, value: &ValueRef, op: i32, index: i32) {
+    let key = c_str(key);
+    let mut dict = dict.clone();
+    kclvm_dict_safe_insert(
+        &mut dict,
+        key.as_ptr() as *const kclvm_char_t,
+        value,
+        op,
+        index,
+    );
+}
+
+/// Merge a dict entry into the dict without the idempotent check.
+pub(crate) fn dict_merge(dict: &ValueRef, key: &str, value: &ValueRef, op: i32, index: i32) {
+    let key = c_str(key);
+    let mut dict = dict.clone();
+    kclvm_dict_merge(
+        &mut dict,
+        key.as_ptr() as *const kclvm_char_t,
+        value,
+        op,
+        index,
+    );
+}
+
+/// Set the dict value with the key.
+pub(crate) fn dict_set_value(dict: &ValueRef, key: &str, value: &ValueRef) {
+    let key = c_str(key);
+    let mut dict = dict.clone();
+    kclvm_dict_set_value(&mut dict, key.as_ptr() as *const kclvm_char_t, value);
+}
+
+/// Union the config entry into the schema value.
+pub(crate) fn value_union(schema: &ValueRef, value: &ValueRef) {
+    let mut schema = schema.clone();
+    kclvm_value_union(&mut schema, value);
+}
+
+/// default_dict(list) insert a key-value pair
+pub(crate) fn default_collection_insert_value(dict: &ValueRef, key: &str, value: &ValueRef) {
+    let key = c_str(key);
+    let mut dict = dict.clone();
+    kclvm_default_collection_insert_value(&mut dict, key.as_ptr() as *const kclvm_char_t, value);
+}
+
+/// Append the unpacked items of the value into the list.
+pub(crate) fn list_append_unpack(list: &ValueRef, value: &ValueRef) {
+    let mut list = list.clone();
+    kclvm_list_append_unpack(&mut list, value);
+}
+
+/// Insert the unpacked entries of the value into the dict.
+pub(crate) fn dict_insert_unpack(dict: &ValueRef, value: &ValueRef) {
+    let mut dict = dict.clone();
+    kclvm_dict_insert_unpack(&mut dict, value);
+}
+
+/// Remove the item from the collection value.
+pub(crate) fn remove_item(collection: &ValueRef, item: &ValueRef) {
+    let mut collection = collection.clone();
+    kclvm_value_remove_item(&mut collection, item);
+}
+
+/// Convert the collection value to the value with the type annotation.
+pub(crate) fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
+    let tpe = c_str(tpe);
+    from_ptr(kclvm_convert_collection_value(
+        value,
+        tpe.as_ptr() as *const kclvm_char_t,
+    ))
+}
+
+/// Get the schema attribute value named `name`, which may be calculated by the
+/// attribute functions in the `cal_map` on demand.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn schema_get_value(
+    schema: &ValueRef,
+    name: &str,
+    config: &ValueRef,
+    config_meta: &ValueRef,
+    cal_map: &ValueRef,
+    target_attr: &str,
+    backtrack_level_map: &ValueRef,
+    backtrack_cache: &ValueRef,
+    args: &ValueRef,
+    kwargs: &ValueRef,
+) -> ValueRef {
+    let name = c_str(name);
+    let target_attr = c_str(target_attr);
+    let mut backtrack_level_map = backtrack_level_map.clone();
+    let mut backtrack_cache = backtrack_cache.clone();
+    let mut args = args.clone();
+    let mut kwargs = kwargs.clone();
+    from_ptr(kclvm_schema_get_value(
+        schema,
+        name.as_ptr() as *const kclvm_char_t,
+        config,
+        config_meta,
+        cal_map,
+        target_attr.as_ptr() as *const kclvm_char_t,
+        &mut backtrack_level_map,
+        &mut backtrack_cache,
+        &mut args,
+        &mut kwargs,
+    ))
+}
+
+/// Cache the schema attribute value named `name` in the backtrack cache.
+pub(crate) fn schema_backtrack_cache(
+    schema: &ValueRef,
+    backtrack_cache: &ValueRef,
+    cal_map: &ValueRef,
+    name: &str,
+    runtime_type: &ValueRef,
+) {
+    let name = c_str(name);
+    let mut backtrack_cache = backtrack_cache.clone();
+    kclvm_schema_backtrack_cache(
+        schema,
+        &mut backtrack_cache,
+        cal_map,
+        name.as_ptr() as *const kclvm_char_t,
+        runtime_type,
+    );
+}
+
+/// Set the attribute type of the schema attribute named `name`.
+pub(crate) fn config_attr_map(schema: &ValueRef, name: &str, type_str: &str) {
+    let name = c_str(name);
+    let type_str = c_str(type_str);
+    let mut schema = schema.clone();
+    kclvm_config_attr_map(
+        &mut schema,
+        name.as_ptr() as *const kclvm_char_t,
+        type_str.as_ptr() as *const kclvm_char_t,
+    );
+}
+
+/// Call the function value `func` with the arguments and keyword arguments.
+pub(crate) fn function_invoke(
+    func: &ValueRef,
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    pkgpath: &str,
+) -> ValueRef {
+    let pkgpath = c_str(pkgpath);
+    let mut args = args.clone();
+    from_ptr(kclvm_value_function_invoke(
+        func,
+        kclvm_context_current(),
+        &mut args,
+        kwargs,
+        pkgpath.as_ptr() as *const kclvm_char_t,
+    ))
+}
+
+/// New a schema instance using the schema type function value and the config.
+pub(crate) fn schema_value_new(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    schema_type: &ValueRef,
+    config: &ValueRef,
+    config_meta: &ValueRef,
+    pkgpath: &str,
+) -> ValueRef {
+    let pkgpath = c_str(pkgpath);
+    let mut args = args.clone();
+    from_ptr(kclvm_schema_value_new(
+        kclvm_context_current(),
+        &mut args,
+        kwargs,
+        schema_type,
+        config,
+        config_meta,
+        pkgpath.as_ptr() as *const kclvm_char_t,
+    ))
+}
+
+/// Build a schema instance value using the schema dict and the config.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn schema_with_config(
+    schema: &ValueRef,
+    config: &ValueRef,
+    name: &str,
+    pkgpath: &str,
+    is_sub_schema: &ValueRef,
+    record_instance: &ValueRef,
+    instance_pkgpath: &ValueRef,
+) -> ValueRef {
+    let name = c_str(name);
+    let pkgpath = c_str(pkgpath);
+    from_ptr(kclvm_value_schema_with_config(
+        schema,
+        config,
+        name.as_ptr() as *const kclvm_char_t,
+        pkgpath.as_ptr() as *const kclvm_char_t,
+        is_sub_schema,
+        record_instance,
+        instance_pkgpath,
+    ))
+}
+
+/// Check the schema value using the index signature.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn schema_value_check(
+    schema: &ValueRef,
+    config: &ValueRef,
+    config_meta: &ValueRef,
+    schema_name: &str,
+    index_sign_value: &ValueRef,
+    key_name: &str,
+    key_type: &str,
+    value_type: &str,
+    any_other: bool,
+) {
+    let schema_name = c_str(schema_name);
+    let key_name = c_str(key_name);
+    let key_type = c_str(key_type);
+    let value_type = c_str(value_type);
+    let mut schema = schema.clone();
+    kclvm_schema_value_check(
+        &mut schema,
+        config,
+        config_meta,
+        schema_name.as_ptr() as *const kclvm_char_t,
+        index_sign_value,
+        key_name.as_ptr() as *const kclvm_char_t,
+        key_type.as_ptr() as *const kclvm_char_t,
+        value_type.as_ptr() as *const kclvm_char_t,
+        any_other as i8,
+        false as i8,
+    );
+}
+
+/// Check the schema attributes which are not optional are not none or undefined.
+pub(crate) fn schema_optional_check(
+    schema: &ValueRef,
+    optional_mapping: &ValueRef,
+    schema_name: &str,
+    config_meta: &ValueRef,
+) {
+    let schema_name = c_str(schema_name);
+    kclvm_schema_optional_check(
+        schema,
+        optional_mapping,
+        schema_name.as_ptr() as *const kclvm_char_t,
+        config_meta,
+    );
+}
+
+/// Append the schema default settings into the schema value.
+pub(crate) fn schema_default_settings(schema: &ValueRef, config: &ValueRef, runtime_type: &str) {
+    let runtime_type = c_str(runtime_type);
+    let mut schema = schema.clone();
+    kclvm_schema_default_settings(
+        &mut schema,
+        config,
+        runtime_type.as_ptr() as *const kclvm_char_t,
+    );
+}
+
+/// Run the decorator named `name` on the schema or the schema attribute.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decorator(
+    name: &str,
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    config_meta: &ValueRef,
+    attr_name: &str,
+    config: &ValueRef,
+    is_schema_target: bool,
+) {
+    let name = c_str(name);
+    let attr_name = c_str(attr_name);
+    kclvm_value_Decorator(
+        name.as_ptr() as *const kclvm_char_t,
+        args,
+        kwargs,
+        config_meta,
+        attr_name.as_ptr() as *const kclvm_char_t,
+        config,
+        &ValueRef::bool(is_schema_target),
+    );
+}
+
+/// Get the plain JSON string of all the values in the dict.
+pub(crate) fn plan_to_json(value: &ValueRef) -> String {
+    from_ptr(kclvm_value_plan_to_json(value)).as_str()
+}
@@ -113,19 +113,28 @@ impl<'a> Lexer<'a> {
                                             // Proper indent level found.
                                             break;
                                         }
-                                        Ordering::Greater => self.sess.struct_span_error(
-                                            "fatal: logic error on dedenting.",
-                                            self.span(self.pos, self.pos),
-                                        ),
+                                        Ordering::Greater => {
+                                            // If we don't abort here, the line is regarded
+                                            // as the outer indent level.
+                                            self.sess.struct_span_error_recoverable(
+                                                "fatal: logic error on dedenting.",
+                                                self.span(self.pos, self.pos),
+                                            );
+                                            break;
+                                        }
                                     }
 
                                     // update cur indent and ordering
                                     cur_indent = self.indent_cxt.indents.last().unwrap();
                                     ordering = indet.cmp(cur_indent);
                                 }
-                                Err(msg) => self
-                                    .sess
-                                    .struct_span_error(msg, self.span(self.pos, self.pos)),
+                                Err(msg) => {
+                                    self.sess.struct_span_error_recoverable(
+                                        msg,
+                                        self.span(self.pos, self.pos),
+                                    );
+                                    break;
+                                }
                             }
                         }
 
@@ -134,9 +143,11 @@ impl<'a> Lexer<'a> {
                     _ => return None,
                 })
             }
-            Err(msg) => self
-                .sess
-                .struct_span_error(msg, self.span(self.pos, self.pos)),
+            Err(msg) => {
+                self.sess
+                    .struct_span_error_recoverable(msg, self.span(self.pos, self.pos));
+                None
+            }
         }
     }
 }
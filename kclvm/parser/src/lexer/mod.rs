@@ -410,13 +410,22 @@ impl<'a> Lexer<'a> {
                 }
             },
             kclvm_lexer::TokenKind::LineContinue => return None,
-            kclvm_lexer::TokenKind::InvalidLineContinue => self.sess.struct_span_error(
-                "unexpected character after line continuation character",
-                self.span(start, self.pos),
-            ),
-            _ => self
-                .sess
-                .struct_span_error("unknown start of token", self.span(start, self.pos)),
+            kclvm_lexer::TokenKind::InvalidLineContinue => {
+                // If we don't abort here, the invalid line continue is skipped.
+                self.sess.struct_span_error_recoverable(
+                    "unexpected character after line continuation character",
+                    self.span(start, self.pos),
+                );
+                return None;
+            }
+            _ => {
+                // If we don't abort here, the unknown token is skipped.
+                self.sess.struct_span_error_recoverable(
+                    "unknown start of token",
+                    self.span(start, self.pos),
+                );
+                return None;
+            }
         })
     }
 
@@ -432,8 +441,10 @@ impl<'a> Lexer<'a> {
                 triple_quoted,
             } => {
                 if !terminated {
-                    self.sess
-                        .struct_span_error("unterminated string", self.span(start, self.pos))
+                    self.sess.struct_span_error_recoverable(
+                        "unterminated string",
+                        self.span(start, self.pos),
+                    )
                 }
 
                 let start_char = self.char_from(start);
@@ -456,10 +467,15 @@ impl<'a> Lexer<'a> {
                 };
 
                 let content_start = start + BytePos::from_u32(offset);
-                let mut content_end = suffix_start - BytePos::from_u32(offset);
-                if is_raw {
-                    content_end = content_end + BytePos::from_u32(1);
-                }
+                // An unterminated string has no closing quotes, its content
+                // ends at the end of the token.
+                let content_end = if !terminated {
+                    suffix_start.max(content_start)
+                } else if is_raw {
+                    suffix_start - BytePos::from_u32(offset) + BytePos::from_u32(1)
+                } else {
+                    suffix_start - BytePos::from_u32(offset)
+                };
                 let string_content = self.str_from_to(content_start, content_end);
                 let value = match str_content_eval(
                     string_content,
@@ -469,10 +485,13 @@ impl<'a> Lexer<'a> {
                     is_raw,
                 ) {
                     Some(v) => v,
-                    None => self.sess.struct_span_error(
-                        "Invalid string syntax",
-                        self.span(content_start, self.pos),
-                    ),
+                    None => {
+                        self.sess.struct_span_error_recoverable(
+                            "Invalid string syntax",
+                            self.span(content_start, self.pos),
+                        );
+                        string_content.to_string()
+                    }
                 };
 
                 (
@@ -487,33 +506,37 @@ impl<'a> Lexer<'a> {
             }
             kclvm_lexer::LiteralKind::Int { base, empty_int } => {
                 if empty_int {
-                    self.sess.struct_span_error(
+                    self.sess.struct_span_error_recoverable(
                         "no valid digits found for number",
                         self.span(start, self.pos),
-                    )
+                    );
+                    // The invalid literal is lexed as zero in the error-tolerant session.
+                    (token::Integer, Symbol::intern("0"), None, None)
                 } else {
-                    self.validate_literal_int(base, start, suffix_start);
+                    let valid = self.validate_literal_int(base, start, suffix_start);
 
                     let suffix = if suffix_start < self.pos {
                         let suffix_str = self.str_from(suffix_start);
                         // int binary suffix
-                        if !NumberBinarySuffix::all_names().contains(&suffix_str) {
-                            self.sess.struct_span_error(
+                        if NumberBinarySuffix::all_names().contains(&suffix_str) {
+                            Some(Symbol::intern(suffix_str))
+                        } else {
+                            self.sess.struct_span_error_recoverable(
                                 "invalid int binary suffix",
                                 self.span(start, self.pos),
-                            )
+                            );
+                            None
                         }
-                        Some(Symbol::intern(suffix_str))
                     } else {
                         None
                     };
 
-                    (
-                        token::Integer,
-                        self.symbol_from_to(start, suffix_start),
-                        suffix,
-                        None,
-                    )
+                    let symbol = if valid {
+                        self.symbol_from_to(start, suffix_start)
+                    } else {
+                        Symbol::intern("0")
+                    };
+                    (token::Integer, symbol, suffix, None)
                 }
             }
 
@@ -521,13 +544,12 @@ impl<'a> Lexer<'a> {
                 base,
                 empty_exponent,
             } => {
-                self.validate_literal_float(base, start, empty_exponent);
-                (
-                    token::Float,
-                    self.symbol_from_to(start, suffix_start),
-                    None,
-                    None,
-                )
+                let symbol = if self.validate_literal_float(base, start, empty_exponent) {
+                    self.symbol_from_to(start, suffix_start)
+                } else {
+                    Symbol::intern("0.0")
+                };
+                (token::Float, symbol, None, None)
             }
             kclvm_lexer::LiteralKind::Bool { terminated: _ } => (
                 token::Bool,
@@ -542,12 +564,19 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn validate_literal_int(&self, base: Base, content_start: BytePos, content_end: BytePos) {
+    /// Validate the digits of the int literal and returns whether it is valid,
+    /// the error is recorded and false is returned in the error-tolerant session.
+    fn validate_literal_int(
+        &self,
+        base: Base,
+        content_start: BytePos,
+        content_end: BytePos,
+    ) -> bool {
         let base = match base {
             Base::Binary => 2,
             Base::Octal => 8,
             Base::Hexadecimal => 16,
-            _ => return,
+            _ => return true,
         };
         let s = self.str_from_to(content_start + BytePos::from_u32(2), content_end);
         for (idx, c) in s.char_indices() {
@@ -556,40 +585,35 @@ impl<'a> Lexer<'a> {
                 let lo = content_start + BytePos::from_u32(2 + idx);
                 let hi = content_start + BytePos::from_u32(2 + idx + c.len_utf8() as u32);
 
-                self.sess.struct_span_error(
+                self.sess.struct_span_error_recoverable(
                     &format!(
                         "invalid digit for a base {} literal, start: {}, stop: {}",
                         base, lo, hi
                     ),
                     self.span(lo, self.pos),
-                )
+                );
+                return false;
             }
         }
+        true
     }
 
-    fn validate_literal_float(&self, base: Base, start: BytePos, empty_exponent: bool) {
-        if empty_exponent {
-            self.sess.struct_span_error(
-                "expected at least one digit in exponent",
-                self.span(start, self.pos),
-            )
-        }
-
-        match base {
-            kclvm_lexer::Base::Hexadecimal => self.sess.struct_span_error(
-                "hexadecimal float literal is not supported",
-                self.span(start, self.pos),
-            ),
-            kclvm_lexer::Base::Octal => self.sess.struct_span_error(
-                "octal float literal is not supported",
-                self.span(start, self.pos),
-            ),
-            kclvm_lexer::Base::Binary => self.sess.struct_span_error(
-                "binary float literal is not supported",
-                self.span(start, self.pos),
-            ),
-            _ => (),
-        }
+    /// Validate the float literal and returns whether it is valid, the error is
+    /// recorded and false is returned in the error-tolerant session.
+    fn validate_literal_float(&self, base: Base, start: BytePos, empty_exponent: bool) -> bool {
+        let msg = if empty_exponent {
+            "expected at least one digit in exponent"
+        } else {
+            match base {
+                kclvm_lexer::Base::Hexadecimal => "hexadecimal float literal is not supported",
+                kclvm_lexer::Base::Octal => "octal float literal is not supported",
+                kclvm_lexer::Base::Binary => "binary float literal is not supported",
+                _ => return true,
+            }
+        };
+        self.sess
+            .struct_span_error_recoverable(msg, self.span(start, self.pos));
+        false
     }
 
     fn span(&self, lo: BytePos, hi: BytePos) -> Span {
@@ -11,7 +11,8 @@ extern crate kclvm_error;
 
 use crate::session::ParseSession;
 use kclvm_ast::ast;
-use kclvm_error::bug;
+use kclvm_config::cache::{load_pkg_cache, save_pkg_cache, CacheOption};
+use kclvm_error::{bug, Diagnostic, FatalErrorMarker};
 use kclvm_runtime::{ErrType, PanicInfo};
 use kclvm_span::{self, FilePathMapping, SourceMap};
 
@@ -23,11 +24,15 @@ use rustc_span::Pos;
 use std::error::Error;
 use std::fs::File;
 use std::io::BufReader;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use kclvm_span::create_session_globals_then;
 
+/// The cache target of the parsed package modules.
+const AST_CACHE_TARGET: &str = "ast";
+
 /// parser mode
 #[derive(Debug, Clone)]
 pub enum ParseMode {
@@ -67,6 +72,35 @@ pub fn parse_program(filename: &str) -> Result<ast::Program, String> {
 }
 
 pub fn parse_file(filename: &str, code: Option<String>) -> Result<ast::Module, String> {
+    parse_file_with_session(filename, code, false).map(|(module, _)| module)
+}
+
+/// Parse a KCL file to the module AST with error recovery. Unlike [`parse_file`],
+/// which aborts the process on the first syntax error, it returns the partial
+/// module with error nodes and all the syntax errors, and returns an error only
+/// when the file can not be loaded.
+///
+/// # Examples
+/// ```
+/// use kclvm_parser::parse_file_with_errors;
+///
+/// let code = "a = \nb = 1\n".to_string();
+/// let (module, errors) = parse_file_with_errors("main.k", Some(code)).unwrap();
+/// assert_eq!(module.body.len(), 2);
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn parse_file_with_errors(
+    filename: &str,
+    code: Option<String>,
+) -> Result<(ast::Module, Vec<Diagnostic>), String> {
+    parse_file_with_session(filename, code, true)
+}
+
+fn parse_file_with_session(
+    filename: &str,
+    code: Option<String>,
+    recovery: bool,
+) -> Result<(ast::Module, Vec<Diagnostic>), String> {
     create_session_globals_then(move || {
         let src = if let Some(s) = code {
             s
@@ -83,7 +117,11 @@ pub fn parse_file(filename: &str, code: Option<String>) -> Result<ast::Module, S
 
         let sm = kclvm_span::SourceMap::new(FilePathMapping::empty());
         let sf = sm.new_source_file(PathBuf::from(filename).into(), src.to_string());
-        let sess = &ParseSession::with_source_map(std::sync::Arc::new(sm));
+        let sess = &if recovery {
+            ParseSession::with_source_map_and_recovery(std::sync::Arc::new(sm))
+        } else {
+            ParseSession::with_source_map(std::sync::Arc::new(sm))
+        };
 
         let src_from_sf = match sf.src.as_ref() {
             Some(src) => src,
@@ -93,15 +131,35 @@ pub fn parse_file(filename: &str, code: Option<String>) -> Result<ast::Module, S
             }
         };
 
-        let stream = lexer::parse_token_streams(sess, src_from_sf.as_str(), sf.start_pos);
-        let mut p = parser::Parser::new(sess, stream);
-        let mut m = p.parse_module();
+        let mut m = if recovery {
+            // The lexer records the invalid tokens such as invalid number literals
+            // in the error-tolerant session, and only aborts on the internal errors
+            // of unknown literal kinds, in which case an empty module is returned.
+            match catch_unwind(AssertUnwindSafe(|| {
+                lexer::parse_token_streams(sess, src_from_sf.as_str(), sf.start_pos)
+            })) {
+                Ok(stream) => parser::Parser::new(sess, stream).parse_module(),
+                Err(err) if err.is::<FatalErrorMarker>() => ast::Module {
+                    filename: "".to_string(),
+                    pkg: "".to_string(),
+                    doc: "".to_string(),
+                    name: "".to_string(),
+                    body: vec![],
+                    comments: vec![],
+                },
+                Err(err) => resume_unwind(err),
+            }
+        } else {
+            let stream = lexer::parse_token_streams(sess, src_from_sf.as_str(), sf.start_pos);
+            parser::Parser::new(sess, stream).parse_module()
+        };
 
         m.filename = filename.to_string();
         m.pkg = kclvm_ast::MAIN_PKG.to_string();
         m.name = kclvm_ast::MAIN_PKG.to_string();
 
-        Ok(m)
+        let diagnostics = sess.handler.borrow().diagnostics.iter().cloned().collect();
+        Ok((m, diagnostics))
     })
 }
 
@@ -153,11 +211,13 @@ pub struct LoadProgramOptions {
     pub _load_packages: bool,
 }
 
+/// Load the program from the paths. When `enable_pkg_cache` is set in the
+/// build section of `kcl.mod`, the parsed modules of the imported packages
+/// are cached under the package root and reused until the package changes.
 pub fn load_program(
     paths: &[&str],
     opts: Option<LoadProgramOptions>,
 ) -> Result<ast::Program, String> {
-    // todo: support cache
     if let Some(opts) = opts {
         Loader::new(paths, Some(opts)).load_main()
     } else {
@@ -165,6 +225,19 @@ pub fn load_program(
     }
 }
 
+/// Load the program like [`load_program`] with error recovery, the syntax errors
+/// of all the files are returned with the partial program instead of aborting
+/// the process, see [`parse_file_with_errors`].
+pub fn load_program_with_errors(
+    paths: &[&str],
+    opts: Option<LoadProgramOptions>,
+) -> Result<(ast::Program, Vec<Diagnostic>), String> {
+    let mut loader = Loader::new(paths, opts);
+    loader.recovery = true;
+    let program = loader.load_main()?;
+    Ok((program, loader.diagnostics))
+}
+
 struct Loader {
     paths: Vec<String>,
     opts: LoadProgramOptions,
@@ -175,6 +248,10 @@ struct Loader {
     pkgs: std::collections::HashMap<String, Vec<ast::Module>>,
     missing_pkgs: Vec<String>,
     // todo: add shared source_map all parse_file.
+    /// Whether to parse files with error recovery.
+    recovery: bool,
+    /// Syntax errors of all the files parsed with error recovery.
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl Loader {
@@ -188,6 +265,19 @@ impl Loader {
             modfile: Default::default(),
             pkgs: Default::default(),
             missing_pkgs: Default::default(),
+
+            recovery: false,
+            diagnostics: Default::default(),
+        }
+    }
+
+    fn parse_file(&mut self, filename: &str, code: Option<String>) -> Result<ast::Module, String> {
+        if self.recovery {
+            let (module, diagnostics) = parse_file_with_errors(filename, code)?;
+            self.diagnostics.extend(diagnostics);
+            Ok(module)
+        } else {
+            parse_file(filename, code)
         }
     }
 
@@ -280,11 +370,11 @@ impl Loader {
         for (i, filename) in (&k_files).iter().enumerate() {
             // todo: add shared source map for all files
             if i < self.opts.k_code_list.len() {
-                let mut m = parse_file(filename, Some(self.opts.k_code_list[i].clone()))?;
+                let mut m = self.parse_file(filename, Some(self.opts.k_code_list[i].clone()))?;
                 self.fix_rel_import_path(&mut m);
                 pkg_files.push(m)
             } else {
-                let mut m = parse_file(filename, None)?;
+                let mut m = self.parse_file(filename, None)?;
                 self.fix_rel_import_path(&mut m);
                 pkg_files.push(m);
             }
@@ -351,19 +441,30 @@ impl Loader {
             return Ok(());
         }
 
-        let mut pkg_files = Vec::new();
-        for filename in k_files {
-            debug_assert!(self.is_file(filename.as_str()));
-            debug_assert!(self.path_exist(filename.as_str()));
+        let pkg_files = match self.load_pkg_files_from_cache(&pkgpath) {
+            Some(pkg_files) => pkg_files,
+            None => {
+                let errors = self.diagnostics.len();
+                let mut pkg_files = Vec::new();
+                for filename in k_files {
+                    debug_assert!(self.is_file(filename.as_str()));
+                    debug_assert!(self.path_exist(filename.as_str()));
 
-            let mut m = parse_file(filename.as_str(), None)?;
+                    let mut m = self.parse_file(filename.as_str(), None)?;
 
-            m.pkg = pkgpath.clone();
-            m.name = "".to_string();
-            self.fix_rel_import_path(&mut m);
+                    m.pkg = pkgpath.clone();
+                    m.name = "".to_string();
+                    self.fix_rel_import_path(&mut m);
 
-            pkg_files.push(m);
-        }
+                    pkg_files.push(m);
+                }
+                // Partial modules with syntax errors are never cached.
+                if self.diagnostics.len() == errors {
+                    self.save_pkg_files_to_cache(&pkgpath, &pkg_files);
+                }
+                pkg_files
+            }
+        };
 
         let import_list = self.get_import_list(&pkg_files);
         self.pkgs.insert(pkgpath, pkg_files);
@@ -375,6 +476,44 @@ impl Loader {
         return Ok(());
     }
 
+    /// Whether to cache the parsed modules of the imported packages. The cache
+    /// is also used with error recovery e.g., in `load_program_with_errors`, but
+    /// the packages with syntax errors are never saved into the cache.
+    fn enable_pkg_cache(&self) -> bool {
+        !self.pkgroot.is_empty() && self.modfile.enable_pkg_cache()
+    }
+
+    /// Load the parsed modules of the package from the cache, the cache is
+    /// invalidated when any file of the package changes.
+    fn load_pkg_files_from_cache(&self, pkgpath: &str) -> Option<Vec<ast::Module>> {
+        if !self.enable_pkg_cache() {
+            return None;
+        }
+        let pkg_files: Vec<ast::Module> = load_pkg_cache(
+            &self.pkgroot,
+            AST_CACHE_TARGET,
+            pkgpath,
+            CacheOption::default(),
+        )?;
+        if pkg_files.is_empty() {
+            None
+        } else {
+            Some(pkg_files)
+        }
+    }
+
+    fn save_pkg_files_to_cache(&self, pkgpath: &str, pkg_files: &[ast::Module]) {
+        if self.enable_pkg_cache() {
+            save_pkg_cache(
+                &self.pkgroot,
+                AST_CACHE_TARGET,
+                pkgpath,
+                pkg_files,
+                CacheOption::default(),
+            );
+        }
+    }
+
     fn get_import_list(&self, pkg: &[ast::Module]) -> Vec<ast::ImportStmt> {
         let mut import_list = Vec::new();
         for m in pkg {
@@ -292,7 +292,7 @@ impl<'a> Parser<'a> {
     }
 
     /// Syntax:
-    /// call_suffix: LEFT_PARENTHESES [arguments [COMMA]] RIGHT_PARENTHESES
+    /// call_suffix: LEFT_PARENTHESES [arguments [COMMA] | NEWLINE _INDENT arguments [COMMA] [NEWLINE] _DEDENT] RIGHT_PARENTHESES
     fn parse_call_expr(&mut self, func: NodeRef<Expr>) -> NodeRef<Expr> {
         let token = self.token;
         let call_expr = self.parse_call(func);
@@ -312,6 +312,20 @@ impl<'a> Parser<'a> {
             ),
         }
 
+        // [NEWLINE _INDENT]
+        let has_newline = if self.token.kind == TokenKind::Newline {
+            self.skip_newlines();
+            if self.token.kind == TokenKind::Indent {
+                self.bump();
+            } else {
+                self.sess
+                    .struct_token_error(&[TokenKind::Indent.into()], self.token)
+            }
+            true
+        } else {
+            false
+        };
+
         // arguments or empty
         let (args, keywords) = if self.token.kind == TokenKind::CloseDelim(DelimToken::Paren) {
             (Vec::new(), Vec::new())
@@ -324,6 +338,17 @@ impl<'a> Parser<'a> {
             self.bump()
         }
 
+        // [NEWLINE] _DEDENT
+        if has_newline {
+            self.skip_newlines();
+            if self.token.kind == TokenKind::Dedent {
+                self.bump();
+            } else {
+                self.sess
+                    .struct_token_error(&[TokenKind::Dedent.into()], self.token)
+            }
+        }
+
         // RIGHT_PARENTHESES
         match self.token.kind {
             TokenKind::CloseDelim(DelimToken::Paren) => self.bump(),
@@ -549,14 +574,24 @@ impl<'a> Parser<'a> {
                     ),
                 }
             }
-            _ => self.sess.struct_token_error(
-                &[
+            _ => {
+                let expected = [
                     TokenKind::ident_value(),
                     TokenKind::literal_value(),
                     TokenKind::OpenDelim(DelimToken::NoDelim).into(),
-                ],
-                self.token,
-            ),
+                ];
+                // If we don't abort here, an operand missing at the end of the
+                // line is recovered as a missing expression, e.g. `a = `.
+                if self.token.kind == TokenKind::Newline {
+                    self.sess
+                        .struct_token_error_recoverable(&expected, self.token);
+                    return Box::new(Node::node(
+                        Expr::Missing(MissingExpr),
+                        self.sess.struct_token_loc(self.token, self.token),
+                    ));
+                }
+                self.sess.struct_token_error(&expected, self.token)
+            }
         }
     }
 
@@ -1809,7 +1844,7 @@ impl<'a> Parser<'a> {
     }
 
     /// Syntax:
-    /// arguments: argument (COMMA argument)*
+    /// arguments: argument (COMMA [NEWLINE] argument)*
     fn parse_arguments_expr(&mut self) -> (Vec<NodeRef<Expr>>, Vec<NodeRef<Keyword>>) {
         let mut args: Vec<NodeRef<Expr>> = Vec::new();
         let mut keywords: Vec<NodeRef<Keyword>> = Vec::new();
@@ -1834,6 +1869,14 @@ impl<'a> Parser<'a> {
 
             if self.token.kind == TokenKind::Comma {
                 self.bump();
+                self.skip_newlines();
+                // The trailing comma of the arguments.
+                if matches!(
+                    self.token.kind,
+                    TokenKind::CloseDelim(DelimToken::Paren) | TokenKind::Dedent
+                ) {
+                    break;
+                }
             } else {
                 break;
             }
@@ -1961,9 +2004,16 @@ impl<'a> Parser<'a> {
                             names.push(id.as_str().to_string());
                             self.bump();
                         }
-                        None => self
-                            .sess
-                            .struct_token_error(&[TokenKind::ident_value()], self.token),
+                        None => {
+                            // If we don't abort here, the missing name after the dot
+                            // is recovered as an empty name, e.g. `a.` for completion.
+                            self.sess.struct_token_error_recoverable(
+                                &[TokenKind::ident_value()],
+                                self.token,
+                            );
+                            names.push("".to_string());
+                            break;
+                        }
                     }
                 }
                 _ => break,
@@ -25,10 +25,15 @@ mod ty;
 
 use crate::session::ParseSession;
 
-use kclvm_ast::ast::{Comment, NodeRef};
+use kclvm_ast::ast::{Comment, Expr, ExprStmt, MissingExpr, Node, NodeRef, Stmt};
+use kclvm_ast::node_ref;
 use kclvm_ast::token::{CommentKind, Token, TokenKind};
 use kclvm_ast::token_stream::{Cursor, TokenStream};
+use kclvm_error::{FatalErrorMarker, Position};
+use kclvm_runtime::PanicInfo;
 use kclvm_span::symbol::Symbol;
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 
 pub struct Parser<'a> {
     /// The current token.
@@ -140,6 +145,99 @@ impl<'a> Parser<'a> {
             self.bump();
         }
     }
+
+    /// Parse a statement with `parse`. In the error-tolerant session, a syntax
+    /// error only aborts the statement being parsed: the rest tokens of the
+    /// statement are skipped and an expression statement holding a missing
+    /// expression is returned as the error node.
+    pub(crate) fn parse_with_recovery<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> T,
+    ) -> Result<T, NodeRef<Stmt>> {
+        if !self.sess.recovery {
+            return Ok(parse(self));
+        }
+        let token = self.token;
+        let start_index = self.cursor.index();
+        match catch_unwind(AssertUnwindSafe(|| parse(self))) {
+            Ok(result) => Ok(result),
+            Err(err) => {
+                self.report_panic(err);
+                self.skip_to_stmt_end(start_index);
+                let pos = self.token_span_pos(token, self.prev_token);
+                Err(node_ref!(
+                    Stmt::Expr(ExprStmt {
+                        exprs: vec![node_ref!(Expr::Missing(MissingExpr), pos.clone())],
+                    }),
+                    pos
+                ))
+            }
+        }
+    }
+
+    /// Record the panic which aborts the parsing of a statement. Syntax errors
+    /// have been recorded before unwinding, and other panics such as invalid
+    /// AST nodes are recorded at the current token.
+    fn report_panic(&mut self, err: Box<dyn Any + Send>) {
+        if err.is::<FatalErrorMarker>() {
+            return;
+        }
+        let msg = match err.downcast_ref::<String>() {
+            Some(msg) => msg.to_string(),
+            None => match err.downcast_ref::<&str>() {
+                Some(msg) => msg.to_string(),
+                None => "Invalid syntax".to_string(),
+            },
+        };
+        let mut handler = self.sess.handler.borrow_mut();
+        match serde_json::from_str::<PanicInfo>(&msg) {
+            Ok(panic_info) => handler.add_panic_info(&panic_info),
+            Err(_) => {
+                let pos: Position = self
+                    .sess
+                    .source_map
+                    .lookup_char_pos(self.token.span.lo())
+                    .into();
+                handler.add_syntex_error(&msg, pos)
+            }
+        };
+    }
+
+    /// Skip the tokens until the end of the statement starting at the cursor
+    /// `start_index`, which is the first newline out of the blocks and the
+    /// brackets opened by the statement. The dedent closing the outer block
+    /// is not skipped, and at least one token is skipped to make progress.
+    fn skip_to_stmt_end(&mut self, start_index: usize) {
+        fn update_depth(kind: TokenKind, indent_depth: &mut i32, delim_depth: &mut i32) {
+            match kind {
+                TokenKind::Indent => *indent_depth += 1,
+                TokenKind::Dedent => *indent_depth -= 1,
+                TokenKind::OpenDelim(_) => *delim_depth += 1,
+                TokenKind::CloseDelim(_) => *delim_depth -= 1,
+                _ => {}
+            }
+        }
+        let mut indent_depth = 0;
+        let mut delim_depth = 0;
+        // The tokens of the statement parsed before the error.
+        let parsed = start_index.saturating_sub(1)..self.cursor.index().saturating_sub(1);
+        for token in &self.cursor.stream[parsed] {
+            update_depth(token.kind, &mut indent_depth, &mut delim_depth);
+        }
+        loop {
+            let progressed = self.cursor.index() != start_index;
+            match self.token.kind {
+                TokenKind::Eof => break,
+                TokenKind::Newline if progressed && indent_depth <= 0 && delim_depth <= 0 => {
+                    self.skip_newlines();
+                    break;
+                }
+                TokenKind::Dedent if progressed && indent_depth <= 0 => break,
+                kind => update_depth(kind, &mut indent_depth, &mut delim_depth),
+            }
+            self.bump();
+        }
+    }
 }
 
 impl<'a> Parser<'a> {
@@ -35,8 +35,12 @@ impl<'a> Parser<'_> {
     fn parse_body(&mut self) -> Vec<NodeRef<Stmt>> {
         let mut stmts = Vec::new();
 
-        while let Some(stmt) = self.parse_stmt() {
-            stmts.push(stmt)
+        loop {
+            match self.parse_with_recovery(|p| p.parse_stmt()) {
+                Ok(Some(stmt)) => stmts.push(stmt),
+                Ok(None) => break,
+                Err(err_stmt) => stmts.push(err_stmt),
+            }
         }
 
         stmts
@@ -135,9 +135,14 @@ impl<'a> Parser<'_> {
                 self.bump_token(close_tok);
                 break;
             }
+            if self.token.kind == TokenKind::Eof {
+                break;
+            }
 
-            if let Some(stmt) = self.parse_stmt() {
-                stmt_list.push(stmt);
+            match self.parse_with_recovery(|p| p.parse_stmt()) {
+                Ok(Some(stmt)) => stmt_list.push(stmt),
+                Ok(None) => {}
+                Err(err_stmt) => stmt_list.push(err_stmt),
             }
         }
 
@@ -837,104 +842,17 @@ impl<'a> Parser<'_> {
         let mut body_index_signature = None;
 
         loop {
-            if self.token.kind == TokenKind::Dedent || self.token.is_keyword(kw::Check) {
+            if matches!(self.token.kind, TokenKind::Dedent | TokenKind::Eof)
+                || self.token.is_keyword(kw::Check)
+            {
                 break;
             }
 
-            // assert stmt
-            if self.token.is_keyword(kw::Assert) {
-                body_body.push(self.parse_assert_stmt().unwrap());
-                continue;
-            }
-
-            // if stmt
-            if self.token.is_keyword(kw::If) {
-                body_body.push(self.parse_if_stmt().unwrap());
-                continue;
-            }
-
-            // schema_attribute_stmt
-            if let TokenKind::At = self.token.kind {
-                let token = self.token;
-                let attr = self.parse_schema_attribute();
-                body_body.push(node_ref!(
-                    Stmt::SchemaAttr(attr),
-                    self.token_span_pos(token, self.prev_token)
-                ));
-                continue;
-            }
-            if let Some(peek) = self.cursor.peek() {
-                if let TokenKind::Question = peek.kind {
-                    let token = self.token;
-                    let attr = self.parse_schema_attribute();
-                    body_body.push(node_ref!(
-                        Stmt::SchemaAttr(attr),
-                        self.token_span_pos(token, self.prev_token)
-                    ));
-                    continue;
-                }
-            }
-
-            // schema_index_signature or list
-            if let TokenKind::OpenDelim(DelimToken::Bracket) = self.token.kind {
-                let token = self.token;
-
-                let (index_sig, or_list_expr) = self.parse_schema_index_signature_or_list();
-
-                if let Some(x) = index_sig {
-                    body_index_signature =
-                        Some(node_ref!(x, self.token_span_pos(token, self.prev_token)));
-                } else if let Some(list_expr) = or_list_expr {
-                    let stmt = Stmt::Expr(ExprStmt {
-                        exprs: vec![node_ref!(
-                            Expr::List(list_expr),
-                            self.token_span_pos(token, self.prev_token)
-                        )],
-                    });
-                    body_body.push(node_ref!(stmt, self.token_span_pos(token, self.prev_token)));
-                } else {
-                    self.sess.struct_compiler_bug("unreachable");
-                }
-
-                self.skip_newlines();
-                continue;
-            }
-
-            // expr or attr
-            if let Some(x) = self.parse_expr_or_assign_stmt() {
-                if let Stmt::SchemaAttr(attr) = &x.node {
-                    body_body.push(node_ref!(Stmt::SchemaAttr(attr.clone()), x.pos()));
-                    continue;
-                }
-
-                if let Stmt::Assign(assign) = x.node.clone() {
-                    if assign.targets.len() == 1 {
-                        let ident = assign.targets[0].clone().node;
-                        if let Some(type_str) = assign.type_annotation {
-                            if !type_str.node.is_empty() {
-                                body_body.push(node_ref!(
-                                    Stmt::SchemaAttr(SchemaAttr {
-                                        doc: "".to_string(),
-                                        name: node_ref!(
-                                            ident.names.join("."),
-                                            assign.targets[0].pos()
-                                        ),
-                                        type_str,
-                                        ty: assign.ty.unwrap(),
-                                        op: Some(BinOrAugOp::Aug(AugOp::Assign)),
-                                        value: Some(assign.value),
-                                        is_optional: false,
-                                        decorators: Vec::new(),
-                                    }),
-                                    x.pos()
-                                ));
-                                continue;
-                            }
-                        };
-                    }
-                }
-
-                body_body.push(x);
+            match self.parse_with_recovery(|p| p.parse_schema_body_stmt(&mut body_index_signature))
+            {
+                Ok(Some(stmt)) => body_body.push(stmt),
+                Ok(None) => {}
+                Err(err_stmt) => body_body.push(err_stmt),
             }
         }
 
@@ -968,6 +886,102 @@ impl<'a> Parser<'_> {
         }
     }
 
+    /// Syntax:
+    /// schema_attribute_stmt | schema_init_stmt | schema_index_signature
+    ///
+    /// The schema index signature is returned by `index_signature`, and the
+    /// other statements are returned directly.
+    fn parse_schema_body_stmt(
+        &mut self,
+        index_signature: &mut Option<NodeRef<SchemaIndexSignature>>,
+    ) -> Option<NodeRef<Stmt>> {
+        // assert stmt
+        if self.token.is_keyword(kw::Assert) {
+            return self.parse_assert_stmt();
+        }
+
+        // if stmt
+        if self.token.is_keyword(kw::If) {
+            return self.parse_if_stmt();
+        }
+
+        // schema_attribute_stmt
+        if let TokenKind::At = self.token.kind {
+            let token = self.token;
+            let attr = self.parse_schema_attribute();
+            return Some(node_ref!(
+                Stmt::SchemaAttr(attr),
+                self.token_span_pos(token, self.prev_token)
+            ));
+        }
+        if let Some(peek) = self.cursor.peek() {
+            if let TokenKind::Question = peek.kind {
+                let token = self.token;
+                let attr = self.parse_schema_attribute();
+                return Some(node_ref!(
+                    Stmt::SchemaAttr(attr),
+                    self.token_span_pos(token, self.prev_token)
+                ));
+            }
+        }
+
+        // schema_index_signature or list
+        if let TokenKind::OpenDelim(DelimToken::Bracket) = self.token.kind {
+            let token = self.token;
+
+            let (index_sig, or_list_expr) = self.parse_schema_index_signature_or_list();
+
+            let stmt = if let Some(x) = index_sig {
+                *index_signature = Some(node_ref!(x, self.token_span_pos(token, self.prev_token)));
+                None
+            } else if let Some(list_expr) = or_list_expr {
+                let stmt = Stmt::Expr(ExprStmt {
+                    exprs: vec![node_ref!(
+                        Expr::List(list_expr),
+                        self.token_span_pos(token, self.prev_token)
+                    )],
+                });
+                Some(node_ref!(stmt, self.token_span_pos(token, self.prev_token)))
+            } else {
+                self.sess.struct_compiler_bug("unreachable")
+            };
+
+            self.skip_newlines();
+            return stmt;
+        }
+
+        // expr or attr
+        let x = self.parse_expr_or_assign_stmt()?;
+        if let Stmt::SchemaAttr(attr) = &x.node {
+            return Some(node_ref!(Stmt::SchemaAttr(attr.clone()), x.pos()));
+        }
+
+        if let Stmt::Assign(assign) = x.node.clone() {
+            if assign.targets.len() == 1 {
+                let ident = assign.targets[0].clone().node;
+                if let Some(type_str) = assign.type_annotation {
+                    if !type_str.node.is_empty() {
+                        return Some(node_ref!(
+                            Stmt::SchemaAttr(SchemaAttr {
+                                doc: "".to_string(),
+                                name: node_ref!(ident.names.join("."), assign.targets[0].pos()),
+                                type_str,
+                                ty: assign.ty.unwrap(),
+                                op: Some(BinOrAugOp::Aug(AugOp::Assign)),
+                                value: Some(assign.value),
+                                is_optional: false,
+                                decorators: Vec::new(),
+                            }),
+                            x.pos()
+                        ));
+                    }
+                };
+            }
+        }
+
+        Some(x)
+    }
+
     /// Syntax:
     /// mixin_stmt: MIXIN LEFT_BRACKETS [mixins | multiline_mixins] RIGHT_BRACKETS NEWLINE
     /// multiline_mixins: NEWLINE _INDENT mixins NEWLINE _DEDENT
@@ -1228,12 +1242,11 @@ impl<'a> Parser<'_> {
             self.skip_newlines();
 
             self.bump_token(TokenKind::Indent);
-            while self.token.kind != TokenKind::Dedent {
-                let expr = self.parse_check_expr();
-                let expr_pos = expr.pos();
-                let check_expr = expr_as!(expr, Expr::Check).unwrap();
-                check_expr_list.push(node_ref!(check_expr, expr_pos));
-                self.skip_newlines();
+            while !matches!(self.token.kind, TokenKind::Dedent | TokenKind::Eof) {
+                // The check expression with syntax errors is dropped.
+                if let Ok(check_expr) = self.parse_with_recovery(|p| p.parse_check_stmt()) {
+                    check_expr_list.push(check_expr);
+                }
             }
             self.bump_token(TokenKind::Dedent);
         }
@@ -1241,6 +1254,16 @@ impl<'a> Parser<'_> {
         check_expr_list
     }
 
+    /// Syntax:
+    /// check_expr: simple_expr [IF simple_expr] [COMMA primary_expr] NEWLINE
+    fn parse_check_stmt(&mut self) -> NodeRef<CheckExpr> {
+        let expr = self.parse_check_expr();
+        let expr_pos = expr.pos();
+        let check_expr = expr_as!(expr, Expr::Check).unwrap();
+        self.skip_newlines();
+        node_ref!(check_expr, expr_pos)
+    }
+
     /// Syntax:
     /// rule_stmt: [decorators] RULE NAME [LEFT_BRACKETS [schema_arguments] RIGHT_BRACKETS] [LEFT_PARENTHESES identifier (COMMA identifier)* RIGHT_PARENTHESES] [for_host] COLON NEWLINE [rule_body]
     /// rule_body: _INDENT (string NEWLINE)* check_expr+ _DEDENT
@@ -1321,12 +1344,11 @@ impl<'a> Parser<'_> {
         };
 
         let mut check_expr_list = vec![];
-        while self.token.kind != TokenKind::Dedent {
-            let expr = self.parse_check_expr();
-            let expr_pos = expr.pos();
-            let check_expr = expr_as!(expr, Expr::Check).unwrap();
-            check_expr_list.push(node_ref!(check_expr, expr_pos));
-            self.skip_newlines();
+        while !matches!(self.token.kind, TokenKind::Dedent | TokenKind::Eof) {
+            // The check expression with syntax errors is dropped.
+            if let Ok(check_expr) = self.parse_with_recovery(|p| p.parse_check_stmt()) {
+                check_expr_list.push(check_expr);
+            }
         }
         self.bump_token(TokenKind::Dedent);
 
@@ -575,6 +575,19 @@ fn call_expr_3() {
     );
 }
 
+#[test]
+fn call_expr_4() {
+    check_parsing_expr(
+        r####"func4(
+    1,
+    x=2,
+)"####,
+        expect![[r#"
+        Node { node: Call(CallExpr { func: Node { node: Identifier(Identifier { names: ["func4"], pkgpath: "", ctx: Load }), filename: "", line: 1, column: 0, end_line: 1, end_column: 5 }, args: [Node { node: NumberLit(NumberLit { binary_suffix: None, value: Int(1) }), filename: "", line: 2, column: 4, end_line: 2, end_column: 5 }], keywords: [Node { node: Keyword { arg: Node { node: Identifier { names: ["x"], pkgpath: "", ctx: Load }, filename: "", line: 3, column: 4, end_line: 3, end_column: 5 }, value: Some(Node { node: NumberLit(NumberLit { binary_suffix: None, value: Int(2) }), filename: "", line: 3, column: 6, end_line: 3, end_column: 7 }) }, filename: "", line: 3, column: 4, end_line: 3, end_column: 7 }] }), filename: "", line: 1, column: 0, end_line: 4, end_column: 1 }
+        "#]],
+    );
+}
+
 #[test]
 fn quant_expr_0() {
     check_parsing_expr(
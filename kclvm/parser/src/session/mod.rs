@@ -1,5 +1,5 @@
 use kclvm_ast::token::Token;
-use kclvm_error::{Handler, ParseError, Position};
+use kclvm_error::{FatalErrorMarker, Handler, ParseError, Position};
 use kclvm_runtime::{ErrType, PanicInfo};
 use kclvm_span::{Loc, SourceMap, Span};
 use std::cell::RefCell;
@@ -8,6 +8,10 @@ use std::sync::Arc;
 pub struct ParseSession {
     pub source_map: Arc<SourceMap>,
     pub handler: RefCell<Handler>,
+    /// Whether the session is error-tolerant. An error-tolerant session records
+    /// syntax errors in the handler instead of aborting the compiler process,
+    /// and the parser recovers from them to build a partial AST.
+    pub recovery: bool,
 }
 
 impl ParseSession {
@@ -16,6 +20,15 @@ impl ParseSession {
         Self {
             handler,
             source_map,
+            recovery: false,
+        }
+    }
+
+    /// Returns an error-tolerant session.
+    pub fn with_source_map_and_recovery(source_map: Arc<SourceMap>) -> Self {
+        Self {
+            recovery: true,
+            ..Self::with_source_map(source_map)
         }
     }
 
@@ -28,7 +41,13 @@ impl ParseSession {
     }
 
     /// Struct and report an error based on a token and abort the compiler process.
+    /// In the error-tolerant session, the error is recorded and only the parsing
+    /// of the current statement is aborted.
     pub fn struct_token_error(&self, expected: &[String], got: Token) -> ! {
+        if self.recovery {
+            self.struct_token_error_recovery(expected, got);
+            abort_statement()
+        }
         let pos: Position = self.source_map.lookup_char_pos(got.span.lo()).into();
         let err = ParseError::UnexpectedToken {
             expected: expected.iter().map(|tok| tok.into()).collect(),
@@ -60,7 +79,13 @@ impl ParseSession {
     }
 
     /// Struct and report an error based on a span and abort the compiler process.
+    /// In the error-tolerant session, the error is recorded and only the parsing
+    /// of the current statement is aborted.
     pub fn struct_span_error(&self, msg: &str, span: Span) -> ! {
+        if self.recovery {
+            self.struct_span_error_recovery(msg, span);
+            abort_statement()
+        }
         let pos: Position = self.source_map.lookup_char_pos(span.lo()).into();
 
         let mut panic_info = PanicInfo::default();
@@ -83,8 +108,37 @@ impl ParseSession {
         self.handler.borrow_mut().add_compile_error(msg, pos);
     }
 
+    /// Struct and report an error based on a token, abort the compiler process
+    /// unless the session is error-tolerant, in which case the caller is able
+    /// to recover from the error.
+    pub fn struct_token_error_recoverable(&self, expected: &[String], got: Token) {
+        if self.recovery {
+            self.struct_token_error_recovery(expected, got)
+        } else {
+            self.struct_token_error(expected, got)
+        }
+    }
+
+    /// Struct and report an error based on a span, abort the compiler process
+    /// unless the session is error-tolerant, in which case the caller is able
+    /// to recover from the error.
+    pub fn struct_span_error_recoverable(&self, msg: &str, span: Span) {
+        if self.recovery {
+            self.struct_span_error_recovery(msg, span)
+        } else {
+            self.struct_span_error(msg, span)
+        }
+    }
+
     /// Report a compiler bug
     pub fn struct_compiler_bug(&self, msg: &str) -> ! {
         self.handler.borrow_mut().bug(msg)
     }
 }
+
+/// Unwind to the statement level recovery point of the parser, see
+/// `Parser::parse_with_recovery`. The error must have been recorded in the
+/// session handler, and the panic hook is not invoked.
+fn abort_statement() -> ! {
+    std::panic::resume_unwind(Box::new(FatalErrorMarker))
+}
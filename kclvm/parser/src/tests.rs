@@ -95,3 +95,118 @@ pub fn test_parse_expr_invalid() {
         check_result_panic_info(result);
     }
 }
+
+fn check_parsing_file_with_errors(src: &str, stmts: usize, errors: usize) -> ast::Module {
+    let (m, diags) = parse_file_with_errors("hello.k", Some(src.into())).unwrap();
+    assert_eq!(m.body.len(), stmts, "{:?}", m.body);
+    assert_eq!(diags.len(), errors, "{:?}", diags);
+    m
+}
+
+#[test]
+fn test_parse_file_with_errors() {
+    check_parsing_file_with_errors("a = 1\nb = 2\n", 2, 0);
+    // Missing expressions.
+    let m = check_parsing_file_with_errors("a = \nb = 1\n", 2, 1);
+    match &m.body[0].node {
+        ast::Stmt::Assign(assign_stmt) => {
+            assert!(matches!(assign_stmt.value.node, ast::Expr::Missing(_)))
+        }
+        stmt => panic!("unexpected statement {:?}", stmt),
+    }
+    // Missing attribute names.
+    let m = check_parsing_file_with_errors("a = p.\nb = 1\n", 2, 1);
+    match &m.body[0].node {
+        ast::Stmt::Assign(assign_stmt) => match &assign_stmt.value.node {
+            ast::Expr::Identifier(identifier) => {
+                assert_eq!(identifier.names, vec!["p".to_string(), "".to_string()])
+            }
+            expr => panic!("unexpected expression {:?}", expr),
+        },
+        stmt => panic!("unexpected statement {:?}", stmt),
+    }
+    // Invalid statements are skipped until the next statement.
+    check_parsing_file_with_errors("a = 1 +* 2\nb = 1\nc = 1 +* 2\nd = 2\n", 4, 2);
+    // Invalid schema attributes.
+    let m = check_parsing_file_with_errors(
+        "schema Person:\n    name: str = \n    age: int = 1\n\nperson = Person {}\n",
+        2,
+        1,
+    );
+    match &m.body[0].node {
+        ast::Stmt::Schema(schema_stmt) => assert_eq!(schema_stmt.body.len(), 2),
+        stmt => panic!("unexpected statement {:?}", stmt),
+    }
+}
+
+#[test]
+fn test_load_program_with_errors() {
+    let src = r#"schema Person:
+    name: str = 
+    age: int = 0x1g
+
+a = 1 +* 2
+person = Person {
+    name = "Alice"
+}
+b = 1.0e
+c = 
+d = person.name
+"#;
+    let opts = LoadProgramOptions {
+        k_code_list: vec![src.to_string()],
+        ..Default::default()
+    };
+    let (program, diags) = load_program_with_errors(&["hello.k"], Some(opts)).unwrap();
+    // The lexer errors are reported before the parser errors.
+    let mut lines: Vec<u64> = diags.iter().map(|diag| diag.messages[0].pos.line).collect();
+    lines.sort_unstable();
+    assert_eq!(lines, vec![2, 3, 5, 9, 10], "{:?}", diags);
+    let module = &program.pkgs[kclvm_ast::MAIN_PKG][0];
+    assert_eq!(module.body.len(), 6);
+    match &module.body[0].node {
+        ast::Stmt::Schema(schema_stmt) => assert_eq!(schema_stmt.body.len(), 2),
+        stmt => panic!("unexpected statement {:?}", stmt),
+    }
+    match &module.body[5].node {
+        ast::Stmt::Assign(assign_stmt) => {
+            assert_eq!(assign_stmt.targets[0].node.names, vec!["d".to_string()]);
+            assert!(matches!(assign_stmt.value.node, ast::Expr::Identifier(_)));
+        }
+        stmt => panic!("unexpected statement {:?}", stmt),
+    }
+}
+
+#[test]
+fn test_load_program_with_errors_pkg_cache() {
+    let root = std::env::temp_dir().join("kclvm_parser_test_pkg_cache");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("pkg")).unwrap();
+    std::fs::write(root.join("kcl.mod"), "[build]\nenable_pkg_cache = true\n").unwrap();
+    std::fs::write(root.join("main.k"), "import pkg\n\nb = pkg.a\n").unwrap();
+    let main_file = root.join("main.k").display().to_string();
+    let pkgroot = root.canonicalize().unwrap().display().to_string();
+    let load_cache = || -> Option<Vec<ast::Module>> {
+        load_pkg_cache(&pkgroot, AST_CACHE_TARGET, "pkg", CacheOption::default())
+    };
+    // The package with syntax errors is not saved into the cache.
+    std::fs::write(root.join("pkg").join("a.k"), "a = 1 +\n").unwrap();
+    let (_, diags) = load_program_with_errors(&[&main_file], None).unwrap();
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert!(load_cache().is_none());
+    // The package without syntax errors is saved with error recovery.
+    std::fs::write(root.join("pkg").join("a.k"), "a = 1\n").unwrap();
+    let (program, diags) = load_program_with_errors(&[&main_file], None).unwrap();
+    assert!(diags.is_empty(), "{:?}", diags);
+    assert_eq!(load_cache().unwrap().len(), program.pkgs["pkg"].len());
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_parse_file_invalid() {
+    set_hook(Box::new(|_| {}));
+    let result = catch_unwind(|| {
+        parse_file("hello.k", Some("a = \nb = 1\n".into())).unwrap();
+    });
+    check_result_panic_info(result);
+}
@@ -64,7 +64,9 @@ std::string _kclvm_plugin_AppContextBase::_run_app(
         int32_t result_buffer_len,
         char* result_buffer,
         int32_t warn_buffer_len,
-        char* warn_buffer
+        char* warn_buffer,
+        int32_t include_schema_type_path,
+        int32_t collect_check_failures
     );
 
     int32_t _option_len = this->option_keys_.size();
@@ -101,7 +103,9 @@ std::string _kclvm_plugin_AppContextBase::_run_app(
         this->buffer_.size()-1,
         &this->buffer_[0],
         this->warn_buffer_.size()-1,
-        &this->warn_buffer_[0]
+        &this->warn_buffer_[0],
+        0, // include_schema_type_path
+        0  // collect_check_failures
     );
 
     if(result_len > 0) {
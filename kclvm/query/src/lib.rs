@@ -2,8 +2,13 @@
 //! KCL code modification `override` and other implementations. We can call the `override_file`
 //! function to modify the file. The main principle is to parse the AST according to the
 //! input file name, and according to the ast::OverrideSpec transforms the nodes in the
-//! AST, recursively modifying or deleting the values of the nodes in the AST.
+//! AST, recursively modifying or deleting the values of the nodes in the AST. We can also
+//! call the `get_file_field_values` function to read the source values and positions of a
+//! field path statically without modifying the file, and call the `get_schema_type`
+//! function to get the schema types with their attributes, defaults and documents.
 pub mod r#override;
+pub mod query;
+pub mod schema;
 
 #[cfg(test)]
 mod tests;
@@ -13,10 +18,14 @@ use anyhow::{anyhow, Result};
 use kclvm_ast::ast;
 use kclvm_ast_pretty::print_ast_module;
 use kclvm_parser::parse_file;
+use similar::TextDiff;
 
-pub use r#override::{apply_override_on_module, apply_overrides};
-
-use self::r#override::parse_override_spec;
+pub use query::{get_field_values, get_file_field_values, get_module_field_values, FieldValue};
+pub use r#override::{apply_override_on_module, apply_overrides, parse_override_spec};
+pub use schema::{
+    get_program_schema_types, get_schema_type, DecoratorInfo, IndexSignatureInfo, SchemaAttrInfo,
+    SchemaTypeInfo,
+};
 
 /// Override and rewrite a file with override specifications. Please note that this is an external user API,
 /// and it can directly modify the KCL file in place.
@@ -28,7 +37,10 @@ use self::r#override::parse_override_spec;
 ///
 /// `specs`: &\[[String]\]
 ///     List of specs that need to be overridden.
-///     Each spec string satisfies the form: <pkgpath>:<field_path>=<filed_value> or <pkgpath>:<field_path>-
+///     Each spec string satisfies the form: <pkgpath>:<field_path><op><field_value> or <pkgpath>:<field_path>-
+///     The operator `<op>` is one of `=` (override), `:` (union) and `+=` (append or insert into a list),
+///     and `-` denotes deleting the field. The field path may contain list indices and quoted keys,
+///     e.g. `config.containers[0].image` and `config.labels["app.kubernetes.io/name"]`.
 ///     When the pkgpath is '__main__', `<pkgpath>:` can be omitted.
 ///
 /// `import_paths`: &\[[String]\]
@@ -73,28 +85,71 @@ use self::r#override::parse_override_spec;
 /// }
 /// ```
 pub fn override_file(file: &str, specs: &[String], import_paths: &[String]) -> Result<bool> {
+    let mut module = parse_module(file)?;
+    let result = override_module(&mut module, specs, import_paths)?;
+    // Print AST module.
+    if result {
+        let code_str = print_ast_module(&module);
+        std::fs::write(file, &code_str)?
+    }
+    Ok(result)
+}
+
+/// Override a file with override specifications like [override_file] in the dry run mode,
+/// which returns the unified diff between the file content and the code [override_file]
+/// will write instead of modifying the file. The overridden module is printed from the AST,
+/// thus the diff also contains the formatting changes of the file. An empty diff denotes
+/// that the file is not modified.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_query::override_file_diff;
+///
+/// let diff = override_file_diff(
+///     "test.k",
+///     &["alice.containers+={image=\"nginx\"}".to_string()],
+///     &[]
+/// ).unwrap();
+/// print!("{}", diff);
+/// ```
+pub fn override_file_diff(file: &str, specs: &[String], import_paths: &[String]) -> Result<String> {
+    let src = std::fs::read_to_string(file)?;
+    let mut module = parse_module(file)?;
+    if !override_module(&mut module, specs, import_paths)? {
+        return Ok("".to_string());
+    }
+    let code_str = print_ast_module(&module);
+    Ok(TextDiff::from_lines(&src, &code_str)
+        .unified_diff()
+        .header(file, file)
+        .to_string())
+}
+
+/// Parse the file to the AST module.
+fn parse_module(file: &str) -> Result<ast::Module> {
+    parse_file(file, None).map_err(|msg| anyhow!("{}", msg))
+}
+
+/// Apply the override specifications on the AST module, returns whether the module
+/// has been modified.
+fn override_module(
+    module: &mut ast::Module,
+    specs: &[String],
+    import_paths: &[String],
+) -> Result<bool> {
     // Parse override spec strings.
     let overrides = specs
         .iter()
         .map(|s| parse_override_spec(s))
         .filter_map(Result::ok)
         .collect::<Vec<ast::OverrideSpec>>();
-    // Parse file to AST module.
-    let mut module = match parse_file(file, None) {
-        Ok(module) => module,
-        Err(msg) => return Err(anyhow!("{}", msg)),
-    };
     let mut result = false;
     // Override AST module.
     for o in &overrides {
-        if apply_override_on_module(&mut module, o, import_paths)? {
+        if apply_override_on_module(module, o, import_paths)? {
             result = true;
         }
     }
-    // Print AST module.
-    if result {
-        let code_str = print_ast_module(&module);
-        std::fs::write(file, &code_str)?
-    }
     Ok(result)
 }
@@ -2,16 +2,16 @@ use std::collections::HashSet;
 
 use anyhow::{anyhow, Result};
 
+use kclvm_ast::ast;
 use kclvm_ast::config::try_get_config_expr_mut;
-use kclvm_ast::path::{get_attr_paths_from_config_expr, get_key_path};
+use kclvm_ast::path::get_key_path;
 use kclvm_ast::walker::MutSelfMutWalker;
-use kclvm_ast::{ast, walk_if_mut};
 use kclvm_ast_pretty::print_ast_module;
 use kclvm_error::bug;
 use kclvm_parser::parse_expr;
 use kclvm_sema::pre_process::{fix_config_expr_nest_attr, transform_multi_assign};
 
-use super::util::{invalid_spec_error, split_field_path};
+use super::util::{invalid_spec_error, parse_field_path, split_override_spec, FieldPathPart};
 
 /// Import statement column offset always start with 1.
 /// todo: The (1-based) column offset needs to be constrained by specifications.
@@ -86,6 +86,10 @@ fn build_expr_from_string(value: &str) -> Option<ast::NodeRef<ast::Expr>> {
 /// The parameters of the method are all compiler internal concepts such as
 /// AST, etc.
 ///
+/// Returns whether the module has been modified by the override. Finding the target
+/// is not enough, e.g., it returns `false` when deleting a missing attribute of the
+/// target, or when the list index of the field path is out of range.
+///
 /// # Examples
 ///
 /// ```no_check
@@ -104,17 +108,17 @@ pub fn apply_override_on_module(
 ) -> Result<bool> {
     // Apply import paths on AST module.
     apply_import_paths_on_module(m, import_paths)?;
-    let ss = o.field_path.split('.').collect::<Vec<&str>>();
-    if ss.len() <= 1 {
-        return Ok(false);
-    }
-    let target_id = ss[0];
-    let field = ss[1..].join(".");
-    let value = &o.field_value;
-    let key = ast::Identifier {
-        names: field.split('.').map(|s| s.to_string()).collect(),
-        ctx: ast::ExprContext::Store,
-        pkgpath: "".to_string(),
+    let parts = parse_field_path(&o.field_path)?;
+    let target_id = match parts.first() {
+        Some(FieldPathPart::Key(target_id)) if parts.len() > 1 => target_id.to_string(),
+        _ => return Ok(false),
+    };
+    let override_value = match o.action {
+        ast::OverrideAction::CreateOrUpdate => Some(
+            build_expr_from_string(&o.field_value)
+                .ok_or_else(|| anyhow!("Invalid override value {:?}", o.field_value))?,
+        ),
+        ast::OverrideAction::Delete => None,
     };
     // Transform config expr to simplify the config path query and override.
     fix_config_expr_nest_attr(m);
@@ -127,13 +131,12 @@ pub fn apply_override_on_module(
     // ```
     transform_multi_assign(m);
     let mut transformer = OverrideTransformer {
-        target_id: target_id.to_string(),
-        field_path: field,
-        override_key: key,
-        override_value: build_expr_from_string(value),
-        override_target_count: 0,
+        target_id,
+        field_path: parts[1..].to_vec(),
+        override_value,
         has_override: false,
         action: o.action.clone(),
+        operation: o.operation.clone(),
     };
     transformer.walk_module(m);
     Ok(transformer.has_override)
@@ -146,36 +149,53 @@ pub fn apply_override_on_module(
 ///     field_path: "alice.age".to_string(),
 ///     field_value: "10".to_string(),
 ///     action: ast::OverrideAction::CreateOrUpdate,
+///     operation: ast::ConfigEntryOperation::Override,
 /// }
-pub(crate) fn parse_override_spec(spec: &str) -> Result<ast::OverrideSpec> {
-    if spec.contains('=') {
-        // Create or update the override value.
-        let split_values = spec.splitn(2, '=').collect::<Vec<&str>>();
-        let path = split_values
-            .get(0)
-            .ok_or_else(|| invalid_spec_error(spec))?;
-        let field_value = split_values
-            .get(1)
-            .ok_or_else(|| invalid_spec_error(spec))?;
-        let (pkgpath, field_path) = split_field_path(path)?;
-        Ok(ast::OverrideSpec {
-            pkgpath,
-            field_path,
-            field_value: field_value.to_string(),
-            action: ast::OverrideAction::CreateOrUpdate,
-        })
-    } else if let Some(stripped_spec) = spec.strip_suffix('-') {
-        // Delete the override value.
-        let (pkgpath, field_path) = split_field_path(stripped_spec)?;
-        Ok(ast::OverrideSpec {
-            pkgpath,
-            field_path,
-            field_value: "".to_string(),
-            action: ast::OverrideAction::Delete,
-        })
-    } else {
-        Err(invalid_spec_error(spec))
+///
+/// The operator of the spec selects the action and the config entry operation:
+///
+/// - `alice.age=10` creates or updates the value with the override operator `=`.
+/// - `alice.labels:{app="web"}` creates or updates the value with the union operator `:`.
+/// - `alice.containers+=Container {name="sidecar"}` appends the value to the list and
+///   `alice.containers[0]+=...` inserts the value before the index.
+/// - `alice.age-` deletes the value.
+///
+/// The field path may contain list indices and quoted keys, e.g. `alice.containers[0].image`
+/// and `alice.labels["app.kubernetes.io/name"]`.
+pub fn parse_override_spec(spec: &str) -> Result<ast::OverrideSpec> {
+    let (pkgpath, field_path, op, field_value) =
+        split_override_spec(spec).ok_or_else(|| invalid_spec_error(spec))?;
+    if field_path.is_empty() {
+        return Err(invalid_spec_error(spec));
     }
+    // Validate the field path.
+    parse_field_path(field_path)?;
+    let (action, operation) = match op {
+        "-" => (
+            ast::OverrideAction::Delete,
+            ast::ConfigEntryOperation::Override,
+        ),
+        _ if field_value.is_empty() => return Err(invalid_spec_error(spec)),
+        ":" => (
+            ast::OverrideAction::CreateOrUpdate,
+            ast::ConfigEntryOperation::Union,
+        ),
+        "+=" => (
+            ast::OverrideAction::CreateOrUpdate,
+            ast::ConfigEntryOperation::Insert,
+        ),
+        _ => (
+            ast::OverrideAction::CreateOrUpdate,
+            ast::ConfigEntryOperation::Override,
+        ),
+    };
+    Ok(ast::OverrideSpec {
+        pkgpath: pkgpath.to_string(),
+        field_path: field_path.to_string(),
+        field_value: field_value.to_string(),
+        action,
+        operation,
+    })
 }
 
 // Transform the AST module with the import path list.
@@ -225,12 +245,11 @@ fn apply_import_paths_on_module(m: &mut ast::Module, import_paths: &[String]) ->
 /// OverrideTransformer is used to walk AST and transform it with the override values.
 struct OverrideTransformer {
     pub target_id: String,
-    pub field_path: String,
-    pub override_key: ast::Identifier,
+    pub field_path: Vec<FieldPathPart>,
     pub override_value: Option<ast::NodeRef<ast::Expr>>,
-    pub override_target_count: usize,
     pub has_override: bool,
     pub action: ast::OverrideAction,
+    pub operation: ast::ConfigEntryOperation,
 }
 
 impl<'ctx> MutSelfMutWalker<'ctx> for OverrideTransformer {
@@ -245,61 +264,25 @@ impl<'ctx> MutSelfMutWalker<'ctx> for OverrideTransformer {
         if name != &self.target_id {
             return;
         }
-        self.override_target_count = 1;
-        self.has_override = true;
-        self.walk_schema_expr(&mut unification_stmt.value.node);
-    }
-
-    fn walk_assign_stmt(&mut self, assign_stmt: &'ctx mut ast::AssignStmt) {
-        if let ast::Expr::Schema(_) = &assign_stmt.value.node {
-            self.override_target_count = 0;
-            for target in &assign_stmt.targets {
-                if target.node.names.len() != 1 {
-                    continue;
-                }
-                if target.node.names[0] != self.target_id {
-                    continue;
-                }
-                self.override_target_count += 1;
+        let field_path = self.field_path.clone();
+        if let ast::Expr::Config(config_expr) = &mut unification_stmt.value.node.config.node {
+            if self.override_config_expr(config_expr, &field_path) {
+                self.has_override = true;
             }
-            if self.override_target_count == 0 {
-                return;
-            }
-            self.has_override = true;
-            self.walk_expr(&mut assign_stmt.value.node);
         }
     }
 
-    fn walk_schema_expr(&mut self, schema_expr: &'ctx mut ast::SchemaExpr) {
-        if self.override_target_count == 0 {
+    fn walk_assign_stmt(&mut self, assign_stmt: &'ctx mut ast::AssignStmt) {
+        let is_target = assign_stmt
+            .targets
+            .iter()
+            .any(|target| target.node.names.len() == 1 && target.node.names[0] == self.target_id);
+        if !is_target {
             return;
         }
-        if let ast::Expr::Config(config_expr) = &mut schema_expr.config.node {
-            if !self.lookup_config_and_replace(config_expr) {
-                // Not exist and append an override value when the action is CREATE_OR_UPDATE
-                if let ast::OverrideAction::CreateOrUpdate = self.action {
-                    if let ast::Expr::Config(config_expr) = &mut schema_expr.config.node {
-                        config_expr
-                            .items
-                            .push(Box::new(ast::Node::dummy_node(ast::ConfigEntry {
-                                key: Some(Box::new(ast::Node::dummy_node(ast::Expr::Identifier(
-                                    self.override_key.clone(),
-                                )))),
-                                value: self.clone_override_value(),
-                                operation: ast::ConfigEntryOperation::Override,
-                                insert_index: -1,
-                            })));
-                    }
-                }
-            }
-        }
-        self.override_target_count = 0;
-    }
-
-    fn walk_config_expr(&mut self, config_expr: &'ctx mut ast::ConfigExpr) {
-        for config_entry in config_expr.items.iter_mut() {
-            walk_if_mut!(self, walk_expr, config_entry.node.key);
-            self.walk_expr(&mut config_entry.node.value.node);
+        let field_path = self.field_path.clone();
+        if self.override_expr(&mut assign_stmt.value.node, &field_path) {
+            self.has_override = true;
         }
     }
 
@@ -315,91 +298,203 @@ impl<'ctx> MutSelfMutWalker<'ctx> for OverrideTransformer {
 }
 
 impl OverrideTransformer {
-    /// Lookup schema config all fields and replace if it is matched with the override spec,
-    /// return whether is found a replaced one.
-    fn lookup_config_and_replace(&mut self, config_expr: &mut ast::ConfigExpr) -> bool {
-        // Get all entry paths from a config expression.
-        let paths = get_attr_paths_from_config_expr(config_expr);
-        // Query whether there is a matching path from the path lookup table.
-        match paths.iter().position(|r| r == &self.field_path) {
-            Some(pos) => {
-                let path = &paths[pos];
-                // Split a path into multiple parts. `a.b.c` -> ["a", "b", "c"]
-                let parts = path.split('.').collect::<Vec<&str>>();
-                self.replace_config_with_path_parts(config_expr, &parts);
-                true
-            }
-            None => false,
+    /// Override the schema, config or list expression with the field path parts, and
+    /// return whether the expression is modified.
+    fn override_expr(&mut self, expr: &mut ast::Expr, parts: &[FieldPathPart]) -> bool {
+        match expr {
+            ast::Expr::List(list_expr) => self.override_list_expr(list_expr, parts),
+            _ => match try_get_config_expr_mut(expr) {
+                Some(config_expr) => self.override_config_expr(config_expr, parts),
+                None => false,
+            },
         }
     }
 
-    /// Replace AST config expr with one part of path. The implementation of this function
-    /// uses recursive matching to find the config entry need to be modified.
-    fn replace_config_with_path_parts(
+    /// Override the config entries matched with the first key part recursively. The implementation
+    /// of this function uses recursive matching to find the config entry need to be modified,
+    /// because rust cannot directly hold shared references to AST nodes (ast::NodeRef<T> is a
+    /// Box<T>). When no entry is found, a new entry is appended for the CreateOrUpdate action.
+    fn override_config_expr(
         &mut self,
         config_expr: &mut ast::ConfigExpr,
-        parts: &[&str],
-    ) {
-        // Do not replace empty path parts and out of index parts on the config expression.
-        if parts.is_empty() {
-            return;
-        }
-        // Always take the first part to match, because recursive search is required.
-        let part = parts[0];
+        parts: &[FieldPathPart],
+    ) -> bool {
+        let (key, rest) = match parts.split_first() {
+            Some((FieldPathPart::Key(key), rest)) => (key, rest),
+            _ => return false,
+        };
+        let mut modified = false;
         let mut delete_index_set = HashSet::new();
         // Loop all entries in the config expression and replace, because there may be duplicate
         // configuration items in config.
         for (i, item) in config_expr.items.iter_mut().enumerate() {
-            // Compare each field of the config structure one by one.
-            // - `part` denotes the path entered by the user to be modified.
-            // - `get_path_key` returns the real config key name.
-            // For example, the real config node is `a: {b: c: {}}`. The path
-            // that needs to be modified is `a.b.c`, and its parts are ["a", "b", "c"].
-            if part == get_key_path(&item.node.key) {
-                // When the last part of the path is successfully recursively matched,
-                // it indicates that the original value that needs to be overwritten
-                // is successfully found, and the new value is used to overwrite it.
-                // - `parts.len() == 1` denotes the path matches exactly.
-                if parts.len() == 1 {
-                    match self.action {
-                        ast::OverrideAction::CreateOrUpdate => {
-                            let mut value = self.clone_override_value();
-                            // Use position information that needs to override the expression.
-                            value.set_pos(item.pos());
-                            // Override the node value.
-                            item.node.value = value;
-                        }
-                        ast::OverrideAction::Delete => {
-                            // Store the config entry delete index into the delete index set.
-                            // Because we can't delete the entry directly in the loop
-                            delete_index_set.insert(i);
-                        }
+            // For example, the real config node is `a: {b: c: {}}`. The path that needs to be
+            // modified is `a.b.c`, and its parts are ["a", "b", "c"].
+            if key != &get_key_path(&item.node.key) {
+                continue;
+            }
+            if !rest.is_empty() {
+                // Override value recursively using the path composed by subsequent parts.
+                modified |= self.override_expr(&mut item.node.value.node, rest);
+                continue;
+            }
+            match (&self.action, &self.operation) {
+                (ast::OverrideAction::Delete, _) => {
+                    // Store the config entry delete index into the delete index set.
+                    // Because we can't delete the entry directly in the loop
+                    delete_index_set.insert(i);
+                    modified = true;
+                }
+                (ast::OverrideAction::CreateOrUpdate, ast::ConfigEntryOperation::Insert) => {
+                    // Append the values to the list literal, or else add an insert entry below.
+                    if let ast::Expr::List(list_expr) = &mut item.node.value.node {
+                        let mut values = self.insert_values();
+                        list_expr.elts.append(&mut values);
+                        return true;
                     }
                 }
-                // Replace value recursively using the path composed by subsequent parts.
-                //
-                // The reason for using recursion instead of looping for path matching
-                // is that rust cannot directly hold shared references to AST nodes
-                // (ast::NodeRef<T> is a Box<T>), so recursive search is performed
-                // directly on AST nodes.
-                else if let Some(config_expr) = try_get_config_expr_mut(&mut item.node.value.node)
-                {
-                    self.replace_config_with_path_parts(config_expr, &parts[1..]);
+                (ast::OverrideAction::CreateOrUpdate, _) => {
+                    let mut value = self.clone_override_value();
+                    // Use position information that needs to override the expression.
+                    value.set_pos(item.pos());
+                    // Override the node value and keep the entry operation.
+                    item.node.value = value;
+                    modified = true;
                 }
             }
         }
         // Delete entries according delete index set.
         if !delete_index_set.is_empty() {
-            let items: Vec<(usize, &ast::NodeRef<ast::ConfigEntry>)> = config_expr
-                .items
-                .iter()
+            let items = std::mem::take(&mut config_expr.items);
+            config_expr.items = items
+                .into_iter()
                 .enumerate()
                 .filter(|(i, _)| !delete_index_set.contains(i))
+                .map(|(_, item)| item)
                 .collect();
-            config_expr.items = items
-                .iter()
-                .map(|(_, item)| <&ast::NodeRef<ast::ConfigEntry>>::clone(item).clone())
-                .collect();
+        }
+        // Not exist and append an override value when the action is CREATE_OR_UPDATE
+        if !modified && self.action == ast::OverrideAction::CreateOrUpdate {
+            if let Some(entry) = self.build_config_entry(parts) {
+                config_expr.items.push(entry);
+                modified = true;
+            }
+        }
+        modified
+    }
+
+    /// Override the list element at the first index part, e.g., `containers[0].image=...`
+    /// updates the element, `containers[0]+=...` inserts the values before it and
+    /// `containers[0]-` deletes it.
+    fn override_list_expr(
+        &mut self,
+        list_expr: &mut ast::ListExpr,
+        parts: &[FieldPathPart],
+    ) -> bool {
+        let (index, rest) = match parts.split_first() {
+            Some((FieldPathPart::Index(index), rest)) => (*index, rest),
+            _ => return false,
+        };
+        if !rest.is_empty() {
+            return match list_expr.elts.get_mut(index) {
+                Some(elt) => self.override_expr(&mut elt.node, rest),
+                None => false,
+            };
+        }
+        let len = list_expr.elts.len();
+        match (&self.action, &self.operation) {
+            (ast::OverrideAction::Delete, _) if index < len => {
+                list_expr.elts.remove(index);
+                true
+            }
+            (ast::OverrideAction::CreateOrUpdate, ast::ConfigEntryOperation::Insert)
+                if index <= len =>
+            {
+                let values = self.insert_values();
+                list_expr.elts.splice(index..index, values);
+                true
+            }
+            (ast::OverrideAction::CreateOrUpdate, op)
+                if index < len && op != &ast::ConfigEntryOperation::Insert =>
+            {
+                let mut value = self.clone_override_value();
+                value.set_pos(list_expr.elts[index].pos());
+                list_expr.elts[index] = value;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Build a new config entry with the key parts, the leading attribute names are merged
+    /// into a nested attribute key e.g., `a.b.c = 1`, and the other keys are string keys
+    /// e.g., `"app.kubernetes.io/name" = "web"`. Return [None] when the parts contain list
+    /// indices which cannot be created.
+    fn build_config_entry(
+        &mut self,
+        parts: &[FieldPathPart],
+    ) -> Option<ast::NodeRef<ast::ConfigEntry>> {
+        let keys = parts
+            .iter()
+            .map(|part| match part {
+                FieldPathPart::Key(key) => Some(key.as_str()),
+                FieldPathPart::Index(_) => None,
+            })
+            .collect::<Option<Vec<&str>>>()?;
+        let names_len = keys.iter().take_while(|key| is_identifier(key)).count();
+        let (key, rest) = if names_len > 0 {
+            (
+                ast::Expr::Identifier(ast::Identifier {
+                    names: keys[..names_len].iter().map(|s| s.to_string()).collect(),
+                    ctx: ast::ExprContext::Store,
+                    pkgpath: "".to_string(),
+                }),
+                &parts[names_len..],
+            )
+        } else {
+            (
+                ast::Expr::StringLit(ast::StringLit {
+                    is_long_string: false,
+                    raw_value: format!("{:?}", keys[0]),
+                    value: keys[0].to_string(),
+                }),
+                &parts[1..],
+            )
+        };
+        let (value, operation) = if rest.is_empty() {
+            let value = match self.operation {
+                // The value of the insert entry is always a list.
+                ast::ConfigEntryOperation::Insert => {
+                    ast::Node::dummy_node(ast::Expr::List(ast::ListExpr {
+                        elts: self.insert_values(),
+                        ctx: ast::ExprContext::Load,
+                    }))
+                }
+                _ => *self.clone_override_value(),
+            };
+            (value, self.operation.clone())
+        } else {
+            let entry = self.build_config_entry(rest)?;
+            (
+                ast::Node::dummy_node(ast::Expr::Config(ast::ConfigExpr { items: vec![entry] })),
+                ast::ConfigEntryOperation::Union,
+            )
+        };
+        Some(Box::new(ast::Node::dummy_node(ast::ConfigEntry {
+            key: Some(Box::new(ast::Node::dummy_node(key))),
+            value: Box::new(value),
+            operation,
+            insert_index: -1,
+        })))
+    }
+
+    /// Returns the values to be inserted into a list, the elements of a list literal
+    /// value are inserted one by one.
+    fn insert_values(&mut self) -> Vec<ast::NodeRef<ast::Expr>> {
+        let value = self.clone_override_value();
+        match value.node {
+            ast::Expr::List(list_expr) => list_expr.elts,
+            _ => vec![value],
         }
     }
 
@@ -412,3 +507,10 @@ impl OverrideTransformer {
         }
     }
 }
+
+/// Whether the key can be used as an attribute name without quotes.
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
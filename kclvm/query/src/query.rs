@@ -0,0 +1,254 @@
+//! The read-only query of the KCL source, which resolves a field path such as
+//! `pkg:app.containers[0].image` against the AST and returns the matched config
+//! entries with their source values and positions without evaluating the program.
+use anyhow::{anyhow, Result};
+
+use kclvm_ast::ast;
+use kclvm_ast_pretty::{print_ast_node, ASTNode};
+use kclvm_parser::{load_program, LoadProgramOptions};
+
+use super::util::{parse_field_path, split_field_path, FieldPathPart};
+
+/// FieldValue is a config entry or a list element matched with a field path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldValue {
+    /// The file name of the entry.
+    pub filename: String,
+    /// The source code of the value expression printed by `ast_pretty`.
+    pub value: String,
+    /// The attribute operator of the entry, e.g., `=` of the assignment `a = 1` and `:` of
+    /// the entry `a: 1`, and [None] for a list element.
+    pub operation: Option<ast::ConfigEntryOperation>,
+    /// The (line, column, end_line, end_column) range of the whole entry.
+    pub range: (u64, u64, u64, u64),
+    /// The (line, column, end_line, end_column) range of the value expression.
+    pub value_range: (u64, u64, u64, u64),
+}
+
+/// Get the source values of the field path in the KCL file, the `code` is used instead of
+/// the file content when it is not [None].
+///
+/// # Parameters
+///
+/// `file`: [&str]
+///     The KCL file to query.
+///
+/// `code`: [Option<String>]
+///     The KCL code of the file.
+///
+/// `field_path`: [&str]
+///     The field path satisfies the form: <pkgpath>:<field_path>, e.g., `app.replicas`,
+///     `app.containers[0].image` and `app.labels["app.kubernetes.io/name"]`.
+///     When the pkgpath is '__main__', `<pkgpath>:` can be omitted.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_query::get_file_field_values;
+///
+/// let values = get_file_field_values(
+///     "main.k",
+///     Some("app = {replicas = 2}".to_string()),
+///     "app.replicas",
+/// ).unwrap();
+/// assert_eq!(values[0].value, "2");
+/// assert_eq!(values[0].range, (1, 7, 1, 19));
+/// ```
+pub fn get_file_field_values(
+    file: &str,
+    code: Option<String>,
+    field_path: &str,
+) -> Result<Vec<FieldValue>> {
+    let opts = code.map(|code| LoadProgramOptions {
+        k_code_list: vec![code],
+        ..Default::default()
+    });
+    let prog = load_program(&[file], opts).map_err(|err| anyhow!("{}", err))?;
+    get_field_values(&prog, field_path)
+}
+
+/// Get the source values of the field path `<pkgpath>:<field_path>` in the AST program,
+/// the values are returned in the order of the modules and the statements.
+pub fn get_field_values(prog: &ast::Program, field_path: &str) -> Result<Vec<FieldValue>> {
+    let (pkgpath, field_path) = split_field_path(field_path)?;
+    let pkgpath = if pkgpath.is_empty() {
+        &prog.main
+    } else {
+        &pkgpath
+    };
+    let mut values = vec![];
+    if let Some(modules) = prog.pkgs.get(pkgpath) {
+        for m in modules {
+            values.append(&mut get_module_field_values(m, &field_path)?);
+        }
+    }
+    Ok(values)
+}
+
+/// Get the source values of the field path in the AST module, e.g., `app.replicas`.
+/// All the assignment and unification statements of the target in the module
+/// body are matched, including the assignments to the attributes of the target
+/// e.g., `app.replicas = 3`. The config entries in all the branches of the `if`
+/// entries are matched because the conditions are not evaluated.
+pub fn get_module_field_values(m: &ast::Module, field_path: &str) -> Result<Vec<FieldValue>> {
+    let parts = parse_field_path(field_path)?;
+    let target_id = match parts.first() {
+        Some(FieldPathPart::Key(target_id)) => target_id,
+        _ => return Err(anyhow!("Invalid field path {:?}", field_path)),
+    };
+    let mut values = vec![];
+    for stmt in &m.body {
+        match &stmt.node {
+            ast::Stmt::Assign(assign_stmt) => {
+                // The assignment target `app.replicas` matches the path `app.replicas.*`,
+                // but not the path `app` whose value is only partly assigned.
+                let parts = match assign_stmt
+                    .targets
+                    .iter()
+                    .find_map(|target| match_names(&target.node.names, &parts))
+                {
+                    Some(parts) => parts,
+                    None => continue,
+                };
+                if parts.is_empty() {
+                    values.push(field_value(
+                        stmt.pos(),
+                        &assign_stmt.value,
+                        Some(ast::ConfigEntryOperation::Override),
+                    ));
+                } else {
+                    lookup_expr(&assign_stmt.value, parts, &mut values);
+                }
+            }
+            ast::Stmt::Unification(unification_stmt) => {
+                if unification_stmt.target.node.names.first() != Some(target_id) {
+                    continue;
+                }
+                let parts = &parts[1..];
+                let value = &unification_stmt.value;
+                let expr = Box::new(ast::Node::node_with_pos(
+                    ast::Expr::Schema(value.node.clone()),
+                    value.pos(),
+                ));
+                if parts.is_empty() {
+                    values.push(field_value(
+                        stmt.pos(),
+                        &expr,
+                        Some(ast::ConfigEntryOperation::Union),
+                    ));
+                } else {
+                    lookup_expr(&expr, parts, &mut values);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(values)
+}
+
+/// Lookup the values matched with the field path parts in the schema, config or list expression.
+fn lookup_expr(
+    expr: &ast::NodeRef<ast::Expr>,
+    parts: &[FieldPathPart],
+    values: &mut Vec<FieldValue>,
+) {
+    match (&expr.node, parts.split_first()) {
+        (ast::Expr::Schema(schema_expr), _) => lookup_expr(&schema_expr.config, parts, values),
+        (ast::Expr::Config(config_expr), Some((FieldPathPart::Key(_), _))) => {
+            lookup_config_entries(&config_expr.items, parts, values)
+        }
+        // The `elif` and `else` branches are the `orelse` expressions.
+        (ast::Expr::ConfigIfEntry(if_entry_expr), Some((FieldPathPart::Key(_), _))) => {
+            lookup_config_entries(&if_entry_expr.items, parts, values);
+            if let Some(orelse) = &if_entry_expr.orelse {
+                lookup_expr(orelse, parts, values);
+            }
+        }
+        (ast::Expr::List(list_expr), Some((FieldPathPart::Index(index), rest))) => {
+            if let Some(elt) = list_expr.elts.get(*index) {
+                if rest.is_empty() {
+                    values.push(field_value(elt.pos(), elt, None));
+                } else {
+                    lookup_expr(elt, rest, values);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Lookup the values matched with the field path parts in the config entries, the entries of
+/// the `if` entries e.g., `if cond: a = 1` are also matched.
+fn lookup_config_entries(
+    items: &[ast::NodeRef<ast::ConfigEntry>],
+    parts: &[FieldPathPart],
+    values: &mut Vec<FieldValue>,
+) {
+    for item in items {
+        let entry = &item.node;
+        let key = match &entry.key {
+            Some(key) => key,
+            None => {
+                if let ast::Expr::ConfigIfEntry(_) = &entry.value.node {
+                    lookup_expr(&entry.value, parts, values);
+                }
+                continue;
+            }
+        };
+        let rest = match match_key(&key.node, parts) {
+            Some(rest) => rest,
+            None => continue,
+        };
+        if rest.is_empty() {
+            values.push(field_value(
+                item.pos(),
+                &entry.value,
+                Some(entry.operation.clone()),
+            ));
+        } else {
+            lookup_expr(&entry.value, rest, values);
+        }
+    }
+}
+
+/// Match the config entry key with the leading parts and return the rest parts, the nested
+/// attribute key e.g., `a.b.c: 1` matches the parts `a`, `b` and `c`.
+fn match_key<'a>(key: &ast::Expr, parts: &'a [FieldPathPart]) -> Option<&'a [FieldPathPart]> {
+    match key {
+        ast::Expr::Identifier(identifier) => match_names(&identifier.names, parts),
+        ast::Expr::StringLit(string_lit) => {
+            match_names(std::slice::from_ref(&string_lit.value), parts)
+        }
+        _ => None,
+    }
+}
+
+/// Match the names e.g., `["a", "b"]` of `a.b` with the leading parts and return the rest parts.
+fn match_names<'a>(names: &[String], parts: &'a [FieldPathPart]) -> Option<&'a [FieldPathPart]> {
+    if names.len() > parts.len() {
+        return None;
+    }
+    let matched = names
+        .iter()
+        .zip(parts)
+        .all(|(name, part)| matches!(part, FieldPathPart::Key(key) if key == name));
+    if matched {
+        Some(&parts[names.len()..])
+    } else {
+        None
+    }
+}
+
+fn field_value(
+    (filename, line, column, end_line, end_column): (String, u64, u64, u64, u64),
+    value: &ast::NodeRef<ast::Expr>,
+    operation: Option<ast::ConfigEntryOperation>,
+) -> FieldValue {
+    FieldValue {
+        filename,
+        value: print_ast_node(ASTNode::Expr(value)),
+        operation,
+        range: (line, column, end_line, end_column),
+        value_range: (value.line, value.column, value.end_line, value.end_column),
+    }
+}
@@ -0,0 +1,408 @@
+//! The schema type introspection of the KCL program, which collects the schema types
+//! produced by the resolver with the source information of the schema statements
+//! such as the default values, decorators and check expressions.
+use std::rc::Rc;
+
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use kclvm_ast::{ast, MAIN_PKG};
+use kclvm_ast_pretty::{print_ast_node, ASTNode};
+use kclvm_parser::{load_program, LoadProgramOptions};
+use kclvm_sema::resolver::{resolve_program, scope::ScopeObjectKind};
+use kclvm_sema::ty::{SchemaType, Type, TypeKind};
+
+/// The internal `__settings__` attribute name of all the schemas.
+const SCHEMA_SETTINGS_ATTR_NAME: &str = "__settings__";
+
+/// SchemaTypeInfo is the schema type produced by the resolver with its source information.
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaTypeInfo {
+    /// The schema name.
+    pub name: String,
+    /// The schema definition package path.
+    pub pkgpath: String,
+    /// The schema definition file path.
+    pub filename: String,
+    /// The schema definition line.
+    pub line: u64,
+    /// The schema document string without the quotes and the common indentation.
+    pub doc: String,
+    /// The parent schema name with the package path, e.g. `@pkg.Base`.
+    pub parent: Option<String>,
+    /// The mixin names with the package paths.
+    pub mixins: Vec<String>,
+    pub is_mixin: bool,
+    pub is_protocol: bool,
+    pub is_rule: bool,
+    /// The schema attributes including the attributes of the parent schemas.
+    pub attrs: IndexMap<String, SchemaAttrInfo>,
+    pub index_signature: Option<IndexSignatureInfo>,
+    pub decorators: Vec<DecoratorInfo>,
+    /// The source code of the check expressions, e.g. `age > 0, "age must be positive"`.
+    pub checks: Vec<String>,
+    /// The schema type.
+    #[serde(skip)]
+    pub ty: Rc<Type>,
+}
+
+/// SchemaAttrInfo is a schema attribute with its source information.
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaAttrInfo {
+    /// The attribute type string, e.g. `[str]` and `{str:Container}`.
+    #[serde(rename = "type")]
+    pub ty_str: String,
+    pub is_optional: bool,
+    /// The source code of the default value, e.g. `"nginx"` of `image: str = "nginx"`.
+    pub default: Option<String>,
+    /// The attribute description in the `Attributes` section of the schema document.
+    pub doc: String,
+    pub decorators: Vec<DecoratorInfo>,
+    /// The attribute definition line.
+    pub line: u64,
+    /// The attribute type.
+    #[serde(skip)]
+    pub ty: Rc<Type>,
+}
+
+/// IndexSignatureInfo is the schema index signature e.g. `[name: str]: int`.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexSignatureInfo {
+    pub key_name: Option<String>,
+    pub key_type: String,
+    pub value_type: String,
+    pub any_other: bool,
+    /// The value type of the index signature.
+    #[serde(skip)]
+    pub value_ty: Rc<Type>,
+}
+
+/// DecoratorInfo is a schema or attribute decorator e.g. `@deprecated(version="1.16")`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DecoratorInfo {
+    pub name: String,
+    /// The source code of the positional arguments.
+    pub arguments: Vec<String>,
+    /// The source code of the keyword arguments.
+    pub keywords: IndexMap<String, String>,
+}
+
+/// Get the schema types defined in the main package of the KCL file, the `code` is used
+/// instead of the file content when it is not [None]. All the schema types are returned
+/// when `schema_name` is [None].
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_query::get_schema_type;
+///
+/// let code = r#"
+/// schema Person:
+///     "The person schema"
+///     name: str = "Alice"
+///     age?: int
+///
+///     check:
+///         age > 0 if age
+/// "#;
+/// let types = get_schema_type("main.k", Some(code.to_string()), Some("Person")).unwrap();
+/// assert_eq!(types[0].doc, "The person schema");
+/// assert_eq!(types[0].attrs["name"].default, Some("\"Alice\"".to_string()));
+/// assert_eq!(types[0].attrs["age"].ty_str, "int");
+/// assert_eq!(types[0].checks, vec!["age > 0 if age".to_string()]);
+/// ```
+pub fn get_schema_type(
+    file: &str,
+    code: Option<String>,
+    schema_name: Option<&str>,
+) -> Result<Vec<SchemaTypeInfo>> {
+    let opts = code.map(|code| LoadProgramOptions {
+        k_code_list: vec![code],
+        ..Default::default()
+    });
+    let prog = load_program(&[file], opts).map_err(|err| anyhow!("{}", err))?;
+    Ok(get_program_schema_types(&prog)
+        .into_iter()
+        .filter(|ty| ty.pkgpath == MAIN_PKG)
+        .filter(|ty| schema_name.map_or(true, |name| ty.name == name))
+        .collect())
+}
+
+/// Get the schema types of all the packages in the AST program, the schema types of the
+/// main package come first. The types are returned even if the program has type errors.
+pub fn get_program_schema_types(prog: &ast::Program) -> Vec<SchemaTypeInfo> {
+    // The resolver transforms the AST, and the source information is got from the original one.
+    let scope = resolve_program(&mut prog.clone());
+    let mut pkgpaths = vec![prog.main.clone()];
+    for pkgpath in scope.scope_map.keys() {
+        if !pkgpaths.contains(pkgpath) && prog.pkgs.contains_key(pkgpath) {
+            pkgpaths.push(pkgpath.clone());
+        }
+    }
+    let mut schema_types = vec![];
+    for pkgpath in &pkgpaths {
+        let pkg_scope = match scope.scope_map.get(pkgpath) {
+            Some(pkg_scope) => pkg_scope,
+            None => continue,
+        };
+        for (name, obj) in &pkg_scope.borrow().elems {
+            let obj = obj.borrow();
+            if !matches!(obj.kind, ScopeObjectKind::Definition) {
+                continue;
+            }
+            if let TypeKind::Schema(schema_ty) = &obj.ty.kind {
+                if !schema_ty.is_instance && &schema_ty.name == name {
+                    schema_types.push(schema_type_info(prog, schema_ty, obj.ty.clone()));
+                }
+            }
+        }
+    }
+    schema_types
+}
+
+fn schema_type_info(prog: &ast::Program, schema_ty: &SchemaType, ty: Rc<Type>) -> SchemaTypeInfo {
+    let mut info = SchemaTypeInfo {
+        name: schema_ty.name.clone(),
+        pkgpath: schema_ty.pkgpath.clone(),
+        filename: schema_ty.filename.clone(),
+        line: 0,
+        doc: clean_doc(&schema_ty.doc),
+        parent: schema_ty
+            .base
+            .as_ref()
+            .map(|base| base.ty_str_with_pkgpath()),
+        mixins: schema_ty
+            .mixins
+            .iter()
+            .map(|mixin| mixin.ty_str_with_pkgpath())
+            .collect(),
+        is_mixin: schema_ty.is_mixin,
+        is_protocol: schema_ty.is_protocol,
+        is_rule: schema_ty.is_rule,
+        attrs: schema_attrs(prog, schema_ty),
+        index_signature: schema_ty.index_signature.as_ref().map(|index_signature| {
+            IndexSignatureInfo {
+                key_name: index_signature.key_name.clone(),
+                key_type: index_signature.key_ty.ty_str(),
+                value_type: index_signature.val_ty.ty_str(),
+                any_other: index_signature.any_other,
+                value_ty: index_signature.val_ty.clone(),
+            }
+        }),
+        decorators: vec![],
+        checks: vec![],
+        ty,
+    };
+    match lookup_schema_stmt(prog, schema_ty) {
+        Some((line, SchemaStmt::Schema(schema_stmt))) => {
+            info.line = line;
+            info.decorators = decorator_infos(&schema_stmt.decorators);
+            info.checks = check_sources(&schema_stmt.checks);
+        }
+        Some((line, SchemaStmt::Rule(rule_stmt))) => {
+            info.line = line;
+            info.decorators = decorator_infos(&rule_stmt.decorators);
+            info.checks = check_sources(&rule_stmt.checks);
+        }
+        None => {}
+    }
+    info
+}
+
+/// Returns the attributes of the schema type, the attributes of the parent schema come first.
+fn schema_attrs(prog: &ast::Program, schema_ty: &SchemaType) -> IndexMap<String, SchemaAttrInfo> {
+    let mut attrs = match &schema_ty.base {
+        Some(base) => schema_attrs(prog, base),
+        None => IndexMap::new(),
+    };
+    let schema_stmt = match lookup_schema_stmt(prog, schema_ty) {
+        Some((_, SchemaStmt::Schema(schema_stmt))) => Some(schema_stmt),
+        _ => None,
+    };
+    let attr_docs = attr_docs(&clean_doc(&schema_ty.doc));
+    for (name, attr) in &schema_ty.attrs {
+        if name == SCHEMA_SETTINGS_ATTR_NAME {
+            continue;
+        }
+        let mut info = SchemaAttrInfo {
+            ty_str: attr.ty.ty_str(),
+            is_optional: attr.is_optional,
+            default: None,
+            doc: attr_docs.get(name).cloned().unwrap_or_default(),
+            decorators: vec![],
+            line: attr.pos.line,
+            ty: attr.ty.clone(),
+        };
+        for stmt in schema_stmt.iter().flat_map(|schema_stmt| &schema_stmt.body) {
+            match &stmt.node {
+                ast::Stmt::SchemaAttr(schema_attr) if &schema_attr.name.node == name => {
+                    info.default = schema_attr.value.as_ref().map(print_expr);
+                    if !schema_attr.doc.is_empty() {
+                        info.doc = schema_attr.doc.clone();
+                    }
+                    info.decorators = decorator_infos(&schema_attr.decorators);
+                }
+                ast::Stmt::Unification(unification_stmt)
+                    if unification_stmt.target.node.get_name() == *name =>
+                {
+                    let value = &unification_stmt.value;
+                    info.default = Some(print_expr(&Box::new(ast::Node::node_with_pos(
+                        ast::Expr::Schema(value.node.clone()),
+                        value.pos(),
+                    ))));
+                }
+                _ => {}
+            }
+        }
+        attrs.insert(name.clone(), info);
+    }
+    attrs
+}
+
+enum SchemaStmt<'a> {
+    Schema(&'a ast::SchemaStmt),
+    Rule(&'a ast::RuleStmt),
+}
+
+/// Lookup the schema or rule statement of the schema type in the AST program.
+fn lookup_schema_stmt<'a>(
+    prog: &'a ast::Program,
+    schema_ty: &SchemaType,
+) -> Option<(u64, SchemaStmt<'a>)> {
+    let modules = prog.pkgs.get(&schema_ty.pkgpath)?;
+    for module in modules.iter().filter(|m| m.filename == schema_ty.filename) {
+        for stmt in &module.body {
+            match &stmt.node {
+                ast::Stmt::Schema(schema_stmt) if schema_stmt.name.node == schema_ty.name => {
+                    return Some((stmt.line, SchemaStmt::Schema(schema_stmt)));
+                }
+                ast::Stmt::Rule(rule_stmt) if rule_stmt.name.node == schema_ty.name => {
+                    return Some((stmt.line, SchemaStmt::Rule(rule_stmt)));
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+fn decorator_infos(decorators: &[ast::NodeRef<ast::CallExpr>]) -> Vec<DecoratorInfo> {
+    decorators
+        .iter()
+        .map(|decorator| DecoratorInfo {
+            name: match &decorator.node.func.node {
+                ast::Expr::Identifier(identifier) => identifier.get_name(),
+                _ => "".to_string(),
+            },
+            arguments: decorator.node.args.iter().map(print_expr).collect(),
+            keywords: decorator
+                .node
+                .keywords
+                .iter()
+                .map(|keyword| {
+                    (
+                        keyword.node.arg.node.get_name(),
+                        keyword
+                            .node
+                            .value
+                            .as_ref()
+                            .map(print_expr)
+                            .unwrap_or_default(),
+                    )
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn check_sources(checks: &[ast::NodeRef<ast::CheckExpr>]) -> Vec<String> {
+    checks
+        .iter()
+        .map(|check| {
+            print_expr(&Box::new(ast::Node::node_with_pos(
+                ast::Expr::Check(check.node.clone()),
+                check.pos(),
+            )))
+        })
+        .collect()
+}
+
+/// Returns the document string without the quotes and the common indentation.
+fn clean_doc(doc: &str) -> String {
+    let doc = doc.trim_start_matches(|c| c == 'r' || c == 'R');
+    let doc = ["\"\"\"", "'''", "\"", "'"]
+        .iter()
+        .find(|quote| doc.len() >= quote.len() * 2 && doc.starts_with(*quote))
+        .map(|quote| &doc[quote.len()..doc.len() - quote.len()])
+        .unwrap_or(doc);
+    let mut lines = doc.lines();
+    let first = lines.next().unwrap_or_default().trim();
+    let rest: Vec<&str> = lines.collect();
+    let indent = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+    let mut cleaned = vec![first];
+    cleaned.extend(
+        rest.iter()
+            .map(|line| line.get(indent..).unwrap_or_default().trim_end()),
+    );
+    cleaned.join("\n").trim().to_string()
+}
+
+/// Returns the attribute descriptions in the `Attributes` section of the schema document, e.g.
+///
+/// ```no_check
+/// Attributes
+/// ----------
+/// name : str
+///     The name of the person.
+/// ```
+fn attr_docs(doc: &str) -> IndexMap<String, String> {
+    let mut docs: IndexMap<String, String> = IndexMap::new();
+    let mut lines = doc.lines().peekable();
+    while let Some(line) = lines.next() {
+        if line.trim() == "Attributes"
+            && matches!(lines.peek(), Some(next) if next.trim().starts_with("---"))
+        {
+            lines.next();
+            break;
+        }
+    }
+    let mut name: Option<String> = None;
+    while let Some(line) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) {
+            if let Some(name) = &name {
+                let doc = docs.entry(name.clone()).or_default();
+                if !doc.is_empty() {
+                    doc.push(' ');
+                }
+                doc.push_str(line.trim());
+            }
+        } else if matches!(lines.peek(), Some(next) if next.trim().starts_with("---")) {
+            // The next section begins.
+            break;
+        } else {
+            let attr = line
+                .split(':')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            docs.insert(attr.clone(), "".to_string());
+            name = Some(attr);
+        }
+    }
+    docs
+}
+
+#[inline]
+fn print_expr(expr: &ast::NodeRef<ast::Expr>) -> String {
+    print_ast_node(ASTNode::Expr(expr))
+}
@@ -134,3 +134,383 @@ fn test_parse_override_spec_invalid() {
         assert!(parse_override_spec(spec).is_err(), "{} test failed", spec);
     }
 }
+
+/// Test override specs with list indices, quoted keys and entry operators.
+#[test]
+fn test_override_file_list() {
+    let specs = vec![
+        "app.containers[0].image=\"nginx:1.25\"".to_string(),
+        "app.containers+=Container {name = \"sidecar\", image = \"envoy\"}".to_string(),
+        "app.containers[0]+={name = \"init\", image = \"busybox\"}".to_string(),
+        "app.ports[1]-".to_string(),
+        "app.ports+=[9090, 9091]".to_string(),
+        "app.labels[\"app.kubernetes.io/name\"]=\"web\"".to_string(),
+        "app.labels.env:\"prod\"".to_string(),
+        "app.env+=\"DEBUG=1\"".to_string(),
+        "config.replicas=3".to_string(),
+        "config.strategy:{type = \"Recreate\"}".to_string(),
+    ];
+    let overrides = specs
+        .iter()
+        .map(|s| parse_override_spec(s).unwrap())
+        .collect::<Vec<ast::OverrideSpec>>();
+
+    let mut cargo_file_path = PathBuf::from(CARGO_FILE_PATH);
+    cargo_file_path.push("src/test_data/list.k");
+    let abs_path = cargo_file_path.to_str().unwrap();
+
+    let mut module = parse_file(abs_path, None).unwrap();
+    for o in &overrides {
+        assert!(
+            apply_override_on_module(&mut module, o, &[]).unwrap(),
+            "{:?} test failed",
+            o
+        );
+    }
+    // The out of range index, the missing attribute and the missing target are not modified.
+    let specs = [
+        "app.containers[10].image=\"nginx\"",
+        "app.containers[0].command-",
+        "config.not_exist-",
+        "not_exist.replicas=3",
+    ];
+    for spec in specs {
+        let o = parse_override_spec(spec).unwrap();
+        assert!(
+            !apply_override_on_module(&mut module, &o, &[]).unwrap(),
+            "{} test failed",
+            spec
+        );
+    }
+    assert_eq!(
+        print_ast_module(&module),
+        r#"schema Container:
+    name: str
+    image: str
+
+schema App:
+    containers: [Container]
+    ports: [int]
+    labels: {str:str}
+    env?: [str]
+
+app = App {
+    containers = [
+        {name = "init", image = "busybox"}
+        Container {
+            name = "main"
+            image = "nginx:1.25"
+        }
+        Container {name = "sidecar", image = "envoy"}
+    ]
+    ports = [
+        80
+        8080
+        9090
+        9091
+    ]
+    labels = {
+        app = "web"
+        "app.kubernetes.io/name" = "web"
+        env: "prod"
+    }
+    env += ["DEBUG=1"]
+}
+
+config = {
+    replicas = 3
+    strategy: {type = "Recreate"}
+}
+"#
+    );
+}
+
+/// Test override_file_diff result without modifying the file, the unformatted `a=1` is
+/// formatted by override_file, thus it is also in the diff.
+#[test]
+fn test_override_file_diff() {
+    let mut cargo_file_path = PathBuf::from(CARGO_FILE_PATH);
+    cargo_file_path.push("src/test_data/diff.k");
+    let abs_path = cargo_file_path.to_str().unwrap();
+    let src = std::fs::read_to_string(abs_path).unwrap();
+
+    let specs = ["config.replicas=3".to_string()];
+    let diff = override_file_diff(abs_path, &specs, &[]).unwrap();
+    assert_eq!(
+        diff,
+        format!(
+            "--- {0}\n+++ {0}\n@@ -1,2 +1,2 @@\n-a=1\n-config = {{replicas = 1}}\n+a = 1\n+config = {{replicas = 3}}\n",
+            abs_path
+        )
+    );
+    assert_eq!(std::fs::read_to_string(abs_path).unwrap(), src);
+
+    // The diff is the change override_file writes to the file.
+    let override_path = PathBuf::from(CARGO_FILE_PATH).join("src/test_data/diff_override.k");
+    let override_path = override_path.to_str().unwrap();
+    std::fs::write(override_path, &src).unwrap();
+    assert!(override_file(override_path, &specs, &[]).unwrap());
+    let overridden = std::fs::read_to_string(override_path).unwrap();
+    std::fs::remove_file(override_path).unwrap();
+    let expected_diff = TextDiff::from_lines(&src, &overridden)
+        .unified_diff()
+        .header(abs_path, abs_path)
+        .to_string();
+    assert_eq!(diff, expected_diff);
+
+    let diff = override_file_diff(abs_path, &["not_exist.replicas=3".to_string()], &[]).unwrap();
+    assert_eq!(diff, "");
+}
+
+/// Test override spec parser with the entry operators.
+#[test]
+fn test_parse_override_spec() {
+    let cases = vec![
+        (
+            "alice.age=10",
+            (
+                "",
+                "alice.age",
+                "10",
+                ast::OverrideAction::CreateOrUpdate,
+                ast::ConfigEntryOperation::Override,
+            ),
+        ),
+        (
+            "pkg.to.path:alice.labels:{app = \"web\"}",
+            (
+                "pkg.to.path",
+                "alice.labels",
+                "{app = \"web\"}",
+                ast::OverrideAction::CreateOrUpdate,
+                ast::ConfigEntryOperation::Union,
+            ),
+        ),
+        (
+            ":alice.containers[0]+={name = \"sidecar\"}",
+            (
+                "",
+                "alice.containers[0]",
+                "{name = \"sidecar\"}",
+                ast::OverrideAction::CreateOrUpdate,
+                ast::ConfigEntryOperation::Insert,
+            ),
+        ),
+        (
+            "alice.labels[\"app.kubernetes.io/name\"]-",
+            (
+                "",
+                "alice.labels[\"app.kubernetes.io/name\"]",
+                "",
+                ast::OverrideAction::Delete,
+                ast::ConfigEntryOperation::Override,
+            ),
+        ),
+        (
+            "alice.image=\"nginx:1.25\"",
+            (
+                "",
+                "alice.image",
+                "\"nginx:1.25\"",
+                ast::OverrideAction::CreateOrUpdate,
+                ast::ConfigEntryOperation::Override,
+            ),
+        ),
+    ];
+    for (spec, (pkgpath, field_path, field_value, action, operation)) in cases {
+        assert_eq!(
+            parse_override_spec(spec).unwrap(),
+            ast::OverrideSpec {
+                pkgpath: pkgpath.to_string(),
+                field_path: field_path.to_string(),
+                field_value: field_value.to_string(),
+                action,
+                operation,
+            },
+            "{} test failed",
+            spec
+        );
+    }
+    let specs = vec!["a[0", "a..b=1", "a[x]=1", "[0].a=1", "a.b+="];
+    for spec in specs {
+        assert!(parse_override_spec(spec).is_err(), "{} test failed", spec);
+    }
+}
+
+/// Test get_file_field_values with schema expressions, nested configs and list indices.
+#[test]
+fn test_get_file_field_values() {
+    let mut cargo_file_path = PathBuf::from(CARGO_FILE_PATH);
+    cargo_file_path.push("src/test_data/query.k");
+    let abs_path = cargo_file_path.to_str().unwrap();
+
+    let values = get_file_field_values(abs_path, None, "app.replicas").unwrap();
+    assert_eq!(
+        values
+            .iter()
+            .map(|v| (v.value.as_str(), v.operation.clone(), v.range))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "2",
+                Some(ast::ConfigEntryOperation::Override),
+                (11, 4, 11, 16)
+            ),
+            (
+                "1",
+                Some(ast::ConfigEntryOperation::Insert),
+                (25, 4, 25, 17)
+            ),
+        ]
+    );
+    assert_eq!(values[0].filename, abs_path);
+    assert_eq!(values[0].value_range, (11, 15, 11, 16));
+
+    let values = get_file_field_values(abs_path, None, "app.containers[0].image").unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].value, "\"nginx\"");
+    assert_eq!(values[0].range, (15, 12, 15, 27));
+
+    let values = get_file_field_values(abs_path, None, "app.containers[0]").unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].operation, None);
+    assert_eq!(values[0].range.0, 13);
+
+    let values =
+        get_file_field_values(abs_path, None, "app.labels[\"app.kubernetes.io/name\"]").unwrap();
+    assert_eq!(values[0].value, "\"web\"");
+    assert_eq!(values[0].operation, Some(ast::ConfigEntryOperation::Union));
+    let values = get_file_field_values(abs_path, None, "__main__:app.labels.env").unwrap();
+    assert_eq!(values[0].value, "\"prod\"");
+    assert_eq!(values[0].range, (21, 4, 21, 22));
+
+    let values = get_file_field_values(abs_path, None, "app").unwrap();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[1].operation, Some(ast::ConfigEntryOperation::Union));
+    assert_eq!((values[1].range.0, values[1].range.2), (24, 27));
+
+    // The `:` in the quoted key is not the separator of the package path.
+    let values = get_file_field_values(abs_path, None, "app.labels[\"a:b\"]").unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].value, "\"c\"");
+    assert_eq!(values[0].range.0, 26);
+    let values = get_file_field_values(abs_path, None, "__main__:app.labels['a:b']").unwrap();
+    assert_eq!(values.len(), 1);
+
+    let values = get_file_field_values(abs_path, None, "app.containers[1].image").unwrap();
+    assert!(values.is_empty());
+    assert!(get_file_field_values(abs_path, None, "app.containers[").is_err());
+}
+
+/// Test get_file_field_values with the config `if` entries and the attribute assignments.
+#[test]
+fn test_get_file_field_values_if_entries() {
+    let mut cargo_file_path = PathBuf::from(CARGO_FILE_PATH);
+    cargo_file_path.push("src/test_data/query_if.k");
+    let abs_path = cargo_file_path.to_str().unwrap();
+
+    // The entries in all the branches and the attribute assignment are matched.
+    let values = get_file_field_values(abs_path, None, "app.replicas").unwrap();
+    assert_eq!(
+        values
+            .iter()
+            .map(|v| (v.value.as_str(), v.operation.clone(), v.range.0))
+            .collect::<Vec<_>>(),
+        vec![
+            ("3", Some(ast::ConfigEntryOperation::Override), 4),
+            ("2", Some(ast::ConfigEntryOperation::Override), 6),
+            ("1", Some(ast::ConfigEntryOperation::Override), 9),
+            ("4", Some(ast::ConfigEntryOperation::Override), 12),
+        ]
+    );
+
+    let values = get_file_field_values(abs_path, None, "app.labels.env").unwrap();
+    assert_eq!(
+        values
+            .iter()
+            .map(|v| (v.value.as_str(), v.range.0))
+            .collect::<Vec<_>>(),
+        vec![("\"test\"", 7), ("\"dev\"", 13)]
+    );
+
+    let values = get_file_field_values(abs_path, None, "app.name").unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].value, "\"web\"");
+
+    // The attribute assignments are not the values of the whole config.
+    let values = get_file_field_values(abs_path, None, "app").unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].range.0, 2);
+}
+
+#[test]
+fn test_get_schema_type() {
+    let mut cargo_file_path = PathBuf::from(CARGO_FILE_PATH);
+    cargo_file_path.push("src/test_data/schema.k");
+    let abs_path = cargo_file_path.to_str().unwrap();
+
+    let types = get_schema_type(abs_path, None, None).unwrap();
+    assert_eq!(
+        types.iter().map(|ty| ty.name.as_str()).collect::<Vec<_>>(),
+        vec!["Metadata", "App", "AppRule"]
+    );
+    let app = &types[1];
+    assert_eq!(app.filename, abs_path);
+    assert_eq!(app.line, 7);
+    assert!(app
+        .doc
+        .starts_with("The application schema.\n\nAttributes\n----------\n"));
+    assert_eq!(
+        app.attrs["replicas"].doc,
+        "The number of the desired application replicas."
+    );
+    assert_eq!(app.attrs["image"].doc, "The container image.");
+    assert_eq!(app.parent, Some("Metadata".to_string()));
+    assert_eq!(
+        app.attrs.keys().collect::<Vec<_>>(),
+        vec!["name", "labels", "replicas", "image", "metadata"]
+    );
+    assert_eq!(
+        app.attrs
+            .values()
+            .map(|attr| (attr.ty_str.as_str(), attr.is_optional, attr.default.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("str", false, None),
+            ("{str:str}", true, Some("{}".to_string())),
+            ("int", false, Some("1".to_string())),
+            ("str|[str]", false, None),
+            (
+                "Metadata",
+                true,
+                Some("Metadata {name = \"app\"}".to_string())
+            ),
+        ]
+    );
+    assert_eq!(
+        app.attrs["image"].decorators,
+        vec![DecoratorInfo {
+            name: "info".to_string(),
+            arguments: vec![],
+            keywords: [("hidden".to_string(), "True".to_string())]
+                .into_iter()
+                .collect(),
+        }]
+    );
+    assert_eq!(app.decorators[0].name, "deprecated");
+    assert_eq!(app.decorators[0].keywords["version"], "\"1.16\"");
+    let index_signature = app.index_signature.as_ref().unwrap();
+    assert_eq!(index_signature.key_name, Some("key".to_string()));
+    assert_eq!(index_signature.value_type, "any");
+    assert_eq!(
+        app.checks,
+        vec!["replicas > 0, \"replicas must be positive\"".to_string()]
+    );
+    assert!(types[2].is_rule);
+
+    let types = get_schema_type(abs_path, None, Some("Metadata")).unwrap();
+    assert_eq!(types.len(), 1);
+    assert_eq!(types[0].doc, "The resource metadata.");
+    let json = serde_json::to_value(&types[0]).unwrap();
+    assert_eq!(json["attrs"]["labels"]["type"], "{str:str}");
+}
@@ -5,25 +5,141 @@ use anyhow::{anyhow, Result};
 /// and move this function into it.)
 ///
 /// split_field_path("pkg.to.path:field") -> ("pkg.to.path", "field")
+/// split_field_path("field[\"a:b\"]") -> ("", "field[\"a:b\"]")
 pub(crate) fn split_field_path(path: &str) -> Result<(String, String)> {
-    let err = Err(anyhow!("Invalid field path {:?}", path));
-    let paths = path.splitn(2, ':').collect::<Vec<&str>>();
-    let (pkgpath, field_path) = if paths.len() == 1 {
-        ("".to_string(), paths[0].to_string())
-    } else if paths.len() == 2 {
-        (paths[0].to_string(), paths[1].to_string())
-    } else {
-        return err;
+    // The `:` in the brackets of the field path is not the package path separator.
+    let end = field_path_len(path);
+    let (pkgpath, field_path) = match path[end..].strip_prefix(':') {
+        Some(field_path) => (&path[..end], field_path),
+        None => ("", path),
     };
     if field_path.is_empty() {
-        err
+        Err(anyhow!("Invalid field path {:?}", path))
     } else {
-        Ok((pkgpath, field_path))
+        Ok((pkgpath.to_string(), field_path.to_string()))
     }
 }
 
 /// Get the invalid spec error message.
 #[inline]
 pub(crate) fn invalid_spec_error(spec: &str) -> anyhow::Error {
-    anyhow!("Invalid spec format '{}', expected <pkgpath>:<field_path>=<filed_value> or <pkgpath>:<field_path>-", spec)
+    anyhow!("Invalid spec format '{}', expected <pkgpath>:<field_path><op><field_value> with the operator =, : or +=, or <pkgpath>:<field_path>-", spec)
+}
+
+/// A part of the override field path, e.g., the field path `a.b[0]["c.d"]`
+/// is composed of the parts `a`, `b`, `[0]` and `["c.d"]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FieldPathPart {
+    /// The config attribute name or the config key.
+    Key(String),
+    /// The list element index.
+    Index(usize),
+}
+
+/// Parse the override field path into parts.
+///
+/// parse_field_path("a.b[0][\"c.d\"]") -> [Key("a"), Key("b"), Index(0), Key("c.d")]
+pub(crate) fn parse_field_path(path: &str) -> Result<Vec<FieldPathPart>> {
+    let err = || anyhow!("Invalid field path {:?}", path);
+    let mut parts = vec![];
+    let mut rest = path;
+    while !rest.is_empty() {
+        if let Some(stripped) = rest.strip_prefix('[') {
+            let end = find_closing_bracket(stripped).ok_or_else(err)?;
+            let inner = stripped[..end].trim();
+            let part = match (inner.chars().next(), inner.chars().last()) {
+                (Some(q @ ('"' | '\'')), Some(last)) if inner.len() > 1 && last == q => {
+                    FieldPathPart::Key(inner[1..inner.len() - 1].to_string())
+                }
+                _ => FieldPathPart::Index(inner.parse().map_err(|_| err())?),
+            };
+            parts.push(part);
+            rest = &stripped[end + 1..];
+        } else {
+            // The attribute name must follow a dot except the first one.
+            if !parts.is_empty() {
+                rest = rest.strip_prefix('.').ok_or_else(err)?;
+            }
+            let end = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+            if end == 0 {
+                return Err(err());
+            }
+            parts.push(FieldPathPart::Key(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+    }
+    match parts.first() {
+        Some(FieldPathPart::Key(_)) => Ok(parts),
+        _ => Err(err()),
+    }
+}
+
+/// Split the override spec into the package path, the field path, the config entry
+/// operator and the field value, the operator is one of `=`, `:`, `+=` and `-`.
+///
+/// split_override_spec("pkg:a.b[0]+=1") -> Some(("pkg", "a.b[0]", "+=", "1"))
+/// split_override_spec("a.b:1") -> Some(("", "a.b", ":", "1"))
+pub(crate) fn split_override_spec(spec: &str) -> Option<(&str, &str, &str, &str)> {
+    let (path, op, value) = split_path_operator(spec)?;
+    // The first `:` may be the separator of the package path and the field path.
+    if op == ":" {
+        if let Some((field_path, op, value)) = split_path_operator(value) {
+            if !field_path.is_empty() {
+                return Some((path, field_path, op, value));
+            }
+        }
+    }
+    Some(("", path, op, value))
+}
+
+/// Split the spec into the leading field path, the operator and the rest value.
+fn split_path_operator(spec: &str) -> Option<(&str, &str, &str)> {
+    let end = field_path_len(spec);
+    let (path, rest) = spec.split_at(end);
+    if rest == "-" {
+        return Some((path, "-", ""));
+    }
+    ["+=", "=", ":"]
+        .iter()
+        .find(|op| rest.starts_with(*op))
+        .map(|op| (path, *op, &rest[op.len()..]))
+}
+
+/// Returns the length of the leading field path of the spec.
+fn field_path_len(spec: &str) -> usize {
+    let mut len = 0;
+    while len < spec.len() {
+        let rest = &spec[len..];
+        if let Some(stripped) = rest.strip_prefix('[') {
+            match find_closing_bracket(stripped) {
+                Some(end) => len += end + 2,
+                None => break,
+            }
+        } else {
+            match rest.chars().next() {
+                Some(c) if is_name_char(c) || c == '.' => len += c.len_utf8(),
+                _ => break,
+            }
+        }
+    }
+    len
+}
+
+/// Returns the index of the `]` which closes the bracket, skipping the quoted strings.
+fn find_closing_bracket(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, ']') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[inline]
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
 }
@@ -17,6 +17,8 @@ use threadpool::ThreadPool;
 
 /// IR code file suffix.
 const DEFAULT_IR_FILE: &str = "_a.out";
+/// The target triple of the WASM object files and module.
+pub const WASM_TARGET: &str = "wasm32-wasi";
 /// Default codegen timeout.
 const DEFAULT_TIME_OUT: u64 = 50;
 
@@ -52,6 +54,9 @@ pub(crate) trait LibAssembler {
     /// e.g. "import test/main_pkg as main", "main" is an "import_name".
     /// e.g. "import test/main_pkg as main", "test/main_pkg" is an import_path.
     ///
+    /// "pkg_vars" is the variables of other packages in the form of `(pkgpath, name)`
+    /// returned together with the global variables of the main package.
+    ///
     /// "code_file" is the filename of the generated intermediate code file.
     /// e.g. code_file : "/test_dir/test_code_file"
     ///
@@ -61,6 +66,7 @@ pub(crate) trait LibAssembler {
         &self,
         compile_prog: &Program,
         import_names: IndexMap<String, IndexMap<String, String>>,
+        pkg_vars: &[(String, String)],
         code_file: &str,
         code_file_path: &str,
     ) -> String;
@@ -73,10 +79,32 @@ pub(crate) trait LibAssembler {
 }
 
 /// This enum lists all the intermediate code assemblers currently supported by kclvm.
-/// Currently only supports assemble llvm intermediate code into dynamic link library.
+/// Currently supports assemble llvm intermediate code into the object files of the host
+/// target for the dynamic link library, and the object files of the `wasm32-wasi` target
+/// for the WASM module.
 #[derive(Clone)]
 pub(crate) enum KclvmLibAssembler {
     LLVM,
+    WASM,
+}
+
+impl KclvmLibAssembler {
+    /// Return the target triple of the object files, which also separates the package caches.
+    #[inline]
+    pub(crate) fn target(&self) -> String {
+        match &self {
+            KclvmLibAssembler::LLVM => env!("KCLVM_DEFAULT_TARGET").to_string(),
+            KclvmLibAssembler::WASM => WASM_TARGET.to_string(),
+        }
+    }
+
+    #[inline]
+    fn llvm_lib_assembler(&self) -> LlvmLibAssembler {
+        match &self {
+            KclvmLibAssembler::LLVM => LlvmLibAssembler::default(),
+            KclvmLibAssembler::WASM => LlvmLibAssembler::new(Some(WASM_TARGET)),
+        }
+    }
 }
 
 /// KclvmLibAssembler is a dispatcher, responsible for calling corresponding methods
@@ -90,49 +118,49 @@ impl LibAssembler for KclvmLibAssembler {
         &self,
         compile_prog: &Program,
         import_names: IndexMap<String, IndexMap<String, String>>,
+        pkg_vars: &[(String, String)],
         code_file: &str,
         object_file_path: &str,
     ) -> String {
-        match &self {
-            KclvmLibAssembler::LLVM => LlvmLibAssembler::default().assemble(
-                compile_prog,
-                import_names,
-                code_file,
-                object_file_path,
-            ),
-        }
+        self.llvm_lib_assembler().assemble(
+            compile_prog,
+            import_names,
+            pkg_vars,
+            code_file,
+            object_file_path,
+        )
     }
 
     #[inline]
     fn add_code_file_suffix(&self, code_file: &str) -> String {
-        match &self {
-            KclvmLibAssembler::LLVM => LlvmLibAssembler::default().add_code_file_suffix(code_file),
-        }
+        self.llvm_lib_assembler().add_code_file_suffix(code_file)
     }
 
     #[inline]
     fn get_code_file_suffix(&self) -> String {
-        match &self {
-            KclvmLibAssembler::LLVM => LlvmLibAssembler::default().get_code_file_suffix(),
-        }
+        self.llvm_lib_assembler().get_code_file_suffix()
     }
 }
 
 /// LlvmLibAssembler is mainly responsible for assembling the generated LLVM IR into a dynamic link library.
+///
+/// "target" is the target triple of the object files, and the host target is used if not set.
 #[derive(Clone)]
-pub(crate) struct LlvmLibAssembler;
+pub(crate) struct LlvmLibAssembler {
+    target: Option<&'static str>,
+}
 
 impl LlvmLibAssembler {
     #[inline]
-    fn new() -> Self {
-        Self {}
+    fn new(target: Option<&'static str>) -> Self {
+        Self { target }
     }
 }
 
 impl Default for LlvmLibAssembler {
     #[inline]
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
     }
 }
 
@@ -145,6 +173,7 @@ impl LibAssembler for LlvmLibAssembler {
         &self,
         compile_prog: &Program,
         import_names: IndexMap<String, IndexMap<String, String>>,
+        pkg_vars: &[(String, String)],
         code_file: &str,
         object_file_path: &str,
     ) -> String {
@@ -159,6 +188,8 @@ impl LibAssembler for LlvmLibAssembler {
                 from_path: None,
                 emit_path: Some(code_file),
                 no_link: true,
+                target: self.target,
+                pkg_vars,
             },
         )
         .expect("Compile KCL to LLVM error");
@@ -191,6 +222,8 @@ pub(crate) struct KclvmAssembler {
     program: ast::Program,
     scope: ProgramScope,
     entry_file: String,
+    /// Variables of other packages returned together with the main package.
+    pkg_vars: Vec<(String, String)>,
     single_file_assembler: KclvmLibAssembler,
     target: String,
 }
@@ -203,6 +236,7 @@ impl KclvmAssembler {
         program: ast::Program,
         scope: ProgramScope,
         entry_file: String,
+        pkg_vars: Vec<(String, String)>,
         single_file_assembler: KclvmLibAssembler,
     ) -> Self {
         Self {
@@ -210,8 +244,9 @@ impl KclvmAssembler {
             program,
             scope,
             entry_file,
+            pkg_vars,
+            target: single_file_assembler.target(),
             single_file_assembler,
-            target: env!("KCLVM_DEFAULT_TARGET").to_string(),
         }
     }
 
@@ -319,6 +354,11 @@ impl KclvmAssembler {
             let code_file_path = assembler.add_code_file_suffix(&code_file);
             let lock_file_path = format!("{}.lock", code_file_path);
             let target = self.target.clone();
+            let pkg_vars = if is_main_pkg {
+                self.pkg_vars.clone()
+            } else {
+                vec![]
+            };
             pool.execute(move || {
                 // Locking file for parallel code generation.
                 let mut file_lock = fslock::LockFile::open(&lock_file_path)
@@ -334,7 +374,13 @@ impl KclvmAssembler {
                 // written.
                 let file_path = if is_main_pkg {
                     // generate dynamic link library for single file kcl program
-                    assembler.assemble(&compile_prog, import_names, &code_file, &code_file_path)
+                    assembler.assemble(
+                        &compile_prog,
+                        import_names,
+                        &pkg_vars,
+                        &code_file,
+                        &code_file_path,
+                    )
                 } else {
                     // Read the lib path cache
                     let file_relative_path: Option<String> =
@@ -361,6 +407,7 @@ impl KclvmAssembler {
                             let file_path = assembler.assemble(
                                 &compile_prog,
                                 import_names,
+                                &pkg_vars,
                                 &code_file,
                                 &code_file_path,
                             );
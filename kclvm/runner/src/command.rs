@@ -3,6 +3,15 @@ use std::env::consts::DLL_SUFFIX;
 use std::ffi::CString;
 use std::path::PathBuf;
 
+use crate::assembler::WASM_TARGET;
+
+/// The undefined symbols of the KCLVM runtime which are imported from the WASM host.
+const RUNTIME_UNDEFINED_WASM_SYMBOLS: &str =
+    include_str!("../../runtime/src/_kclvm_undefined_wasm.txt");
+
+/// The filename suffix of WASM modules.
+const WASM_SUFFIX: &str = ".wasm";
+
 #[derive(Debug)]
 pub struct Command {
     executable_root: String,
@@ -123,6 +132,75 @@ impl Command {
         path.to_str().unwrap().to_string()
     }
 
+    /// Link the `wasm32-wasi` object files and the KCLVM runtime static library
+    /// into a self-contained WASM module using cc-rs lib.
+    ///
+    /// The runtime static library `libkclvm_runtime.a` is found in the directory
+    /// returned by [Command::wasm_runtime_lib_dir], and the WASI sysroot can be
+    /// set by the `WASI_SYSROOT` environment variable.
+    pub(crate) fn link_libs_to_wasm_with_cc(&mut self, libs: &[String], wasm_path: &str) -> String {
+        let wasm_path = if wasm_path.is_empty() {
+            format!("{}{}", "_a.out", WASM_SUFFIX)
+        } else {
+            wasm_path.to_string()
+        };
+
+        #[cfg(not(target_os = "windows"))]
+        let host = format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS);
+
+        #[cfg(target_os = "windows")]
+        let host = format!("{}-{}", std::env::consts::ARCH, Self::cc_env_windows());
+
+        let mut build = cc::Build::new();
+
+        build
+            .cargo_metadata(false)
+            .no_default_flags(false)
+            .opt_level(0)
+            .target(WASM_TARGET)
+            .host(&host)
+            .flag("-o")
+            .flag(&wasm_path);
+
+        // The runtime functions imported from the WASM host are allowed to be undefined.
+        let undefined_file = format!("{}.undefined.txt", wasm_path);
+        std::fs::write(&undefined_file, RUNTIME_UNDEFINED_WASM_SYMBOLS)
+            .unwrap_or_else(|_| panic!("{} can not be written", undefined_file));
+
+        // Run command with cc.
+        let mut cmd = build.try_get_compiler().unwrap().to_command();
+        if let Ok(sysroot) = std::env::var("WASI_SYSROOT") {
+            cmd.arg(format!("--sysroot={}", sysroot));
+        }
+        cmd.args(libs)
+            .arg(format!("-L{}", Self::wasm_runtime_lib_dir()))
+            .arg("-lkclvm_runtime")
+            .arg(format!("-Wl,--allow-undefined-file={}", undefined_file));
+        let result = cmd.output().expect("run cc command failed");
+        let _ = std::fs::remove_file(&undefined_file);
+        if !result.status.success() {
+            panic!(
+                "run cc failed: stdout {}, stderr: {}",
+                String::from_utf8(result.stdout).unwrap(),
+                String::from_utf8(result.stderr).unwrap()
+            )
+        }
+        // Use absolute path.
+        let path = PathBuf::from(&wasm_path)
+            .canonicalize()
+            .unwrap_or_else(|_| panic!("{} not found", wasm_path));
+        path.to_str().unwrap().to_string()
+    }
+
+    /// Get the directory of the KCLVM runtime static library of the `wasm32-wasi` target,
+    /// which is `$KCLVM_WASM_LIB_PATH` if set, otherwise `<kclvm_root>/lib/wasm32-wasi`.
+    pub(crate) fn wasm_runtime_lib_dir() -> String {
+        match std::env::var("KCLVM_WASM_LIB_PATH") {
+            Ok(path) => path,
+            Err(_) => format!("{}/lib/{}", Self::get_executable_root(), WASM_TARGET),
+        }
+    }
+
     /// Add args for cc.
     pub(crate) fn add_args(
         &self,
@@ -197,6 +275,11 @@ impl Command {
         DLL_SUFFIX.to_string()
     }
 
+    /// Specifies the filename suffix used for WASM modules.
+    pub(crate) fn get_wasm_suffix() -> String {
+        WASM_SUFFIX.to_string()
+    }
+
     fn is_windows() -> bool {
         cfg!(target_os = "windows")
     }
@@ -206,7 +289,7 @@ impl Command {
         "msvc".to_string()
     }
 
-    fn find_it<P>(exe_name: P) -> Option<std::path::PathBuf>
+    pub(crate) fn find_it<P>(exe_name: P) -> Option<std::path::PathBuf>
     where
         P: AsRef<std::path::Path>,
     {
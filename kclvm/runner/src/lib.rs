@@ -1,17 +1,22 @@
 use std::{collections::HashMap, path::Path, time::SystemTime};
 
+use indexmap::IndexSet;
+
 use assembler::KclvmLibAssembler;
 use command::Command;
 use kclvm_ast::{
     ast::{Module, Program},
     MAIN_PKG,
 };
-use kclvm_parser::load_program;
+use kclvm_parser::load_program_with_errors;
 use kclvm_query::apply_overrides;
-use kclvm_runtime::{ErrType, PanicInfo, ValueRef};
-use kclvm_sema::resolver::resolve_program;
-pub use runner::ExecProgramArgs;
-use runner::{ExecProgramResult, KclvmRunner, KclvmRunnerOptions};
+use kclvm_runtime::{ContextConfig, ErrType, PanicInfo, PathSegment, PathSelector, ValueRef};
+use kclvm_sema::resolver::{
+    resolve_program,
+    scope::{ProgramScope, ScopeObjectKind},
+};
+pub use runner::{ExecProgramArgs, ExecProgramError};
+use runner::{ExecProgramResult, FastRunner, KclvmRunner, KclvmRunnerOptions};
 use tempfile::tempdir;
 
 pub mod assembler;
@@ -48,6 +53,10 @@ pub mod tests;
 ///
 /// At last, KclvmRunner will be constructed and call method "run" to execute the kcl program.
 ///
+/// When the program fails, the returned [ExecProgramError] holds the diagnostics of all
+/// the phases: all the syntax errors of the program files, all the errors and warnings
+/// found by the resolver, or the runtime error with the resolver warnings.
+///
 /// # Examples
 ///
 /// ```
@@ -64,7 +73,142 @@ pub mod tests;
 pub fn exec_program(
     args: &ExecProgramArgs,
     plugin_agent: u64,
-) -> Result<ExecProgramResult, String> {
+) -> Result<ExecProgramResult, ExecProgramError> {
+    let mut program = load_program_with_args(args)?;
+    let path_selector = parse_path_selector(&program, &args.path_selector)?;
+
+    let start_time = SystemTime::now();
+    let scope = resolve_program_with_diagnostics(&mut program)?;
+    let pkg_vars = selected_pkg_vars(&scope, &path_selector)?;
+    let warnings = scope.diagnostics.iter().cloned().collect::<Vec<_>>();
+    let exec_result = execute_with_scope(program, scope, pkg_vars, plugin_agent, args);
+    let escape_time = match SystemTime::now().duration_since(start_time) {
+        Ok(dur) => dur.as_secs_f32(),
+        Err(err) => return Err(err.to_string().into()),
+    };
+    let mut result = ExecProgramResult::default();
+    result.escaped_time = escape_time.to_string();
+    result.warnings = warnings.clone();
+    // Exec result is a JSON or YAML string.
+    let exec_result = match exec_result {
+        Ok(res) => res,
+        Err(res) => {
+            if res.is_empty() {
+                return Ok(result);
+            } else {
+                let mut err = ExecProgramError::new(warnings);
+                err.diagnostics
+                    .extend(ExecProgramError::from(res).diagnostics);
+                return Err(err);
+            }
+        }
+    };
+    // Exec result is the option list help message in the list option mode.
+    if args.list_option_mode {
+        result.json_result = exec_result.clone();
+        result.yaml_result = exec_result;
+        return Ok(result);
+    }
+    let mut kcl_val = match ValueRef::from_yaml_stream(&exec_result) {
+        Ok(v) => v,
+        Err(err) => return Err(err.to_string().into()),
+    };
+    // The variables of the selected packages are returned with the main package.
+    let pkg_results = kcl_val.take_pkg_vars();
+    // Plan the result with the output settings of this run.
+    let cfg = ContextConfig {
+        include_schema_type_path: args.include_schema_type_path,
+        ..Default::default()
+    };
+    let (json_result, yaml_result) = if path_selector.is_empty() {
+        kcl_val.plan_with_config(&cfg)
+    } else {
+        kcl_val.plan_with_path_selector(&path_selector, &pkg_results, &cfg)?
+    };
+    result.json_result = json_result;
+    if !args.disable_yaml_result {
+        result.yaml_result = yaml_result;
+    }
+    Ok(result)
+}
+
+/// `build_wasm_program` compiles the KCL program in "args" into a self-contained
+/// `wasm32-wasi` module at "output", and returns the absolute path of the module.
+///
+/// The WASM module runs the program with the top-level arguments passed in the
+/// `-D name=value` form, and prints the planned YAML result, or the JSON result
+/// with the `--json` flag. The module plans the result by itself, so the output
+/// settings `path_selector` and `include_schema_type_path` are not supported.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_runner::{build_wasm_program, ExecProgramArgs};
+///
+/// // Get default args
+/// let mut args = ExecProgramArgs::default();
+/// args.k_filename_list = vec!["./src/test_datas/init_check_order_0/main.k".to_string()];
+///
+/// // Resolve ast, generate `wasm32-wasi` libs and link them with the runtime.
+/// let wasm_path = build_wasm_program(&args, "main.wasm").unwrap();
+/// ```
+pub fn build_wasm_program(
+    args: &ExecProgramArgs,
+    output: &str,
+) -> Result<String, ExecProgramError> {
+    if !args.path_selector.is_empty() {
+        return Err("the path selector is not supported by the WASM module"
+            .to_string()
+            .into());
+    }
+    if args.include_schema_type_path {
+        return Err("the schema type path is not supported by the WASM module"
+            .to_string()
+            .into());
+    }
+    let mut program = load_program_with_args(args)?;
+    let scope = resolve_program_with_diagnostics(&mut program)?;
+    Ok(build_wasm_with_scope(program, scope, output))
+}
+
+/// `build_wasm` resolves the ast program and generates the `wasm32-wasi` libs
+/// as method "execute" does, then links all the libs and the KCLVM runtime static
+/// library of the `wasm32-wasi` target into the WASM module at "output" instead
+/// of running them.
+///
+/// It returns the absolute path of the WASM module.
+pub fn build_wasm(mut program: Program, output: &str) -> String {
+    // Resolve ast
+    let scope = resolve_program(&mut program);
+    scope.check_scope_diagnostics();
+    build_wasm_with_scope(program, scope, output)
+}
+
+/// Generate the `wasm32-wasi` libs of the resolved program and link them into
+/// the WASM module at "output".
+fn build_wasm_with_scope(program: Program, scope: ProgramScope, output: &str) -> String {
+    // Create a temp entry file and the temp dir will be delete automatically
+    let temp_dir = tempdir().unwrap();
+    let temp_dir_path = temp_dir.path().to_str().unwrap();
+    let temp_entry_file = temp_file(temp_dir_path);
+
+    // Generate libs
+    let lib_paths = assembler::KclvmAssembler::new(
+        program,
+        scope,
+        temp_entry_file,
+        vec![],
+        KclvmLibAssembler::WASM,
+    )
+    .gen_libs();
+
+    // Link libs into the WASM module
+    linker::KclvmLinker::link_all_libs_to_wasm(lib_paths, output.to_string())
+}
+
+/// Load the KCL program from the files and the code in "args", and apply the overrides on it.
+/// All the syntax errors of the program files are returned at once.
+fn load_program_with_args(args: &ExecProgramArgs) -> Result<Program, ExecProgramError> {
     // parse args from json string
     let opts = args.get_load_program_options();
     let k_files = &args.k_filename_list;
@@ -79,12 +223,12 @@ pub fn exec_program(
         if file.starts_with(".") {
             match Path::new(&work_dir).join(file).canonicalize() {
                 Ok(path) => kcl_paths.push(String::from(path.to_str().unwrap())),
-                Err(err) => {
-                    return Err(str_to_panic_info(&format!(
+                Err(_) => {
+                    return Err(ExecProgramError::new(vec![str_to_panic_info(&format!(
                         "Cannot find the kcl file, please check whether the file path {}",
                         file
                     ))
-                    .to_json_string())
+                    .into()]))
                 }
             }
         } else {
@@ -94,41 +238,90 @@ pub fn exec_program(
 
     let kcl_paths_str = kcl_paths.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
 
-    let mut program = load_program(kcl_paths_str.as_slice(), Some(opts))?;
+    let (mut program, diagnostics) =
+        load_program_with_errors(kcl_paths_str.as_slice(), Some(opts))?;
+    if !diagnostics.is_empty() {
+        return Err(ExecProgramError::new(diagnostics));
+    }
 
     if let Err(err) = apply_overrides(&mut program, &args.overrides, &[], args.print_override_ast) {
-        return Err(err.to_string());
+        return Err(err.to_string().into());
     }
+    Ok(program)
+}
 
-    let start_time = SystemTime::now();
-    let exec_result = execute(program, plugin_agent, args);
-    let escape_time = match SystemTime::now().duration_since(start_time) {
-        Ok(dur) => dur.as_secs_f32(),
-        Err(err) => return Err(err.to_string()),
-    };
-    let mut result = ExecProgramResult::default();
-    result.escaped_time = escape_time.to_string();
-    // Exec result is a JSON or YAML string.
-    let exec_result = match exec_result {
-        Ok(res) => res,
-        Err(res) => {
-            if res.is_empty() {
-                return Ok(result);
-            } else {
-                return Err(res);
+/// Parse the path selectors in "args" and check that the packages of the selectors
+/// are in the program, and the selectors of the packages other than the main package
+/// start with a variable name.
+fn parse_path_selector(
+    program: &Program,
+    path_selector: &[String],
+) -> Result<Vec<PathSelector>, ExecProgramError> {
+    let mut selectors = vec![];
+    for selector in path_selector {
+        let selector: PathSelector = selector.parse()?;
+        if !selector.is_main_pkg() {
+            if !program.pkgs.contains_key(&selector.pkgpath) {
+                return Err(format!(
+                    "Cannot find the package '{}' of the path selector '{}' in the program",
+                    selector.pkgpath, selector
+                )
+                .into());
+            }
+            if !matches!(selector.path.first(), Some(PathSegment::Key(_))) {
+                return Err(format!(
+                    "The path selector '{}' of a package must start with a variable name",
+                    selector
+                )
+                .into());
             }
         }
-    };
-    let kcl_val = match ValueRef::from_yaml_stream(&exec_result) {
-        Ok(v) => v,
-        Err(err) => return Err(err.to_string()),
-    };
-    let (json_result, yaml_result) = kcl_val.plan();
-    result.json_result = json_result;
-    if !args.disable_yaml_result {
-        result.yaml_result = yaml_result;
+        selectors.push(selector);
+    }
+    Ok(selectors)
+}
+
+/// Return the variables of the packages other than the main package selected by the
+/// path selectors in the form of `(pkgpath, name)`, and check that the variables are
+/// defined in the packages.
+fn selected_pkg_vars(
+    scope: &ProgramScope,
+    path_selector: &[PathSelector],
+) -> Result<Vec<(String, String)>, ExecProgramError> {
+    let mut pkg_vars = IndexSet::new();
+    for selector in path_selector.iter().filter(|s| !s.is_main_pkg()) {
+        if let Some(PathSegment::Key(var)) = selector.path.first() {
+            let obj = scope
+                .scope_map
+                .get(&selector.pkgpath)
+                .and_then(|scope| scope.borrow().elems.get(var).cloned());
+            let is_var = matches!(obj, Some(obj) if obj.borrow().kind == ScopeObjectKind::Variable);
+            if !is_var {
+                return Err(format!(
+                    "Cannot find the variable '{}' of the path selector '{}' in the package '{}'",
+                    var, selector, selector.pkgpath
+                )
+                .into());
+            }
+            pkg_vars.insert((selector.pkgpath.clone(), var.clone()));
+        }
+    }
+    Ok(pkg_vars.into_iter().collect())
+}
+
+/// Resolve the program and return all the errors and warnings found by the resolver
+/// when there are any errors.
+fn resolve_program_with_diagnostics(
+    program: &mut Program,
+) -> Result<ProgramScope, ExecProgramError> {
+    let scope = resolve_program(program);
+    if scope.diagnostics.iter().any(|diag| diag.is_error()) {
+        Err(ExecProgramError::new(
+            scope.diagnostics.iter().cloned().collect(),
+        ))
+    } else {
+        Ok(scope)
     }
-    Ok(result)
 }
 
 /// After the kcl program passed through kclvm-parser in the compiler frontend,
@@ -157,6 +350,9 @@ pub fn exec_program(
 ///
 /// At last, KclvmRunner will be constructed and call method "run" to execute the kcl program.
 ///
+/// When "args.fast_eval" is set, the resolved program is executed by FastRunner with the AST
+/// evaluator directly, and no LLVM IR or dynamic link library is generated.
+///
 /// # Examples
 ///
 /// ```
@@ -185,6 +381,26 @@ pub fn execute(
     // Resolve ast
     let scope = resolve_program(&mut program);
     scope.check_scope_diagnostics();
+    execute_with_scope(program, scope, vec![], plugin_agent, args)
+}
+
+/// Execute the resolved program with its scope, see method "execute". The variables
+/// of other packages in "pkg_vars" are returned together with the global variables
+/// of the main package keyed by [kclvm_runtime::pkg_var_key].
+fn execute_with_scope(
+    program: Program,
+    scope: ProgramScope,
+    pkg_vars: Vec<(String, String)>,
+    plugin_agent: u64,
+    args: &ExecProgramArgs,
+) -> Result<String, String> {
+    // Run with the AST evaluator
+    if args.fast_eval {
+        let runner = FastRunner::new(Some(KclvmRunnerOptions {
+            plugin_agent_ptr: plugin_agent,
+        }));
+        return runner.run(&program, scope.import_names, pkg_vars, args);
+    }
 
     // Create a temp entry file and the temp dir will be delete automatically
     let temp_dir = tempdir().unwrap();
@@ -196,6 +412,7 @@ pub fn execute(
         program,
         scope,
         temp_entry_file.clone(),
+        pkg_vars,
         KclvmLibAssembler::LLVM,
     )
     .gen_libs();
@@ -228,21 +445,46 @@ pub fn execute(
 /// `execute_module` constructs `Program` with default pkg name `MAIN_PKG`,
 /// and calls method `execute` with default `plugin_agent` and `ExecProgramArgs`.
 /// For more information, see doc above method `execute`.
-pub fn execute_module(mut m: Module) -> Result<String, String> {
+pub fn execute_module(m: Module) -> Result<String, String> {
+    execute(module_program(m), 0, &ExecProgramArgs::default())
+}
+
+/// `exec_module` executes the ast `Module` with the options and settings in `args`
+/// like method `execute_module`, and returns the diagnostics of all the phases
+/// like method `exec_program` when it fails.
+///
+/// With `args.collect_check_failures`, all the failed schema checks are returned
+/// as diagnostics instead of the first one.
+pub fn exec_module(m: Module, args: &ExecProgramArgs) -> Result<String, ExecProgramError> {
+    let mut program = module_program(m);
+    let scope = resolve_program_with_diagnostics(&mut program)?;
+    let warnings = scope.diagnostics.iter().cloned().collect::<Vec<_>>();
+    match execute_with_scope(program, scope, vec![], 0, args) {
+        Ok(res) => Ok(res),
+        Err(res) if res.is_empty() => Ok(res),
+        Err(res) => {
+            let mut err = ExecProgramError::new(warnings);
+            err.diagnostics
+                .extend(ExecProgramError::from(res).diagnostics);
+            Err(err)
+        }
+    }
+}
+
+/// Constructs the `Program` of the ast `Module` with default pkg name `MAIN_PKG`.
+fn module_program(mut m: Module) -> Program {
     m.pkg = MAIN_PKG.to_string();
 
     let mut pkgs = HashMap::new();
     pkgs.insert(MAIN_PKG.to_string(), vec![m]);
 
-    let prog = Program {
+    Program {
         root: MAIN_PKG.to_string(),
         main: MAIN_PKG.to_string(),
         pkgs,
         cmd_args: vec![],
         cmd_overrides: vec![],
-    };
-
-    execute(prog, 0, &ExecProgramArgs::default())
+    }
 }
 
 /// Clean all the tmp files generated during lib generating and linking.
@@ -16,6 +16,13 @@ impl KclvmLinker {
         // not allow external mounting of the implementation.
         cmd.link_libs_with_cc(&lib_paths, &lib_path)
     }
+
+    /// Link the `wasm32-wasi` object files generated by KclvmAssembler and the
+    /// KCLVM runtime into a WASM module, and return the module path.
+    pub fn link_all_libs_to_wasm(lib_paths: Vec<String>, wasm_path: String) -> String {
+        let mut cmd = Command::new();
+        cmd.link_libs_to_wasm_with_cc(&lib_paths, &wasm_path)
+    }
 }
 
 #[allow(dead_code)]
@@ -1,6 +1,9 @@
+use indexmap::IndexMap;
 use kclvm_ast::ast;
 use kclvm_config::settings::SettingsFile;
-use kclvm_runtime::ValueRef;
+use kclvm_error::{Diagnostic, Handler, Level, Position};
+use kclvm_evaluator::Evaluator;
+use kclvm_runtime::{mut_ptr_as_ref, PanicInfo, ValueRef};
 use serde::{Deserialize, Serialize};
 
 #[allow(non_camel_case_types)]
@@ -20,6 +23,9 @@ pub struct ExecProgramArgs {
 
     pub args: Vec<ast::CmdArgSpec>,
     pub overrides: Vec<ast::OverrideSpec>,
+    // -S --path-selector: only output the values selected by the paths
+    #[serde(default)]
+    pub path_selector: Vec<String>,
 
     pub disable_yaml_result: bool,
     pub print_override_ast: bool,
@@ -39,16 +45,106 @@ pub struct ExecProgramArgs {
     pub sort_keys: bool,
     // include schema type path in JSON/YAML result
     pub include_schema_type_path: bool,
+
+    // -L --list-options: list the option help of the program instead of the result
+    #[serde(default)]
+    pub list_option_mode: bool,
+
+    // run the program with the AST evaluator instead of the LLVM backend
+    #[serde(default)]
+    pub fast_eval: bool,
+
+    // report all the failed schema checks instead of the first one
+    #[serde(default)]
+    pub collect_check_failures: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct ExecProgramResult {
     pub json_result: String,
     pub yaml_result: String,
+    /// The warnings found by the resolver when the program runs successfully.
+    #[serde(default)]
+    pub warnings: Vec<Diagnostic>,
 
     pub escaped_time: String,
 }
 
+/// ExecProgramError holds the diagnostics of all the phases when a KCL program
+/// fails to execute, including the syntax errors, the type errors and warnings
+/// found by the resolver and the runtime error.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExecProgramError {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ExecProgramError {
+    pub fn new(diagnostics: Vec<Diagnostic>) -> Self {
+        Self { diagnostics }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::ser::to_string(self).unwrap()
+    }
+
+    /// Emit all the diagnostics to stderr and abort if there are any errors.
+    pub fn abort_if_any_errors(self) {
+        let mut handler = Handler::default();
+        handler.diagnostics = self.diagnostics.into_iter().collect();
+        handler.abort_if_any_errors();
+    }
+
+    /// Return the legacy [PanicInfo] json string of the first error.
+    pub fn to_panic_info_json(&self) -> String {
+        let diag = self
+            .diagnostics
+            .iter()
+            .find(|diag| diag.is_error())
+            .or_else(|| self.diagnostics.first());
+        match diag {
+            Some(diag) => PanicInfo::from(diag).to_json_string(),
+            None => PanicInfo {
+                __kcl_PanicInfo__: true,
+                ..Default::default()
+            }
+            .to_json_string(),
+        }
+    }
+}
+
+/// Convert an error message into [ExecProgramError]. The message is parsed as a
+/// [PanicInfo] json string when possible, otherwise it is used as the error
+/// message directly. Each collected check failure becomes a diagnostic.
+impl From<String> for ExecProgramError {
+    fn from(msg: String) -> Self {
+        let diagnostics = match serde_json::from_str::<PanicInfo>(&msg) {
+            Ok(panic_info) if panic_info.__kcl_PanicInfo__ => {
+                if panic_info.check_failures.is_empty() {
+                    vec![panic_info.into()]
+                } else {
+                    panic_info
+                        .check_failures
+                        .into_iter()
+                        .map(|failure| failure.into())
+                        .collect()
+                }
+            }
+            _ => vec![Diagnostic::new(Level::Error, &msg, Position::dummy_pos())],
+        };
+        Self::new(diagnostics)
+    }
+}
+
+impl std::fmt::Display for ExecProgramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut handler = Handler::default();
+        handler.diagnostics = self.diagnostics.iter().cloned().collect();
+        write!(f, "{}", handler.format_diagnostic().join("\n"))
+    }
+}
+
+impl std::error::Error for ExecProgramError {}
+
 impl ExecProgramArgs {
     pub fn from_str(s: &str) -> Self {
         if s.trim().is_empty() {
@@ -87,6 +183,7 @@ impl From<SettingsFile> for ExecProgramArgs {
             args.disable_none = cli_configs.disable_none.unwrap_or_default();
             args.verbose = cli_configs.verbose.unwrap_or_default() as i32;
             args.debug = cli_configs.debug.unwrap_or_default() as i32;
+            args.path_selector = cli_configs.path_selector.unwrap_or_default();
         }
         if let Some(options) = settings.kcl_options {
             args.args = options
@@ -170,74 +267,134 @@ impl KclvmRunner {
         lib: &libloading::Library,
         args: &ExecProgramArgs,
     ) -> Result<String, String> {
-        let kcl_run: libloading::Symbol<
-            unsafe extern "C" fn(
-                kclvm_main_ptr: u64, // main.k => kclvm_main
-                option_len: kclvm_size_t,
-                option_keys: *const *const kclvm_char_t,
-                option_values: *const *const kclvm_char_t,
-                strict_range_check: i32,
-                disable_none: i32,
-                disable_schema_check: i32,
-                list_option_mode: i32,
-                debug_mode: i32,
-                result_buffer_len: kclvm_size_t,
-                result_buffer: *mut kclvm_char_t,
-                warn_buffer_len: kclvm_size_t,
-                warn_buffer: *mut kclvm_char_t,
-            ) -> kclvm_size_t,
-        > = lib.get(b"_kcl_run").unwrap();
+        let kcl_run: libloading::Symbol<KclRunFn> = lib.get(b"_kcl_run").unwrap();
 
         let kclvm_main: libloading::Symbol<u64> = lib.get(b"kclvm_main").unwrap();
         let kclvm_main_ptr = kclvm_main.into_raw().into_raw() as u64;
 
-        let option_len = args.args.len() as kclvm_size_t;
-
-        let cstr_argv: Vec<_> = args
-            .args
-            .iter()
-            .map(|arg| std::ffi::CString::new(arg.name.as_str()).unwrap())
-            .collect();
-
-        let mut p_argv: Vec<_> = cstr_argv
-            .iter() // do NOT into_iter()
-            .map(|arg| arg.as_ptr())
-            .collect();
-        p_argv.push(std::ptr::null());
-
-        let p: *const *const kclvm_char_t = p_argv.as_ptr();
-        let option_keys = p;
-
-        let cstr_argv: Vec<_> = args
-            .args
-            .iter()
-            .map(|arg| std::ffi::CString::new(arg.value.as_str()).unwrap())
-            .collect();
-
-        let mut p_argv: Vec<_> = cstr_argv
-            .iter() // do NOT into_iter()
-            .map(|arg| arg.as_ptr())
-            .collect();
-        p_argv.push(std::ptr::null());
+        kcl_run_with(KclMain::Lib(*kcl_run, kclvm_main_ptr), args)
+    }
+}
 
-        let p: *const *const kclvm_char_t = p_argv.as_ptr();
-        let option_values = p;
+/// The runtime entry `_kcl_run` which runs the program entry `kclvm_main_ptr`.
+type KclRunFn = unsafe extern "C" fn(
+    kclvm_main_ptr: u64, // main.k => kclvm_main
+    option_len: kclvm_size_t,
+    option_keys: *const *const kclvm_char_t,
+    option_values: *const *const kclvm_char_t,
+    strict_range_check: i32,
+    disable_none: i32,
+    disable_schema_check: i32,
+    list_option_mode: i32,
+    debug_mode: i32,
+    result_buffer_len: kclvm_size_t,
+    result_buffer: *mut kclvm_char_t,
+    warn_buffer_len: kclvm_size_t,
+    warn_buffer: *mut kclvm_char_t,
+    include_schema_type_path: i32,
+    collect_check_failures: i32,
+) -> kclvm_size_t;
+
+/// FastRunner runs the program with the AST evaluator in `kclvm-evaluator`,
+/// which needs no LLVM code generation and linking.
+pub struct FastRunner {
+    opts: KclvmRunnerOptions,
+}
 
-        let strict_range_check = args.strict_range_check as i32;
-        let disable_none = args.disable_none as i32;
-        let disable_schema_check = 0; // todo
-        let list_option_mode = 0; // todo
-        let debug_mode = args.debug as i32;
+impl FastRunner {
+    pub fn new(opts: Option<KclvmRunnerOptions>) -> Self {
+        Self {
+            opts: opts.unwrap_or_default(),
+        }
+    }
 
-        let mut result = vec![0u8; 1024 * 1024];
-        let result_buffer_len = result.len() as i32 - 1;
-        let result_buffer = result.as_mut_ptr() as *mut i8;
+    /// Run the program and return the YAML result, the variables of other packages in
+    /// "pkg_vars" are returned together with the global variables of the main package.
+    pub fn run(
+        &self,
+        program: &ast::Program,
+        import_names: IndexMap<String, IndexMap<String, String>>,
+        pkg_vars: Vec<(String, String)>,
+        args: &ExecProgramArgs,
+    ) -> Result<String, String> {
+        unsafe {
+            if self.opts.plugin_agent_ptr != 0 {
+                let plugin_method: extern "C" fn(
+                    method: *const i8,
+                    args: *const i8,
+                    kwargs: *const i8,
+                ) -> *const i8 = std::mem::transmute(self.opts.plugin_agent_ptr);
+                kclvm_runtime::kclvm_plugin_init(plugin_method);
+            }
+            let mut evaluator = Evaluator::new(program, import_names);
+            evaluator.pkg_vars = pkg_vars;
+            kcl_run_with(KclMain::Evaluator(&evaluator), args)
+        }
+    }
+}
 
-        let mut warn_data = vec![0u8; 1024 * 1024];
-        let warn_buffer_len = warn_data.len() as i32 - 1;
-        let warn_buffer = warn_data.as_mut_ptr() as *mut i8;
+/// The main function of the program run by `kcl_run_with`.
+enum KclMain<'a> {
+    /// The runtime entry `_kcl_run` and the program entry `kclvm_main` in a
+    /// compiled library.
+    Lib(KclRunFn, u64),
+    /// The AST evaluator run in the runtime linked into the runner.
+    Evaluator(&'a Evaluator<'a>),
+}
 
-        let n = kcl_run(
+/// Run the program `main` with the options and settings in `args`, and return
+/// the YAML result or the error message.
+unsafe fn kcl_run_with(main: KclMain, args: &ExecProgramArgs) -> Result<String, String> {
+    let option_len = args.args.len() as kclvm_size_t;
+
+    let cstr_argv: Vec<_> = args
+        .args
+        .iter()
+        .map(|arg| std::ffi::CString::new(arg.name.as_str()).unwrap())
+        .collect();
+
+    let mut p_argv: Vec<_> = cstr_argv
+        .iter() // do NOT into_iter()
+        .map(|arg| arg.as_ptr())
+        .collect();
+    p_argv.push(std::ptr::null());
+
+    let p: *const *const kclvm_char_t = p_argv.as_ptr();
+    let option_keys = p;
+
+    let cstr_argv: Vec<_> = args
+        .args
+        .iter()
+        .map(|arg| std::ffi::CString::new(arg.value.as_str()).unwrap())
+        .collect();
+
+    let mut p_argv: Vec<_> = cstr_argv
+        .iter() // do NOT into_iter()
+        .map(|arg| arg.as_ptr())
+        .collect();
+    p_argv.push(std::ptr::null());
+
+    let p: *const *const kclvm_char_t = p_argv.as_ptr();
+    let option_values = p;
+
+    let strict_range_check = args.strict_range_check as i32;
+    let disable_none = args.disable_none as i32;
+    let disable_schema_check = 0; // todo
+    let list_option_mode = args.list_option_mode as i32;
+    let debug_mode = args.debug as i32;
+    let include_schema_type_path = args.include_schema_type_path as i32;
+    let collect_check_failures = args.collect_check_failures as i32;
+
+    let mut result = vec![0u8; 1024 * 1024];
+    let result_buffer_len = result.len() as i32 - 1;
+    let result_buffer = result.as_mut_ptr() as *mut i8;
+
+    let mut warn_data = vec![0u8; 1024 * 1024];
+    let warn_buffer_len = warn_data.len() as i32 - 1;
+    let warn_buffer = warn_data.as_mut_ptr() as *mut i8;
+
+    let n = match main {
+        KclMain::Lib(kcl_run, kclvm_main_ptr) => kcl_run(
             kclvm_main_ptr,
             option_len,
             option_keys,
@@ -251,17 +408,40 @@ impl KclvmRunner {
             result_buffer,
             warn_buffer_len,
             warn_buffer,
-        );
-
-        if n > 0 {
-            let return_len = n;
-            let s = std::str::from_utf8(&result[0..return_len as usize]).unwrap();
-            wrap_msg_in_result(s)
-        } else {
-            let return_len = 0 - n;
-            let s = std::str::from_utf8(&warn_data[0..return_len as usize]).unwrap();
-            Err(s.to_string())
+            include_schema_type_path,
+            collect_check_failures,
+        ),
+        KclMain::Evaluator(evaluator) => kclvm_runtime::kcl_run_with_main(
+            |ctx| evaluator.main(mut_ptr_as_ref(ctx)).into_raw(),
+            option_len,
+            option_keys,
+            option_values,
+            strict_range_check,
+            disable_none,
+            disable_schema_check,
+            list_option_mode,
+            debug_mode,
+            result_buffer_len,
+            result_buffer,
+            warn_buffer_len,
+            warn_buffer,
+            include_schema_type_path,
+            collect_check_failures,
+        ),
+    };
+
+    if n > 0 {
+        let return_len = n;
+        let s = std::str::from_utf8(&result[0..return_len as usize]).unwrap();
+        if args.list_option_mode {
+            // The result is the option list help message in the list option mode.
+            return Ok(s.to_string());
         }
+        wrap_msg_in_result(s)
+    } else {
+        let return_len = 0 - n;
+        let s = std::str::from_utf8(&warn_data[0..return_len as usize]).unwrap();
+        Err(s.to_string())
     }
 }
 
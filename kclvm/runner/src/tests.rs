@@ -2,6 +2,8 @@ use crate::assembler::clean_path;
 use crate::assembler::KclvmAssembler;
 use crate::assembler::KclvmLibAssembler;
 use crate::assembler::LibAssembler;
+use crate::build_wasm_program;
+use crate::command::Command;
 use crate::exec_program;
 use crate::temp_file;
 use crate::{execute, runner::ExecProgramArgs};
@@ -11,7 +13,6 @@ use kclvm_ast::ast::{Module, Program};
 use kclvm_compiler::codegen::llvm::OBJECT_FILE_SUFFIX;
 use kclvm_config::settings::load_file;
 use kclvm_parser::load_program;
-use kclvm_runtime::PanicInfo;
 use kclvm_sema::resolver::resolve_program;
 use std::fs::create_dir_all;
 use std::panic::catch_unwind;
@@ -152,6 +153,7 @@ fn gen_assembler(entry_file: &str, test_kcl_case_path: &str) -> KclvmAssembler {
         prog.clone(),
         scope,
         entry_file.to_string(),
+        vec![],
         KclvmLibAssembler::LLVM,
     )
 }
@@ -206,6 +208,7 @@ fn assemble_lib_for_test(
     assembler.assemble(
         &program,
         scope.import_names,
+        &[],
         entry_file,
         temp_entry_file_path,
     )
@@ -301,7 +304,8 @@ fn test_clean_path_for_genlibs() {
     let mut prog =
         parse_program("./src/test_datas/multi_file_compilation/import_abs_path/app-main/main.k");
     let scope = resolve_program(&mut prog);
-    let assembler = KclvmAssembler::new(prog, scope, String::new(), KclvmLibAssembler::LLVM);
+    let assembler =
+        KclvmAssembler::new(prog, scope, String::new(), vec![], KclvmLibAssembler::LLVM);
 
     let temp_dir = tempdir().unwrap();
     let temp_dir_path = temp_dir.path().to_str().unwrap();
@@ -381,6 +385,31 @@ fn test_exec_file() {
     std::panic::set_hook(prev_hook);
 }
 
+/// Compare the exec results of the kcl files in the exec data with their
+/// `<name>.stdout.golden` files if any.
+fn test_exec_file_with_golden() {
+    for file in get_files(EXEC_DATA_PATH, false, true, ".k") {
+        let golden = Path::new(&file).with_extension("stdout.golden");
+        if !golden.exists() {
+            continue;
+        }
+        let expected = fs::read_to_string(&golden).unwrap();
+        for fast_eval in [false, true] {
+            let mut args = ExecProgramArgs::default();
+            args.k_filename_list.push(file.to_string());
+            args.fast_eval = fast_eval;
+            let result = exec_program(&args, 0).unwrap();
+            assert_eq!(result.yaml_result, expected.trim_end(), "{}", file);
+        }
+    }
+}
+
+fn test_exec_file_with_fast_eval() {
+    for file in get_files(EXEC_DATA_PATH, false, true, ".k") {
+        assert_eq!(exec_with_fast_eval(&file), exec(&file), "{}", file);
+    }
+}
+
 fn test_custom_manifests_output() {
     exec_with_result_at(CUSTOM_MANIFESTS_DATA_PATH);
 }
@@ -392,12 +421,183 @@ fn test_exec_with_err_result() {
 #[test]
 fn test_exec() {
     test_exec_file();
+    test_exec_file_with_golden();
+    test_exec_file_with_fast_eval();
     test_kclvm_runner_execute();
     test_kclvm_runner_execute_timeout();
     test_custom_manifests_output();
     test_exec_with_err_result()
 }
 
+#[test]
+fn test_exec_with_multi_errors() {
+    for (file, lines) in [("syntax_errors.k", [1, 3]), ("type_errors.k", [1, 2])] {
+        let mut args = ExecProgramArgs::default();
+        args.k_filename_list.push(
+            Path::new(TEST_CASE_PATH)
+                .join("multi_errors")
+                .join(file)
+                .display()
+                .to_string(),
+        );
+        let err = exec_program(&args, 0).unwrap_err();
+        assert_eq!(
+            err.diagnostics
+                .iter()
+                .map(|diag| diag.messages[0].pos.line)
+                .collect::<Vec<u64>>(),
+            lines,
+            "{}",
+            file
+        );
+        assert!(err.diagnostics.iter().all(|diag| diag.is_error()));
+    }
+}
+
+#[test]
+fn test_exec_with_check_failures() {
+    for fast_eval in [false, true] {
+        let mut args = ExecProgramArgs::default();
+        args.k_filename_list.push(
+            Path::new(TEST_CASE_PATH)
+                .join("multi_errors")
+                .join("check_failures.k")
+                .display()
+                .to_string(),
+        );
+        args.fast_eval = fast_eval;
+        args.collect_check_failures = true;
+        let err = exec_program(&args, 0).unwrap_err();
+        assert_eq!(
+            err.diagnostics
+                .iter()
+                .map(|diag| (diag.messages[0].pos.line, diag.messages[1].pos.line))
+                .collect::<Vec<(u64, u64)>>(),
+            [(6, 9), (7, 9), (7, 13)]
+        );
+    }
+}
+
+#[test]
+fn test_exec_with_path_selector() {
+    let cases: &[(&[&str], &str)] = &[
+        (
+            &["appConfiguration.frontend"],
+            "image: nginx\nports:\n  - 80\n  - 443\nreplicas: 1",
+        ),
+        (&["appConfiguration.*.image"], "nginx\n---\nredis"),
+        (&["appConfiguration.backend.ports[0]"], "6379"),
+        (&["app:defaults.labels"], "tier: default"),
+        (
+            &[
+                "appConfiguration.frontend.replicas",
+                "app:defaults.replicas",
+            ],
+            "1\n---\n1",
+        ),
+        (&["defaults.replicas", "app:defaults.replicas"], "3\n---\n1"),
+        (&["app:_secret"], "app-secret"),
+    ];
+    for fast_eval in [false, true] {
+        for (path_selector, expected) in cases {
+            let mut args = ExecProgramArgs::default();
+            args.k_filename_list.push(
+                Path::new(TEST_CASE_PATH)
+                    .join("path_selector")
+                    .join("main.k")
+                    .display()
+                    .to_string(),
+            );
+            args.path_selector = path_selector.iter().map(|s| s.to_string()).collect();
+            args.fast_eval = fast_eval;
+            let result = exec_program(&args, 0).unwrap();
+            assert_eq!(result.yaml_result, *expected, "{:?}", path_selector);
+        }
+    }
+    let mut args = ExecProgramArgs::default();
+    args.k_filename_list.push(
+        Path::new(TEST_CASE_PATH)
+            .join("path_selector")
+            .join("main.k")
+            .display()
+            .to_string(),
+    );
+    for path_selector in ["appConfiguration.database", "app:database"] {
+        args.path_selector = vec![path_selector.to_string()];
+        assert!(exec_program(&args, 0).is_err(), "{}", path_selector);
+    }
+}
+
+#[test]
+fn test_exec_with_schema_type_path() {
+    let case_path = Path::new(TEST_CASE_PATH).join("schema_type_path");
+    let expected = fs::read_to_string(case_path.join("stdout.golden")).unwrap();
+    for fast_eval in [false, true] {
+        let mut args = ExecProgramArgs::default();
+        args.k_filename_list
+            .push(case_path.join("main.k").display().to_string());
+        args.include_schema_type_path = true;
+        args.fast_eval = fast_eval;
+        let result = exec_program(&args, 0).unwrap();
+        assert_eq!(result.yaml_result, expected.trim_end());
+    }
+}
+
+/// Build all the kcl files in the exec data into `wasm32-wasi` modules, run them with
+/// `wasmtime` and compare the outputs with the native exec results. It needs `wasmtime`
+/// and the `wasm32-wasi` KCLVM runtime static library, run it with `--ignored`.
+#[test]
+#[ignore = "requires wasmtime and the wasm32-wasi KCLVM runtime static library"]
+fn test_exec_wasm() {
+    let wasmtime = Command::find_it("wasmtime").expect("wasmtime is not found in PATH");
+    let runtime_lib = Path::new(&Command::wasm_runtime_lib_dir()).join("libkclvm_runtime.a");
+    assert!(
+        runtime_lib.exists(),
+        "the wasm32-wasi KCLVM runtime static library {} is not found",
+        runtime_lib.display()
+    );
+    let temp_dir = tempdir().unwrap();
+    for file in get_files(EXEC_DATA_PATH, false, true, ".k") {
+        let mut args = ExecProgramArgs::default();
+        args.k_filename_list.push(file.to_string());
+        let expected = exec_program(&args, 0).unwrap().yaml_result;
+        let wasm_file = Path::new(&file).with_extension("wasm");
+        let wasm_file = temp_dir.path().join(wasm_file.file_name().unwrap());
+        let wasm_path = build_wasm_program(&args, wasm_file.to_str().unwrap()).unwrap();
+        let output = std::process::Command::new(&wasmtime)
+            .arg(&wasm_path)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            format!("{}\n", expected),
+            "{}",
+            file
+        );
+    }
+}
+
+#[test]
+fn test_build_wasm_with_unsupported_args() {
+    let mut args = ExecProgramArgs::default();
+    args.k_filename_list
+        .push("./src/test_datas/init_check_order_0/main.k".to_string());
+    args.path_selector.push("alice".to_string());
+    let err = build_wasm_program(&args, "main.wasm").unwrap_err();
+    assert!(err.to_string().contains("path selector"), "{}", err);
+
+    args.path_selector.clear();
+    args.include_schema_type_path = true;
+    let err = build_wasm_program(&args, "main.wasm").unwrap_err();
+    assert!(err.to_string().contains("schema type path"), "{}", err);
+}
+
 fn exec(file: &str) -> Result<String, String> {
     let mut args = ExecProgramArgs::default();
     args.k_filename_list.push(file.to_string());
@@ -409,6 +609,17 @@ fn exec(file: &str) -> Result<String, String> {
     execute(program, plugin_agent, &args)
 }
 
+fn exec_with_fast_eval(file: &str) -> Result<String, String> {
+    let mut args = ExecProgramArgs::default();
+    args.k_filename_list.push(file.to_string());
+    args.fast_eval = true;
+    let opts = args.get_load_program_options();
+    // Load AST program
+    let program = load_program(&[file], Some(opts)).unwrap();
+    // Resolve AST and execute it with the AST evaluator.
+    execute(program, 0, &args)
+}
+
 /// Run all kcl files at path and compare the exec result with the expect output.
 fn exec_with_result_at(path: &str) {
     let kcl_files = get_files(path, false, true, ".k");
@@ -438,13 +649,15 @@ fn exec_with_err_result_at(path: &str) {
         for (kcl_file, output_json_file) in kcl_files.iter().zip(&output_files) {
             let mut args = ExecProgramArgs::default();
             args.k_filename_list.push(kcl_file.to_string());
-            let panic_info = PanicInfo::from_json_string(&exec_program(&args, 0).unwrap_err());
+            let err = exec_program(&args, 0).unwrap_err();
             let expect_info: SimplePanicInfo =
                 serde_json::from_str(std::fs::read_to_string(output_json_file).unwrap().as_str())
                     .unwrap();
-            assert_eq!(panic_info.kcl_line, expect_info.line);
-            assert_eq!(panic_info.kcl_col, expect_info.col);
-            assert_eq!(panic_info.message, expect_info.message);
+            assert_eq!(err.diagnostics.len(), 1);
+            let message = &err.diagnostics[0].messages[0];
+            assert_eq!(message.pos.line, expect_info.line as u64);
+            assert_eq!(message.pos.column, Some(expect_info.col as u64));
+            assert_eq!(message.message, expect_info.message);
         }
     });
     assert!(result.is_ok());
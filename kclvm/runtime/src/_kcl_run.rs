@@ -37,6 +37,9 @@ pub type kclvm_float_t = f64;
 
 // const SHOULD_PROFILE: bool = false;
 
+/// The runtime entry which runs the program entry `kclvm_main_ptr` in a new runtime
+/// context. The arguments added after the first release are appended at the end,
+/// and the C declaration in `_kclvm.h` must be kept the same as this function.
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn _kcl_run(
@@ -53,19 +56,193 @@ pub extern "C" fn _kcl_run(
     result_buffer: *mut kclvm_char_t,
     warn_buffer_len: kclvm_size_t,
     warn_buffer: *mut kclvm_char_t,
+    include_schema_type_path: i32,
+    collect_check_failures: i32,
 ) -> kclvm_size_t {
-    let ctx = kclvm_context_new();
+    let kclvm_main = (&kclvm_main_ptr as *const u64) as *const ()
+        as *const extern "C" fn(ctx: *mut kclvm_context_t) -> *mut kclvm_value_ref_t;
+    let kclvm_main = if kclvm_main.is_null() {
+        None
+    } else {
+        Some(|ctx: *mut kclvm_context_t| unsafe { (*kclvm_main)(ctx) })
+    };
+    _kcl_run_with_main(
+        kclvm_main,
+        option_len,
+        option_keys,
+        option_values,
+        strict_range_check,
+        disable_none,
+        disable_schema_check,
+        list_option_mode,
+        debug_mode,
+        result_buffer_len,
+        result_buffer,
+        warn_buffer_len,
+        warn_buffer,
+        include_schema_type_path,
+        collect_check_failures,
+    )
+}
 
-    let prev_hook = std::panic::take_hook();
-    std::panic::set_hook(Box::new(|info: &std::panic::PanicInfo| {
-        let ctx = Context::current_context_mut();
-        ctx.set_panic_info(info);
-        let _ = ctx;
-    }));
+/// Run the main function `kclvm_main` in a new runtime context like `_kcl_run`,
+/// but the main function is a Rust closure, which makes non-native backends
+/// e.g., the AST evaluator able to pass their states to the main function.
+#[allow(clippy::too_many_arguments)]
+pub fn kcl_run_with_main<F>(
+    kclvm_main: F,
+    option_len: kclvm_size_t,
+    option_keys: *const *const kclvm_char_t,
+    option_values: *const *const kclvm_char_t,
+    strict_range_check: i32,
+    disable_none: i32,
+    disable_schema_check: i32,
+    list_option_mode: i32,
+    debug_mode: i32,
+    result_buffer_len: kclvm_size_t,
+    result_buffer: *mut kclvm_char_t,
+    warn_buffer_len: kclvm_size_t,
+    warn_buffer: *mut kclvm_char_t,
+    include_schema_type_path: i32,
+    collect_check_failures: i32,
+) -> kclvm_size_t
+where
+    F: FnOnce(*mut kclvm_context_t) -> *mut kclvm_value_ref_t,
+{
+    _kcl_run_with_main(
+        Some(kclvm_main),
+        option_len,
+        option_keys,
+        option_values,
+        strict_range_check,
+        disable_none,
+        disable_schema_check,
+        list_option_mode,
+        debug_mode,
+        result_buffer_len,
+        result_buffer,
+        warn_buffer_len,
+        warn_buffer,
+        include_schema_type_path,
+        collect_check_failures,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn _kcl_run_with_main<F>(
+    kclvm_main: Option<F>,
+    option_len: kclvm_size_t,
+    option_keys: *const *const kclvm_char_t,
+    option_values: *const *const kclvm_char_t,
+    strict_range_check: i32,
+    disable_none: i32,
+    disable_schema_check: i32,
+    list_option_mode: i32,
+    debug_mode: i32,
+    result_buffer_len: kclvm_size_t,
+    result_buffer: *mut kclvm_char_t,
+    warn_buffer_len: kclvm_size_t,
+    warn_buffer: *mut kclvm_char_t,
+    include_schema_type_path: i32,
+    collect_check_failures: i32,
+) -> kclvm_size_t
+where
+    F: FnOnce(*mut kclvm_context_t) -> *mut kclvm_value_ref_t,
+{
+    let (result, warn) = _kcl_run_to_string(
+        kclvm_main,
+        option_len,
+        option_keys,
+        option_values,
+        strict_range_check,
+        disable_none,
+        disable_schema_check,
+        list_option_mode,
+        debug_mode,
+        include_schema_type_path,
+        collect_check_failures,
+    );
+
+    let c_str_ptr = result.as_ptr() as *const i8;
+    let mut return_len = result.len() as i32;
+
+    unsafe {
+        if return_len <= result_buffer_len {
+            std::ptr::copy(c_str_ptr, result_buffer, return_len as usize);
+        } else {
+            *result_buffer = '\0' as kclvm_char_t;
+            return_len = 0 - return_len;
+        }
+
+        if let Some(warn) = warn {
+            let c_str_ptr = warn.as_ptr() as *const i8;
+            let c_str_len = warn.len() as i32;
+            if c_str_len <= warn_buffer_len {
+                std::ptr::copy(c_str_ptr, warn_buffer, c_str_len as usize);
+            }
+        }
+    }
+
+    return_len
+}
+
+thread_local! {
+    /// The number of the programs running in `_kcl_run_to_string` on the current thread.
+    static RUNNING_DEPTH: std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
+static PANIC_HOOK: std::sync::Once = std::sync::Once::new();
+
+/// Install the panic hook of the runs once. The runs on different threads may overlap, so
+/// the global hook is not swapped by each run. The hook records the panic information in
+/// the context of the run on the panicking thread, and calls the previous hook for the
+/// panics outside the runs.
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let prev_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info: &std::panic::PanicInfo| {
+            if RUNNING_DEPTH.with(|depth| depth.get()) == 0 {
+                prev_hook(info);
+                return;
+            }
+            let ctx = Context::current_context_mut();
+            ctx.set_panic_info(info);
+            // Panics abort on the WASM target and can not be caught, so the
+            // panic information is written to the stderr here.
+            #[cfg(target_arch = "wasm32")]
+            eprintln!("{}", ctx.get_panic_info_json_string());
+        }));
+    });
+}
 
-    let result = std::panic::catch_unwind(|| {
+/// Run the main function `kclvm_main` in a new runtime context and return the
+/// result string and the warning string. The result string is the output of the
+/// main function, or the panic information JSON string when the program fails,
+/// and the warning string is the panic information JSON string when it does not.
+#[allow(clippy::too_many_arguments)]
+fn _kcl_run_to_string<F>(
+    kclvm_main: Option<F>,
+    option_len: kclvm_size_t,
+    option_keys: *const *const kclvm_char_t,
+    option_values: *const *const kclvm_char_t,
+    strict_range_check: i32,
+    disable_none: i32,
+    disable_schema_check: i32,
+    list_option_mode: i32,
+    debug_mode: i32,
+    include_schema_type_path: i32,
+    collect_check_failures: i32,
+) -> (String, Option<String>)
+where
+    F: FnOnce(*mut kclvm_context_t) -> *mut kclvm_value_ref_t,
+{
+    install_panic_hook();
+    let ctx = kclvm_context_new();
+
+    RUNNING_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         _kcl_run_in_closure(
-            kclvm_main_ptr,
+            kclvm_main,
             option_len,
             option_keys,
             option_values,
@@ -74,53 +251,36 @@ pub extern "C" fn _kcl_run(
             disable_schema_check,
             list_option_mode,
             debug_mode,
-            result_buffer_len,
-            result_buffer,
+            include_schema_type_path,
+            collect_check_failures,
         )
-    });
-    std::panic::set_hook(prev_hook);
-    match result {
-        Ok(n) => {
-            let json_panic_info = Context::current_context().get_panic_info_json_string();
-
-            let c_str_ptr = json_panic_info.as_ptr() as *const i8;
-            let c_str_len = json_panic_info.len() as i32;
-
-            unsafe {
-                if c_str_len <= warn_buffer_len {
-                    std::ptr::copy(c_str_ptr, warn_buffer, c_str_len as usize);
-                }
-            }
-
-            kclvm_context_delete(ctx);
-            n
-        }
-        Err(_) => {
-            let json_panic_info = Context::current_context().get_panic_info_json_string();
-
-            let c_str_ptr = json_panic_info.as_ptr() as *const i8;
-            let c_str_len = json_panic_info.len() as i32;
-
-            let mut return_len = c_str_len;
-
-            unsafe {
-                if return_len <= result_buffer_len {
-                    std::ptr::copy(c_str_ptr, result_buffer, return_len as usize);
-                } else {
-                    *result_buffer = '\0' as kclvm_char_t;
-                    return_len = 0 - return_len;
-                }
+    }));
+    RUNNING_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    let output = match result {
+        Ok(result) => {
+            // Return the collected check failures as the panic information in the result.
+            let check_failures = &Context::current_context().check_failures;
+            if !check_failures.is_empty() {
+                let mut panic_info = check_failures[0].clone();
+                panic_info.check_failures = check_failures.clone();
+                (panic_info.to_json_string(), None)
+            } else {
+                let json_panic_info = Context::current_context().get_panic_info_json_string();
+                (result, Some(json_panic_info))
             }
-
-            kclvm_context_delete(ctx);
-            return_len
         }
-    }
+        Err(_) => (
+            Context::current_context().get_panic_info_json_string(),
+            None,
+        ),
+    };
+    kclvm_context_delete(ctx);
+    output
 }
 
 #[allow(clippy::too_many_arguments)]
-fn _kcl_run_in_closure(
-    kclvm_main_ptr: u64, // main.k => kclvm_main
+fn _kcl_run_in_closure<F>(
+    kclvm_main: Option<F>,
     option_len: kclvm_size_t,
     option_keys: *const *const kclvm_char_t,
     option_values: *const *const kclvm_char_t,
@@ -129,19 +289,21 @@ fn _kcl_run_in_closure(
     disable_schema_check: i32,
     list_option_mode: i32,
     debug_mode: i32,
-    result_buffer_len: kclvm_size_t,
-    result_buffer: *mut kclvm_char_t,
-) -> kclvm_size_t {
+    include_schema_type_path: i32,
+    collect_check_failures: i32,
+) -> String
+where
+    F: FnOnce(*mut kclvm_context_t) -> *mut kclvm_value_ref_t,
+{
     let ctx = kclvm_context_current();
 
-    let kclvm_main = (&kclvm_main_ptr as *const u64) as *const ()
-        as *const extern "C" fn(ctx: *mut kclvm_context_t) -> *mut kclvm_value_ref_t;
-
     kclvm_context_set_strict_range_check(ctx, strict_range_check as kclvm_bool_t);
     kclvm_context_set_disable_none(ctx, disable_none as kclvm_bool_t);
     kclvm_context_set_disable_schema_check(ctx, disable_schema_check as kclvm_bool_t);
     kclvm_context_set_list_option_mode(ctx, list_option_mode as kclvm_bool_t);
     kclvm_context_set_debug_mode(ctx, debug_mode as kclvm_bool_t);
+    mut_ptr_as_ref(ctx).cfg.include_schema_type_path = include_schema_type_path != 0;
+    mut_ptr_as_ref(ctx).cfg.collect_check_failures = collect_check_failures != 0;
 
     unsafe {
         let option_keys = std::slice::from_raw_parts(option_keys, option_len as usize);
@@ -151,27 +313,94 @@ fn _kcl_run_in_closure(
             kclvm_builtin_option_init(ctx, option_keys[i], option_values[i]);
         }
 
-        let value = if kclvm_main.is_null() {
-            kclvm_value_Str(b"{}\0" as *const u8 as *const kclvm_char_t)
-        } else {
-            kclvm_context_main_begin_hook(ctx);
-            let x = (*kclvm_main)(ctx);
-            kclvm_context_main_end_hook(ctx, x)
-        };
-
-        let c_str_ptr = kclvm_value_Str_ptr(value);
-        let c_str_len = kclvm_value_len(value);
+        match kclvm_main {
+            Some(kclvm_main) => {
+                kclvm_context_main_begin_hook(ctx);
+                let x = kclvm_main(ctx);
+                ptr_as_ref(kclvm_context_main_end_hook(ctx, x)).as_str()
+            }
+            None => "{}".to_string(),
+        }
+    }
+}
 
-        let mut return_len = c_str_len;
+#[cfg(target_os = "wasi")]
+extern "C" {
+    /// The main function of the KCL program linked into the WASM module.
+    fn kclvm_main(ctx: *mut kclvm_context_t) -> *mut kclvm_value_ref_t;
+}
 
-        if return_len <= result_buffer_len {
-            std::ptr::copy(c_str_ptr, result_buffer, return_len as usize);
-        } else {
-            *result_buffer = '\0' as kclvm_char_t;
-            return_len = 0 - return_len;
+/// The entry of the `wasm32-wasi` module compiled from a KCL program, which runs
+/// `kclvm_main` with the `-D name=value` top-level arguments in the command line
+/// and prints the planned YAML result, or the JSON result with the `--json` flag.
+///
+/// The start function of the WASI libc calls it as the C `int main(void)` function.
+#[cfg(target_os = "wasi")]
+#[no_mangle]
+pub extern "C" fn __main_void() -> i32 {
+    let mut options = vec![];
+    let mut json = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "-D" => match args.next().as_ref().and_then(|arg| arg.split_once('=')) {
+                Some((name, value)) => options.push((name.to_string(), value.to_string())),
+                None => {
+                    eprintln!("Invalid top-level argument, expect -D name=value");
+                    return 1;
+                }
+            },
+            _ => {
+                eprintln!("Unknown argument '{}'", arg);
+                return 1;
+            }
         }
+    }
 
-        // Delete by context to ignore pointer double free.
-        return_len
+    let cstr_keys: Vec<_> = options
+        .iter()
+        .map(|(name, _)| std::ffi::CString::new(name.as_str()).unwrap())
+        .collect();
+    let cstr_values: Vec<_> = options
+        .iter()
+        .map(|(_, value)| std::ffi::CString::new(value.as_str()).unwrap())
+        .collect();
+    let mut option_keys: Vec<_> = cstr_keys.iter().map(|s| s.as_ptr()).collect();
+    option_keys.push(std::ptr::null());
+    let mut option_values: Vec<_> = cstr_values.iter().map(|s| s.as_ptr()).collect();
+    option_values.push(std::ptr::null());
+
+    // Get the result string directly instead of copying it into fixed size
+    // buffers, so large results are not truncated.
+    let (s, _) = _kcl_run_to_string(
+        Some(|ctx: *mut kclvm_context_t| unsafe { kclvm_main(ctx) }),
+        options.len() as kclvm_size_t,
+        option_keys.as_ptr(),
+        option_values.as_ptr(),
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    );
+    match ValueRef::from_yaml_stream(&s) {
+        Ok(value) => {
+            if let Some(panic_info) = value.get_by_key("__kcl_PanicInfo__") {
+                if panic_info.is_truthy() {
+                    eprintln!("{}", s);
+                    return 1;
+                }
+            }
+            let (json_result, yaml_result) = value.plan();
+            println!("{}", if json { json_result } else { yaml_result });
+            0
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
     }
 }
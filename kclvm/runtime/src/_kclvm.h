@@ -760,6 +760,10 @@ kclvm_value_ref_t* kclvm_yaml_dump_to_file(kclvm_context_t* _ctx, kclvm_value_re
 
 kclvm_value_ref_t* kclvm_yaml_encode(kclvm_context_t* _ctx, kclvm_value_ref_t* args, kclvm_value_ref_t* kwargs);
 
+// please keep same as 'kclvm/runtime/src/_kcl_run.rs#_kcl_run'
+
+kclvm_size_t _kcl_run(uint64_t kclvm_main_ptr, kclvm_size_t option_len, kclvm_char_t** option_keys, kclvm_char_t** option_values, int32_t strict_range_check, int32_t disable_none, int32_t disable_schema_check, int32_t list_option_mode, int32_t debug_mode, kclvm_size_t result_buffer_len, kclvm_char_t* result_buffer, kclvm_size_t warn_buffer_len, kclvm_char_t* warn_buffer, int32_t include_schema_type_path, int32_t collect_check_failures);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif
@@ -180,6 +180,7 @@ impl Hash for ValueRef {
             }
             Value::func_value(ref v) => {
                 v.fn_ptr.hash(state);
+                v.proxy.hash(state);
             }
         }
     }
@@ -243,6 +244,9 @@ pub struct DictValue {
     pub values: IndexMap<String, ValueRef>,
     pub ops: IndexMap<String, ConfigEntryOperationKind>,
     pub insert_indexs: IndexMap<String, i32>,
+    /// The following list item operations of the repeated key in one config
+    /// e.g., `{key[0]: v0, key[1]: v1}`, which are applied after the key entry in order.
+    pub item_entries: IndexMap<String, Vec<(ConfigEntryOperationKind, i32, ValueRef)>>,
     pub attr_map: IndexMap<String, String>,
 }
 
@@ -269,6 +273,9 @@ pub struct FuncValue {
     pub closure: ValueRef,
     pub external_name: String,
     pub runtime_type: String,
+    /// Function index in a non-native backend such as the AST evaluator,
+    /// which is called through `Context::proxy_func_handler`.
+    pub proxy: Option<u64>,
 }
 
 #[derive(PartialEq, Clone, Default, Debug)]
@@ -309,6 +316,10 @@ pub struct PanicInfo {
     pub message: String,
     pub err_type_code: i32,
     pub is_warning: bool,
+
+    /// All the failed schema checks when the check failures are collected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub check_failures: Vec<PanicInfo>,
 }
 
 #[derive(PartialEq, Eq, Clone, Default, Debug)]
@@ -320,6 +331,10 @@ pub struct ContextConfig {
     pub disable_schema_check: bool,
 
     pub list_option_mode: bool,
+    /// Annotate each planned schema instance with its type path.
+    pub include_schema_type_path: bool,
+    /// Collect all the failed schema checks instead of panicking at the first one.
+    pub collect_check_failures: bool,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -361,6 +376,8 @@ pub struct Context {
     pub cfg: ContextConfig,
     pub output: ContextOutput,
     pub panic_info: PanicInfo,
+    /// The failed schema checks collected when `cfg.collect_check_failures` is set.
+    pub check_failures: Vec<PanicInfo>,
 
     pub main_pkg_path: String,
     pub main_pkg_files: Vec<String>,
@@ -374,6 +391,11 @@ pub struct Context {
     pub symbol_names: Vec<String>,
     pub symbol_values: Vec<Value>,
     pub func_handlers: Vec<FuncHandler>,
+    /// The `ProxyFuncHandler` address used to call proxy functions, 0 denotes no handler.
+    pub proxy_func_handler: u64,
+    /// The backend state address passed to the `proxy_func_handler`, which is
+    /// only valid while the main function of the context is running.
+    pub proxy_func_data: u64,
 
     pub option_helps: Vec<OptionHelp>,
     pub buffer: ContextBuffer,
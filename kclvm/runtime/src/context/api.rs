@@ -39,17 +39,21 @@ type kclvm_float_t = f64;
 
 // singleton
 
-#[allow(non_camel_case_types, non_upper_case_globals)]
-static mut _kclvm_context_current: u64 = 0;
+thread_local! {
+    /// The current context of each thread, which makes programs running on
+    /// different threads e.g., with the AST evaluator not share a context.
+    #[allow(non_upper_case_globals)]
+    static _kclvm_context_current: std::cell::Cell<u64> = std::cell::Cell::new(0);
+}
 
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_context_current() -> *mut kclvm_context_t {
-    unsafe {
-        if _kclvm_context_current == 0 {
-            _kclvm_context_current = kclvm_context_new() as u64;
-        }
-        _kclvm_context_current as *mut kclvm_context_t
+    let p = _kclvm_context_current.with(|current| current.get());
+    if p == 0 {
+        kclvm_context_new()
+    } else {
+        p as *mut kclvm_context_t
     }
 }
 
@@ -57,9 +61,7 @@ pub extern "C" fn kclvm_context_current() -> *mut kclvm_context_t {
 #[runtime_fn]
 pub extern "C" fn kclvm_context_new() -> *mut kclvm_context_t {
     let p = Box::into_raw(Box::new(Context::new()));
-    unsafe {
-        _kclvm_context_current = p as u64;
-    }
+    _kclvm_context_current.with(|current| current.set(p as u64));
     p
 }
 
@@ -71,11 +73,9 @@ pub extern "C" fn kclvm_context_delete(p: *mut kclvm_context_t) {
         let ptr = (*o) as *mut kclvm_value_ref_t;
         kclvm_value_delete(ptr);
     }
-    unsafe {
-        //todo: remove global _kclvm_context_current
-        //set _kclvm_context_current to null to invoid internal unsoundness
-        _kclvm_context_current = 0;
-    }
+    //todo: remove global _kclvm_context_current
+    //set _kclvm_context_current to null to invoid internal unsoundness
+    _kclvm_context_current.with(|current| current.set(0));
     free_mut_ptr(p);
 }
 
@@ -590,13 +590,14 @@ pub extern "C" fn kclvm_value_function_invoke(
     if func.is_func() {
         let func = func.as_function();
         let fn_ptr = func.fn_ptr;
+        let proxy = func.proxy;
         let closure = &func.closure;
         let is_schema = !func.runtime_type.is_empty();
         let is_external = !func.external_name.is_empty();
         let ctx_ref = mut_ptr_as_ref(ctx);
         let now_meta_info = ctx_ref.panic_info.clone();
         unsafe {
-            let call_fn: SchemaTypeFunc = transmute_copy(&fn_ptr);
+            let call_fn = |args, kwargs| call_schema_type_fn(fn_ptr, proxy, ctx, args, kwargs);
             // Call schema constructor twice
             let value = if is_schema {
                 let pkgpath = c2str(pkgpath);
@@ -609,7 +610,7 @@ pub extern "C" fn kclvm_value_function_invoke(
                 let instance_pkgpath_index = closure.len() - 1;
                 args_ref.list_append_unpack(closure);
                 let args = args_ref.clone().into_raw();
-                call_fn(ctx, args, kwargs);
+                call_fn(args, kwargs);
                 let cal_map = closure.list_get(cal_map_index).unwrap();
                 // is sub schema
                 closure_new.list_set(0, &ValueRef::bool(true));
@@ -627,7 +628,7 @@ pub extern "C" fn kclvm_value_function_invoke(
                 );
                 closure_new.list_set(config_meta_index as usize, &config_meta);
                 args_new.list_append_unpack(&closure_new);
-                call_fn(ctx, args_new.into_raw(), kwargs)
+                call_fn(args_new.into_raw(), kwargs)
             // Normal kcl function, call directly
             } else if is_external {
                 let name = format!("{}\0", func.external_name);
@@ -635,7 +636,7 @@ pub extern "C" fn kclvm_value_function_invoke(
             } else {
                 args_ref.list_append_unpack_first(closure);
                 let args = args_ref.clone().into_raw();
-                call_fn(ctx, args, kwargs)
+                call_fn(args, kwargs)
             };
             ctx_ref.panic_info = now_meta_info;
             return value;
@@ -1126,18 +1127,29 @@ pub extern "C" fn kclvm_dict_merge(
         }
     };
     if attr_map.contains_key(key) {
-        let v = type_pack_and_check(v, vec![attr_map.get(key).unwrap()]);
-        p.dict_merge(
-            key,
-            &v,
-            ConfigEntryOperationKind::from_i32(op),
-            insert_index,
-        );
+        let op = ConfigEntryOperationKind::from_i32(op);
+        let tpe = config_entry_value_type(attr_map.get(key).unwrap(), &op, insert_index);
+        let v = type_pack_and_check(v, vec![&tpe]);
+        p.dict_merge(key, &v, op, insert_index);
     } else {
         p.dict_merge(key, v, ConfigEntryOperationKind::from_i32(op), insert_index);
     }
 }
 
+/// Get the expected type of the config entry value with the attribute type `tpe`,
+/// and the value of the list item operation e.g., `key[0]: v` is the list item.
+pub(crate) fn config_entry_value_type(
+    tpe: &str,
+    op: &ConfigEntryOperationKind,
+    insert_index: i32,
+) -> String {
+    if insert_index >= 0 && *op != ConfigEntryOperationKind::Insert && is_list_type(tpe) {
+        dereference_type(tpe)
+    } else {
+        tpe.to_string()
+    }
+}
+
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_dict_insert_value(
@@ -1741,9 +1753,22 @@ pub extern "C" fn kclvm_value_union(
         let mut result = schema;
         for (k, v) in &dict.values {
             if attr_map.contains_key(k) {
-                let v = type_pack_and_check(v, vec![attr_map.get(k).unwrap()]);
+                let op = dict.ops.get(k).unwrap_or(&ConfigEntryOperationKind::Union);
+                let index = *dict.insert_indexs.get(k).unwrap_or(&-1);
+                let tpe = config_entry_value_type(attr_map.get(k).unwrap(), op, index);
+                let v = type_pack_and_check(v, vec![&tpe]);
                 let mut entry = b.dict_get_entry(k).unwrap().deep_copy();
                 entry.dict_update_key_value(k, v);
+                if let Some(entries) = dict.item_entries.get(k) {
+                    let entries: Vec<_> = entries
+                        .iter()
+                        .map(|(op, index, v)| {
+                            let tpe = config_entry_value_type(attr_map.get(k).unwrap(), op, *index);
+                            (op.clone(), *index, type_pack_and_check(v, vec![&tpe]))
+                        })
+                        .collect();
+                    entry.dict_update_item_entries(k, &entries);
+                }
                 result = a
                     .union_entry(&entry, true, false, false, false)
                     .clone()
@@ -2178,6 +2203,11 @@ pub extern "C" fn kclvm_schema_assert(
     let config_meta = ptr_as_ref(config_meta);
     if !value.is_truthy() {
         let ctx = Context::current_context_mut();
+        let prev_panic_info = if ctx.cfg.collect_check_failures {
+            Some(ctx.panic_info.clone())
+        } else {
+            None
+        };
         ctx.set_err_type(&ErrType::SchemaCheckFailure_TYPE);
         if let Some(config_meta_file) = config_meta.get_by_key(CONFIG_META_FILENAME) {
             let config_meta_line = config_meta.get_by_key(CONFIG_META_LINE).unwrap();
@@ -2192,6 +2222,17 @@ pub extern "C" fn kclvm_schema_assert(
 
         ctx.set_kcl_location_info(Some("Check failed on the condition"), None, None, None);
 
+        // Record the failure and restore the panic information to go on
+        // checking the remaining conditions and schema instances.
+        if let Some(prev_panic_info) = prev_panic_info {
+            let mut failure = std::mem::replace(&mut ctx.panic_info, prev_panic_info);
+            failure.message = msg.as_str();
+            if !ctx.check_failures.contains(&failure) {
+                ctx.check_failures.push(failure);
+            }
+            return;
+        }
+
         panic!("{}", msg.as_str());
     }
 }
@@ -2211,9 +2252,12 @@ pub extern "C" fn kclvm_schema_value_new(
     if schema_value_or_func.is_func() {
         let schema_func = schema_value_or_func.as_function();
         let schema_fn_ptr = schema_func.fn_ptr;
+        let schema_fn_proxy = schema_func.proxy;
         let value = unsafe {
             let org_args = ptr_as_ref(args).deep_copy();
-            let schema_fn: SchemaTypeFunc = transmute_copy(&schema_fn_ptr);
+            let schema_fn = |args, kwargs| {
+                call_schema_type_fn(schema_fn_ptr, schema_fn_proxy, ctx, args, kwargs)
+            };
             let cal_map = kclvm_value_Dict();
             let instance_pkgpath = kclvm_value_Str(pkgpath);
             // Schema function closures
@@ -2242,7 +2286,7 @@ pub extern "C" fn kclvm_schema_value_new(
             for value in values {
                 kclvm_list_append(args, value);
             }
-            schema_fn(ctx, args, kwargs);
+            schema_fn(args, kwargs);
             // schema args
             let args = org_args.into_raw();
             let values = [
@@ -2270,7 +2314,7 @@ pub extern "C" fn kclvm_schema_value_new(
             for value in values {
                 kclvm_list_append(args, value);
             }
-            schema_fn(ctx, args, kwargs)
+            schema_fn(args, kwargs)
         };
         value
     } else {
@@ -2345,14 +2389,19 @@ pub extern "C" fn kclvm_schema_get_value(
             };
             return value.into_raw();
         }
-        let fn_ptr = &attr_code.values[index];
-        let fn_ptr = fn_ptr.as_int();
+        // The attribute function is a native function pointer or a proxy function value.
+        let attr_fn = &attr_code.values[index];
+        let (fn_ptr, proxy) = if attr_fn.is_func() {
+            let attr_fn = attr_fn.as_function();
+            (attr_fn.fn_ptr, attr_fn.proxy)
+        } else {
+            (attr_fn.as_int() as u64, None)
+        };
         // When we calculate other schema attribute values, we retain
         // the row and column number information of the current schema attribute.
         let ctx = Context::current_context_mut();
         let panic_info = ctx.panic_info.clone();
         unsafe {
-            let attr_fn: SchemaTypeFunc = transmute_copy(&fn_ptr);
             // args_0: config_meta, args_1: config, args_2: schema, args_3: cal_map
             let config_meta = ptr_as_ref(config_meta);
             let config = ptr_as_ref(config);
@@ -2371,7 +2420,7 @@ pub extern "C" fn kclvm_schema_get_value(
             let args = args.into_raw();
             let kwargs = kwargs.clone().into_raw();
             let ctx = kclvm_context_current();
-            attr_fn(ctx, args, kwargs);
+            call_schema_type_fn(fn_ptr, proxy, ctx, args, kwargs);
         };
         // Restore the panic info of current schema attribute.
         ctx.panic_info = panic_info;
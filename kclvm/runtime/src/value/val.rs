@@ -79,6 +79,20 @@ impl ValueRef {
             closure,
             external_name: name.to_string(),
             runtime_type: runtime_type.to_string(),
+            proxy: None,
+        })))
+    }
+
+    /// New a function value implemented by a non-native backend, where `proxy`
+    /// is the function index in the backend.
+    pub fn proxy_func(proxy: u64, closure: ValueRef, runtime_type: &str) -> Self {
+        Self::from(Value::func_value(Box::new(FuncValue {
+            fn_ptr: 0,
+            check_fn_ptr: 0,
+            closure,
+            external_name: "".to_string(),
+            runtime_type: runtime_type.to_string(),
+            proxy: Some(proxy),
         })))
     }
 }
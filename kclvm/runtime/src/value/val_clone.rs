@@ -22,6 +22,7 @@ impl ValueRef {
                     closure: v.closure.deep_copy(),
                     external_name: v.external_name.clone(),
                     runtime_type: v.runtime_type.clone(),
+                    proxy: v.proxy,
                 })))),
             },
             Value::bool_value(ref v) => ValueRef {
@@ -55,6 +56,13 @@ impl ValueRef {
                         &op.clone(),
                         &index.clone(),
                     );
+                    if let Some(entries) = v.item_entries.get(key) {
+                        let entries: Vec<_> = entries
+                            .iter()
+                            .map(|(op, index, v)| (op.clone(), *index, v.deep_copy()))
+                            .collect();
+                        dict.dict_update_item_entries(key, &entries);
+                    }
                 }
                 dict
             }
@@ -73,6 +81,13 @@ impl ValueRef {
                         &op.clone(),
                         &index.clone(),
                     );
+                    if let Some(entries) = v.config.item_entries.get(key) {
+                        let entries: Vec<_> = entries
+                            .iter()
+                            .map(|(op, index, v)| (op.clone(), *index, v.deep_copy()))
+                            .collect();
+                        dict.dict_update_item_entries(key, &entries);
+                    }
                     if let Some(type_str) = v.config.attr_map.get(key) {
                         dict.update_attr_map(key, type_str);
                     }
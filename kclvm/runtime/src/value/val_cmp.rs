@@ -60,7 +60,9 @@ impl ValueRef {
                     }
                     true
                 }
-                (Value::func_value(a), Value::func_value(b)) => a.fn_ptr == b.fn_ptr,
+                (Value::func_value(a), Value::func_value(b)) => {
+                    a.fn_ptr == b.fn_ptr && a.proxy == b.proxy
+                }
                 _ => false,
             },
         }
@@ -134,6 +134,9 @@ impl ValueRef {
                         -1
                     };
                     d.dict_update_entry(key, value, op, &index);
+                    if let Some(entries) = dict.item_entries.get(key) {
+                        d.dict_update_item_entries(key, entries);
+                    }
                     Some(d)
                 } else {
                     None
@@ -154,6 +157,9 @@ impl ValueRef {
                         -1
                     };
                     d.dict_update_entry(key, value, op, &index);
+                    if let Some(entries) = schema.config.item_entries.get(key) {
+                        d.dict_update_item_entries(key, entries);
+                    }
                     Some(d)
                 } else {
                     None
@@ -178,6 +184,9 @@ impl ValueRef {
                             .unwrap_or(&ConfigEntryOperationKind::Union);
                         let index = dict.insert_indexs.get(key).unwrap_or(&-1);
                         d.dict_update_entry(key, value, op, index);
+                        if let Some(entries) = dict.item_entries.get(key) {
+                            d.dict_update_item_entries(key, entries);
+                        }
                     }
                 }
                 d
@@ -194,6 +203,9 @@ impl ValueRef {
                             .unwrap_or(&ConfigEntryOperationKind::Union);
                         let index = schema.config.insert_indexs.get(key).unwrap_or(&-1);
                         d.dict_update_entry(key, value, op, index);
+                        if let Some(entries) = schema.config.item_entries.get(key) {
+                            d.dict_update_item_entries(key, entries);
+                        }
                     }
                 }
                 d
@@ -249,6 +261,32 @@ impl ValueRef {
         dict.values.insert(key.to_string(), val.clone());
         dict.ops.insert(key.to_string(), op.clone());
         dict.insert_indexs.insert(key.to_string(), *index);
+        dict.item_entries.remove(key);
+    }
+
+    /// Replace the list item entries of the repeated key e.g., `{key[0]: v0, key[1]: v1}`
+    /// in `item_entries` with `entries`, and remove them when `entries` is empty. The
+    /// entries are not checked or merged by the attribute operators.
+    ///
+    /// Use it after [ValueRef::dict_update_entry] when copying a config entry together with
+    /// its list item operations, e.g., in deep copies and type conversions. Use
+    /// [ValueRef::dict_update_key_value] to only set the value of the key.
+    pub fn dict_update_item_entries(
+        &mut self,
+        key: &str,
+        entries: &[(ConfigEntryOperationKind, i32, ValueRef)],
+    ) {
+        let mut binding = self.rc.borrow_mut();
+        let dict = match &mut *binding {
+            Value::dict_value(v) => v.as_mut(),
+            Value::schema_value(v) => v.config.as_mut(),
+            _ => panic!("invalid dict update value: {}", self.type_str()),
+        };
+        if entries.is_empty() {
+            dict.item_entries.remove(key);
+        } else {
+            dict.item_entries.insert(key.to_string(), entries.to_vec());
+        }
     }
 
     /// Insert key value pair with the idempotent check
@@ -259,7 +297,73 @@ impl ValueRef {
         op: ConfigEntryOperationKind,
         insert_index: i32,
     ) {
+        let entry_op = self.dict_get_entry_op(key);
+        if let Some((_, entry_index)) = &entry_op {
+            if *entry_index >= 0 {
+                // Keep the list item operations of the repeated key e.g., `{key[0]: v0, key[1]: v1}`
+                // in order, they are applied to the list attribute when the config is unioned.
+                if insert_index >= 0 {
+                    let mut binding = self.rc.borrow_mut();
+                    let dict = match &mut *binding {
+                        Value::dict_value(v) => v.as_mut(),
+                        Value::schema_value(v) => v.config.as_mut(),
+                        _ => return,
+                    };
+                    dict.item_entries.entry(key.to_string()).or_default().push((
+                        op,
+                        insert_index,
+                        v.clone(),
+                    ));
+                    return;
+                }
+                panic!(
+                    "the list item of the attribute '{}' can not be operated with the repeated key",
+                    key
+                );
+            }
+        }
+        let is_override = op == ConfigEntryOperationKind::Override && insert_index < 0;
         self.dict_merge_key_value_pair(key, v, op, insert_index, true);
+        // For the repeated key in one config e.g., `{key: [0], key += [1]}`, the value
+        // has been merged with the operation, and the entry keeps the operation of the
+        // first key unless it is overridden as a whole.
+        if let Some((entry_op, entry_index)) = entry_op {
+            let (op, index) = if is_override {
+                (ConfigEntryOperationKind::Override, -1)
+            } else {
+                (entry_op, entry_index)
+            };
+            let mut binding = self.rc.borrow_mut();
+            let dict = match &mut *binding {
+                Value::dict_value(v) => v.as_mut(),
+                Value::schema_value(v) => v.config.as_mut(),
+                _ => return,
+            };
+            dict.ops.insert(key.to_string(), op);
+            dict.insert_indexs.insert(key.to_string(), index);
+        }
+    }
+
+    /// Get the operation and the insert index of the config entry `key`, return None
+    /// when the key is not found.
+    fn dict_get_entry_op(&self, key: &str) -> Option<(ConfigEntryOperationKind, i32)> {
+        let binding = self.rc.borrow();
+        let dict = match &*binding {
+            Value::dict_value(v) => v.as_ref(),
+            Value::schema_value(v) => v.config.as_ref(),
+            _ => return None,
+        };
+        if dict.values.contains_key(key) {
+            Some((
+                dict.ops
+                    .get(key)
+                    .cloned()
+                    .unwrap_or(ConfigEntryOperationKind::Union),
+                *dict.insert_indexs.get(key).unwrap_or(&-1),
+            ))
+        } else {
+            None
+        }
     }
 
     /// Merge key value pair without the idempotent check
@@ -384,6 +488,86 @@ mod test_value_dict {
         }
     }
 
+    #[test]
+    fn test_dict_insert_repeated_key() {
+        let cases = [
+            (
+                vec![
+                    (vec![0], ConfigEntryOperationKind::Union),
+                    (vec![1], ConfigEntryOperationKind::Insert),
+                ],
+                vec![0, 1],
+                ConfigEntryOperationKind::Union,
+            ),
+            (
+                vec![
+                    (vec![0], ConfigEntryOperationKind::Union),
+                    (vec![1], ConfigEntryOperationKind::Override),
+                ],
+                vec![1],
+                ConfigEntryOperationKind::Override,
+            ),
+            (
+                vec![
+                    (vec![1], ConfigEntryOperationKind::Override),
+                    (vec![2], ConfigEntryOperationKind::Insert),
+                ],
+                vec![1, 2],
+                ConfigEntryOperationKind::Override,
+            ),
+        ];
+        for (entries, expected_val, expected_op) in cases {
+            let mut test_dict = ValueRef::dict(None);
+            for (val, op) in entries {
+                test_dict.dict_insert("key", &ValueRef::list_int(val.as_slice()), op, -1);
+            }
+            let dict = test_dict.as_dict_ref();
+            assert_eq!(
+                dict.values.get("key").unwrap().clone(),
+                ValueRef::list_int(expected_val.as_slice())
+            );
+            assert_eq!(*dict.ops.get("key").unwrap(), expected_op);
+            assert_eq!(*dict.insert_indexs.get("key").unwrap(), -1);
+        }
+    }
+
+    #[test]
+    fn test_dict_insert_repeated_list_item_key() {
+        let mut patch = ValueRef::dict(None);
+        patch.dict_insert(
+            "key",
+            &ValueRef::dict_int(&[("k1", 1)]),
+            ConfigEntryOperationKind::Union,
+            0,
+        );
+        patch.dict_insert(
+            "key",
+            &ValueRef::dict_int(&[("k2", 2)]),
+            ConfigEntryOperationKind::Override,
+            1,
+        );
+        {
+            let dict = patch.as_dict_ref();
+            assert_eq!(*dict.insert_indexs.get("key").unwrap(), 0);
+            assert_eq!(dict.item_entries.get("key").unwrap().len(), 1);
+        }
+        let mut config = ValueRef::dict(Some(&[(
+            "key",
+            &ValueRef::list(Some(&[
+                &ValueRef::dict_int(&[("k0", 0)]),
+                &ValueRef::dict_int(&[("k0", 0)]),
+            ])),
+        )]));
+        config.bin_aug_bit_or(&patch.deep_copy());
+        assert_eq!(
+            config.dict_get_value("key").unwrap(),
+            ValueRef::list(Some(&[
+                &ValueRef::dict_int(&[("k0", 0), ("k1", 1)]),
+                &ValueRef::dict_int(&[("k2", 2)]),
+            ]))
+        );
+    }
+
     #[test]
     fn test_dict_update() {
         let entries = [("key1", "value1"), ("key2", "value2")];
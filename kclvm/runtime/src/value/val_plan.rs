@@ -3,37 +3,42 @@
 use crate::*;
 use std::boxed::Box;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 pub const KCL_PRIVATE_VAR_PREFIX: &str = "_";
+/// The key of the type path annotated on each planned schema instance when
+/// `include_schema_type_path` is set, which is the same as `typeof(x, full_name=True)`.
+pub const SCHEMA_TYPE_PATH_KEY: &str = "_type";
 const LIST_DICT_TEMP_KEY: &str = "$";
 const YAML_STREAM_SEP: &str = "\n---\n";
 
-fn filter_results(key_values: &ValueRef) -> Vec<ValueRef> {
+fn filter_results(key_values: &ValueRef, cfg: &ContextConfig) -> Vec<ValueRef> {
     let mut results: Vec<ValueRef> = vec![];
     // Plan list value with the yaml stream format.
     if key_values.is_list() {
         let key_values_list = &key_values.as_list_ref().values;
         for key_values in key_values_list {
-            results.append(&mut filter_results(key_values));
+            results.append(&mut filter_results(key_values, cfg));
         }
         results
     }
     // Plan dict value
     else if key_values.is_config() {
-        let ctx = Context::current_context();
         // index 0 for in-line keyvalues output, index 1: for standalone keyvalues outputs
         let result = ValueRef::dict(None);
         results.push(result);
         let key_values = key_values.as_dict_ref();
         for (key, value) in &key_values.values {
-            if value.is_none() && ctx.cfg.disable_none {
+            if value.is_none() && cfg.disable_none {
                 continue;
             }
-            if key.starts_with(KCL_PRIVATE_VAR_PREFIX) || value.is_undefined() || value.is_func() {
+            let is_private = key.starts_with(KCL_PRIVATE_VAR_PREFIX)
+                && !(cfg.include_schema_type_path && key == SCHEMA_TYPE_PATH_KEY);
+            if is_private || value.is_undefined() || value.is_func() {
                 continue;
             } else if value.is_schema() || value.has_key(SCHEMA_SETTINGS_ATTR_NAME) {
-                let (filtered, standalone) = handle_schema(value);
+                let (filtered, standalone) = handle_schema(value, cfg);
                 if !filtered.is_empty() {
                     if standalone {
                         // if the instance is marked as 'STANDALONE', treat it as a separate one and
@@ -54,7 +59,7 @@ fn filter_results(key_values: &ValueRef) -> Vec<ValueRef> {
                     }
                 }
             } else if value.is_dict() {
-                let filtered = filter_results(value);
+                let filtered = filter_results(value, cfg);
                 if !results.is_empty() {
                     let result = results.get_mut(0).unwrap();
                     if !filtered.is_empty() {
@@ -74,7 +79,7 @@ fn filter_results(key_values: &ValueRef) -> Vec<ValueRef> {
                 let list_value = value.as_list_ref();
                 for v in &list_value.values {
                     if v.is_schema() || v.has_key(SCHEMA_SETTINGS_ATTR_NAME) {
-                        let (filtered, standalone) = handle_schema(v);
+                        let (filtered, standalone) = handle_schema(v, cfg);
                         if filtered.is_empty() {
                             ignore_schema_count += 1;
                             continue;
@@ -88,15 +93,15 @@ fn filter_results(key_values: &ValueRef) -> Vec<ValueRef> {
                             }
                         }
                     } else if v.is_dict() {
-                        let filtered = filter_results(v);
+                        let filtered = filter_results(v, cfg);
                         for v in filtered {
                             filtered_list.push(v);
                         }
-                    } else if v.is_none() && ctx.cfg.disable_none {
+                    } else if v.is_none() && cfg.disable_none {
                         continue;
                     } else if !v.is_undefined() {
                         let list_dict = ValueRef::dict(Some(&[(LIST_DICT_TEMP_KEY, v)]));
-                        let filtered = filter_results(&list_dict);
+                        let filtered = filter_results(&list_dict, cfg);
                         if !filtered.is_empty() {
                             if let Some(v) = filtered[0].get_by_key(LIST_DICT_TEMP_KEY) {
                                 filtered_list.push(v.clone());
@@ -138,8 +143,8 @@ fn filter_results(key_values: &ValueRef) -> Vec<ValueRef> {
     }
 }
 
-fn handle_schema(value: &ValueRef) -> (Vec<ValueRef>, bool) {
-    let filtered = filter_results(value);
+fn handle_schema(value: &ValueRef, cfg: &ContextConfig) -> (Vec<ValueRef>, bool) {
+    let filtered = filter_results(value, cfg);
     if filtered.is_empty() {
         return (filtered, false);
     }
@@ -165,13 +170,179 @@ fn handle_schema(value: &ValueRef) -> (Vec<ValueRef>, bool) {
     (filtered, standalone)
 }
 
+/// PathSegment is a segment of the path selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// The attribute name of a dict or schema value.
+    Key(String),
+    /// The index of a list value, negative index counts from the end.
+    Index(i64),
+    /// All the attributes of a dict or schema value, or all the items of a list value.
+    Wildcard,
+}
+
+/// PathSelector selects values from the planned result, the path selector is
+/// in the form of `pkg:var.attr`, where the `pkg:` prefix is optional and
+/// denotes the main package when omitted or empty. Attributes are separated
+/// by `.`, list items are selected with `[index]`, and `*` or `[*]` selects
+/// all the attributes or items e.g., `app.containers[0].name`, `app.*.image`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathSelector {
+    pub pkgpath: String,
+    pub path: Vec<PathSegment>,
+}
+
+impl PathSelector {
+    /// Whether the selector selects values of the main package.
+    pub fn is_main_pkg(&self) -> bool {
+        self.pkgpath == MAIN_PKG_PATH
+    }
+
+    /// Select all the values matched by the selector from the value.
+    pub fn select(&self, value: &ValueRef) -> Vec<ValueRef> {
+        let mut results = vec![];
+        select_path(value, &self.path, &mut results);
+        results
+    }
+}
+
+impl std::fmt::Display for PathSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.is_main_pkg() {
+            write!(f, "{}:", self.pkgpath)?;
+        }
+        for (i, segment) in self.path.iter().enumerate() {
+            match segment {
+                PathSegment::Key(key) if i == 0 => write!(f, "{}", key)?,
+                PathSegment::Key(key) => write!(f, ".{}", key)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+                PathSegment::Wildcard if i == 0 => write!(f, "*")?,
+                PathSegment::Wildcard => write!(f, "[*]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for PathSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid path selector '{}'", s);
+        let (pkgpath, path) = match s.split_once(':') {
+            Some((pkgpath, path)) if !pkgpath.trim().is_empty() => (pkgpath.trim(), path),
+            Some((_, path)) => (MAIN_PKG_PATH, path),
+            None => (MAIN_PKG_PATH, s),
+        };
+        let mut segments = vec![];
+        for part in path.trim().split('.') {
+            let (name, mut rest) = match part.find('[') {
+                Some(i) => (&part[..i], &part[i..]),
+                None => (part, ""),
+            };
+            match name {
+                "" if rest.is_empty() || segments.is_empty() => return Err(invalid()),
+                "" => {}
+                "*" => segments.push(PathSegment::Wildcard),
+                _ => segments.push(PathSegment::Key(name.to_string())),
+            }
+            while !rest.is_empty() {
+                let end = match (rest.starts_with('['), rest.find(']')) {
+                    (true, Some(end)) => end,
+                    _ => return Err(invalid()),
+                };
+                let index = rest[1..end].trim();
+                if index == "*" {
+                    segments.push(PathSegment::Wildcard);
+                } else {
+                    segments.push(PathSegment::Index(index.parse().map_err(|_| invalid())?));
+                }
+                rest = &rest[end + 1..];
+            }
+        }
+        Ok(PathSelector {
+            pkgpath: pkgpath.to_string(),
+            path: segments,
+        })
+    }
+}
+
+/// The key of the variable `name` of the package `pkgpath` in the global variables
+/// of the main package, which is used to return the variables selected by the path
+/// selectors of other packages together with the main package e.g., `@pkg.config`.
+pub fn pkg_var_key(pkgpath: &str, name: &str) -> String {
+    format!("{}{}.{}", PKG_PATH_PREFIX, pkgpath, name)
+}
+
+/// Plan each variable of a package separately, so the private variables selected by
+/// name are kept.
+fn filter_pkg_vars(vars: &ValueRef, cfg: &ContextConfig) -> Vec<ValueRef> {
+    let mut result = ValueRef::dict(None);
+    if vars.is_config() {
+        for (name, value) in &vars.as_dict_ref().values {
+            let var_dict = ValueRef::dict(Some(&[(LIST_DICT_TEMP_KEY, value)]));
+            let filtered = filter_results(&var_dict, cfg);
+            if let Some(v) = filtered
+                .first()
+                .and_then(|r| r.get_by_key(LIST_DICT_TEMP_KEY))
+            {
+                result.dict_update_key_value(name, v);
+            }
+        }
+    }
+    vec![result]
+}
+
+fn select_path(value: &ValueRef, path: &[PathSegment], results: &mut Vec<ValueRef>) {
+    let (segment, path) = match path.split_first() {
+        Some(v) => v,
+        None => {
+            results.push(value.clone());
+            return;
+        }
+    };
+    match segment {
+        PathSegment::Key(key) => {
+            if value.is_config() {
+                if let Some(v) = value.get_by_key(key) {
+                    select_path(&v, path, results);
+                }
+            }
+        }
+        PathSegment::Index(index) => {
+            if value.is_list() {
+                let values = &value.as_list_ref().values;
+                let index = if *index < 0 {
+                    values.len() as i64 + index
+                } else {
+                    *index
+                };
+                if index >= 0 && (index as usize) < values.len() {
+                    select_path(&values[index as usize], path, results);
+                }
+            }
+        }
+        PathSegment::Wildcard => {
+            if value.is_config() {
+                for v in value.as_dict_ref().values.values() {
+                    select_path(v, path, results);
+                }
+            } else if value.is_list() {
+                for v in &value.as_list_ref().values {
+                    select_path(v, path, results);
+                }
+            }
+        }
+    }
+}
+
 impl ValueRef {
     fn is_planned_empty(&self) -> bool {
         self.is_dict() && !self.is_truthy()
     }
 
     pub fn plan_to_json_string(&self) -> String {
-        let result = self.filter_results();
+        let result = self.filter_results(&Context::current_context().cfg);
         if result.is_planned_empty() {
             return "".to_string();
         }
@@ -179,13 +350,13 @@ impl ValueRef {
     }
 
     pub fn plan_to_yaml_string(&self) -> String {
-        let result = self.filter_results();
+        let result = self.filter_results(&Context::current_context().cfg);
         result.to_yaml_string()
     }
 
     /// Plan the value to the YAML string with delimiter `---`.
     pub fn plan_to_yaml_string_with_delimiter(&self) -> String {
-        let results = filter_results(self);
+        let results = filter_results(self, &Context::current_context().cfg);
         let results = results
             .iter()
             .map(|r| r.to_yaml_string())
@@ -195,7 +366,13 @@ impl ValueRef {
 
     /// Plan the value to JSON and YAML strings
     pub fn plan(&self) -> (String, String) {
-        let results = filter_results(self);
+        self.plan_with_config(&Context::current_context().cfg)
+    }
+
+    /// Plan the value to JSON and YAML strings with the output settings in `cfg`
+    /// instead of the settings of the current runtime context.
+    pub fn plan_with_config(&self, cfg: &ContextConfig) -> (String, String) {
+        let results = filter_results(self, cfg);
         let yaml_result = results
             .iter()
             .map(|r| r.to_yaml_string().strip_suffix("\n").unwrap().to_string())
@@ -209,8 +386,92 @@ impl ValueRef {
         (json_result, yaml_result)
     }
 
-    fn filter_results(&self) -> ValueRef {
-        let ctx = Context::current_context();
+    /// Plan the value and select the values matched by the path selectors from
+    /// the planned result to JSON and YAML strings. The selectors of the main
+    /// package select values from the value itself, and the selectors of other
+    /// packages select values from the variables of the packages in `pkg_results`
+    /// keyed by the package path, where the private variables can be selected by
+    /// name. It is an error if a selector matches nothing. The output settings are
+    /// read from `cfg` like [ValueRef::plan_with_config].
+    pub fn plan_with_path_selector(
+        &self,
+        path_selector: &[PathSelector],
+        pkg_results: &HashMap<String, ValueRef>,
+        cfg: &ContextConfig,
+    ) -> Result<(String, String), String> {
+        let main_results = filter_results(self, cfg);
+        let mut selected = vec![];
+        for selector in path_selector {
+            let results = if selector.is_main_pkg() {
+                main_results.clone()
+            } else {
+                match pkg_results.get(&selector.pkgpath) {
+                    Some(value) => filter_pkg_vars(value, cfg),
+                    None => {
+                        return Err(format!(
+                            "Cannot find the result of the package '{}' of the path selector '{}'",
+                            selector.pkgpath, selector
+                        ))
+                    }
+                }
+            };
+            let len = selected.len();
+            for result in &results {
+                selected.append(&mut selector.select(result));
+            }
+            if selected.len() == len {
+                return Err(format!(
+                    "No value is matched by the path selector '{}'",
+                    selector
+                ));
+            }
+        }
+        let yaml_result = selected
+            .iter()
+            .map(|r| r.to_yaml_string().trim_end_matches('\n').to_string())
+            .collect::<Vec<String>>()
+            .join(YAML_STREAM_SEP);
+        let mut list_result = ValueRef::list(None);
+        for r in &selected {
+            list_result.list_append(r);
+        }
+        let json_result = list_result.to_json_string();
+        Ok((json_result, yaml_result))
+    }
+
+    /// Take the package variables keyed by [pkg_var_key] out of the global variables
+    /// of the main package, and return the variables grouped by the package path.
+    pub fn take_pkg_vars(&mut self) -> HashMap<String, ValueRef> {
+        let mut pkg_vars: HashMap<String, ValueRef> = HashMap::new();
+        let values = if self.is_list() {
+            self.as_list_ref().values.clone()
+        } else {
+            vec![self.clone()]
+        };
+        for mut value in values.into_iter().filter(|v| v.is_config()) {
+            let keys: Vec<String> = value
+                .as_dict_ref()
+                .values
+                .keys()
+                .filter(|k| k.starts_with(PKG_PATH_PREFIX))
+                .cloned()
+                .collect();
+            for key in keys {
+                if let (Some(var), Some((pkgpath, name))) =
+                    (value.get_by_key(&key), key[1..].rsplit_once('.'))
+                {
+                    pkg_vars
+                        .entry(pkgpath.to_string())
+                        .or_insert_with(|| ValueRef::dict(None))
+                        .dict_update_key_value(name, var);
+                }
+                value.dict_remove(&key);
+            }
+        }
+        pkg_vars
+    }
+
+    fn filter_results(&self, cfg: &ContextConfig) -> ValueRef {
         match &*self.rc.borrow() {
             Value::undefined => ValueRef {
                 rc: Rc::new(RefCell::new(Value::undefined)),
@@ -243,8 +504,8 @@ impl ValueRef {
                     })))),
                 };
                 for x in v.values.iter() {
-                    if !(x.is_undefined() || x.is_func() || ctx.cfg.disable_none && x.is_none()) {
-                        list.list_append(&x.filter_results());
+                    if !(x.is_undefined() || x.is_func() || cfg.disable_none && x.is_none()) {
+                        list.list_append(&x.filter_results(cfg));
                     }
                 }
                 list
@@ -255,19 +516,17 @@ impl ValueRef {
                         values: IndexMap::default(),
                         ops: IndexMap::default(),
                         insert_indexs: IndexMap::default(),
+                        item_entries: IndexMap::default(),
                         attr_map: IndexMap::default(),
                     })))),
                 };
                 for (key, val) in v.values.iter() {
-                    if !(val.is_undefined()
-                        || val.is_func()
-                        || ctx.cfg.disable_none && val.is_none())
-                    {
+                    if !(val.is_undefined() || val.is_func() || cfg.disable_none && val.is_none()) {
                         dict.dict_insert(
                             key,
-                            &val.filter_results(),
+                            &val.filter_results(cfg),
                             ConfigEntryOperationKind::Override,
-                            0,
+                            -1,
                         );
                     }
                 }
@@ -282,18 +541,30 @@ impl ValueRef {
                             values: IndexMap::default(),
                             ops: IndexMap::default(),
                             insert_indexs: IndexMap::default(),
+                            item_entries: IndexMap::default(),
                             attr_map: IndexMap::default(),
                         }),
                         config_keys: vec![],
                     })))),
                 };
+                if cfg.include_schema_type_path {
+                    schema.dict_insert(
+                        SCHEMA_TYPE_PATH_KEY,
+                        &type_of(self, &ValueRef::bool(true)),
+                        ConfigEntryOperationKind::Override,
+                        -1,
+                    );
+                }
                 for (key, val) in v.config.values.iter() {
+                    if cfg.include_schema_type_path && key == SCHEMA_TYPE_PATH_KEY {
+                        continue;
+                    }
                     if !val.is_undefined() && !val.is_func() {
                         schema.dict_insert(
                             key,
-                            &val.filter_results(),
+                            &val.filter_results(cfg),
                             ConfigEntryOperationKind::Union,
-                            0,
+                            -1,
                         );
                     }
                 }
@@ -305,9 +576,9 @@ impl ValueRef {
 
 #[cfg(test)]
 mod test_value_plan {
-    use crate::ValueRef;
+    use crate::{Context, ContextConfig, ValueRef};
 
-    use super::filter_results;
+    use super::{filter_results, PathSegment, PathSelector};
 
     #[test]
     fn test_filter_results() {
@@ -317,14 +588,111 @@ mod test_value_plan {
         let dict_list = vec![&dict1, &dict2, &dict3];
         let list_data = ValueRef::list(Some(&dict_list));
         assert_eq!(
-            filter_results(&list_data),
+            filter_results(&list_data, &ContextConfig::default()),
             dict_list
                 .iter()
                 .map(|v| v.deep_copy())
                 .collect::<Vec<ValueRef>>()
         );
         for dict in dict_list {
-            assert_eq!(filter_results(dict), vec![dict.deep_copy()]);
+            assert_eq!(
+                filter_results(dict, &ContextConfig::default()),
+                vec![dict.deep_copy()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_path_selector() {
+        let selector: PathSelector = "app.containers[0].*".parse().unwrap();
+        assert!(selector.is_main_pkg());
+        assert_eq!(
+            selector.path,
+            vec![
+                PathSegment::Key("app".to_string()),
+                PathSegment::Key("containers".to_string()),
+                PathSegment::Index(0),
+                PathSegment::Wildcard,
+            ]
+        );
+        assert_eq!(selector.to_string(), "app.containers[0][*]");
+        let selector: PathSelector = "pkg.sub:config.items[*]".parse().unwrap();
+        assert_eq!(selector.pkgpath, "pkg.sub");
+        assert_eq!(selector.to_string(), "pkg.sub:config.items[*]");
+        let selector: PathSelector = ":config".parse().unwrap();
+        assert!(selector.is_main_pkg());
+        for invalid in ["", "pkg:", "a..b", "a[0", "a[x]", "[0]"] {
+            assert!(invalid.parse::<PathSelector>().is_err(), "{}", invalid);
         }
     }
+
+    #[test]
+    fn test_plan_with_path_selector() {
+        let value = ValueRef::from_json(
+            r#"{"app": {"frontend": {"image": "nginx", "ports": [80, 443]}, "backend": {"image": "redis", "ports": [6379]}}, "_private": 1}"#,
+        )
+        .unwrap();
+        let mut globals = ValueRef::from_json(
+            r#"{"name": "main", "@pkg.config": {"replicas": 2, "_private": 1}, "@pkg._secret": "s"}"#,
+        )
+        .unwrap();
+        let pkg_results = globals.take_pkg_vars();
+        assert_eq!(globals.to_json_string(), r#"{"name": "main"}"#);
+        let plan = |selectors: &[&str]| {
+            let selectors: Vec<PathSelector> =
+                selectors.iter().map(|s| s.parse().unwrap()).collect();
+            value.plan_with_path_selector(&selectors, &pkg_results, &ContextConfig::default())
+        };
+        assert_eq!(
+            plan(&["app.frontend"]).unwrap(),
+            (
+                r#"[{"image": "nginx", "ports": [80, 443]}]"#.to_string(),
+                "image: nginx\nports:\n  - 80\n  - 443".to_string()
+            )
+        );
+        assert_eq!(plan(&["app.*.image"]).unwrap().1, "nginx\n---\nredis");
+        assert_eq!(plan(&["app.frontend.ports[-1]"]).unwrap().1, "443");
+        assert_eq!(
+            plan(&["app.frontend.ports[0]", "app.backend.ports[*]"])
+                .unwrap()
+                .0,
+            "[80, 6379]"
+        );
+        assert!(plan(&["_private"]).is_err());
+        assert!(plan(&["app.frontend.ports[2]"]).is_err());
+        assert_eq!(
+            plan(&["app.backend.image", "pkg:config.replicas"])
+                .unwrap()
+                .1,
+            "redis\n---\n2"
+        );
+        assert_eq!(plan(&["pkg:_secret"]).unwrap().1, "s");
+        assert!(plan(&["pkg:config._private"]).is_err());
+        assert!(plan(&["pkg:app"]).is_err());
+        assert!(plan(&["other:config"]).is_err());
+    }
+
+    #[test]
+    fn test_plan_with_schema_type_path() {
+        let person = ValueRef::dict_str(&[("name", "Alice")]).dict_to_schema("Person", "pkg", &[]);
+        let main = ValueRef::dict_str(&[("name", "Bob")]).dict_to_schema("Person", "__main__", &[]);
+        let value = ValueRef::dict(Some(&[("alice", &person), ("bob", &main)]));
+        let ctx = Context::current_context_mut();
+        ctx.cfg.include_schema_type_path = true;
+        let json = value.plan_to_json_string();
+        ctx.cfg.include_schema_type_path = false;
+        // Plan the result again with the settings of the run instead of the context.
+        let cfg = ContextConfig {
+            include_schema_type_path: true,
+            ..Default::default()
+        };
+        let yaml = ValueRef::from_json(&json).unwrap().plan_with_config(&cfg).1;
+        assert_eq!(
+            yaml,
+            "alice:\n  _type: pkg.Person\n  name: Alice\nbob:\n  _type: Person\n  name: Bob"
+        );
+        let json = value.plan_to_json_string();
+        let yaml = ValueRef::from_json(&json).unwrap().plan().1;
+        assert_eq!(yaml, "alice:\n  name: Alice\nbob:\n  name: Bob");
+    }
 }
@@ -161,6 +161,7 @@ impl ValueRef {
             let values = &mut schema.config.values;
             let ops = &mut schema.config.ops;
             let insert_indexs = &mut schema.config.insert_indexs;
+            let item_entries = &mut schema.config.item_entries;
             for (k, v) in &value.config.values {
                 let op = value
                     .config
@@ -171,6 +172,14 @@ impl ValueRef {
                 values.insert(k.clone(), v.clone());
                 ops.insert(k.clone(), op.clone());
                 insert_indexs.insert(k.clone(), *index);
+                match value.config.item_entries.get(k) {
+                    Some(entries) => {
+                        item_entries.insert(k.clone(), entries.clone());
+                    }
+                    None => {
+                        item_entries.remove(k);
+                    }
+                }
             }
         }
     }
@@ -42,6 +42,38 @@ pub type SchemaTypeFunc = unsafe extern "C" fn(
     *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t;
 
+/// ProxyFuncHandler calls the function numbered `proxy` in a non-native backend
+/// denoted by the `Context::proxy_func_data` address with the arguments `args`
+/// and the keyword arguments `kwargs`.
+pub type ProxyFuncHandler =
+    fn(data: u64, proxy: u64, args: &ValueRef, kwargs: &ValueRef) -> ValueRef;
+
+/// Call the schema type function denoted by `fn_ptr` or `proxy`.
+pub(crate) unsafe fn call_schema_type_fn(
+    fn_ptr: u64,
+    proxy: Option<u64>,
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    match proxy {
+        Some(proxy) => {
+            let handler: ProxyFuncHandler = transmute_copy(&(*ctx).proxy_func_handler);
+            handler(
+                (*ctx).proxy_func_data,
+                proxy,
+                ptr_as_ref(args),
+                ptr_as_ref(kwargs),
+            )
+            .into_raw()
+        }
+        None => {
+            let schema_fn: SchemaTypeFunc = transmute_copy(&fn_ptr);
+            schema_fn(ctx, args, kwargs)
+        }
+    }
+}
+
 // common
 impl ValueRef {
     pub fn type_str(&self) -> String {
@@ -83,6 +115,7 @@ pub fn resolve_schema(schema: &ValueRef, keys: &[String]) -> ValueRef {
         };
         let schema_type = schema_type.as_function();
         let schema_fn_ptr = schema_type.fn_ptr;
+        let schema_fn_proxy = schema_type.proxy;
         let keys = keys.iter().map(|v| v.as_str()).collect();
         let config = schema.dict_get_entries(keys);
         let config_new = config.clone();
@@ -93,7 +126,6 @@ pub fn resolve_schema(schema: &ValueRef, keys: &[String]) -> ValueRef {
         );
         let config_meta_new = config_meta.clone();
         let value = unsafe {
-            let schema_fn: SchemaTypeFunc = transmute_copy(&schema_fn_ptr);
             let ctx = kclvm_context_current();
             let cal_map = kclvm_value_Dict();
             let list = kclvm_value_List();
@@ -119,7 +151,7 @@ pub fn resolve_schema(schema: &ValueRef, keys: &[String]) -> ValueRef {
             // instance pkgpath
             kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
             let dict = kclvm_value_Dict();
-            schema_fn(ctx, list, dict);
+            call_schema_type_fn(schema_fn_ptr, schema_fn_proxy, ctx, list, dict);
             let list = kclvm_value_List();
             // Schema function closures
             // is sub schema
@@ -142,7 +174,7 @@ pub fn resolve_schema(schema: &ValueRef, keys: &[String]) -> ValueRef {
             kclvm_list_append(list, ValueRef::bool(true).into_raw());
             // instance pkgpath
             kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
-            let value = schema_fn(ctx, list, dict);
+            let value = call_schema_type_fn(schema_fn_ptr, schema_fn_proxy, ctx, list, dict);
             ptr_as_ref(value)
         };
         ctx.panic_info = now_meta_info;
@@ -221,7 +253,17 @@ pub fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
                 .get(k)
                 .unwrap_or(&ConfigEntryOperationKind::Union);
             let index = dict_ref.insert_indexs.get(k).unwrap_or(&-1);
-            expected_dict.dict_update_entry(k, &expected_value, op, index)
+            expected_dict.dict_update_entry(k, &expected_value, op, index);
+            if let Some(entries) = dict_ref.item_entries.get(k) {
+                let entries: Vec<_> = entries
+                    .iter()
+                    .map(|(op, index, v)| {
+                        let tpe = config_entry_value_type(&value_tpe, op, *index);
+                        (op.clone(), *index, convert_collection_value(v, &tpe))
+                    })
+                    .collect();
+                expected_dict.dict_update_item_entries(k, &entries);
+            }
         }
         expected_dict
     } else if is_list_type(tpe) {
@@ -283,8 +325,8 @@ pub fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
             };
             let schema_type = schema_type.as_function();
             let schema_fn_ptr = schema_type.fn_ptr;
+            let schema_fn_proxy = schema_type.proxy;
             let value = unsafe {
-                let schema_fn: SchemaTypeFunc = transmute_copy(&schema_fn_ptr);
                 let ctx = kclvm_context_current();
                 let cal_order = kclvm_value_Dict();
                 let list = kclvm_value_List();
@@ -310,7 +352,7 @@ pub fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
                 // instance pkgpath
                 kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
                 let dict = kclvm_value_Dict();
-                schema_fn(ctx, list, dict);
+                call_schema_type_fn(schema_fn_ptr, schema_fn_proxy, ctx, list, dict);
                 let list = kclvm_value_List();
                 // Schema function closures
                 // is_sub_schema
@@ -333,7 +375,7 @@ pub fn convert_collection_value(value: &ValueRef, tpe: &str) -> ValueRef {
                 kclvm_list_append(list, ValueRef::bool(true).into_raw());
                 // instance pkgpath
                 kclvm_list_append(list, ValueRef::str(&now_meta_info.kcl_pkgpath).into_raw());
-                let value = schema_fn(ctx, list, dict);
+                let value = call_schema_type_fn(schema_fn_ptr, schema_fn_proxy, ctx, list, dict);
                 ptr_as_ref(value)
             };
             ctx.panic_info = now_meta_info;
@@ -33,6 +33,18 @@ impl ValueRef {
             for (k, v) in &delta.insert_indexs {
                 obj.insert_indexs.insert(k.clone(), *v);
             }
+            // Update the list item entries of the repeated keys, which are kept only when
+            // there is no list attribute to apply them.
+            for k in delta.values.keys() {
+                match delta.item_entries.get(k) {
+                    Some(entries) if !obj.values.contains_key(k) => {
+                        obj.item_entries.insert(k.clone(), entries.clone());
+                    }
+                    _ => {
+                        obj.item_entries.remove(k);
+                    }
+                }
+            }
             for (k, v) in &delta.values {
                 let operation = if let Some(op) = delta.ops.get(k) {
                     op
@@ -46,10 +58,54 @@ impl ValueRef {
                 };
                 if !obj.values.contains_key(k) {
                     obj.values.insert(k.clone(), v.clone());
-                } else {
+                    continue;
+                }
+                // Apply the entry and the following list item entries of the repeated key in order.
+                let item_entries = delta
+                    .item_entries
+                    .get(k)
+                    .map(|entries| entries.as_slice())
+                    .unwrap_or_default();
+                let entries = std::iter::once((operation, index, v)).chain(
+                    item_entries
+                        .iter()
+                        .map(|(operation, index, v)| (operation, *index, v)),
+                );
+                for (operation, index, v) in entries {
                     match operation {
                         ConfigEntryOperationKind::Union => {
                             let obj_value = obj.values.get_mut(k).unwrap();
+                            // Union the list item at the index e.g., `key[0]: {k: v}`
+                            if index >= 0 {
+                                if !obj_value.is_list() {
+                                    panic!(
+                                        "only list attribute can be unioned value with the index"
+                                    );
+                                }
+                                let mut item = match obj_value.list_get_option(index as isize) {
+                                    Some(item) => item,
+                                    None => panic!(
+                                        "index {} out of range of the list attribute '{}'",
+                                        index, k
+                                    ),
+                                };
+                                item.union(
+                                    v,
+                                    false,
+                                    should_list_override,
+                                    should_idempotent_check,
+                                    should_config_resolve,
+                                    union_context,
+                                );
+                                if union_context.conflict {
+                                    union_context
+                                        .path_backtrace
+                                        .push(format!("{}[{}]", k, index));
+                                    return;
+                                }
+                                obj_value.list_set(index as usize, &item);
+                                continue;
+                            }
                             if should_idempotent_check && !value_subsume(v, obj_value, false) {
                                 union_context.conflict = true;
                                 union_context.path_backtrace.push(k.clone());
@@ -457,6 +513,29 @@ mod test_value_union {
         }
     }
 
+    #[test]
+    fn test_dict_union_list_item() {
+        let mut left_value = ValueRef::dict(None);
+        let list = ValueRef::list(Some(&[
+            &ValueRef::dict_int(&[("key1", 1)]),
+            &ValueRef::dict_int(&[("key2", 2)]),
+        ]));
+        left_value.dict_update_entry("key", &list, &ConfigEntryOperationKind::Union, &-1);
+        let mut right_value = ValueRef::dict(None);
+        right_value.dict_update_entry(
+            "key",
+            &ValueRef::dict_int(&[("key3", 3)]),
+            &ConfigEntryOperationKind::Union,
+            &1,
+        );
+        let result = left_value.bin_bit_or(&right_value);
+        let expected = ValueRef::list(Some(&[
+            &ValueRef::dict_int(&[("key1", 1)]),
+            &ValueRef::dict_int(&[("key2", 2), ("key3", 3)]),
+        ]));
+        assert_eq!(result.dict_get_value("key").unwrap(), expected);
+    }
+
     #[test]
     fn test_dict_union_same_ref() {
         let cases = [
@@ -1,5 +1,9 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 
+use std::rc::Rc;
+
+use crate::ty::{Parameter, Type};
+
 pub const BASE64: &str = "base64";
 pub const BASE64_FUNCTION_NAMES: [&str; 2] = ["encode", "decode"];
 
@@ -134,3 +138,153 @@ pub fn get_system_module_members(name: &str) -> Vec<&str> {
         _ => bug!("invalid system module name '{}'", name),
     }
 }
+
+/// Get the type of the system module member, e.g., the function type of
+/// `math.log` or the number type of `units.Ki`.
+pub fn get_system_member_type(name: &str, member: &str) -> Type {
+    let str_ty = || Rc::new(Type::STR);
+    let int_ty = || Rc::new(Type::INT);
+    let float_ty = || Rc::new(Type::FLOAT);
+    let bool_ty = || Rc::new(Type::BOOL);
+    let any_ty = || Rc::new(Type::ANY);
+    let none_ty = || Rc::new(Type::NONE);
+    let number_ty = || Type::union_ref(&[int_ty(), float_ty()]);
+    let param = |name: &str, ty: Rc<Type>, has_default: bool| Parameter {
+        name: name.to_string(),
+        ty,
+        has_default,
+    };
+    let (params, return_ty) = match (name, member) {
+        (BASE64, _) | (CRYPTO, _) => (
+            vec![
+                param("value", str_ty(), false),
+                param("encoding", str_ty(), true),
+            ],
+            str_ty(),
+        ),
+        (NET, "split_host_port") => (
+            vec![param("ip_end_point", str_ty(), false)],
+            Type::list_ref(str_ty()),
+        ),
+        (NET, "join_host_port") => (
+            vec![
+                param("host", str_ty(), false),
+                param("port", Type::union_ref(&[int_ty(), str_ty()]), false),
+            ],
+            str_ty(),
+        ),
+        (NET, "fqdn") => (vec![param("name", str_ty(), true)], str_ty()),
+        (NET, "parse_IP") | (NET, "to_IP4") | (NET, "to_IP16") | (NET, "IP_string") => {
+            (vec![param("ip", str_ty(), false)], str_ty())
+        }
+        (NET, _) => (vec![param("ip", str_ty(), false)], bool_ty()),
+        (MANIFESTS, _) => (
+            vec![
+                param("values", Type::list_ref(any_ty()), false),
+                param("opts", Type::dict_ref(str_ty(), any_ty()), true),
+            ],
+            none_ty(),
+        ),
+        (MATH, "gcd") => (
+            vec![param("a", int_ty(), false), param("b", int_ty(), false)],
+            int_ty(),
+        ),
+        (MATH, "pow") => (
+            vec![
+                param("x", number_ty(), false),
+                param("y", number_ty(), false),
+            ],
+            float_ty(),
+        ),
+        (MATH, "log") => (
+            vec![
+                param("x", number_ty(), false),
+                param("e", number_ty(), true),
+            ],
+            float_ty(),
+        ),
+        (MATH, "ceil") | (MATH, "factorial") | (MATH, "floor") => {
+            (vec![param("x", number_ty(), false)], int_ty())
+        }
+        (MATH, "isfinite") | (MATH, "isinf") | (MATH, "isnan") => {
+            (vec![param("x", number_ty(), false)], bool_ty())
+        }
+        (MATH, "modf") => (
+            vec![param("x", number_ty(), false)],
+            Type::list_ref(float_ty()),
+        ),
+        (MATH, _) => (vec![param("x", number_ty(), false)], float_ty()),
+        (DATETIME, "ticks") => (vec![], float_ty()),
+        (DATETIME, _) => (vec![], str_ty()),
+        (REGEX, "replace") => (
+            vec![
+                param("string", str_ty(), false),
+                param("pattern", str_ty(), false),
+                param("replace", str_ty(), false),
+                param("count", int_ty(), true),
+            ],
+            str_ty(),
+        ),
+        (REGEX, "compile") => (vec![param("pattern", str_ty(), false)], bool_ty()),
+        (REGEX, "findall") => (
+            vec![
+                param("string", str_ty(), false),
+                param("pattern", str_ty(), false),
+            ],
+            Type::list_ref(str_ty()),
+        ),
+        (REGEX, "split") => (
+            vec![
+                param("string", str_ty(), false),
+                param("pattern", str_ty(), false),
+                param("maxsplit", int_ty(), true),
+            ],
+            Type::list_ref(str_ty()),
+        ),
+        (REGEX, _) => (
+            vec![
+                param("string", str_ty(), false),
+                param("pattern", str_ty(), false),
+            ],
+            bool_ty(),
+        ),
+        (YAML, "decode") | (JSON, "decode") => (vec![param("value", str_ty(), false)], any_ty()),
+        (YAML, "encode") | (JSON, "encode") => (
+            vec![
+                param("data", any_ty(), false),
+                param("sort_keys", bool_ty(), true),
+                param("ignore_private", bool_ty(), true),
+                param("ignore_none", bool_ty(), true),
+            ],
+            str_ty(),
+        ),
+        (YAML, _) | (JSON, _) => (
+            vec![
+                param("data", any_ty(), false),
+                param("filename", str_ty(), false),
+                param("sort_keys", bool_ty(), true),
+                param("ignore_private", bool_ty(), true),
+                param("ignore_none", bool_ty(), true),
+            ],
+            none_ty(),
+        ),
+        (TESTING, "arguments") => (
+            vec![
+                param("name", str_ty(), false),
+                param("value", any_ty(), false),
+            ],
+            none_ty(),
+        ),
+        (TESTING, _) => (vec![param("filename", str_ty(), false)], none_ty()),
+        (UNITS, UNITS_NUMBER_MULTIPLIER) => return Type::number_multiplier_non_lit_ty(),
+        (UNITS, "n") | (UNITS, "u") | (UNITS, "m") => return Type::FLOAT,
+        (UNITS, _) if UNITS_FIELD_NAMES.contains(&member) => return Type::INT,
+        (UNITS, _) => (vec![param("num", number_ty(), false)], str_ty()),
+        (COLLECTION, _) => (
+            vec![param("data", Type::list_ref(any_ty()), false)],
+            Type::dict_ref(str_ty(), any_ty()),
+        ),
+        _ => bug!("invalid system module member '{}.{}'", name, member),
+    };
+    Type::function(None, return_ty, &params, "", false, None)
+}
@@ -0,0 +1,705 @@
+//! This package provides the scope-aware code completion API. It resolves
+//! the program and returns the completion items at a position, including
+//! the names visible in the scope, the members of schemas and modules,
+//! the schema attribute keys in config expressions and the keyword snippets.
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+
+use indexmap::IndexSet;
+use kclvm_ast::ast;
+use kclvm_error::Position;
+
+use crate::builtin::system_module::{get_system_member_type, get_system_module_members};
+use crate::builtin::STRING_MEMBER_FUNCTIONS;
+use crate::info::is_private_field;
+use crate::resolver::pos::ContainsPos;
+use crate::resolver::resolve_program;
+use crate::resolver::scope::{builtin_scope, ProgramScope, Scope, ScopeObject, ScopeObjectKind};
+use crate::ty::{ModuleKind, SchemaType, Type, TypeKind, SCHEMA_MEMBER_FUNCTIONS};
+
+#[cfg(test)]
+mod tests;
+
+/// Keyword snippets, the snippet syntax follows the language server protocol.
+const KEYWORD_SNIPPETS: &[(&str, &str)] = &[
+    ("import", "import ${1:pkg}"),
+    ("schema", "schema ${1:Name}:\n    ${0}"),
+    ("mixin", "mixin ${1:Name}Mixin:\n    ${0}"),
+    ("protocol", "protocol ${1:Name}Protocol:\n    ${0}"),
+    ("rule", "rule ${1:Name}:\n    ${0}"),
+    ("check", "check:\n    ${0}"),
+    ("if", "if ${1:cond}:\n    ${0}"),
+    ("elif", "elif ${1:cond}:\n    ${0}"),
+    ("else", "else:\n    ${0}"),
+    ("for", "for ${1:x} in ${2:items}"),
+    ("lambda", "lambda ${1:x} {\n    ${0}\n}"),
+    ("assert", "assert ${1:cond}"),
+    ("type", "type ${1:Name} = ${0}"),
+    ("all", "all ${1:x} in ${2:items} {\n    ${0}\n}"),
+    ("any", "any ${1:x} in ${2:items} {\n    ${0}\n}"),
+    ("filter", "filter ${1:x} in ${2:items} {\n    ${0}\n}"),
+    ("map", "map ${1:x} in ${2:items} {\n    ${0}\n}"),
+    ("as", "as"),
+    ("and", "and"),
+    ("or", "or"),
+    ("not", "not"),
+    ("in", "in"),
+    ("is", "is"),
+    ("True", "True"),
+    ("False", "False"),
+    ("None", "None"),
+    ("Undefined", "Undefined"),
+];
+
+/// The kind of the completion item.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompletionKind {
+    Variable,
+    Attribute,
+    Schema,
+    Function,
+    Module,
+    TypeAlias,
+    Keyword,
+}
+
+/// The completion item returned by the completion API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompletionItem {
+    /// The name to be completed.
+    pub label: String,
+    /// The completion item kind.
+    pub kind: CompletionKind,
+    /// The type string of the item, it is empty for keywords.
+    pub ty: String,
+    /// The snippet inserted into the code, the label is inserted when it is None.
+    pub insert_text: Option<String>,
+}
+
+impl CompletionItem {
+    fn new(label: &str, kind: CompletionKind, ty: &Type) -> Self {
+        CompletionItem {
+            label: label.to_string(),
+            kind,
+            ty: ty.ty_str(),
+            insert_text: None,
+        }
+    }
+
+    fn from_scope_object(obj: &ScopeObject) -> Self {
+        let kind = match obj.kind {
+            ScopeObjectKind::Variable | ScopeObjectKind::Parameter => CompletionKind::Variable,
+            ScopeObjectKind::Attribute => CompletionKind::Attribute,
+            ScopeObjectKind::Definition => match obj.ty.kind {
+                TypeKind::Schema(_) => CompletionKind::Schema,
+                _ => CompletionKind::Function,
+            },
+            ScopeObjectKind::TypeAlias => CompletionKind::TypeAlias,
+            ScopeObjectKind::Module => CompletionKind::Module,
+        };
+        CompletionItem::new(&obj.name, kind, &obj.ty)
+    }
+}
+
+/// The completion context at the position.
+#[derive(Debug, Clone, PartialEq)]
+enum CompletionContext {
+    /// Names visible in the scope, e.g. `a = b|`.
+    Name,
+    /// Members of the dotted names, e.g. `a = b.c.|`.
+    Member(Vec<String>),
+    /// Attribute keys of the schema config, the schema type is found by the schema
+    /// name and the attribute path of nested configs, e.g. `p = Person { base = { | } }`.
+    ConfigKey {
+        schema: Vec<String>,
+        path: Vec<String>,
+    },
+}
+
+/// Returns the completion items at the position of the program, the file
+/// to complete is the filename of the position.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_error::Position;
+/// use kclvm_parser::load_program;
+/// use kclvm_sema::complete::complete;
+///
+/// let file = "./src/complete/test_data/complete.k";
+/// let mut program = load_program(&[file], None).unwrap();
+/// let pos = Position {
+///     filename: file.to_string(),
+///     line: 1,
+///     column: Some(0),
+/// };
+/// let items = complete(&mut program, &pos);
+/// assert!(items.iter().any(|item| item.label == "Person"));
+/// ```
+pub fn complete(program: &mut ast::Program, pos: &Position) -> Vec<CompletionItem> {
+    let scope = resolve_program(program);
+    let (pkgpath, module) = match program.pkgs.iter().find_map(|(pkgpath, modules)| {
+        modules
+            .iter()
+            .find(|m| is_same_file(&m.filename, &pos.filename))
+            .map(|m| (pkgpath, m))
+    }) {
+        Some(result) => result,
+        None => return vec![],
+    };
+    // The module filename is joined with the program root, use it as the position
+    // filename to compare with the positions of AST nodes and scopes.
+    let pos = &Position {
+        filename: module.filename.clone(),
+        ..pos.clone()
+    };
+    let pkg_scope = match scope.scope_map.get(pkgpath) {
+        Some(scope) => scope,
+        None => return vec![],
+    };
+    let mut finder = ContextFinder {
+        pos,
+        ctx: CompletionContext::Name,
+    };
+    for stmt in &module.body {
+        finder.stmt(stmt);
+    }
+    let completer = Completer {
+        program_scope: &scope,
+        scope: inner_most_scope(pkg_scope, pos),
+    };
+    let items = match &finder.ctx {
+        CompletionContext::Name => completer.complete_names(),
+        CompletionContext::Member(names) => completer.complete_members(names),
+        CompletionContext::ConfigKey { schema, path } => {
+            completer.complete_config_keys(schema, path)
+        }
+    };
+    items.into_iter().collect()
+}
+
+/// Whether the two paths refer to the same file, e.g., the relative input path
+/// and the absolute module filename.
+fn is_same_file(a: &str, b: &str) -> bool {
+    a == b
+        || match (Path::new(a).canonicalize(), Path::new(b).canonicalize()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+}
+
+/// Returns the inner most scope which contains the position. The package
+/// scope is returned when no child scope contains the position.
+fn inner_most_scope(scope: &Rc<RefCell<Scope>>, pos: &Position) -> Rc<RefCell<Scope>> {
+    for child in scope.borrow().children.iter() {
+        if child.borrow().contains_pos(pos) {
+            return inner_most_scope(child, pos);
+        }
+    }
+    Rc::clone(scope)
+}
+
+struct Completer<'a> {
+    program_scope: &'a ProgramScope,
+    scope: Rc<RefCell<Scope>>,
+}
+
+impl<'a> Completer<'a> {
+    /// Complete the names defined in the scope and its parents, the inner
+    /// names shadow the outer ones.
+    fn complete_names(&self) -> IndexSet<CompletionItem> {
+        let mut items = IndexSet::new();
+        let mut names = IndexSet::new();
+        let mut scope = Some(Rc::clone(&self.scope));
+        while let Some(current) = scope {
+            for (name, obj) in &current.borrow().elems {
+                if names.insert(name.to_string()) {
+                    items.insert(CompletionItem::from_scope_object(&obj.borrow()));
+                }
+            }
+            // The builtin scope owned by the resolver has been dropped, so the
+            // parent of the package scope can't be upgraded.
+            scope = current
+                .borrow()
+                .parent
+                .as_ref()
+                .and_then(|parent| parent.upgrade());
+        }
+        for (name, obj) in &builtin_scope().elems {
+            if names.insert(name.to_string()) {
+                items.insert(CompletionItem::from_scope_object(&obj.borrow()));
+            }
+        }
+        for (keyword, snippet) in KEYWORD_SNIPPETS {
+            items.insert(CompletionItem {
+                label: keyword.to_string(),
+                kind: CompletionKind::Keyword,
+                ty: "".to_string(),
+                insert_text: Some(snippet.to_string()),
+            });
+        }
+        items
+    }
+
+    /// Complete the members of the value referred by the dotted names.
+    fn complete_members(&self, names: &[String]) -> IndexSet<CompletionItem> {
+        let mut items = IndexSet::new();
+        let ty = match self.lookup_type(names) {
+            Some(ty) => ty,
+            None => return items,
+        };
+        match &ty.kind {
+            TypeKind::Str | TypeKind::StrLit(_) => {
+                for (name, func_ty) in STRING_MEMBER_FUNCTIONS.iter() {
+                    items.insert(CompletionItem::new(name, CompletionKind::Function, func_ty));
+                }
+            }
+            TypeKind::Schema(schema_ty) => {
+                items.extend(schema_attr_items(schema_ty));
+                if !schema_ty.is_instance {
+                    for name in SCHEMA_MEMBER_FUNCTIONS.iter() {
+                        items.insert(CompletionItem::new(
+                            name,
+                            CompletionKind::Function,
+                            &Type::function(
+                                Some(ty.clone()),
+                                Type::list_ref(Rc::new(Type::ANY)),
+                                &[],
+                                "",
+                                false,
+                                None,
+                            ),
+                        ));
+                    }
+                }
+            }
+            TypeKind::Module(module_ty) => match module_ty.kind {
+                ModuleKind::User => {
+                    if let Some(scope) = self.program_scope.scope_map.get(&module_ty.pkgpath) {
+                        for (name, obj) in &scope.borrow().elems {
+                            if !is_private_field(name) {
+                                items.insert(CompletionItem::from_scope_object(&obj.borrow()));
+                            }
+                        }
+                    }
+                }
+                ModuleKind::System => {
+                    for name in get_system_module_members(&module_ty.pkgpath) {
+                        let ty = get_system_member_type(&module_ty.pkgpath, name);
+                        let kind = match ty.kind {
+                            TypeKind::Function(_) => CompletionKind::Function,
+                            TypeKind::NumberMultiplier(_) => CompletionKind::TypeAlias,
+                            _ => CompletionKind::Variable,
+                        };
+                        items.insert(CompletionItem::new(name, kind, &ty));
+                    }
+                }
+                ModuleKind::Plugin => {}
+            },
+            _ => {}
+        }
+        items
+    }
+
+    /// Complete the attribute keys of the schema config.
+    fn complete_config_keys(&self, schema: &[String], path: &[String]) -> IndexSet<CompletionItem> {
+        let mut ty = match self.lookup_type(schema) {
+            Some(ty) => ty,
+            None => return IndexSet::new(),
+        };
+        for key in path {
+            ty = match member_type(self.program_scope, &ty, key) {
+                Some(ty) => ty,
+                None => return IndexSet::new(),
+            };
+        }
+        match &ty.kind {
+            TypeKind::Schema(schema_ty) => schema_attr_items(schema_ty),
+            _ => IndexSet::new(),
+        }
+    }
+
+    /// Lookup the type of the dotted names from the scope.
+    fn lookup_type(&self, names: &[String]) -> Option<Rc<Type>> {
+        let (first, rest) = names.split_first()?;
+        let obj = self.scope.borrow().lookup(first)?;
+        let mut ty = obj.borrow().ty.clone();
+        for name in rest {
+            ty = member_type(self.program_scope, &ty, name)?;
+        }
+        Some(ty)
+    }
+}
+
+/// Returns the type of the member of schemas and user modules.
+fn member_type(program_scope: &ProgramScope, ty: &Type, name: &str) -> Option<Rc<Type>> {
+    match &ty.kind {
+        TypeKind::Schema(schema_ty) => schema_ty.get_type_of_attr(name),
+        TypeKind::Module(module_ty) if matches!(module_ty.kind, ModuleKind::User) => {
+            let scope = program_scope.scope_map.get(&module_ty.pkgpath)?;
+            let obj = scope.borrow().elems.get(name).cloned()?;
+            let ty = obj.borrow().ty.clone();
+            Some(ty)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the public attributes of the schema including the attributes of
+/// its base schema, protocol and mixins.
+fn schema_attr_items(schema_ty: &SchemaType) -> IndexSet<CompletionItem> {
+    let mut items = IndexSet::new();
+    if let Some(base) = &schema_ty.base {
+        items.extend(schema_attr_items(base));
+    }
+    if let Some(protocol) = &schema_ty.protocol {
+        items.extend(schema_attr_items(protocol));
+    }
+    for mixin in &schema_ty.mixins {
+        items.extend(schema_attr_items(mixin));
+    }
+    for (name, attr) in &schema_ty.attrs {
+        if !is_private_field(name) {
+            items.insert(CompletionItem::new(
+                name,
+                CompletionKind::Attribute,
+                &attr.ty,
+            ));
+        }
+    }
+    items
+}
+
+/// ContextFinder walks the AST nodes which contain the position and
+/// records the completion context of the inner most node.
+struct ContextFinder<'a> {
+    pos: &'a Position,
+    ctx: CompletionContext,
+}
+
+impl<'a> ContextFinder<'a> {
+    fn stmts(&mut self, stmts: &[ast::NodeRef<ast::Stmt>]) {
+        for stmt in stmts {
+            self.stmt(stmt);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &ast::Node<ast::Stmt>) {
+        if !stmt.contains_pos(self.pos) {
+            return;
+        }
+        match &stmt.node {
+            ast::Stmt::Expr(expr_stmt) => self.exprs(&expr_stmt.exprs),
+            ast::Stmt::Unification(unification_stmt) => {
+                self.identifier(&unification_stmt.target);
+                let schema_expr = &unification_stmt.value.node;
+                self.schema_expr(schema_expr);
+            }
+            ast::Stmt::Assign(assign_stmt) => {
+                for target in &assign_stmt.targets {
+                    self.identifier(target);
+                }
+                match (&assign_stmt.type_annotation, &assign_stmt.value.node) {
+                    // Complete the schema attributes in the config with the type annotation,
+                    // e.g. `person: Person = { | }`.
+                    (Some(type_annotation), ast::Expr::Config(_))
+                        if assign_stmt.value.contains_pos(self.pos) =>
+                    {
+                        let schema = type_annotation
+                            .node
+                            .split('.')
+                            .map(|name| name.to_string())
+                            .collect();
+                        self.config(
+                            &assign_stmt.value,
+                            CompletionContext::ConfigKey {
+                                schema,
+                                path: vec![],
+                            },
+                        );
+                    }
+                    _ => self.expr(&assign_stmt.value),
+                }
+            }
+            ast::Stmt::AugAssign(aug_assign_stmt) => {
+                self.identifier(&aug_assign_stmt.target);
+                self.expr(&aug_assign_stmt.value);
+            }
+            ast::Stmt::Assert(assert_stmt) => {
+                self.expr(&assert_stmt.test);
+                self.opt_expr(&assert_stmt.if_cond);
+                self.opt_expr(&assert_stmt.msg);
+            }
+            ast::Stmt::If(if_stmt) => {
+                self.expr(&if_stmt.cond);
+                self.stmts(&if_stmt.body);
+                self.stmts(&if_stmt.orelse);
+            }
+            ast::Stmt::SchemaAttr(schema_attr) => {
+                for decorator in &schema_attr.decorators {
+                    self.call_expr(&decorator.node);
+                }
+                self.opt_expr(&schema_attr.value);
+            }
+            ast::Stmt::Schema(schema_stmt) => {
+                if let Some(parent_name) = &schema_stmt.parent_name {
+                    self.identifier(parent_name);
+                }
+                for mixin in &schema_stmt.mixins {
+                    self.identifier(mixin);
+                }
+                for decorator in &schema_stmt.decorators {
+                    self.call_expr(&decorator.node);
+                }
+                if let Some(index_signature) = &schema_stmt.index_signature {
+                    self.opt_expr(&index_signature.node.value);
+                }
+                self.stmts(&schema_stmt.body);
+                for check in &schema_stmt.checks {
+                    self.check_expr(&check.node);
+                }
+            }
+            ast::Stmt::Rule(rule_stmt) => {
+                for parent_rule in &rule_stmt.parent_rules {
+                    self.identifier(parent_rule);
+                }
+                for check in &rule_stmt.checks {
+                    self.check_expr(&check.node);
+                }
+            }
+            ast::Stmt::TypeAlias(_) | ast::Stmt::Import(_) => {}
+        }
+    }
+
+    fn exprs(&mut self, exprs: &[ast::NodeRef<ast::Expr>]) {
+        for expr in exprs {
+            self.expr(expr);
+        }
+    }
+
+    fn opt_expr(&mut self, expr: &Option<ast::NodeRef<ast::Expr>>) {
+        if let Some(expr) = expr {
+            self.expr(expr);
+        }
+    }
+
+    fn expr(&mut self, expr: &ast::Node<ast::Expr>) {
+        if !expr.contains_pos(self.pos) {
+            return;
+        }
+        match &expr.node {
+            ast::Expr::Identifier(identifier) => {
+                self.identifier_names(&identifier.names, expr.column)
+            }
+            ast::Expr::Unary(unary_expr) => self.expr(&unary_expr.operand),
+            ast::Expr::Binary(binary_expr) => {
+                self.expr(&binary_expr.left);
+                self.expr(&binary_expr.right);
+            }
+            ast::Expr::If(if_expr) => {
+                self.expr(&if_expr.body);
+                self.expr(&if_expr.cond);
+                self.expr(&if_expr.orelse);
+            }
+            ast::Expr::Selector(selector_expr) => {
+                self.expr(&selector_expr.value);
+                if selector_expr.attr.contains_pos(self.pos) {
+                    if let ast::Expr::Identifier(identifier) = &selector_expr.value.node {
+                        self.ctx = CompletionContext::Member(identifier.names.clone());
+                    }
+                }
+            }
+            ast::Expr::Call(call_expr) => self.call_expr(call_expr),
+            ast::Expr::Paren(paren_expr) => self.expr(&paren_expr.expr),
+            ast::Expr::Quant(quant_expr) => {
+                self.expr(&quant_expr.target);
+                self.expr(&quant_expr.test);
+                self.opt_expr(&quant_expr.if_cond);
+            }
+            ast::Expr::List(list_expr) => self.exprs(&list_expr.elts),
+            ast::Expr::ListIfItem(list_if_item_expr) => {
+                self.expr(&list_if_item_expr.if_cond);
+                self.exprs(&list_if_item_expr.exprs);
+                self.opt_expr(&list_if_item_expr.orelse);
+            }
+            ast::Expr::ListComp(list_comp) => {
+                self.expr(&list_comp.elt);
+                for generator in &list_comp.generators {
+                    self.comp_clause(&generator.node);
+                }
+            }
+            ast::Expr::Starred(starred_expr) => self.expr(&starred_expr.value),
+            ast::Expr::DictComp(dict_comp) => {
+                self.opt_expr(&dict_comp.entry.key);
+                self.expr(&dict_comp.entry.value);
+                for generator in &dict_comp.generators {
+                    self.comp_clause(&generator.node);
+                }
+            }
+            ast::Expr::ConfigIfEntry(config_if_entry_expr) => {
+                self.expr(&config_if_entry_expr.if_cond);
+                for item in &config_if_entry_expr.items {
+                    self.config_entry(item, &CompletionContext::Name);
+                }
+                self.opt_expr(&config_if_entry_expr.orelse);
+            }
+            ast::Expr::CompClause(comp_clause) => self.comp_clause(comp_clause),
+            ast::Expr::Schema(schema_expr) => self.schema_expr(schema_expr),
+            ast::Expr::Config(config_expr) => {
+                for item in &config_expr.items {
+                    self.config_entry(item, &CompletionContext::Name);
+                }
+            }
+            ast::Expr::Check(check_expr) => self.check_expr(check_expr),
+            ast::Expr::Lambda(lambda_expr) => {
+                if let Some(args) = &lambda_expr.args {
+                    for default in args.node.defaults.iter().flatten() {
+                        self.expr(default);
+                    }
+                }
+                self.stmts(&lambda_expr.body);
+            }
+            ast::Expr::Subscript(subscript) => {
+                self.expr(&subscript.value);
+                self.opt_expr(&subscript.index);
+                self.opt_expr(&subscript.lower);
+                self.opt_expr(&subscript.upper);
+                self.opt_expr(&subscript.step);
+            }
+            ast::Expr::Keyword(keyword) => self.opt_expr(&keyword.value),
+            ast::Expr::Compare(compare) => {
+                self.expr(&compare.left);
+                self.exprs(&compare.comparators);
+            }
+            ast::Expr::JoinedString(joined_string) => self.exprs(&joined_string.values),
+            ast::Expr::FormattedValue(formatted_value) => self.expr(&formatted_value.value),
+            ast::Expr::Arguments(_)
+            | ast::Expr::NumberLit(_)
+            | ast::Expr::StringLit(_)
+            | ast::Expr::NameConstantLit(_)
+            | ast::Expr::Missing(_) => {}
+        }
+    }
+
+    fn call_expr(&mut self, call_expr: &ast::CallExpr) {
+        self.expr(&call_expr.func);
+        self.exprs(&call_expr.args);
+        for keyword in &call_expr.keywords {
+            self.opt_expr(&keyword.node.value);
+        }
+    }
+
+    fn check_expr(&mut self, check_expr: &ast::CheckExpr) {
+        self.expr(&check_expr.test);
+        self.opt_expr(&check_expr.if_cond);
+        self.opt_expr(&check_expr.msg);
+    }
+
+    fn comp_clause(&mut self, comp_clause: &ast::CompClause) {
+        self.expr(&comp_clause.iter);
+        self.exprs(&comp_clause.ifs);
+    }
+
+    fn schema_expr(&mut self, schema_expr: &ast::SchemaExpr) {
+        self.identifier(&schema_expr.name);
+        self.exprs(&schema_expr.args);
+        for kwarg in &schema_expr.kwargs {
+            self.opt_expr(&kwarg.node.value);
+        }
+        if schema_expr.config.contains_pos(self.pos) {
+            self.config(
+                &schema_expr.config,
+                CompletionContext::ConfigKey {
+                    schema: schema_expr.name.node.names.clone(),
+                    path: vec![],
+                },
+            );
+        }
+    }
+
+    /// The position in the config is a key position unless it is in an
+    /// entry value.
+    fn config(&mut self, config: &ast::Node<ast::Expr>, ctx: CompletionContext) {
+        match &config.node {
+            ast::Expr::Config(config_expr) => {
+                self.ctx = ctx.clone();
+                for item in &config_expr.items {
+                    self.config_entry(item, &ctx);
+                }
+            }
+            _ => self.expr(config),
+        }
+    }
+
+    fn config_entry(&mut self, entry: &ast::Node<ast::ConfigEntry>, ctx: &CompletionContext) {
+        if !entry.contains_pos(self.pos) {
+            return;
+        }
+        let entry = &entry.node;
+        if let Some(key) = &entry.key {
+            if key.contains_pos(self.pos) {
+                self.ctx = ctx.clone();
+                return;
+            }
+        }
+        if !entry.value.contains_pos(self.pos) {
+            return;
+        }
+        match (&entry.key, &entry.value.node) {
+            // Nested config of the schema attribute, e.g. `Person { base = { | } }`.
+            (Some(key), ast::Expr::Config(_)) => match (&key.node, ctx) {
+                (
+                    ast::Expr::Identifier(identifier),
+                    CompletionContext::ConfigKey { schema, path },
+                ) => {
+                    let mut path = path.clone();
+                    path.extend(identifier.names.iter().cloned());
+                    self.config(
+                        &entry.value,
+                        CompletionContext::ConfigKey {
+                            schema: schema.clone(),
+                            path,
+                        },
+                    );
+                }
+                _ => {
+                    self.ctx = CompletionContext::Name;
+                    self.expr(&entry.value);
+                }
+            },
+            // The key is being typed without the value, e.g. `Person { na| }`.
+            (None, ast::Expr::Identifier(identifier)) if identifier.names.len() == 1 => {
+                self.ctx = ctx.clone();
+            }
+            _ => {
+                self.ctx = CompletionContext::Name;
+                self.expr(&entry.value);
+            }
+        }
+    }
+
+    fn identifier(&mut self, identifier: &ast::Node<ast::Identifier>) {
+        if identifier.contains_pos(self.pos) {
+            self.identifier_names(&identifier.node.names, identifier.column);
+        }
+    }
+
+    /// Complete the members when the position is after the first name of
+    /// the dotted names, e.g. `a.b|`.
+    fn identifier_names(&mut self, names: &[String], column: u64) {
+        let name = names.join(".");
+        let offset = self
+            .pos
+            .column
+            .unwrap_or_default()
+            .saturating_sub(column)
+            .min(name.len() as u64) as usize;
+        let index = name.get(..offset).unwrap_or(&name).matches('.').count();
+        self.ctx = if index > 0 {
+            CompletionContext::Member(names[..index].to_vec())
+        } else {
+            CompletionContext::Name
+        };
+    }
+}
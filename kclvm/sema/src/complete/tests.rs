@@ -0,0 +1,124 @@
+use std::path::Path;
+
+use crate::builtin::system_module::{
+    get_system_member_type, get_system_module_members, STANDARD_SYSTEM_MODULES,
+};
+use crate::complete::{complete, CompletionItem, CompletionKind};
+use kclvm_error::Position;
+use kclvm_parser::load_program;
+
+const FILE: &str = "./src/complete/test_data/complete.k";
+
+fn complete_at(line: u64, column: u64) -> Vec<CompletionItem> {
+    complete_file_at(FILE, line, column)
+}
+
+fn complete_file_at(file: &str, line: u64, column: u64) -> Vec<CompletionItem> {
+    let mut program = load_program(&[file], None).unwrap();
+    complete(
+        &mut program,
+        &Position {
+            filename: file.to_string(),
+            line,
+            column: Some(column),
+        },
+    )
+}
+
+fn labels(items: &[CompletionItem]) -> Vec<&str> {
+    items.iter().map(|item| item.label.as_str()).collect()
+}
+
+fn find<'a>(items: &'a [CompletionItem], label: &str) -> &'a CompletionItem {
+    items
+        .iter()
+        .find(|item| item.label == label)
+        .unwrap_or_else(|| panic!("completion item '{}' not found in {:?}", label, items))
+}
+
+#[test]
+fn test_complete_names() {
+    // name = |person.name
+    let items = complete_at(17, 7);
+    let person = find(&items, "Person");
+    assert_eq!(person.kind, CompletionKind::Schema);
+    assert_eq!(person.ty, "Person");
+    let person = find(&items, "person");
+    assert_eq!(person.kind, CompletionKind::Variable);
+    assert_eq!(person.ty, "Person");
+    assert_eq!(find(&items, "math").kind, CompletionKind::Module);
+    assert_eq!(find(&items, "option").kind, CompletionKind::Function);
+    let schema = find(&items, "schema");
+    assert_eq!(schema.kind, CompletionKind::Keyword);
+    assert!(schema.insert_text.is_some());
+}
+
+#[test]
+fn test_complete_schema_members() {
+    // name = person.|name
+    let items = complete_at(17, 14);
+    assert_eq!(labels(&items), vec!["id", "name", "age", "base"]);
+    let name = find(&items, "name");
+    assert_eq!(name.kind, CompletionKind::Attribute);
+    assert_eq!(name.ty, "str");
+}
+
+#[test]
+fn test_complete_system_module_members() {
+    // n = math.|log(10)
+    let items = complete_at(18, 9);
+    assert!(labels(&items).contains(&"log"));
+    assert!(labels(&items).contains(&"sqrt"));
+    let log = find(&items, "log");
+    assert_eq!(log.kind, CompletionKind::Function);
+    assert_eq!(log.ty, "function");
+}
+
+#[test]
+fn test_system_module_member_types() {
+    for module in STANDARD_SYSTEM_MODULES {
+        for member in get_system_module_members(module) {
+            let ty = get_system_member_type(module, member);
+            assert!(!ty.is_any(), "{}.{} has the any type", module, member);
+        }
+    }
+}
+
+#[test]
+fn test_complete_relative_and_absolute_path() {
+    let absolute = Path::new(FILE).canonicalize().unwrap();
+    let absolute = absolute.to_str().unwrap();
+    let relative_items = complete_at(17, 14);
+    assert_eq!(labels(&relative_items), vec!["id", "name", "age", "base"]);
+    assert_eq!(complete_file_at(absolute, 17, 14), relative_items);
+}
+
+#[test]
+fn test_complete_builtin_names() {
+    // name = |person.name
+    let items = complete_at(17, 7);
+    for name in ["len", "print", "typeof"] {
+        assert_eq!(find(&items, name).kind, CompletionKind::Function);
+    }
+}
+
+#[test]
+fn test_complete_str_members() {
+    // s = name.|lower()
+    let items = complete_at(19, 9);
+    assert!(labels(&items).contains(&"lower"));
+    assert!(labels(&items).contains(&"startswith"));
+}
+
+#[test]
+fn test_complete_config_keys() {
+    // person = Person {
+    //     |name = "Alice"
+    let items = complete_at(12, 4);
+    assert_eq!(labels(&items), vec!["id", "name", "age", "base"]);
+    // base = {
+    //     |id = 1
+    let items = complete_at(14, 8);
+    assert_eq!(labels(&items), vec!["id"]);
+    assert_eq!(find(&items, "id").ty, "int");
+}
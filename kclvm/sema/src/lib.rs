@@ -1,4 +1,5 @@
 pub mod builtin;
+pub mod complete;
 pub mod eval;
 pub mod info;
 pub mod lint;
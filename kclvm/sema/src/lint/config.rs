@@ -0,0 +1,75 @@
+use indexmap::IndexMap;
+use kclvm_config::modfile::KCLModFile;
+use kclvm_error::{Level, WarningKind};
+
+/// The name which refers to all the lints in the lint config and the `# kcl-lint: disable=...` comments.
+pub const ALL_LINTS: &str = "all";
+
+/// The lint config loaded from the lint section of `kcl.mod`, lints are referred by their codes
+/// e.g., `W0411`, their names e.g., `unused_import` or `all`.
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    /// Lints to be enabled, e.g., the lints disabled by default.
+    pub enable: Vec<String>,
+    /// Lints to be disabled.
+    pub disable: Vec<String>,
+    /// The diagnostic level of lints.
+    pub level: IndexMap<String, Level>,
+}
+
+impl LintConfig {
+    /// Load the lint config from the `kcl.mod` file, invalid levels are ignored.
+    pub fn from_mod_file(mod_file: &KCLModFile) -> Self {
+        let lint = match &mod_file.lint {
+            Some(lint) => lint,
+            None => return LintConfig::default(),
+        };
+        let mut level = IndexMap::new();
+        if let Some(levels) = &lint.level {
+            for (name, value) in levels {
+                let value = match value.to_lowercase().as_str() {
+                    "error" => Level::Error,
+                    "warning" => Level::Warning,
+                    "note" => Level::Note,
+                    _ => continue,
+                };
+                level.insert(name.to_string(), value);
+            }
+        }
+        LintConfig {
+            enable: lint.enable.clone().unwrap_or_default(),
+            disable: lint.disable.clone().unwrap_or_default(),
+            level,
+        }
+    }
+
+    /// Whether the lint which reports the warning is enabled, the config of the lint
+    /// code or name takes precedence over the config of `all`.
+    pub fn is_enabled(&self, kind: &WarningKind, enabled_by_default: bool) -> bool {
+        if self.disable.iter().any(|name| is_lint_name(name, kind)) {
+            false
+        } else if self.enable.iter().any(|name| is_lint_name(name, kind)) {
+            true
+        } else if self.disable.iter().any(|name| name == ALL_LINTS) {
+            false
+        } else if self.enable.iter().any(|name| name == ALL_LINTS) {
+            true
+        } else {
+            enabled_by_default
+        }
+    }
+
+    /// Get the diagnostic level of the lint which reports the warning.
+    pub fn level(&self, kind: &WarningKind, default_level: Level) -> Level {
+        self.level
+            .iter()
+            .find(|(name, _)| is_lint_name(name, kind))
+            .or_else(|| self.level.iter().find(|(name, _)| *name == ALL_LINTS))
+            .map_or(default_level, |(_, level)| *level)
+    }
+}
+
+/// Whether the name is the code or the name of the lint which reports the warning.
+pub(crate) fn is_lint_name(name: &str, kind: &WarningKind) -> bool {
+    name.eq_ignore_ascii_case(kind.code()) || name.eq_ignore_ascii_case(kind.lint_name())
+}
@@ -30,6 +30,9 @@ pub struct Lint {
 
     // Suggest methods to fix this problem
     pub note: Option<&'static str>,
+
+    /// Whether the lint is enabled by default, which can be changed in the lint section of `kcl.mod`.
+    pub enabled: bool,
 }
 
 pub type LintArray = Vec<&'static Lint>;
@@ -1,10 +1,13 @@
+use crate::info::is_private_field;
 use crate::lint::lint::{Lint, LintArray, LintContext};
 use crate::lint::lintpass::LintPass;
-use crate::resolver::scope::Scope;
+use crate::resolver::pos::GetPos;
+use crate::resolver::scope::{Scope, ScopeKind, ScopeObject};
+use crate::ty::TypeKind;
 use crate::{declare_lint_pass, resolver::scope::ScopeObjectKind};
 use indexmap::IndexSet;
 use kclvm_ast::ast;
-use kclvm_error::{Handler, Level, Message, Position, Style, WarningKind};
+use kclvm_error::{Handler, Level, Message, Position, Replacement, Style, Suggestion, WarningKind};
 
 /// The 'import_position' lint detects import statements that are not declared at the top of file.
 /// ### Example
@@ -23,8 +26,9 @@ pub static IMPORT_POSITION: &Lint = &Lint {
     name: stringify!("IMPORT_POSITION"),
     level: Level::Warning,
     desc: "Check for importstmt that are not defined at the top of file",
-    code: "W0413",
+    code: WarningKind::ImportPositionWarning.code(),
     note: Some("Consider moving tihs statement to the top of the file"),
+    enabled: true,
 };
 
 declare_lint_pass!(ImportPosition => [IMPORT_POSITION]);
@@ -32,20 +36,32 @@ declare_lint_pass!(ImportPosition => [IMPORT_POSITION]);
 impl LintPass for ImportPosition {
     fn check_module(&mut self, handler: &mut Handler, ctx: &mut LintContext, module: &ast::Module) {
         let mut first_non_importstmt = std::u64::MAX;
+        // The line to move the misplaced import statements to, which is the line after the
+        // leading import statements or the line of the first non-import statement.
+        let mut insert_line = 1;
+        let mut last_leading_import_line = None;
         for stmt in &module.body {
             match &stmt.node {
-                ast::Stmt::Import(_import_stmt) => {}
+                ast::Stmt::Import(_import_stmt) => {
+                    if first_non_importstmt == std::u64::MAX {
+                        last_leading_import_line = Some(stmt.end_line);
+                    }
+                }
                 _ => {
                     if stmt.line < first_non_importstmt {
-                        first_non_importstmt = stmt.line
+                        first_non_importstmt = stmt.line;
+                        insert_line = match last_leading_import_line {
+                            Some(line) => line + 1,
+                            None => stmt_start_line(stmt),
+                        };
                     }
                 }
             }
         }
         for stmt in &module.body {
-            if let ast::Stmt::Import(_import_stmt) = &stmt.node {
+            if let ast::Stmt::Import(import_stmt) = &stmt.node {
                 if stmt.line > first_non_importstmt {
-                    handler.add_warning(
+                    handler.add_warning_with_suggestions(
                         WarningKind::ImportPositionWarning,
                         &[Message {
                             pos: Position {
@@ -61,6 +77,18 @@ impl LintPass for ImportPosition {
                                 "Consider moving tihs statement to the top of the file".to_string(),
                             ),
                         }],
+                        vec![Suggestion {
+                            message: "Move the import statement to the top of the module"
+                                .to_string(),
+                            replacements: vec![
+                                Replacement::insert_before_line(
+                                    &ctx.filename,
+                                    insert_line,
+                                    &format!("{}\n", import_stmt_source(import_stmt)),
+                                ),
+                                Replacement::remove_lines(&ctx.filename, stmt.line, stmt.end_line),
+                            ],
+                        }],
                     );
                 }
             }
@@ -68,6 +96,27 @@ impl LintPass for ImportPosition {
     }
 }
 
+/// Returns the first line of the statement including the decorators of schemas and rules.
+fn stmt_start_line(stmt: &ast::NodeRef<ast::Stmt>) -> u64 {
+    let decorators = match &stmt.node {
+        ast::Stmt::Schema(schema_stmt) => &schema_stmt.decorators,
+        ast::Stmt::Rule(rule_stmt) => &rule_stmt.decorators,
+        _ => return stmt.line,
+    };
+    decorators
+        .iter()
+        .map(|decorator| decorator.line)
+        .fold(stmt.line, u64::min)
+}
+
+/// Returns the source code of the import statement.
+fn import_stmt_source(import_stmt: &ast::ImportStmt) -> String {
+    match &import_stmt.asname {
+        Some(asname) => format!("import {} as {}", import_stmt.rawpath, asname),
+        None => format!("import {}", import_stmt.rawpath),
+    }
+}
+
 /// The 'unused_import' lint detects import statements that are declared but not used.
 ///
 /// ### Example
@@ -86,8 +135,9 @@ pub static UNUSED_IMPORT: &Lint = &Lint {
     name: stringify!("UNUSED_IMPORT"),
     level: Level::Warning,
     desc: "Check for unused importstmt",
-    code: "W0411",
+    code: WarningKind::UnusedImportWarning.code(),
     note: Some("Consider removing this statement"),
+    enabled: true,
 };
 
 declare_lint_pass!(UnusedImport => [UNUSED_IMPORT]);
@@ -98,7 +148,7 @@ impl LintPass for UnusedImport {
         for (_, scope_obj) in scope_objs {
             let scope_obj = scope_obj.borrow();
             if scope_obj.kind == ScopeObjectKind::Module && scope_obj.used == false {
-                handler.add_warning(
+                handler.add_warning_with_suggestions(
                     WarningKind::UnusedImportWarning,
                     &[Message {
                         pos: Position {
@@ -110,6 +160,14 @@ impl LintPass for UnusedImport {
                         message: format!("Module '{}' imported but unused", scope_obj.name),
                         note: Some("Consider removing this statement".to_string()),
                     }],
+                    vec![Suggestion {
+                        message: "Remove the unused import statement".to_string(),
+                        replacements: vec![Replacement::remove_lines(
+                            &scope_obj.start.filename,
+                            scope_obj.start.line,
+                            scope_obj.end.line,
+                        )],
+                    }],
                 );
             }
         }
@@ -134,8 +192,9 @@ pub static REIMPORT: &Lint = &Lint {
     name: stringify!("REIMPORT"),
     level: Level::Warning,
     desc: "Check for deplicate importstmt",
-    code: "W0404",
+    code: WarningKind::ReimportWarning.code(),
     note: Some("Consider removing this statement"),
+    enabled: true,
 };
 
 declare_lint_pass!(ReImport => [REIMPORT]);
@@ -146,7 +205,7 @@ impl LintPass for ReImport {
         for stmt in &module.body {
             if let ast::Stmt::Import(import_stmt) = &stmt.node {
                 if import_names.contains(&import_stmt.path) {
-                    handler.add_warning(
+                    handler.add_warning_with_suggestions(
                         WarningKind::ReimportWarning,
                         &[Message {
                             pos: Position {
@@ -161,6 +220,14 @@ impl LintPass for ReImport {
                             ),
                             note: Some("Consider removing this statement".to_string()),
                         }],
+                        vec![Suggestion {
+                            message: "Remove the duplicate import statement".to_string(),
+                            replacements: vec![Replacement::remove_lines(
+                                &ctx.filename,
+                                stmt.line,
+                                stmt.end_line,
+                            )],
+                        }],
                     );
                 } else {
                     import_names.insert(import_stmt.path.clone());
@@ -169,3 +236,557 @@ impl LintPass for ReImport {
         }
     }
 }
+
+/// The 'unused_variable' lint detects private variables in the package and local variables
+/// in the lambda expression that are declared but not used.
+///
+/// ### Example
+///
+/// ```kcl
+/// _name = "Alice"
+///
+/// f = lambda x: int {
+///     y = x + 1
+///     x
+/// }
+/// ```
+/// ### Explanation
+///
+/// Private variables are not in the output and local variables can't be referred outside the
+/// lambda expression, so the unused ones are useless.
+pub static UNUSED_VARIABLE: &Lint = &Lint {
+    name: stringify!("UNUSED_VARIABLE"),
+    level: Level::Warning,
+    desc: "Check for unused private and local variables",
+    code: WarningKind::UnusedVariableWarning.code(),
+    note: Some("Consider removing this variable"),
+    enabled: true,
+};
+
+/// The 'unused_private_attr' lint detects private schema attributes that are declared but not used.
+///
+/// ### Example
+///
+/// ```kcl
+/// schema Person:
+///     _age: int = 1
+///     name: str
+/// ```
+/// ### Explanation
+///
+/// Private schema attributes are not in the output, so the unused ones are useless. The schemas
+/// inherited by other schemas and mixins are skipped, the attributes may be used in the sub schemas.
+pub static UNUSED_PRIVATE_ATTR: &Lint = &Lint {
+    name: stringify!("UNUSED_PRIVATE_ATTR"),
+    level: Level::Warning,
+    desc: "Check for unused private schema attributes",
+    code: WarningKind::UnusedPrivateAttrWarning.code(),
+    note: Some("Consider removing this attribute"),
+    enabled: true,
+};
+
+declare_lint_pass!(UnusedVariable => [UNUSED_VARIABLE, UNUSED_PRIVATE_ATTR]);
+
+impl LintPass for UnusedVariable {
+    fn check_scope(&mut self, handler: &mut Handler, _ctx: &mut LintContext, scope: &Scope) {
+        match &scope.kind {
+            ScopeKind::Package => {
+                for (name, scope_obj) in &scope.elems {
+                    let scope_obj = scope_obj.borrow();
+                    if scope_obj.kind == ScopeObjectKind::Variable
+                        && is_private_field(name)
+                        && !scope_obj.used
+                    {
+                        add_unused_variable_warning(handler, &scope_obj);
+                    }
+                }
+                // Schemas inherited by other schemas in the package.
+                let mut base_names = IndexSet::<String>::new();
+                for (_, scope_obj) in &scope.elems {
+                    if let TypeKind::Schema(schema_ty) = &scope_obj.borrow().ty.kind {
+                        if let Some(base) = &schema_ty.base {
+                            base_names.insert(base.name.clone());
+                        }
+                    }
+                }
+                for child in &scope.children {
+                    let child = child.borrow();
+                    let schema_name = match &child.kind {
+                        ScopeKind::Schema(name) => name,
+                        _ => continue,
+                    };
+                    let is_mixin_or_protocol = match scope.elems.get(schema_name) {
+                        Some(scope_obj) => match &scope_obj.borrow().ty.kind {
+                            TypeKind::Schema(schema_ty) => {
+                                schema_ty.is_mixin || schema_ty.is_protocol
+                            }
+                            _ => true,
+                        },
+                        None => true,
+                    };
+                    if is_mixin_or_protocol || base_names.contains(schema_name) {
+                        continue;
+                    }
+                    for (name, scope_obj) in &child.elems {
+                        let scope_obj = scope_obj.borrow();
+                        if scope_obj.kind == ScopeObjectKind::Variable
+                            && is_private_field(name)
+                            && !scope_obj.used
+                        {
+                            handler.add_warning(
+                                WarningKind::UnusedPrivateAttrWarning,
+                                &[Message {
+                                    pos: scope_obj.start.clone(),
+                                    style: Style::LineAndColumn,
+                                    message: format!(
+                                        "Private attribute '{}' of schema '{}' is unused",
+                                        name, schema_name
+                                    ),
+                                    note: Some("Consider removing this attribute".to_string()),
+                                }],
+                            );
+                        }
+                    }
+                }
+            }
+            ScopeKind::Lambda => {
+                for (_, scope_obj) in &scope.elems {
+                    let scope_obj = scope_obj.borrow();
+                    if scope_obj.kind == ScopeObjectKind::Variable && !scope_obj.used {
+                        add_unused_variable_warning(handler, &scope_obj);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn add_unused_variable_warning(handler: &mut Handler, scope_obj: &ScopeObject) {
+    handler.add_warning(
+        WarningKind::UnusedVariableWarning,
+        &[Message {
+            pos: scope_obj.start.clone(),
+            style: Style::LineAndColumn,
+            message: format!("Variable '{}' is assigned but never used", scope_obj.name),
+            note: Some("Consider removing this variable".to_string()),
+        }],
+    );
+}
+
+/// The 'shadowed_name' lint detects the lambda parameters, lambda local variables and loop
+/// variables which shadow the names defined in the outer scopes. It is disabled by default.
+///
+/// ### Example
+///
+/// ```kcl
+/// x = 1
+/// y = [x * 2 for x in [1, 2, 3]]
+/// ```
+/// ### Explanation
+///
+/// The outer names can't be referred in the inner scope once they are shadowed, which is
+/// confusing for code readers.
+pub static SHADOWED_NAME: &Lint = &Lint {
+    name: stringify!("SHADOWED_NAME"),
+    level: Level::Warning,
+    desc: "Check for names which shadow the names defined in the outer scopes",
+    code: WarningKind::ShadowedNameWarning.code(),
+    note: Some("Consider renaming this variable"),
+    enabled: false,
+};
+
+declare_lint_pass!(ShadowedName => [SHADOWED_NAME]);
+
+impl LintPass for ShadowedName {
+    fn check_scope(&mut self, handler: &mut Handler, _ctx: &mut LintContext, scope: &Scope) {
+        if !matches!(scope.kind, ScopeKind::Lambda | ScopeKind::Loop) {
+            return;
+        }
+        let parent = match scope.parent.as_ref().and_then(|parent| parent.upgrade()) {
+            Some(parent) => parent,
+            None => return,
+        };
+        for (name, scope_obj) in &scope.elems {
+            if parent.borrow().lookup(name).is_some() {
+                let scope_obj = scope_obj.borrow();
+                handler.add_warning(
+                    WarningKind::ShadowedNameWarning,
+                    &[Message {
+                        pos: scope_obj.start.clone(),
+                        style: Style::LineAndColumn,
+                        message: format!(
+                            "Name '{}' shadows the name defined in the outer scope",
+                            name
+                        ),
+                        note: Some("Consider renaming this variable".to_string()),
+                    }],
+                );
+            }
+        }
+    }
+}
+
+/// The 'naming_convention' lint detects schema names that are not in CamelCase and schema
+/// attribute names that are not in snake_case. It is disabled by default.
+///
+/// ### Example
+///
+/// ```kcl
+/// schema person:
+///     firstName: str
+/// ```
+/// ### Explanation
+///
+/// According to the KCL code style conventions, schema names are in CamelCase and schema
+/// attribute names are in snake_case.
+pub static NAMING_CONVENTION: &Lint = &Lint {
+    name: stringify!("NAMING_CONVENTION"),
+    level: Level::Warning,
+    desc: "Check for schema and schema attribute names that break the naming conventions",
+    code: WarningKind::NamingConventionWarning.code(),
+    note: Some("Consider renaming it"),
+    enabled: false,
+};
+
+declare_lint_pass!(NamingConvention => [NAMING_CONVENTION]);
+
+impl LintPass for NamingConvention {
+    fn check_schema_stmt(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        schema_stmt: &ast::SchemaStmt,
+    ) {
+        if !is_camel_case(&schema_stmt.name.node) {
+            add_naming_warning(
+                handler,
+                schema_stmt.name.get_pos(),
+                format!(
+                    "Schema name '{}' is not in CamelCase",
+                    schema_stmt.name.node
+                ),
+            );
+        }
+        for stmt in &schema_stmt.body {
+            if let ast::Stmt::SchemaAttr(schema_attr) = &stmt.node {
+                if !is_snake_case(&schema_attr.name.node) {
+                    add_naming_warning(
+                        handler,
+                        schema_attr.name.get_pos(),
+                        format!(
+                            "Schema attribute name '{}' is not in snake_case",
+                            schema_attr.name.node
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_rule_stmt(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        rule_stmt: &ast::RuleStmt,
+    ) {
+        if !is_camel_case(&rule_stmt.name.node) {
+            add_naming_warning(
+                handler,
+                rule_stmt.name.get_pos(),
+                format!("Rule name '{}' is not in CamelCase", rule_stmt.name.node),
+            );
+        }
+    }
+}
+
+fn add_naming_warning(handler: &mut Handler, pos: Position, message: String) {
+    handler.add_warning(
+        WarningKind::NamingConventionWarning,
+        &[Message {
+            pos,
+            style: Style::LineAndColumn,
+            message,
+            note: Some("Consider renaming it".to_string()),
+        }],
+    );
+}
+
+/// Whether the name is in CamelCase e.g., `Person` and `_PersonBase`.
+fn is_camel_case(name: &str) -> bool {
+    let name = name.trim_start_matches('_');
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Whether the name is in snake_case e.g., `name`, `_full_name` and `$if`.
+fn is_snake_case(name: &str) -> bool {
+    let name = name.trim_start_matches(|c| c == '_' || c == '$');
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// The 'private_access' lint detects private variables and attributes which are accessed
+/// through the imported packages outside their packages. It is disabled by default.
+///
+/// ### Example
+///
+/// ```kcl
+/// import pkg
+///
+/// name = pkg._name
+/// ```
+/// ### Explanation
+///
+/// Private variables and attributes are internal details of the package and schema, which
+/// should not be accessed outside.
+pub static PRIVATE_ACCESS: &Lint = &Lint {
+    name: stringify!("PRIVATE_ACCESS"),
+    level: Level::Warning,
+    desc: "Check for private variables and attributes accessed outside",
+    code: WarningKind::PrivateAccessWarning.code(),
+    note: Some("Consider making it public or not accessing it"),
+    enabled: false,
+};
+
+declare_lint_pass!(PrivateAccess => [PRIVATE_ACCESS]);
+
+impl LintPass for PrivateAccess {
+    fn check_identifier(
+        &mut self,
+        handler: &mut Handler,
+        ctx: &mut LintContext,
+        id: &ast::Identifier,
+    ) {
+        // Only the names accessed through the imported packages e.g., `pkg._name` are
+        // outside their packages, the package path of the identifier is set in the pre-process.
+        if !matches!(id.ctx, ast::ExprContext::Load) || id.pkgpath.is_empty() {
+            return;
+        }
+        check_private_names(handler, &ctx.start_pos, &id.names, 1);
+    }
+
+    fn check_selector_expr(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        selector_expr: &ast::SelectorExpr,
+    ) {
+        if !matches!(selector_expr.ctx, ast::ExprContext::Load) {
+            return;
+        }
+        match &selector_expr.value.node {
+            ast::Expr::Identifier(id) if !id.pkgpath.is_empty() => check_private_names(
+                handler,
+                &selector_expr.attr.get_pos(),
+                &selector_expr.attr.node.names,
+                0,
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Report the private names from the `skip` index of the dotted `names` starting at `pos`,
+/// the diagnostics are at the positions of the private names.
+fn check_private_names(handler: &mut Handler, pos: &Position, names: &[String], skip: usize) {
+    let mut column = pos.column.unwrap_or_default();
+    for (i, name) in names.iter().enumerate() {
+        if i >= skip && is_private_field(name) {
+            add_private_access_warning(
+                handler,
+                Position {
+                    column: Some(column),
+                    ..pos.clone()
+                },
+                name,
+            );
+        }
+        // The name and the following dot.
+        column += name.chars().count() as u64 + 1;
+    }
+}
+
+fn add_private_access_warning(handler: &mut Handler, pos: Position, name: &str) {
+    handler.add_warning(
+        WarningKind::PrivateAccessWarning,
+        &[Message {
+            pos,
+            style: Style::LineAndColumn,
+            message: format!(
+                "Private variable or attribute '{}' is accessed outside",
+                name
+            ),
+            note: Some("Consider making it public or not accessing it".to_string()),
+        }],
+    );
+}
+
+/// The 'unreachable_branch' lint detects the branches of `if` statements and expressions which
+/// are never executed because the condition is a constant.
+///
+/// ### Example
+///
+/// ```kcl
+/// if False:
+///     a = 1
+/// ```
+/// ### Explanation
+///
+/// The unreachable branches are dead code, which are useless and confusing.
+pub static UNREACHABLE_BRANCH: &Lint = &Lint {
+    name: stringify!("UNREACHABLE_BRANCH"),
+    level: Level::Warning,
+    desc: "Check for unreachable branches of if statements and expressions",
+    code: WarningKind::UnreachableBranchWarning.code(),
+    note: Some("Consider removing the unreachable branch"),
+    enabled: true,
+};
+
+declare_lint_pass!(UnreachableBranch => [UNREACHABLE_BRANCH]);
+
+impl LintPass for UnreachableBranch {
+    fn check_if_stmt(
+        &mut self,
+        handler: &mut Handler,
+        ctx: &mut LintContext,
+        if_stmt: &ast::IfStmt,
+    ) {
+        match constant_truth(&if_stmt.cond.node) {
+            Some(true) => {
+                if let Some(stmt) = if_stmt.orelse.first() {
+                    add_unreachable_branch_warning(handler, stmt.get_pos(), true);
+                }
+            }
+            Some(false) => {
+                add_unreachable_branch_warning(handler, ctx.start_pos.clone(), false);
+            }
+            None => {}
+        }
+    }
+
+    fn check_if_expr(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        if_expr: &ast::IfExpr,
+    ) {
+        match constant_truth(&if_expr.cond.node) {
+            Some(true) => add_unreachable_branch_warning(handler, if_expr.orelse.get_pos(), true),
+            Some(false) => add_unreachable_branch_warning(handler, if_expr.body.get_pos(), false),
+            None => {}
+        }
+    }
+}
+
+fn add_unreachable_branch_warning(handler: &mut Handler, pos: Position, cond: bool) {
+    let message = if cond {
+        "The else branch is unreachable because the condition is always true"
+    } else {
+        "The if branch is unreachable because the condition is always false"
+    };
+    handler.add_warning(
+        WarningKind::UnreachableBranchWarning,
+        &[Message {
+            pos,
+            style: Style::LineAndColumn,
+            message: message.to_string(),
+            note: Some("Consider removing the unreachable branch".to_string()),
+        }],
+    );
+}
+
+/// Returns the truth value of the constant condition expression, and None when it
+/// is not a constant.
+fn constant_truth(expr: &ast::Expr) -> Option<bool> {
+    match expr {
+        ast::Expr::NameConstantLit(name_constant_lit) => {
+            Some(matches!(name_constant_lit.value, ast::NameConstant::True))
+        }
+        ast::Expr::NumberLit(number_lit) => Some(match number_lit.value {
+            ast::NumberLitValue::Int(value) => value != 0,
+            ast::NumberLitValue::Float(value) => value != 0.0,
+        }),
+        ast::Expr::StringLit(string_lit) => Some(!string_lit.value.is_empty()),
+        ast::Expr::Paren(paren_expr) => constant_truth(&paren_expr.expr.node),
+        _ => None,
+    }
+}
+
+/// The 'redundant_type_annotation' lint detects type annotations of immutable variables
+/// which are the same as the types of their literal values. It is disabled by default.
+///
+/// ### Example
+///
+/// ```kcl
+/// name: str = "Alice"
+/// ```
+/// ### Explanation
+///
+/// The types of literal values are inferred, so the annotations are redundant. The private
+/// variables are skipped because the annotations also check the values assigned later.
+pub static REDUNDANT_TYPE_ANNOTATION: &Lint = &Lint {
+    name: stringify!("REDUNDANT_TYPE_ANNOTATION"),
+    level: Level::Warning,
+    desc: "Check for type annotations which are the same as the types of literal values",
+    code: WarningKind::RedundantTypeAnnotationWarning.code(),
+    note: Some("Consider removing the type annotation"),
+    enabled: false,
+};
+
+declare_lint_pass!(RedundantTypeAnnotation => [REDUNDANT_TYPE_ANNOTATION]);
+
+impl LintPass for RedundantTypeAnnotation {
+    fn check_assign_stmt(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        assign_stmt: &ast::AssignStmt,
+    ) {
+        let type_annotation = match &assign_stmt.type_annotation {
+            Some(type_annotation) => type_annotation,
+            None => return,
+        };
+        if assign_stmt
+            .targets
+            .iter()
+            .any(|target| target.node.names.iter().any(|name| is_private_field(name)))
+        {
+            return;
+        }
+        let literal_ty = match &assign_stmt.value.node {
+            ast::Expr::NumberLit(number_lit) if number_lit.binary_suffix.is_none() => {
+                match number_lit.value {
+                    ast::NumberLitValue::Int(_) => "int",
+                    ast::NumberLitValue::Float(_) => "float",
+                }
+            }
+            ast::Expr::StringLit(_) => "str",
+            ast::Expr::NameConstantLit(name_constant_lit)
+                if matches!(
+                    name_constant_lit.value,
+                    ast::NameConstant::True | ast::NameConstant::False
+                ) =>
+            {
+                "bool"
+            }
+            _ => return,
+        };
+        if type_annotation.node == literal_ty {
+            handler.add_warning(
+                WarningKind::RedundantTypeAnnotationWarning,
+                &[Message {
+                    pos: type_annotation.get_pos(),
+                    style: Style::LineAndColumn,
+                    message: format!(
+                        "Type annotation '{}' is redundant for the {} literal",
+                        type_annotation.node, literal_ty
+                    ),
+                    note: Some("Consider removing the type annotation".to_string()),
+                }],
+            );
+        }
+    }
+}
@@ -10,6 +10,10 @@
 //! lints_def.rs: Defined the various lints and the corresponding lintpasses implementation
 //! lintpass.rs: Definition of `Lintpass`
 //! lint.rs: Definition of `Lint`
+//! config.rs: Definition of `LintConfig`, which enables, disables lints and changes their levels
+//! according to the lint section of `kcl.mod`
+//! suppression.rs: Definition of `Suppressions`, which disables lints for a line or a file
+//! according to the `# kcl-lint: disable=...` comments
 //!               
 //! Steps to define a new lint:
 //! 1. Define a static instance of the `Lint` structure in lints_def.rs，e.g.,
@@ -71,24 +75,53 @@
 //! 6. If new `check_*` method was added in step 4, it needs to override the walk_* method in Linter.
 //! In addition to calling the self.pass.check_* function, the original walk method in MutSelfWalker
 //! should be copied here so that it can continue to traverse the child nodes.
+//!
+//! Lints can be configured in the lint section of `kcl.mod` with their codes or names, e.g.,
+//!
+//!    ```toml
+//!    [lint]
+//!    enable = ["naming_convention"]
+//!    disable = ["W0411"]
+//!
+//!    [lint.level]
+//!    reimport = "error"
+//!    ```
+//!
+//! where the `error` level only takes effect in the lint tool, otherwise the lints are reported as
+//! warnings and never fail the compilation. Lints can also be disabled for a line or a file
+//! (the comments before the first statement) by the comments, e.g.,
+//!
+//!    ```kcl
+//!    import foo  # kcl-lint: disable=W0411
+//!    ```
 
 use crate::resolver::pos::GetPos;
 use crate::resolver::{scope::Scope, Resolver};
-use kclvm_error::{Handler, Position};
+use indexmap::IndexMap;
+use kclvm_error::{DiagnosticId, Handler, Level, Position};
 mod combinedlintpass;
+mod config;
 mod lint;
 mod lintpass;
 mod lints_def;
+mod suppression;
 use kclvm_ast::ast;
 use kclvm_ast::walker::MutSelfWalker;
 
-pub use self::{combinedlintpass::CombinedLintPass, lint::LintContext, lintpass::LintPass};
+pub use self::{
+    combinedlintpass::CombinedLintPass, config::LintConfig, lint::LintContext, lintpass::LintPass,
+    suppression::Suppressions,
+};
 
 /// The struct `Linter` is used to traverse the AST and call the `check_*` method defined in `CombinedLintPass`.
 pub struct Linter<T: LintPass> {
     pub pass: T,
     pub handler: Handler,
     pub ctx: LintContext,
+    /// The lint config loaded from `kcl.mod`.
+    pub config: LintConfig,
+    /// The lint suppressions of each file.
+    pub suppressions: IndexMap<String, Suppressions>,
 }
 
 impl LintContext {
@@ -107,18 +140,62 @@ impl Linter<CombinedLintPass> {
             pass: CombinedLintPass::new(),
             handler: Handler::default(),
             ctx: LintContext::dummy_ctx(),
+            config: LintConfig::default(),
+            suppressions: IndexMap::default(),
         }
     }
     pub fn walk_scope(&mut self, scope: &Scope) {
         self.pass
             .check_scope(&mut self.handler, &mut self.ctx, scope);
     }
+
+    /// Remove the diagnostics of lints disabled by the lint config or the suppression comments,
+    /// and set the diagnostic levels configured in the lint config, the `error` level is
+    /// reported as the warning unless `allow_error_level` is set.
+    pub fn apply_config(&mut self, allow_error_level: bool) {
+        let lints = CombinedLintPass::get_lints();
+        let diagnostics = std::mem::take(&mut self.handler.diagnostics);
+        self.handler.diagnostics = diagnostics
+            .into_iter()
+            .filter_map(|mut diag| {
+                let kind = match &diag.code {
+                    Some(DiagnosticId::Warning(kind)) => kind.clone(),
+                    _ => return Some(diag),
+                };
+                let lint = lints.iter().find(|lint| lint.code == kind.code());
+                if !self
+                    .config
+                    .is_enabled(&kind, lint.map_or(true, |lint| lint.enabled))
+                {
+                    return None;
+                }
+                if let Some(msg) = diag.messages.first() {
+                    if let Some(suppressions) = self.suppressions.get(&msg.pos.filename) {
+                        if suppressions.is_suppressed(&kind, msg.pos.line) {
+                            return None;
+                        }
+                    }
+                }
+                diag.level = match self
+                    .config
+                    .level(&kind, lint.map_or(diag.level, |lint| lint.level))
+                {
+                    Level::Error if !allow_error_level => Level::Warning,
+                    level => level,
+                };
+                Some(diag)
+            })
+            .collect();
+    }
 }
 
 impl Resolver<'_> {
     /// Iterate the module and run lint checks, generating diagnostics and save them in `lint.handler`
     pub fn lint_check_module(&mut self, module: &ast::Module) {
         self.linter.ctx.filename = module.filename.clone();
+        self.linter
+            .suppressions
+            .insert(module.filename.clone(), Suppressions::from_module(module));
         self.linter.walk_module(module);
     }
     /// Recursively iterate the scope and its child scope, run lint checks, generating diagnostics and save them in `lint.handler`
@@ -197,6 +274,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         self.walk_schema_expr(&unification_stmt.value.node);
     }
     fn walk_assign_stmt(&mut self, assign_stmt: &ast::AssignStmt) {
+        self.pass
+            .check_assign_stmt(&mut self.handler, &mut self.ctx, assign_stmt);
         for target in &assign_stmt.targets {
             set_pos!(self, &target);
             self.walk_identifier(&target.node)
@@ -217,6 +296,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         walk_set_if!(self, walk_expr, assert_stmt.msg);
     }
     fn walk_if_stmt(&mut self, if_stmt: &ast::IfStmt) {
+        self.pass
+            .check_if_stmt(&mut self.handler, &mut self.ctx, if_stmt);
         set_pos!(self, &if_stmt.cond);
         self.walk_expr(&if_stmt.cond.node);
         walk_set_list!(self, walk_stmt, if_stmt.body);
@@ -231,6 +312,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         walk_set_if!(self, walk_expr, schema_attr.value);
     }
     fn walk_schema_stmt(&mut self, schema_stmt: &ast::SchemaStmt) {
+        self.pass
+            .check_schema_stmt(&mut self.handler, &mut self.ctx, schema_stmt);
         walk_set_if!(self, walk_identifier, schema_stmt.parent_name);
         walk_set_if!(self, walk_identifier, schema_stmt.for_host_name);
         walk_set_if!(self, walk_arguments, schema_stmt.args);
@@ -244,6 +327,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         walk_set_list!(self, walk_stmt, schema_stmt.body);
     }
     fn walk_rule_stmt(&mut self, rule_stmt: &ast::RuleStmt) {
+        self.pass
+            .check_rule_stmt(&mut self.handler, &mut self.ctx, rule_stmt);
         walk_set_list!(self, walk_identifier, rule_stmt.parent_rules);
         walk_set_list!(self, walk_call_expr, rule_stmt.decorators);
         walk_set_list!(self, walk_check_expr, rule_stmt.checks);
@@ -259,6 +344,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         walk_set_if!(self, walk_expr, quant_expr.if_cond);
     }
     fn walk_if_expr(&mut self, if_expr: &ast::IfExpr) {
+        self.pass
+            .check_if_expr(&mut self.handler, &mut self.ctx, if_expr);
         set_pos!(self, &if_expr.cond);
         self.walk_expr(&if_expr.cond.node);
         set_pos!(self, &if_expr.body);
@@ -277,6 +364,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         self.walk_expr(&binary_expr.right.node);
     }
     fn walk_selector_expr(&mut self, selector_expr: &ast::SelectorExpr) {
+        self.pass
+            .check_selector_expr(&mut self.handler, &mut self.ctx, selector_expr);
         set_pos!(self, &selector_expr.value);
         self.walk_expr(&selector_expr.value.node);
         set_pos!(self, &selector_expr.attr);
@@ -391,8 +480,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         walk_set_list!(self, walk_expr, compare.comparators);
     }
     fn walk_identifier(&mut self, identifier: &ast::Identifier) {
-        // Nothing to do.
-        let _ = identifier;
+        self.pass
+            .check_identifier(&mut self.handler, &mut self.ctx, identifier);
     }
     fn walk_number_lit(&mut self, number_lit: &ast::NumberLit) {
         let _ = number_lit;
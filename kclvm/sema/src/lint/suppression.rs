@@ -0,0 +1,57 @@
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_error::WarningKind;
+
+use crate::lint::config::{is_lint_name, ALL_LINTS};
+
+/// The prefix of comments which disable lints, e.g., `# kcl-lint: disable=W0411,reimport`.
+const DISABLE_COMMENT_PREFIX: &str = "kcl-lint: disable=";
+
+/// Lints disabled by the `# kcl-lint: disable=...` comments in a module. The comments before
+/// the first statement disable lints for the whole file, and the other comments disable lints
+/// for the line where they are.
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    /// Lints disabled for the whole file.
+    pub file: Vec<String>,
+    /// Lints disabled for the line.
+    pub lines: IndexMap<u64, Vec<String>>,
+}
+
+impl Suppressions {
+    /// Collect the suppressions from the module comments.
+    pub fn from_module(module: &ast::Module) -> Self {
+        let first_stmt_line = module.body.first().map_or(u64::MAX, |stmt| stmt.line);
+        let mut suppressions = Suppressions::default();
+        for comment in &module.comments {
+            let text = comment.node.text.trim_start_matches('#').trim();
+            let names = match text.strip_prefix(DISABLE_COMMENT_PREFIX) {
+                Some(names) => names
+                    .split(',')
+                    .map(|name| name.trim().to_string())
+                    .filter(|name| !name.is_empty()),
+                None => continue,
+            };
+            if comment.line < first_stmt_line {
+                suppressions.file.extend(names);
+            } else {
+                suppressions
+                    .lines
+                    .entry(comment.line)
+                    .or_default()
+                    .extend(names);
+            }
+        }
+        suppressions
+    }
+
+    /// Whether the lint which reports the warning at the line is disabled.
+    pub fn is_suppressed(&self, kind: &WarningKind, line: u64) -> bool {
+        let is_disabled = |name: &String| name == ALL_LINTS || is_lint_name(name, kind);
+        self.file.iter().any(is_disabled)
+            || self
+                .lines
+                .get(&line)
+                .map_or(false, |names| names.iter().any(is_disabled))
+    }
+}
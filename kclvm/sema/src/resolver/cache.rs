@@ -0,0 +1,213 @@
+use std::{cell::RefCell, rc::Rc};
+
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_config::cache::{
+    get_cache_info, get_pkg_realpath_from_pkgpath, load_pkg_cache, save_pkg_cache, CacheInfo,
+    CacheOption,
+};
+use kclvm_error::Position;
+use serde::{Deserialize, Serialize};
+
+use crate::resolver::scope::{Scope, ScopeKind, ScopeObject, ScopeObjectKind};
+use crate::resolver::Resolver;
+
+/// The cache target of the resolved package scopes.
+const SCOPE_CACHE_TARGET: &str = "scope";
+
+/// The resolved result of a package stored in the package cache.
+#[derive(Default, Serialize, Deserialize)]
+struct PkgScopeCache {
+    /// The content information of the package and all its transitive dependencies
+    /// when the package is resolved, the cache is invalid if any of them changes.
+    deps: IndexMap<String, CacheInfo>,
+    /// The objects of the package scope.
+    elems: IndexMap<String, ScopeObject>,
+    /// The child scopes of the package scope.
+    children: Vec<ScopeCache>,
+}
+
+/// The owned copy of a child scope stored in the cache, the shared scope
+/// references and the parent links are rebuilt when it is loaded.
+#[derive(Serialize, Deserialize)]
+struct ScopeCache {
+    elems: IndexMap<String, ScopeObject>,
+    children: Vec<ScopeCache>,
+    start: Position,
+    end: Position,
+    kind: ScopeKind,
+}
+
+impl ScopeCache {
+    fn from_scope(scope: &Scope) -> Self {
+        ScopeCache {
+            elems: scope
+                .elems
+                .iter()
+                .map(|(name, obj)| (name.clone(), obj.borrow().clone()))
+                .collect(),
+            children: scope
+                .children
+                .iter()
+                .map(|child| ScopeCache::from_scope(&child.borrow()))
+                .collect(),
+            start: scope.start.clone(),
+            end: scope.end.clone(),
+            kind: scope.kind.clone(),
+        }
+    }
+
+    fn into_scope(self, parent: &Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
+        let scope = Rc::new(RefCell::new(Scope {
+            parent: Some(Rc::downgrade(parent)),
+            children: vec![],
+            elems: self
+                .elems
+                .into_iter()
+                .map(|(name, obj)| (name, Rc::new(RefCell::new(obj))))
+                .collect(),
+            start: self.start,
+            end: self.end,
+            kind: self.kind,
+        }));
+        let children = self
+            .children
+            .into_iter()
+            .map(|child| child.into_scope(&scope))
+            .collect();
+        scope.borrow_mut().children = children;
+        scope
+    }
+}
+
+impl<'ctx> Resolver<'ctx> {
+    /// Resolve the imported package. When the package cache is enabled, the
+    /// package scope is loaded from the cache if the package and all its
+    /// transitive dependencies are unchanged, otherwise it is resolved and
+    /// saved into the cache.
+    pub(crate) fn check_imported_pkg(&mut self, pkgpath: &str) {
+        if !self.options.enable_pkg_cache {
+            self.check(pkgpath);
+            return;
+        }
+        if self.load_pkg_scope_from_cache(pkgpath) {
+            return;
+        }
+        let diagnostics_count = self.handler.diagnostics.len();
+        self.check(pkgpath);
+        // Packages with diagnostics are not cached, so that the diagnostics
+        // are reported in every compilation.
+        if self.handler.diagnostics.len() == diagnostics_count {
+            self.save_pkg_scope_to_cache(pkgpath);
+        }
+    }
+
+    /// Load the package scope from the cache, returns false if the cache is
+    /// not found or invalid.
+    fn load_pkg_scope_from_cache(&mut self, pkgpath: &str) -> bool {
+        let cache: PkgScopeCache = match load_pkg_cache(
+            &self.program.root,
+            SCOPE_CACHE_TARGET,
+            pkgpath,
+            CacheOption::default(),
+        ) {
+            Some(cache) => cache,
+            None => return false,
+        };
+        if cache.deps != self.pkg_deps_cache_info(pkgpath) {
+            return false;
+        }
+        // Record the import names of the package and resolve its imported
+        // packages, which may be loaded from the cache as well.
+        self.check_import(pkgpath);
+        // The schema types, the type aliases and the declared variable types are
+        // rebuilt from the AST like `check`, only the statements are not checked.
+        self.init_global_types();
+        let scope = match self.scope_map.get(pkgpath) {
+            Some(scope) => Rc::clone(scope),
+            None => return false,
+        };
+        {
+            let mut scope_ref = scope.borrow_mut();
+            // Keep the rebuilt schema and rule definitions, and restore the
+            // inferred types of the variables.
+            for (name, obj) in cache.elems {
+                let is_definition = matches!(
+                    scope_ref.elems.get(&name),
+                    Some(current) if current.borrow().kind == ScopeObjectKind::Definition
+                );
+                if !is_definition {
+                    scope_ref.elems.insert(name, Rc::new(RefCell::new(obj)));
+                }
+            }
+        }
+        let children = cache
+            .children
+            .into_iter()
+            .map(|child| child.into_scope(&scope))
+            .collect();
+        scope.borrow_mut().children = children;
+        if self.options.lint_check {
+            if let Some(modules) = self.program.pkgs.get(pkgpath) {
+                for module in modules {
+                    self.lint_check_module(module);
+                }
+            }
+        }
+        true
+    }
+
+    /// Save the resolved package scope into the cache.
+    fn save_pkg_scope_to_cache(&self, pkgpath: &str) {
+        let scope = match self.scope_map.get(pkgpath) {
+            Some(scope) => scope.borrow(),
+            None => return,
+        };
+        let cache = PkgScopeCache {
+            deps: self.pkg_deps_cache_info(pkgpath),
+            elems: scope
+                .elems
+                .iter()
+                .map(|(name, obj)| (name.clone(), obj.borrow().clone()))
+                .collect(),
+            children: scope
+                .children
+                .iter()
+                .map(|child| ScopeCache::from_scope(&child.borrow()))
+                .collect(),
+        };
+        save_pkg_cache(
+            &self.program.root,
+            SCOPE_CACHE_TARGET,
+            pkgpath,
+            cache,
+            CacheOption::default(),
+        );
+    }
+
+    /// Returns the content information of the package and all its transitive
+    /// dependencies in the program.
+    fn pkg_deps_cache_info(&self, pkgpath: &str) -> IndexMap<String, CacheInfo> {
+        let mut deps = IndexMap::default();
+        let mut pkgpaths = vec![pkgpath.to_string()];
+        while let Some(pkgpath) = pkgpaths.pop() {
+            if deps.contains_key(&pkgpath) {
+                continue;
+            }
+            if let Some(modules) = self.program.pkgs.get(&pkgpath) {
+                for module in modules {
+                    for stmt in &module.body {
+                        if let ast::Stmt::Import(import_stmt) = &stmt.node {
+                            if self.program.pkgs.contains_key(&import_stmt.path) {
+                                pkgpaths.push(import_stmt.path.clone());
+                            }
+                        }
+                    }
+                }
+            }
+            let real_path = get_pkg_realpath_from_pkgpath(&self.program.root, &pkgpath);
+            deps.insert(pkgpath, get_cache_info(&real_path));
+        }
+        deps
+    }
+}
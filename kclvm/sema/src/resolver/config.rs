@@ -251,11 +251,14 @@ impl<'ctx> Resolver<'ctx> {
     /// Args:
     ///     key: the key of 'ConfigExpr'.
     ///     value: the value of 'ConfigExpr'.
+    ///     op: the attribute operation of 'ConfigExpr', the value of the list item operation
+    ///         e.g., `key[0]: value` is the list item, and `key[0] += value` is a list.
     ///     check_rules: Some checks on the key individually，such as check_defined.
     pub(crate) fn check_config_entry(
         &mut self,
         key: &'ctx Option<ast::NodeRef<ast::Expr>>,
         value: &'ctx ast::NodeRef<ast::Expr>,
+        op: &ast::ConfigEntryOperation,
     ) {
         if let Some(key) = key {
             if let Some(Some(_)) = self.ctx.config_expr_context.last() {
@@ -290,7 +293,7 @@ impl<'ctx> Resolver<'ctx> {
                 for _ in 0..names.len() - 1 {
                     val_ty = Type::dict_ref(self.str_ty(), val_ty);
                 }
-                if has_index {
+                if has_index && !matches!(op, ast::ConfigEntryOperation::Insert) {
                     val_ty = Type::list_ref(val_ty);
                 }
                 if let Some(Some(obj_last)) = self.ctx.config_expr_context.last() {
@@ -188,7 +188,7 @@ impl<'ctx> Resolver<'ctx> {
                             }
                             // Switch pkgpath context
                             if !self.scope_map.contains_key(&import_stmt.path) {
-                                self.check(&import_stmt.path);
+                                self.check_imported_pkg(&import_stmt.path);
                             }
                             // Restore the current context
                             self.change_package_context(&current_pkgpath, &current_filename);
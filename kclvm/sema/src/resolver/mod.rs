@@ -1,5 +1,6 @@
 mod arg;
 mod attr;
+mod cache;
 mod calculation;
 mod config;
 mod format;
@@ -21,13 +22,14 @@ mod tests;
 use indexmap::IndexMap;
 use std::{cell::RefCell, rc::Rc};
 
-use crate::lint::{CombinedLintPass, Linter};
+use crate::lint::{CombinedLintPass, LintConfig, Linter};
 use crate::pre_process::pre_process_program;
 use crate::resolver::scope::ScopeObject;
 use crate::resolver::ty_alias::process_program_type_alias;
 use crate::{resolver::scope::Scope, ty::SchemaType};
 use kclvm_ast::ast::Program;
 use kclvm_ast::walker::MutSelfTypedResultWalker;
+use kclvm_config::modfile::{load_mod_file, KCLModFile};
 use kclvm_error::*;
 
 use crate::ty::TypeContext;
@@ -91,6 +93,7 @@ impl<'ctx> Resolver<'ctx> {
     pub(crate) fn check_and_lint(&mut self, pkgpath: &str) -> ProgramScope {
         let mut scope = self.check(pkgpath);
         self.lint_check_scope_map();
+        self.linter.apply_config(self.options.lint_error_level);
         for diag in &self.linter.handler.diagnostics {
             scope.diagnostics.insert(diag.clone());
         }
@@ -135,19 +138,46 @@ pub struct Options {
     pub raise_err: bool,
     pub config_auto_fix: bool,
     pub lint_check: bool,
+    /// Whether to cache the resolved scopes of the imported packages.
+    pub enable_pkg_cache: bool,
+    /// Whether to report the lints configured with the `error` level as errors, it is only
+    /// set by the lint tool, otherwise they are reported as warnings and never fail the
+    /// compilation.
+    pub lint_error_level: bool,
 }
 
-/// Resolve program
+/// Resolve program, the resolved scopes of the imported packages are cached
+/// when `enable_pkg_cache` is set in the build section of `kcl.mod`.
 pub fn resolve_program(program: &mut Program) -> ProgramScope {
-    pre_process_program(program);
-    let mut resolver = Resolver::new(
+    resolve_program_with_opts(
         program,
         Options {
             raise_err: true,
             config_auto_fix: false,
             lint_check: true,
+            ..Default::default()
+        },
+    )
+}
+
+/// Resolve program with the resolve options, `enable_pkg_cache` is always loaded from
+/// the build section of `kcl.mod`.
+pub fn resolve_program_with_opts(program: &mut Program, opts: Options) -> ProgramScope {
+    pre_process_program(program);
+    let mod_file = if program.root.is_empty() {
+        KCLModFile::default()
+    } else {
+        load_mod_file(&program.root)
+    };
+    let enable_pkg_cache = mod_file.enable_pkg_cache();
+    let mut resolver = Resolver::new(
+        program,
+        Options {
+            enable_pkg_cache,
+            ..opts
         },
     );
+    resolver.linter.config = LintConfig::from_mod_file(&mod_file);
     resolver.resolve_import();
     let scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
     let type_alias_mapping = resolver.ctx.type_alias_mapping.clone();
@@ -286,6 +286,13 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             .map_or(self.any_ty(), |ty| ty);
         // Schema attribute decorators
         self.resolve_decorators(&schema_attr.decorators, DecoratorTarget::Attribute, name);
+        // Keep the used flag of the attribute which is referred before its declaration.
+        let used = self
+            .scope
+            .borrow()
+            .elems
+            .get(name)
+            .map_or(false, |obj| obj.borrow().used);
         self.insert_object(
             name,
             ScopeObject {
@@ -294,7 +301,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 end,
                 ty: expected_ty.clone(),
                 kind: ScopeObjectKind::Variable,
-                used: false,
+                used,
             },
         );
         if let Some(value) = &schema_attr.value {
@@ -641,8 +648,9 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         for entry in &config_if_entry_expr.items {
             let key = &entry.node.key;
             let value = &entry.node.value;
+            let op = &entry.node.operation;
             let mut stack_depth = 0;
-            self.check_config_entry(key, value);
+            self.check_config_entry(key, value, op);
             stack_depth += self.switch_config_expr_context_by_key(key) as usize;
             let mut entry_key_ty = self.any_ty();
             let mut entry_val_ty = self.expr(value).clone();
@@ -827,9 +835,8 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             let value = &item.node.value;
             let op = &item.node.operation;
             let mut stack_depth: usize = 0;
-            self.check_config_entry(key, value);
+            self.check_config_entry(key, value, op);
             stack_depth += self.switch_config_expr_context_by_key(key) as usize;
-            let mut has_insert_index = false;
             let val_ty = match key {
                 Some(key) => match &key.node {
                     ast::Expr::Identifier(identifier) => {
@@ -856,10 +863,13 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                     ast::Expr::Subscript(subscript)
                         if matches!(subscript.value.node, ast::Expr::Identifier(_)) =>
                     {
-                        has_insert_index = true;
                         let val_ty = self.expr(value);
                         key_types.push(self.str_ty());
-                        val_types.push(Type::list_ref(val_ty.clone()));
+                        if matches!(op, ast::ConfigEntryOperation::Insert) {
+                            val_types.push(val_ty.clone());
+                        } else {
+                            val_types.push(Type::list_ref(val_ty.clone()));
+                        }
                         val_ty
                     }
                     _ => {
@@ -914,7 +924,6 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 }
             };
             if matches!(op, ast::ConfigEntryOperation::Insert)
-                && !has_insert_index
                 && !val_ty.is_any()
                 && !val_ty.is_list()
             {
@@ -950,9 +959,11 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
     fn walk_lambda_expr(&mut self, lambda_expr: &'ctx ast::LambdaExpr) -> Self::Result {
         let mut ret_ty = self.any_ty();
         let mut params = vec![];
+        let mut param_positions = vec![];
         self.do_parameters_check(&lambda_expr.args);
         if let Some(args) = &lambda_expr.args {
             for (i, arg) in args.node.args.iter().enumerate() {
+                param_positions.push(arg.get_span_pos());
                 let name = arg.node.get_name();
                 let arg_ty = args.node.get_arg_type(i);
                 let ty = self.parse_ty_with_scope(&arg_ty, arg.get_pos());
@@ -972,13 +983,13 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         self.enter_scope(start.clone(), end.clone(), ScopeKind::Lambda);
         self.ctx.in_lambda_expr.push(true);
         // Lambda parameters
-        for param in &params {
+        for (param, (param_start, param_end)) in params.iter().zip(param_positions) {
             self.insert_object(
                 &param.name,
                 ScopeObject {
                     name: param.name.clone(),
-                    start: start.clone(),
-                    end: end.clone(),
+                    start: param_start,
+                    end: param_end,
                     ty: param.ty.clone(),
                     kind: ScopeObjectKind::Parameter,
                     used: false,
@@ -1107,6 +1118,11 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         self.expr(&formatted_value.value)
     }
 
+    fn walk_missing_expr(&mut self, _missing_expr: &'ctx ast::MissingExpr) -> Self::Result {
+        // The syntax error has been reported by the parser.
+        self.any_ty()
+    }
+
     fn walk_comment(&mut self, _comment: &'ctx ast::Comment) -> Self::Result {
         // Nothing to do.
         self.any_ty()
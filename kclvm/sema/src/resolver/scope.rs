@@ -3,6 +3,7 @@ use indexmap::IndexSet;
 use kclvm_ast::{ast, MAIN_PKG};
 use kclvm_error::Diagnostic;
 use kclvm_error::Handler;
+use serde::{Deserialize, Serialize};
 
 use std::{
     cell::RefCell,
@@ -16,7 +17,7 @@ use crate::{builtin::BUILTIN_FUNCTIONS, ty::TypeInferMethods};
 use kclvm_error::Position;
 
 /// The object stored in the scope.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct ScopeObject {
     /// The scope object name.
     pub name: String,
@@ -40,7 +41,7 @@ impl ScopeObject {
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum ScopeObjectKind {
     Variable,
     Attribute,
@@ -108,7 +109,7 @@ impl ContainsPos for Scope {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ScopeKind {
     /// Package scope.
     Package,
@@ -287,6 +288,17 @@ impl<'ctx> Resolver<'ctx> {
             .map(|obj| obj.borrow().ty.clone())
     }
 
+    /// Record the scope object found by name as used, for checking unused variables. Imported
+    /// modules are recorded by the package path of identifiers in `resolve_var`.
+    pub fn set_used_in_scope(&mut self, name: &str) {
+        if let Some(obj) = self.scope.borrow().lookup(name) {
+            let mut obj = obj.borrow_mut();
+            if obj.kind != ScopeObjectKind::Module {
+                obj.used = true;
+            }
+        }
+    }
+
     /// Lookup type from the scope by name, if not found, emit a compile error and
     /// return the any type.
     pub fn lookup_type_from_scope(&mut self, name: &str, pos: Position) -> Rc<Type> {
@@ -0,0 +1,6 @@
+[lint]
+enable = ["naming_convention", "W0107"]
+disable = ["shadowed_name"]
+
+[lint.level]
+W0612 = "error"
@@ -0,0 +1,2 @@
+[lint]
+enable = ["private_access"]
@@ -2,8 +2,8 @@ use super::Options;
 use super::Resolver;
 use crate::builtin::BUILTIN_FUNCTION_NAMES;
 use crate::pre_process::pre_process_program;
-use crate::resolver::resolve_program;
 use crate::resolver::scope::*;
+use crate::resolver::{resolve_program, resolve_program_with_opts};
 use crate::ty::Type;
 use kclvm_ast::ast;
 use kclvm_error::*;
@@ -86,6 +86,21 @@ fn test_resolve_program_fail() {
     );
 }
 
+#[test]
+fn test_resolve_program_config_list_item_insert_fail() {
+    let mut program =
+        parse_program("./src/resolver/test_fail_data/config_list_item_insert.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let err_messages = [
+        "expect [{str:any}], got {str(key2):int(2)}",
+        "only list type can in inserted, got '{str(key2):int(2)}'",
+    ];
+    assert_eq!(scope.diagnostics.len(), err_messages.len());
+    for (diag, msg) in scope.diagnostics.iter().zip(err_messages.iter()) {
+        assert_eq!(diag.messages[0].message, msg.to_string(),);
+    }
+}
+
 #[test]
 fn test_resolve_program_cycle_reference_fail() {
     let mut program = load_program(
@@ -231,6 +246,8 @@ fn test_lint() {
             raise_err: true,
             config_auto_fix: false,
             lint_check: true,
+            enable_pkg_cache: false,
+            lint_error_level: false,
         },
     );
     resolver.resolve_import();
@@ -239,7 +256,7 @@ fn test_lint() {
     let root = &program.root.clone();
     let filename = root.clone() + "/lint.k";
     let mut handler = Handler::default();
-    handler.add_warning(
+    handler.add_warning_with_suggestions(
         WarningKind::ImportPositionWarning,
         &[Message {
             pos: Position {
@@ -251,8 +268,15 @@ fn test_lint() {
             message: format!("Importstmt should be placed at the top of the module"),
             note: Some("Consider moving tihs statement to the top of the file".to_string()),
         }],
+        vec![Suggestion {
+            message: "Move the import statement to the top of the module".to_string(),
+            replacements: vec![
+                Replacement::insert_before_line(&filename, 3, "import import_test.b\n"),
+                Replacement::remove_lines(&filename, 10, 10),
+            ],
+        }],
     );
-    handler.add_warning(
+    handler.add_warning_with_suggestions(
         WarningKind::ReimportWarning,
         &[Message {
             pos: Position {
@@ -264,8 +288,12 @@ fn test_lint() {
             message: format!("Module 'a' is reimported multiple times"),
             note: Some("Consider removing this statement".to_string()),
         }],
+        vec![Suggestion {
+            message: "Remove the duplicate import statement".to_string(),
+            replacements: vec![Replacement::remove_lines(&filename, 2, 2)],
+        }],
     );
-    handler.add_warning(
+    handler.add_warning_with_suggestions(
         WarningKind::UnusedImportWarning,
         &[Message {
             pos: Position {
@@ -277,6 +305,10 @@ fn test_lint() {
             message: format!("Module 'import_test.a' imported but unused"),
             note: Some("Consider removing this statement".to_string()),
         }],
+        vec![Suggestion {
+            message: "Remove the unused import statement".to_string(),
+            replacements: vec![Replacement::remove_lines(&filename, 1, 1)],
+        }],
     );
     for (d1, d2) in resolver
         .linter
@@ -288,3 +320,241 @@ fn test_lint() {
         assert_eq!(d1, d2);
     }
 }
+
+/// Returns the level, code, line and message of the lint diagnostics of the file.
+fn lint_diagnostics(file: &str, lint_error_level: bool) -> Vec<(Level, &'static str, u64, String)> {
+    let mut program = load_program(&[file], None).unwrap();
+    let scope = resolve_program_with_opts(
+        &mut program,
+        Options {
+            raise_err: true,
+            config_auto_fix: false,
+            lint_check: true,
+            lint_error_level,
+            ..Default::default()
+        },
+    );
+    scope
+        .diagnostics
+        .iter()
+        .filter_map(|diag| match &diag.code {
+            Some(DiagnosticId::Warning(kind)) => Some((
+                diag.level,
+                kind.code(),
+                diag.messages[0].pos.line,
+                diag.messages[0].message.clone(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_lint_default_lints() {
+    let diags = lint_diagnostics("./src/resolver/test_data/lint_rules.k", false);
+    let expected = [
+        (
+            "W0125",
+            20,
+            "The if branch is unreachable because the condition is always false",
+        ),
+        (
+            "W0125",
+            22,
+            "The else branch is unreachable because the condition is always true",
+        ),
+        ("W0612", 1, "Variable '_unused' is assigned but never used"),
+        (
+            "W0238",
+            7,
+            "Private attribute '_age' of schema 'person' is unused",
+        ),
+        ("W0612", 15, "Variable 'y' is assigned but never used"),
+    ];
+    assert_eq!(diags.len(), expected.len(), "{:?}", diags);
+    for ((level, code, line, message), (expected_code, expected_line, expected_message)) in
+        diags.iter().zip(expected.iter())
+    {
+        assert_eq!(*level, Level::Warning);
+        assert_eq!(code, expected_code);
+        assert_eq!(line, expected_line);
+        assert_eq!(message, expected_message);
+    }
+}
+
+#[test]
+fn test_lint_config_and_suppression() {
+    // The lint config is in `test_data/lint_config/kcl.mod`.
+    let file = "./src/resolver/test_data/lint_config/main.k";
+    // The lints configured with the `error` level are reported as errors only by the lint tool.
+    for (lint_error_level, unused_variable_level) in [(false, Level::Warning), (true, Level::Error)]
+    {
+        let diags = lint_diagnostics(file, lint_error_level);
+        let expected = [
+            (
+                Level::Warning,
+                "C0103",
+                5,
+                "Schema name 'person' is not in CamelCase",
+            ),
+            (
+                Level::Warning,
+                "C0103",
+                6,
+                "Schema attribute name 'firstName' is not in snake_case",
+            ),
+            (
+                Level::Warning,
+                "W0107",
+                12,
+                "Type annotation 'int' is redundant for the int literal",
+            ),
+            (
+                unused_variable_level,
+                "W0612",
+                2,
+                "Variable '_unused' is assigned but never used",
+            ),
+        ];
+        assert_eq!(diags.len(), expected.len(), "{:?}", diags);
+        for (diag, (level, code, line, message)) in diags.iter().zip(expected.iter()) {
+            assert_eq!(diag.0, *level);
+            assert_eq!(diag.1, *code);
+            assert_eq!(diag.2, *line);
+            assert_eq!(diag.3, *message);
+        }
+    }
+}
+
+#[test]
+fn test_lint_private_access() {
+    // The private access lint is enabled in `test_data/lint_private_access/kcl.mod`.
+    let mut program = load_program(
+        &["./src/resolver/test_data/lint_private_access/main.k"],
+        None,
+    )
+    .unwrap();
+    let scope = resolve_program(&mut program);
+    let diags: Vec<&Diagnostic> = scope
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.code == Some(DiagnosticId::Warning(WarningKind::PrivateAccessWarning)))
+        .collect();
+    // The private names accessed in the same package are not reported.
+    assert_eq!(diags.len(), 2, "{:?}", diags);
+    let expected = [(9, 8, "_b"), (10, 15, "_c")];
+    for (diag, (line, column, name)) in diags.iter().zip(expected.iter()) {
+        assert_eq!(diag.messages[0].pos.line, *line);
+        assert_eq!(diag.messages[0].pos.column, Some(*column));
+        assert_eq!(
+            diag.messages[0].message,
+            format!(
+                "Private variable or attribute '{}' is accessed outside",
+                name
+            )
+        );
+    }
+}
+
+#[test]
+fn test_resolve_program_with_pkg_cache() {
+    let root = std::env::temp_dir().join(format!("kclvm_sema_pkg_cache_{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    let write = |name: &str, code: &str| std::fs::write(root.join(name), code).unwrap();
+    write("kcl.mod", "[build]\nenable_pkg_cache = true\n");
+    write("main.k", "import a\n\nconfig = a.A {x = 1}\n");
+    write("a.k", "import b\n\nschema A(b.B):\n    y: int = 1\n");
+    write("b.k", "schema B:\n    x: int\n");
+    let main = root.join("main.k").to_str().unwrap().to_string();
+    let resolve_errors = || {
+        let mut program = load_program(&[&main], None).unwrap();
+        let scope = resolve_program(&mut program);
+        scope
+            .diagnostics
+            .iter()
+            .filter(|diag| diag.level == Level::Error)
+            .count()
+    };
+    assert_eq!(resolve_errors(), 0);
+    assert!(root.join(".kclvm").join("cache").exists());
+    // The imported packages are loaded from the cache.
+    assert_eq!(resolve_errors(), 0);
+    // The cache of the package `a` is invalid when its dependency changes.
+    write("b.k", "schema B:\n    x: str\n");
+    assert_eq!(resolve_errors(), 1);
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_resolve_program_with_pkg_cache_cold_and_warm() {
+    let root =
+        std::env::temp_dir().join(format!("kclvm_sema_pkg_cache_warm_{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    let write = |name: &str, code: &str| std::fs::write(root.join(name), code).unwrap();
+    write("kcl.mod", "[build]\nenable_pkg_cache = true\n");
+    write(
+        "main.k",
+        "import a\n\nconfig = a.A {x = 1}\nname = a.name\ny = a.double(config.y)\n",
+    );
+    write(
+        "a.k",
+        r#"import b
+
+type Name = str
+
+schema A(b.B):
+    y: int = 1
+    name?: Name
+
+    check:
+        y > 0
+
+name = "a"
+double = lambda x: int -> int {
+    x * 2
+}
+"#,
+    );
+    write("b.k", "schema B:\n    x: int\n");
+    let main = root.join("main.k").to_str().unwrap().to_string();
+    let resolve = || {
+        let mut program = load_program(&[&main], None).unwrap();
+        let scope = resolve_program(&mut program);
+        let scopes: Vec<String> = scope
+            .scope_map
+            .iter()
+            .map(|(pkgpath, scope)| format!("{}: {:?}", pkgpath, scope.borrow()))
+            .collect();
+        // The type aliases in the AST are replaced after the resolution.
+        let ast = format!("{:?}", program.pkgs["a"]);
+        (scopes, scope.diagnostics, ast)
+    };
+    let (cold_scopes, cold_diags, cold_ast) = resolve();
+    assert!(root.join(".kclvm").join("cache").exists());
+    let (warm_scopes, warm_diags, warm_ast) = resolve();
+    assert_eq!(cold_diags, warm_diags);
+    assert_eq!(cold_ast, warm_ast);
+    assert_eq!(cold_scopes.len(), warm_scopes.len());
+    for (cold, warm) in cold_scopes.iter().zip(warm_scopes.iter()) {
+        assert_eq!(cold, warm);
+    }
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_schema_unification_attr() {
+    let mut program =
+        load_program(&["./src/resolver/test_data/schema_unification.k"], None).unwrap();
+    let scope = resolve_program(&mut program);
+    assert_eq!(scope.diagnostics.len(), 0, "{:?}", scope.diagnostics);
+    let main_scope = scope.main_scope().unwrap();
+    let main_scope = main_scope.borrow();
+    let person = main_scope.lookup("Person").unwrap();
+    let person_ty = person.borrow().ty.clone();
+    let schema_ty = person_ty.into_schema_type();
+    // The unification attribute is registered under the target name with the schema type.
+    let attr = schema_ty.attrs.get("name").unwrap();
+    assert_eq!(attr.ty.ty_str(), "Name");
+    assert!(attr.is_optional);
+    assert!(!schema_ty.attrs.contains_key("Name"));
+}
@@ -27,6 +27,7 @@ impl<'ctx> Resolver<'ctx> {
                 let ty = schema_ty.get_type_of_attr(name);
                 // Load from schema if in schema
                 if !self.ctx.l_value {
+                    self.set_used_in_scope(name);
                     let scope_ty = self.find_type_in_scope(name);
                     if self.ctx.local_vars.contains(name) {
                         return scope_ty.map_or(self.any_ty(), |ty| ty);
@@ -62,6 +63,7 @@ impl<'ctx> Resolver<'ctx> {
             } else {
                 // Load from schema if in schema
                 if !self.ctx.l_value {
+                    self.set_used_in_scope(name);
                     self.lookup_type_from_scope(name, pos)
                 }
                 // Store
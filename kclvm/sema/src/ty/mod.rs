@@ -17,11 +17,12 @@ pub use unify::*;
 pub use walker::walk_type;
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Type {
     // The type kind.
     pub kind: TypeKind,
@@ -75,7 +76,7 @@ impl Type {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TypeKind {
     /// A primitive None name constant.
     None,
@@ -120,6 +121,7 @@ pub enum TypeKind {
 bitflags::bitflags! {
     /// TypeFlags provides fast access to information that is also contained
     /// in `kind`.
+    #[derive(Serialize, Deserialize)]
     pub struct TypeFlags: u16 {
         const VOID = 1 << 0;
         const INT = 1 << 1;
@@ -141,7 +143,7 @@ bitflags::bitflags! {
 }
 
 /// The schema type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaType {
     /// The schema name.
     pub name: String,
@@ -250,7 +252,7 @@ impl SchemaType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaAttr {
     pub is_optional: bool,
     pub has_default: bool,
@@ -258,7 +260,7 @@ pub struct SchemaAttr {
     pub pos: Position,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SchemaIndexSignature {
     pub key_name: Option<String>,
     pub key_ty: Rc<Type>,
@@ -280,7 +282,7 @@ impl SchemaIndexSignature {
 }
 
 /// The module type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModuleType {
     pub pkgpath: String,
     pub imported: Vec<String>,
@@ -288,14 +290,14 @@ pub struct ModuleType {
 }
 
 /// The module kind.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ModuleKind {
     User,
     System,
     Plugin,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Decorator {
     pub target: DecoratorTarget,
     /// The decorator name.
@@ -304,14 +306,14 @@ pub struct Decorator {
     pub key: String,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DecoratorTarget {
     Schema,
     Attribute,
 }
 
 /// The number multiplier type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NumberMultiplierType {
     pub value: f64,
     pub raw_value: i64,
@@ -329,7 +331,7 @@ impl NumberMultiplierType {
 }
 
 /// The function type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionType {
     pub doc: String,
     pub params: Vec<Parameter>,
@@ -340,7 +342,7 @@ pub struct FunctionType {
 }
 
 /// The function parameter.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Parameter {
     pub name: String,
     pub ty: Rc<Type>,
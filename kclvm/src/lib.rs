@@ -53,5 +53,7 @@ pub extern "C" fn kclvm_cli_run(args: *const i8, plugin_agent: *const i8) -> *co
 pub fn kclvm_cli_run_unsafe(args: *const i8, plugin_agent: *const i8) -> Result<String, String> {
     let args = ExecProgramArgs::from_str(kclvm_runtime::c2str(args));
     let plugin_agent = plugin_agent as u64;
-    exec_program(&args, plugin_agent).map(|r| r.json_result)
+    exec_program(&args, plugin_agent)
+        .map(|r| r.json_result)
+        .map_err(|err| err.to_panic_info_json())
 }
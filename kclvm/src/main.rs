@@ -4,11 +4,21 @@
 extern crate clap;
 
 use clap::ArgMatches;
+use kclvm_ast_pretty::print_ast_module;
 use kclvm_config::settings::{load_file, merge_settings, SettingsFile};
-use kclvm_error::Handler;
-use kclvm_runner::{exec_program, ExecProgramArgs};
-use kclvm_runtime::PanicInfo;
-use kclvm_tools::lint::lint_files;
+use kclvm_error::{Diagnostic, Handler, OutputFormat};
+use kclvm_parser::parse_file;
+use kclvm_query::{
+    apply_override_on_module, override_file, override_file_diff, parse_override_spec,
+};
+use kclvm_runner::assembler::WASM_TARGET;
+use kclvm_runner::{build_wasm_program, exec_program, ExecProgramArgs};
+use kclvm_tools::format::{format, FormatOptions};
+use kclvm_tools::jsonschema::export::{export_file, ExportFormat, ExportOptions};
+use kclvm_tools::jsonschema::import::{import_file, ImportFormat, ImportOptions};
+use kclvm_tools::lint::{fix, lint_files};
+use kclvm_tools::util::loader::LoaderKind;
+use kclvm_tools::vet::validator::{validate_documents, ValidateOption};
 
 fn main() {
     let matches = clap_app!(kcl =>
@@ -21,6 +31,15 @@ fn main() {
             (@arg debug: -d --debug "Run in debug mode (for developers only)")
             (@arg sort_key: -k --sort "Sort result keys")
             (@arg ARGUMENT: ... -D --argument "Specify the top-level argument")
+            (@arg list_options: -L --list-options "Show the option list of the KCL program")
+            (@arg path_selector: ... -S --path-selector +takes_value "Only output the values selected by the path e.g., pkg:var.attr, var.list[0] and var.*.attr")
+            (@arg fast_eval: --fast-eval "Run the program with the AST evaluator instead of the LLVM backend")
+        )
+        (@subcommand build =>
+            (@arg INPUT: ... "Sets the input file to use")
+            (@arg OUTPUT: -o --output +takes_value "Sets the WASM module output file path")
+            (@arg SETTING: ... -Y --setting +takes_value "Sets the input file to use")
+            (@arg target: --target +takes_value "Sets the compilation target, only wasm32-wasi is supported")
         )
         (@subcommand lint =>
             (@arg INPUT: ... "Sets the input file to use")
@@ -32,6 +51,41 @@ fn main() {
             (@arg sort_key: -k --sort "Sort result keys")
             (@arg ARGUMENT: ... -D --argument "Specify the top-level argument")
             (@arg EMIT_WARNING: --emit_warning "Emit warning message")
+            (@arg format: --format +takes_value "Output format of the lint results, support json, sarif and junit")
+            (@arg fix: --fix "Apply the automatic fixes of the lint warnings")
+            (@arg dry_run: --dry-run "Output the diff of the automatic fixes without modifying the files")
+        )
+        (@subcommand fmt =>
+            (@arg INPUT: +required "Input file or path name for formatting")
+            (@arg recursive: -R --recursive "Iterate through subdirectories recursively")
+            (@arg std_output: -w --std-output "Whether to output format to stdout")
+            (@arg check: -c --check "Check whether the files are formatted without modifying them")
+            (@arg diff: --diff "Output the diff of the unformatted files without modifying them")
+        )
+        (@subcommand vet =>
+            (@arg DATA_FILE: +required "Validation data file, directory or glob pattern")
+            (@arg KCL_FILE: +required "KCL file")
+            (@arg schema: -d --schema +takes_value "The schema name used to validate the data")
+            (@arg attribute_name: -n --attribute-name +takes_value "The attribute name for the data loading")
+            (@arg format: --format +takes_value "Validation data file format, support YAML, JSON and TOML")
+        )
+        (@subcommand override =>
+            (@arg INPUT: +required "Input KCL file to override")
+            (@arg OVERRIDE_SPEC: ... -O --overrides +takes_value +required "Specify the configuration override path and value")
+            (@arg IMPORT_PATH: ... -I --import-path +takes_value "Specify the import paths to be added")
+            (@arg in_place: -i --in-place "Override the KCL file in place")
+            (@arg diff: --diff "Output the diff of the overridden file without modifying it")
+        )
+        (@subcommand export =>
+            (@arg INPUT: +required "Input KCL file to export the schemas")
+            (@arg schema: -d --schema +takes_value "The schema name to export with the schemas it references")
+            (@arg format: --format +takes_value "Output format of the schemas, support jsonschema and openapi")
+            (@arg OUTPUT: -o --output +takes_value "Sets the output file path")
+        )
+        (@subcommand import =>
+            (@arg INPUT: +required "Input JSON Schema, OpenAPI or CRD file to import as KCL schemas")
+            (@arg format: --format +takes_value "Input format of the definitions, support jsonschema, openapi and crd, detected from the input by default")
+            (@arg OUTPUT: -o --output +takes_value "Sets the output directory of the generated KCL files, defaults to the current directory")
         )
     )
     .arg_required_else_help(true)
@@ -43,19 +97,48 @@ fn main() {
             (_, _) => {
                 // Config settings build
                 let settings = build_settings(matches);
-                match exec_program(&settings.into(), 1) {
+                let mut args: ExecProgramArgs = settings.into();
+                args.list_option_mode = matches.occurrences_of("list_options") > 0;
+                args.fast_eval = matches.occurrences_of("fast_eval") > 0;
+                if let Some(path_selector) = matches.values_of("path_selector") {
+                    args.path_selector = path_selector.map(|s| s.to_string()).collect();
+                }
+                match exec_program(&args, 1) {
                     Ok(result) => {
+                        emit_diagnostics(result.warnings);
                         println!("{}", result.yaml_result);
                     }
-                    Err(msg) => {
-                        let mut handler = Handler::default();
-                        handler
-                            .add_panic_info(&PanicInfo::from_json_string(&msg))
-                            .abort_if_any_errors();
+                    Err(err) => {
+                        emit_diagnostics(err.diagnostics);
+                        std::process::exit(1)
                     }
                 }
             }
         }
+    } else if let Some(matches) = matches.subcommand_matches("build") {
+        let target = matches.value_of("target").unwrap_or(WASM_TARGET);
+        if target != WASM_TARGET {
+            exit_with_error(&format!(
+                "Unsupported target '{}', only {} is supported",
+                target, WASM_TARGET
+            ))
+        }
+        let mut args: ExecProgramArgs = match matches.values_of("SETTING") {
+            Some(files) => {
+                merge_settings(&files.map(load_file).collect::<Vec<SettingsFile>>()).into()
+            }
+            None => ExecProgramArgs::default(),
+        };
+        if let Some(files) = matches.values_of("INPUT") {
+            args.k_filename_list = files.map(|f| f.to_string()).collect();
+        }
+        if args.k_filename_list.is_empty() {
+            exit_with_error("no KCL files")
+        }
+        let output = matches.value_of("OUTPUT").unwrap_or_default();
+        if let Err(err) = build_wasm_program(&args, output) {
+            err.abort_if_any_errors();
+        }
     } else if let Some(matches) = matches.subcommand_matches("lint") {
         let (files, setting) = (matches.values_of("INPUT"), matches.values_of("SETTING"));
         match (files, setting) {
@@ -74,19 +157,219 @@ fn main() {
                 } else {
                     args.get_files()
                 };
+                let is_dry_run = matches.occurrences_of("dry_run") > 0;
+                if matches.occurrences_of("fix") > 0 || is_dry_run {
+                    if let Err(err) = fix(&files, Some(args.get_load_program_options()), is_dry_run)
+                    {
+                        exit_with_error(&err.to_string())
+                    }
+                    return;
+                }
                 let (mut err_handler, mut warning_handler) =
                     (Handler::default(), Handler::default());
                 (err_handler.diagnostics, warning_handler.diagnostics) =
                     lint_files(&files, Some(args.get_load_program_options()));
-                err_handler.emit();
-                if matches.occurrences_of("EMIT_WARNING") > 0 {
-                    warning_handler.emit();
+                let emit_warning = matches.occurrences_of("EMIT_WARNING") > 0;
+                let has_errors = err_handler.has_errors();
+                match matches.value_of("format") {
+                    Some(format) => {
+                        let format: OutputFormat = match format.parse() {
+                            Ok(format) => format,
+                            Err(err) => exit_with_error(&err),
+                        };
+                        let mut diags: Vec<Diagnostic> =
+                            err_handler.diagnostics.into_iter().collect();
+                        if emit_warning {
+                            diags.extend(warning_handler.diagnostics);
+                        }
+                        format
+                            .emitter("kcl-lint")
+                            .emit_diagnostics(&mut std::io::stdout(), &diags)
+                            .unwrap();
+                    }
+                    None => {
+                        err_handler.emit();
+                        if emit_warning {
+                            warning_handler.emit();
+                        }
+                    }
+                }
+                // Exit with an error code when there are lint errors.
+                if has_errors {
+                    std::process::exit(1)
+                }
+            }
+        }
+    } else if let Some(matches) = matches.subcommand_matches("fmt") {
+        let input = matches.value_of("INPUT").unwrap();
+        let opts = FormatOptions {
+            is_stdout: matches.occurrences_of("std_output") > 0,
+            recursively: matches.occurrences_of("recursive") > 0,
+            is_check: matches.occurrences_of("check") > 0,
+            is_diff: matches.occurrences_of("diff") > 0,
+        };
+        match format(input, &opts) {
+            Ok(changed_paths) => {
+                // Exit with an error code when there are unformatted files in the check mode.
+                if (opts.is_check || opts.is_diff) && !changed_paths.is_empty() {
+                    std::process::exit(1)
                 }
             }
+            Err(err) => exit_with_error(&err.to_string()),
+        }
+    } else if let Some(matches) = matches.subcommand_matches("vet") {
+        let data_file = matches.value_of("DATA_FILE").unwrap();
+        let kcl_file = matches.value_of("KCL_FILE").unwrap();
+        let kind = match matches
+            .value_of("format")
+            .unwrap_or("json")
+            .to_lowercase()
+            .as_str()
+        {
+            "json" => LoaderKind::JSON,
+            "yaml" | "yml" => LoaderKind::YAML,
+            "toml" => LoaderKind::TOML,
+            other => exit_with_error(&format!("Unsupported data file format '{}'", other)),
+        };
+        let opt = ValidateOption::new(
+            matches.value_of("schema").map(|s| s.to_string()),
+            matches
+                .value_of("attribute_name")
+                .unwrap_or("value")
+                .to_string(),
+            data_file.to_string(),
+            kind,
+            Some(kcl_file.to_string()),
+            None,
+        );
+        match validate_documents(opt) {
+            Ok(reports) => {
+                let mut error_count = 0;
+                for report in &reports {
+                    for err in &report.errors {
+                        eprintln!("{}[{}]: {}", report.filename, report.index, err);
+                    }
+                    error_count += report.errors.len();
+                }
+                let failed = reports.iter().filter(|r| !r.errors.is_empty()).count();
+                if error_count == 0 {
+                    println!("Validate success! ({} document(s))", reports.len());
+                } else {
+                    exit_with_error(&format!(
+                        "Validate failed with {} error(s) in {} of {} document(s)",
+                        error_count,
+                        failed,
+                        reports.len()
+                    ))
+                }
+            }
+            Err(msg) => exit_with_error(&msg),
+        }
+    } else if let Some(matches) = matches.subcommand_matches("override") {
+        let file = matches.value_of("INPUT").unwrap();
+        let specs: Vec<String> = match matches.values_of("OVERRIDE_SPEC") {
+            Some(specs) => specs.map(|s| s.to_string()).collect(),
+            None => vec![],
+        };
+        let import_paths: Vec<String> = match matches.values_of("IMPORT_PATH") {
+            Some(paths) => paths.map(|s| s.to_string()).collect(),
+            None => vec![],
+        };
+        if matches.occurrences_of("diff") > 0 {
+            match override_file_diff(file, &specs, &import_paths) {
+                Ok(diff) => print!("{}", diff),
+                Err(err) => exit_with_error(&err.to_string()),
+            }
+        } else if matches.occurrences_of("in_place") > 0 {
+            if let Err(err) = override_file(file, &specs, &import_paths) {
+                exit_with_error(&err.to_string())
+            }
+        } else {
+            // Print the overridden KCL code to stdout without modifying the file.
+            let mut module = match parse_file(file, None) {
+                Ok(module) => module,
+                Err(msg) => exit_with_error(&msg),
+            };
+            for spec in &specs {
+                let result = parse_override_spec(spec)
+                    .and_then(|o| apply_override_on_module(&mut module, &o, &import_paths));
+                if let Err(err) = result {
+                    exit_with_error(&err.to_string())
+                }
+            }
+            print!("{}", print_ast_module(&module));
+        }
+    } else if let Some(matches) = matches.subcommand_matches("export") {
+        let format = match matches
+            .value_of("format")
+            .unwrap_or("jsonschema")
+            .to_lowercase()
+            .as_str()
+        {
+            "jsonschema" => ExportFormat::JsonSchema,
+            "openapi" => ExportFormat::OpenAPI,
+            other => exit_with_error(&format!("Unsupported export format '{}'", other)),
+        };
+        let opts = ExportOptions {
+            format,
+            schema_name: matches.value_of("schema").map(|s| s.to_string()),
+        };
+        let document = match export_file(matches.value_of("INPUT").unwrap(), &opts) {
+            Ok(document) => document,
+            Err(err) => exit_with_error(&err.to_string()),
+        };
+        let content = serde_json::to_string_pretty(&document).unwrap() + "\n";
+        match matches.value_of("OUTPUT") {
+            Some(output) => {
+                if let Err(err) = std::fs::write(output, content) {
+                    exit_with_error(&err.to_string())
+                }
+            }
+            None => print!("{}", content),
+        }
+    } else if let Some(matches) = matches.subcommand_matches("import") {
+        let format = match matches.value_of("format").map(|s| s.to_lowercase()) {
+            None => None,
+            Some(format) => match format.as_str() {
+                "jsonschema" => Some(ImportFormat::JsonSchema),
+                "openapi" => Some(ImportFormat::OpenAPI),
+                "crd" => Some(ImportFormat::CRD),
+                other => exit_with_error(&format!("Unsupported import format '{}'", other)),
+            },
+        };
+        let opts = ImportOptions { format };
+        let result = match import_file(matches.value_of("INPUT").unwrap(), &opts) {
+            Ok(result) => result,
+            Err(err) => exit_with_error(&format!("{:#}", err)),
+        };
+        for warning in &result.warnings {
+            eprintln!("Warning: {}", warning);
+        }
+        let output = std::path::Path::new(matches.value_of("OUTPUT").unwrap_or("."));
+        for (path, code) in result.files {
+            let path = output.join(path);
+            let result = std::fs::create_dir_all(path.parent().unwrap_or(output))
+                .and_then(|_| std::fs::write(&path, code));
+            if let Err(err) = result {
+                exit_with_error(&format!("Failed to write '{}': {}", path.display(), err))
+            }
         }
     }
 }
 
+/// Print the diagnostics including the warnings to stderr.
+fn emit_diagnostics(diagnostics: Vec<Diagnostic>) {
+    let mut handler = Handler::default();
+    handler.diagnostics = diagnostics.into_iter().collect();
+    handler.emit();
+}
+
+/// Print the error message to stderr and exit the process with an error code.
+fn exit_with_error(msg: &str) -> ! {
+    eprintln!("Error: {}", msg);
+    std::process::exit(1)
+}
+
 /// Build settings from arg matches.
 fn build_settings(matches: &ArgMatches) -> SettingsFile {
     let files: Vec<&str> = match matches.values_of("INPUT") {
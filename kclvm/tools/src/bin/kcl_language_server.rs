@@ -0,0 +1,10 @@
+//! The KCL language server which speaks the language server protocol over stdio.
+
+use kclvm_tools::langserver::server::run_server;
+
+fn main() {
+    if let Err(err) = run_server() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
@@ -5,23 +5,41 @@
 //! The basic principle is to call the [kclvm_parser::parse_file] function to parse the
 //! AST Module, and then use the AST printer [kclvm_tools::printer::print_ast_module]
 //! to print it as source code string.
+//!
+//! The printer config such as the indent width, the max line width and the quote
+//! style is read from the format section of the `kcl.mod` of the formatted files.
+//!
+//! For editors, [format_code] formats the source in memory and [format_range] returns
+//! the text edits of the formatted lines, both of them check that the formatting
+//! keeps all the comments and is idempotent. They read the printer config from the
+//! `kcl.mod` of the current working directory, and [format_code_with_config] and
+//! [format_range_with_config] take the config of the edited file instead.
 use anyhow::{anyhow, Result};
-use kclvm_ast_pretty::print_ast_module;
+use kclvm_ast::ast::Module;
+use kclvm_ast_pretty::{print_ast_module_with_config, Config};
+use kclvm_config::modfile::{get_pkg_root, load_mod_file};
+use kclvm_error::{Position, Replacement};
+use similar::{capture_diff_slices, Algorithm, DiffTag, TextDiff};
+use std::ops::Range;
 use std::path::Path;
 
 use crate::util::get_kcl_files;
-use kclvm_parser::parse_file;
+use kclvm_parser::{parse_file, parse_file_with_errors};
 
 #[cfg(test)]
 mod tests;
 
-/// FormatOptions contains two options:
+/// FormatOptions contains four options:
 /// - is_stdout: whether to output the formatted result to stdout.
 /// - recursively: whether to recursively traverse a folder and format all KCL files in it.
+/// - is_check: whether to only check the files are formatted without modifying them.
+/// - is_diff: whether to output the unified diff of the unformatted files without modifying them.
 #[derive(Debug, Default)]
 pub struct FormatOptions {
     pub is_stdout: bool,
     pub recursively: bool,
+    pub is_check: bool,
+    pub is_diff: bool,
 }
 
 /// Formats kcl file or directory path contains kcl files and
@@ -52,7 +70,17 @@ pub fn format<P: AsRef<Path>>(path: P, opts: &FormatOptions) -> Result<Vec<Strin
             changed_paths.push(file)
         }
     }
-    if !opts.is_stdout {
+    if opts.is_check || opts.is_diff {
+        let n = changed_paths.len();
+        println!(
+            "KCL format check done and {} {} be formatted:",
+            n,
+            if n <= 1 { "file would" } else { "files would" }
+        );
+        for p in &changed_paths {
+            println!("{}", p);
+        }
+    } else if !opts.is_stdout {
         let n = changed_paths.len();
         println!(
             "KCL format done and {} {} formatted:",
@@ -69,8 +97,14 @@ pub fn format<P: AsRef<Path>>(path: P, opts: &FormatOptions) -> Result<Vec<Strin
 /// Formats a file and returns whether the file has been formatted and modified.
 fn format_file(file: &str, opts: &FormatOptions) -> Result<bool> {
     let src = std::fs::read_to_string(file)?;
-    let (source, is_formatted) = format_source(&src)?;
-    if opts.is_stdout {
+    let (source, is_formatted) = format_source_with_config(&src, load_format_config(file)?)?;
+    if opts.is_diff {
+        if is_formatted {
+            print!("{}", unified_diff(file, &src, &source));
+        }
+    } else if opts.is_check {
+        // Only check whether the file is formatted and do not modify it.
+    } else if opts.is_stdout {
         println!("{}", source);
     } else {
         std::fs::write(file, &source)?
@@ -78,14 +112,221 @@ fn format_file(file: &str, opts: &FormatOptions) -> Result<bool> {
     Ok(is_formatted)
 }
 
+/// Returns the unified diff between the original source and the formatted source.
+pub(crate) fn unified_diff(file: &str, src: &str, formatted_src: &str) -> String {
+    TextDiff::from_lines(src, formatted_src)
+        .unified_diff()
+        .header(file, file)
+        .to_string()
+}
+
 /// Formats a code source and returns the formatted source and
 /// whether the source is changed.
-fn format_source(src: &str) -> Result<(String, bool)> {
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_tools::format::format_source;
+///
+/// let (src, is_formatted) = format_source("a=1").unwrap();
+/// assert!(src.starts_with("a = 1"));
+/// assert!(is_formatted);
+/// ```
+pub fn format_source(src: &str) -> Result<(String, bool)> {
+    format_source_with_config(src, Config::default())
+}
+
+/// Formats a code source with the printer config and returns the formatted
+/// source and whether the source is changed.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_ast_pretty::Config;
+/// use kclvm_tools::format::format_source_with_config;
+///
+/// let cfg = Config {
+///     indent_len: 2,
+///     ..Default::default()
+/// };
+/// let (src, _) = format_source_with_config("schema A:\n    a: int", cfg).unwrap();
+/// assert_eq!(src, "schema A:\n  a: int\n\n");
+/// ```
+pub fn format_source_with_config(src: &str, cfg: Config) -> Result<(String, bool)> {
     let module = match parse_file("", Some(src.to_string())) {
         Ok(module) => module,
         Err(err) => return Err(anyhow!("{}", err)),
     };
-    let formatted_src = print_ast_module(&module);
+    let formatted_src = print_ast_module_with_config(&module, cfg);
     let is_formatted = src != formatted_src;
     Ok((formatted_src, is_formatted))
 }
+
+/// Formats a code source in memory and returns the formatted source. Unlike
+/// [format_source], it returns an error instead of aborting on syntax errors,
+/// and returns an error when the formatted source loses any comment or is
+/// changed by formatting it again, so the result is always safe to apply.
+/// The printer config is loaded from the `kcl.mod` of the current working
+/// directory.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_tools::format::format_code;
+///
+/// assert_eq!(format_code("a=1 # one").unwrap(), "a = 1  # one\n");
+/// assert!(format_code("a = ").is_err());
+/// ```
+pub fn format_code(src: &str) -> Result<String> {
+    format_code_with_config(src, load_format_config(".")?)
+}
+
+/// Formats a code source in memory with the printer config like [format_code].
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_ast_pretty::{Config, QuoteStyle};
+/// use kclvm_tools::format::format_code_with_config;
+///
+/// let cfg = Config {
+///     quote_style: QuoteStyle::Double,
+///     ..Default::default()
+/// };
+/// let src = format_code_with_config("a = 'x'", cfg).unwrap();
+/// assert_eq!(src, "a = \"x\"\n");
+/// ```
+pub fn format_code_with_config(src: &str, cfg: Config) -> Result<String> {
+    let module = parse_code(src)?;
+    let formatted_src = print_ast_module_with_config(&module, cfg.clone());
+    let formatted_module = parse_code(&formatted_src)
+        .map_err(|err| anyhow!("the formatted source is invalid: {}", err))?;
+    if comment_texts(&module) != comment_texts(&formatted_module) {
+        return Err(anyhow!("the formatted source loses comments"));
+    }
+    if print_ast_module_with_config(&formatted_module, cfg) != formatted_src {
+        return Err(anyhow!("the formatting is not idempotent"));
+    }
+    Ok(formatted_src)
+}
+
+/// Formats the lines from `start_line` to `end_line` (1 based and inclusive) of a
+/// code source, and returns the minimal text edits in the source order, which are
+/// the changed lines between the source and the [format_code] result that
+/// intersect the range. The end position of an edit may be the line after the
+/// last line of the source. The printer config is loaded from the `kcl.mod` of
+/// the current working directory.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_tools::format::format_range;
+///
+/// let edits = format_range("a=1\nb=2\n", 2, 2).unwrap();
+/// assert_eq!(edits.len(), 1);
+/// assert_eq!(edits[0].start.line, 2);
+/// assert_eq!(edits[0].end.line, 3);
+/// assert_eq!(edits[0].text, "b = 2\n");
+/// ```
+pub fn format_range(src: &str, start_line: u64, end_line: u64) -> Result<Vec<Replacement>> {
+    format_range_with_config(src, start_line, end_line, load_format_config(".")?)
+}
+
+/// Formats the lines of a code source with the printer config like [format_range].
+pub fn format_range_with_config(
+    src: &str,
+    start_line: u64,
+    end_line: u64,
+    cfg: Config,
+) -> Result<Vec<Replacement>> {
+    let formatted_src = format_code_with_config(src, cfg)?;
+    let lines: Vec<&str> = src.split_inclusive('\n').collect();
+    let formatted_lines: Vec<&str> = formatted_src.split_inclusive('\n').collect();
+    // Diff the lines ignoring the whitespaces, thus the lines only reformatted
+    // are paired and replaced one by one.
+    let keys = |lines: &[&str]| -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.split_whitespace().collect())
+            .collect()
+    };
+    let mut hunks: Vec<(Range<usize>, Range<usize>)> = vec![];
+    for op in capture_diff_slices(Algorithm::Myers, &keys(&lines), &keys(&formatted_lines)) {
+        let (tag, old_range, new_range) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            hunks.extend(
+                old_range
+                    .zip(new_range)
+                    .filter(|(i, j)| lines[*i] != formatted_lines[*j])
+                    .map(|(i, j)| (i..i + 1, j..j + 1)),
+            );
+        } else {
+            hunks.push((old_range, new_range));
+        }
+    }
+    // The 0 based line range [start, end) of the source.
+    let (start, end) = (start_line.saturating_sub(1) as usize, end_line as usize);
+    Ok(hunks
+        .into_iter()
+        .filter(|(old_range, _)| {
+            if old_range.is_empty() {
+                start <= old_range.start && old_range.start <= end
+            } else {
+                old_range.start < end && start < old_range.end
+            }
+        })
+        .map(|(old_range, new_range)| Replacement {
+            start: Position::line_start("", old_range.start as u64 + 1),
+            end: Position::line_start("", old_range.end as u64 + 1),
+            text: formatted_lines[new_range].concat(),
+        })
+        .collect())
+}
+
+/// Parses the code source and returns the first syntax error if any.
+fn parse_code(src: &str) -> Result<Module> {
+    let (module, errors) =
+        parse_file_with_errors("", Some(src.to_string())).map_err(|err| anyhow!(err))?;
+    match errors.first().and_then(|error| error.messages.first()) {
+        Some(msg) => Err(anyhow!(
+            "syntax error at line {}: {}",
+            msg.pos.line,
+            msg.message
+        )),
+        None => Ok(module),
+    }
+}
+
+/// Returns the sorted comment texts of the module.
+fn comment_texts(module: &Module) -> Vec<&str> {
+    let mut texts: Vec<&str> = module
+        .comments
+        .iter()
+        .map(|comment| comment.node.text.as_str())
+        .collect();
+    texts.sort_unstable();
+    texts
+}
+
+/// Loads the printer config of the file from the format section of the
+/// `kcl.mod` in the package root of the file, and returns the default config
+/// when there is no `kcl.mod`.
+pub fn load_format_config(file: &str) -> Result<Config> {
+    let mut cfg = Config::default();
+    let root = match get_pkg_root(file) {
+        Some(root) => root,
+        None => return Ok(cfg),
+    };
+    if let Some(format) = load_mod_file(&root).format {
+        if let Some(indent_width) = format.indent_width {
+            cfg.indent_len = indent_width;
+        }
+        if let Some(max_width) = format.max_width {
+            cfg.max_width = max_width;
+        }
+        if let Some(quote_style) = &format.quote_style {
+            cfg.quote_style = quote_style.parse().map_err(|err: String| anyhow!(err))?;
+        }
+    }
+    Ok(cfg)
+}
@@ -0,0 +1,4 @@
+[format]
+indent_width = 2
+max_width = 40
+quote_style = "double"
@@ -1,4 +1,7 @@
 use super::*;
+use crate::lint::apply_suggestions;
+use kclvm_ast_pretty::QuoteStyle;
+use kclvm_error::Suggestion;
 use pretty_assertions::assert_eq;
 
 const FILE_INPUT_SUFFIX: &str = ".input";
@@ -72,6 +75,7 @@ fn test_format_with_stdout_option() {
     let opts = FormatOptions {
         is_stdout: true,
         recursively: false,
+        ..Default::default()
     };
     let changed_files = format("./src/format/test_data/format_path_data/if.k", &opts).unwrap();
     assert_eq!(changed_files.len(), 1);
@@ -80,7 +84,135 @@ fn test_format_with_stdout_option() {
     let opts = FormatOptions {
         is_stdout: true,
         recursively: true,
+        ..Default::default()
     };
     let changed_files = format("./src/format/test_data/format_path_data/", &opts).unwrap();
     assert_eq!(changed_files.len(), 2);
 }
+
+#[test]
+fn test_format_with_check_option() {
+    let file = "./src/format/test_data/format_path_data/if.k";
+    let src = std::fs::read_to_string(file).unwrap();
+    let opts = FormatOptions {
+        is_check: true,
+        ..Default::default()
+    };
+    let changed_files = format(file, &opts).unwrap();
+    assert_eq!(changed_files.len(), 1);
+    // The check mode does not modify the file.
+    assert_eq!(std::fs::read_to_string(file).unwrap(), src);
+}
+
+#[test]
+fn test_unified_diff() {
+    let diff = unified_diff("test.k", "a=1\n", "a = 1\n");
+    assert_eq!(diff, "--- test.k\n+++ test.k\n@@ -1 +1 @@\n-a=1\n+a = 1\n");
+}
+
+#[test]
+fn test_format_with_mod_file_config() {
+    let file = "./src/format/test_data/format_config_data/main.k";
+    let cfg = load_format_config(file).unwrap();
+    assert_eq!(cfg.indent_len, 2);
+    assert_eq!(cfg.max_width, 40);
+    assert_eq!(cfg.quote_style, QuoteStyle::Double);
+    let src = std::fs::read_to_string(file).unwrap();
+    let (formatted_src, is_formatted) = format_source_with_config(&src, cfg).unwrap();
+    assert!(is_formatted);
+    assert_eq!(
+        formatted_src,
+        std::fs::read_to_string("./src/format/test_data/format_config_data/main.golden").unwrap()
+    );
+    // The in-memory formatting with the config of the file.
+    assert_eq!(
+        format_code_with_config(&src, load_format_config(file).unwrap()).unwrap(),
+        formatted_src
+    );
+}
+
+const FORMAT_CODE_TEST_CASES: &[&str; 2] = &["comment", "literal"];
+
+/// Returns the sorted comment texts of the source.
+fn comments_of(src: &str) -> Vec<String> {
+    let module = parse_file("", Some(src.to_string())).unwrap();
+    let mut comments: Vec<String> = module
+        .comments
+        .iter()
+        .map(|comment| comment.node.text.clone())
+        .collect();
+    comments.sort();
+    comments
+}
+
+#[test]
+fn test_format_code() {
+    for case in FORMAT_CODE_TEST_CASES {
+        let path = format!("./src/format/test_data/format_code_data/{}", case);
+        let src = std::fs::read_to_string(format!("{}{}", path, FILE_INPUT_SUFFIX)).unwrap();
+        let golden = std::fs::read_to_string(format!("{}{}", path, FILE_OUTPUT_SUFFIX)).unwrap();
+        assert_eq!(
+            format_code(&src).unwrap(),
+            golden,
+            "Test failed on {}",
+            case
+        );
+    }
+}
+
+#[test]
+fn test_format_code_idempotence_and_comments() {
+    let paths = TEST_CASES
+        .iter()
+        .map(|case| format!("./src/format/test_data/format_data/{}", case))
+        .chain(
+            FORMAT_CODE_TEST_CASES
+                .iter()
+                .map(|case| format!("./src/format/test_data/format_code_data/{}", case)),
+        );
+    for path in paths {
+        let src = std::fs::read_to_string(format!("{}{}", path, FILE_INPUT_SUFFIX)).unwrap();
+        let formatted_src = format_code(&src).unwrap();
+        assert_eq!(
+            format_code(&formatted_src).unwrap(),
+            formatted_src,
+            "Test failed on {}",
+            path
+        );
+        assert_eq!(
+            comments_of(&formatted_src),
+            comments_of(&src),
+            "Test failed on {}",
+            path
+        );
+    }
+}
+
+#[test]
+fn test_format_code_with_syntax_error() {
+    let src =
+        std::fs::read_to_string("./src/format/test_data/fail_format_data/syntax_err.k").unwrap();
+    assert!(format_code(&src).is_err());
+}
+
+#[test]
+fn test_format_range() {
+    let src = "a=1\nb=2\n\n\nc=3";
+    let edits = format_range(src, 2, 2).unwrap();
+    assert_eq!(edits.len(), 1);
+    assert_eq!((edits[0].start.line, edits[0].end.line), (2, 3));
+    assert_eq!(edits[0].text, "b = 2\n");
+    // The edits of the whole source make the formatted source.
+    let suggestion = Suggestion {
+        message: "".to_string(),
+        replacements: format_range(src, 1, 5).unwrap(),
+    };
+    assert_eq!(
+        apply_suggestions(src, &[&suggestion]),
+        format_code(src).unwrap()
+    );
+    // No edits for the formatted lines.
+    assert!(format_range(&format_code(src).unwrap(), 1, 3)
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,680 @@
+//! Export the KCL schemas as the JSON Schema (draft 2020-12) document or the OpenAPI v3
+//! component schemas.
+//!
+//! The schema types are resolved by [kclvm_query::get_program_schema_types] and mapped as
+//! follows:
+//!
+//! - The schema is an object schema, its attributes are the properties, the attributes which
+//!   are not optional are required, and the parent schema is referenced by `allOf`.
+//! - `[T]` and `{K:V}` are the array and the object with the `additionalProperties` schemas,
+//!   the literal types and the unions of them are `enum`s and the other unions are `anyOf`s.
+//! - The index signature is the schema of the additional properties, and the schema without
+//!   the index signature is closed because KCL schemas can't be added unknown attributes.
+//! - The default values and the `@deprecated` decorators are the annotations.
+//! - The checks which can be represented are the validation keywords of the properties, such
+//!   as `age >= 0`, `len(name) <= 63`, `regex.match(name, "^[a-z]+$")`, `multiplyof(port, 2)`,
+//!   `isunique(items)` and `kind in ["a", "b"]`, and the other checks are ignored.
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use anyhow::{anyhow, Result};
+use kclvm_ast::{ast, MAIN_PKG};
+use kclvm_parser::{load_program, parse_expr};
+use kclvm_query::{get_program_schema_types, SchemaTypeInfo};
+use kclvm_sema::ty::{Type, TypeKind};
+use serde_json::{json, Map, Value};
+
+/// The JSON Schema dialect of the exported document.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+/// The OpenAPI version of the exported document.
+pub const OPENAPI_VERSION: &str = "3.0.3";
+
+const DEPRECATED_DECORATOR: &str = "deprecated";
+
+/// The exported document format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// The JSON Schema document with the schemas in `$defs`.
+    #[default]
+    JsonSchema,
+    /// The OpenAPI v3 document with the schemas in `components.schemas`.
+    OpenAPI,
+}
+
+/// ExportOptions contains two options:
+/// - format: the exported document format.
+/// - schema_name: the main package schema to export with the schemas it references,
+///   all the schemas in the main package are exported when it is [None].
+#[derive(Debug, Default)]
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    pub schema_name: Option<String>,
+}
+
+/// Export the schemas in the KCL file as the JSON Schema or the OpenAPI document.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_tools::jsonschema::export::{export_file, ExportFormat, ExportOptions};
+///
+/// let opts = ExportOptions {
+///     format: ExportFormat::OpenAPI,
+///     schema_name: Some("App".to_string()),
+/// };
+/// let document = export_file("main.k", &opts).unwrap();
+/// println!("{}", serde_json::to_string_pretty(&document).unwrap());
+/// ```
+pub fn export_file(file: &str, opts: &ExportOptions) -> Result<Value> {
+    let prog = load_program(&[file], None).map_err(|err| anyhow!("{}", err))?;
+    export_program(&prog, opts)
+}
+
+/// Export the schemas in the AST program as the JSON Schema or the OpenAPI document.
+pub fn export_program(prog: &ast::Program, opts: &ExportOptions) -> Result<Value> {
+    let schema_types: Vec<SchemaTypeInfo> = get_program_schema_types(prog)
+        .into_iter()
+        .filter(|ty| !ty.is_rule && !ty.is_mixin && !ty.is_protocol)
+        .collect();
+    let mut worklist: Vec<&SchemaTypeInfo> = match &opts.schema_name {
+        Some(name) => match schema_types
+            .iter()
+            .find(|ty| ty.pkgpath == MAIN_PKG && &ty.name == name)
+        {
+            Some(ty) => vec![ty],
+            None => return Err(anyhow!("Schema '{}' not found", name)),
+        },
+        None => schema_types
+            .iter()
+            .filter(|ty| ty.pkgpath == MAIN_PKG)
+            .collect(),
+    };
+    // Collect the schemas and the schemas they reference.
+    let mut exported: Vec<&SchemaTypeInfo> = vec![];
+    while let Some(schema_ty) = worklist.pop() {
+        if exported
+            .iter()
+            .any(|ty| ty.pkgpath == schema_ty.pkgpath && ty.name == schema_ty.name)
+        {
+            continue;
+        }
+        let mut refs = vec![];
+        if let TypeKind::Schema(ty) = &schema_ty.ty.kind {
+            if let Some(base) = &ty.base {
+                refs.push((base.pkgpath.clone(), base.name.clone()));
+            }
+        }
+        for attr in schema_ty.attrs.values() {
+            collect_schema_refs(&attr.ty, &mut refs);
+        }
+        if let Some(index_signature) = &schema_ty.index_signature {
+            collect_schema_refs(&index_signature.value_ty, &mut refs);
+        }
+        for (pkgpath, name) in refs {
+            if let Some(ty) = schema_types
+                .iter()
+                .find(|ty| ty.pkgpath == pkgpath && ty.name == name)
+            {
+                worklist.push(ty);
+            }
+        }
+        exported.push(schema_ty);
+    }
+    exported.sort_by_key(|ty| def_name(&ty.pkgpath, &ty.name));
+    let exporter = Exporter {
+        prog,
+        format: opts.format,
+        parents: exported
+            .iter()
+            .filter_map(|ty| match &ty.ty.kind {
+                TypeKind::Schema(ty) => ty
+                    .base
+                    .as_ref()
+                    .map(|base| def_name(&base.pkgpath, &base.name)),
+                _ => None,
+            })
+            .collect(),
+    };
+    let mut defs = Map::new();
+    for schema_ty in &exported {
+        defs.insert(
+            def_name(&schema_ty.pkgpath, &schema_ty.name),
+            exporter.schema_def(schema_ty),
+        );
+    }
+    let document = match opts.format {
+        ExportFormat::JsonSchema => {
+            let mut document = json!({ "$schema": JSON_SCHEMA_DIALECT });
+            if let Some(name) = &opts.schema_name {
+                document["$ref"] = json!(exporter.ref_path(name));
+            }
+            document["$defs"] = Value::Object(defs);
+            document
+        }
+        ExportFormat::OpenAPI => json!({
+            "openapi": OPENAPI_VERSION,
+            "info": {
+                "title": opts.schema_name.as_deref().unwrap_or(MAIN_PKG),
+                "version": "0.0.0",
+            },
+            "paths": {},
+            "components": { "schemas": defs },
+        }),
+    };
+    Ok(document)
+}
+
+/// Returns the definition name of the schema, the names of the schemas not in the main
+/// package are prefixed with the package path, e.g. `App` and `pkg.Metadata`.
+fn def_name(pkgpath: &str, name: &str) -> String {
+    if pkgpath.is_empty() || pkgpath == MAIN_PKG {
+        name.to_string()
+    } else {
+        format!("{}.{}", pkgpath, name)
+    }
+}
+
+fn collect_schema_refs(ty: &Type, refs: &mut Vec<(String, String)>) {
+    match &ty.kind {
+        TypeKind::List(item_ty) => collect_schema_refs(item_ty, refs),
+        TypeKind::Dict(key_ty, val_ty) => {
+            collect_schema_refs(key_ty, refs);
+            collect_schema_refs(val_ty, refs);
+        }
+        TypeKind::Union(types) => {
+            for ty in types {
+                collect_schema_refs(ty, refs);
+            }
+        }
+        TypeKind::Schema(schema_ty) => {
+            refs.push((schema_ty.pkgpath.clone(), schema_ty.name.clone()));
+        }
+        _ => {}
+    }
+}
+
+struct Exporter<'a> {
+    prog: &'a ast::Program,
+    format: ExportFormat,
+    /// The definition names of the schemas which are inherited by other schemas.
+    parents: HashSet<String>,
+}
+
+impl<'a> Exporter<'a> {
+    fn ref_path(&self, def_name: &str) -> String {
+        match self.format {
+            ExportFormat::JsonSchema => format!("#/$defs/{}", def_name),
+            ExportFormat::OpenAPI => format!("#/components/schemas/{}", def_name),
+        }
+    }
+
+    fn schema_def(&self, schema_ty: &SchemaTypeInfo) -> Value {
+        let (base, own_attrs) = match &schema_ty.ty.kind {
+            TypeKind::Schema(ty) => (ty.base.as_ref(), ty.attrs.keys().collect::<Vec<_>>()),
+            _ => (None, vec![]),
+        };
+        let mut properties = Map::new();
+        let mut required = vec![];
+        for (name, attr) in &schema_ty.attrs {
+            if base.is_some() && !own_attrs.contains(&name) {
+                continue;
+            }
+            let mut annotations = Map::new();
+            if !attr.doc.is_empty() {
+                annotations.insert("description".to_string(), json!(attr.doc));
+            }
+            if let Some(default) = attr.default.as_deref().and_then(parse_expr) {
+                if let Some(default) = expr_to_json(&default.node) {
+                    annotations.insert("default".to_string(), default);
+                }
+            }
+            if is_deprecated(&attr.decorators) {
+                annotations.insert("deprecated".to_string(), json!(true));
+            }
+            properties.insert(
+                name.clone(),
+                self.with_annotations(self.type_schema(&attr.ty), annotations),
+            );
+            if !attr.is_optional {
+                required.push(json!(name));
+            }
+        }
+        for check in self.schema_checks(schema_ty) {
+            self.check_constraints(schema_ty, check, &mut properties);
+        }
+
+        let mut def = Map::new();
+        def.insert("type".to_string(), json!("object"));
+        if !schema_ty.doc.is_empty() {
+            def.insert("description".to_string(), json!(schema_ty.doc));
+        }
+        if is_deprecated(&schema_ty.decorators) {
+            def.insert("deprecated".to_string(), json!(true));
+        }
+        def.insert("properties".to_string(), Value::Object(properties));
+        if !required.is_empty() {
+            def.insert("required".to_string(), Value::Array(required));
+        }
+        let is_parent = self
+            .parents
+            .contains(&def_name(&schema_ty.pkgpath, &schema_ty.name));
+        match (&schema_ty.index_signature, self.format) {
+            (Some(index_signature), ExportFormat::JsonSchema) => {
+                def.insert(
+                    "unevaluatedProperties".to_string(),
+                    self.type_schema(&index_signature.value_ty),
+                );
+            }
+            (Some(index_signature), ExportFormat::OpenAPI) => {
+                def.insert(
+                    "additionalProperties".to_string(),
+                    self.type_schema(&index_signature.value_ty),
+                );
+            }
+            // The `unevaluatedProperties` sees the properties of the parent schemas in `allOf`,
+            // and the parent schemas are open to be extended.
+            (None, ExportFormat::JsonSchema) if !is_parent => {
+                def.insert("unevaluatedProperties".to_string(), json!(false));
+            }
+            (None, ExportFormat::OpenAPI) if !is_parent && base.is_none() => {
+                def.insert("additionalProperties".to_string(), json!(false));
+            }
+            _ => {}
+        }
+        if let Some(base) = base {
+            def.insert(
+                "allOf".to_string(),
+                json!([{ "$ref": self.ref_path(&def_name(&base.pkgpath, &base.name)) }]),
+            );
+        }
+        Value::Object(def)
+    }
+
+    fn type_schema(&self, ty: &Type) -> Value {
+        match &ty.kind {
+            TypeKind::Bool => json!({"type": "boolean"}),
+            TypeKind::Int => json!({"type": "integer"}),
+            TypeKind::Float => json!({"type": "number"}),
+            // The number multipliers e.g. `1Gi` are output as strings.
+            TypeKind::Str | TypeKind::NumberMultiplier(_) => json!({"type": "string"}),
+            TypeKind::BoolLit(_)
+            | TypeKind::IntLit(_)
+            | TypeKind::FloatLit(_)
+            | TypeKind::StrLit(_) => {
+                let (ty, value) = literal_value(ty).unwrap();
+                match self.format {
+                    ExportFormat::JsonSchema => json!({"type": ty, "const": value}),
+                    ExportFormat::OpenAPI => json!({"type": ty, "enum": [value]}),
+                }
+            }
+            TypeKind::None => match self.format {
+                ExportFormat::JsonSchema => json!({"type": "null"}),
+                ExportFormat::OpenAPI => json!({"nullable": true}),
+            },
+            TypeKind::List(item_ty) => json!({
+                "type": "array",
+                "items": self.type_schema(item_ty),
+            }),
+            TypeKind::Dict(key_ty, val_ty) => {
+                let mut schema = json!({
+                    "type": "object",
+                    "additionalProperties": self.type_schema(val_ty),
+                });
+                if let (TypeKind::Union(_) | TypeKind::StrLit(_), ExportFormat::JsonSchema) =
+                    (&key_ty.kind, self.format)
+                {
+                    schema["propertyNames"] = self.type_schema(key_ty);
+                }
+                schema
+            }
+            TypeKind::Union(types) => self.union_schema(types),
+            TypeKind::Schema(schema_ty) => {
+                json!({"$ref": self.ref_path(&def_name(&schema_ty.pkgpath, &schema_ty.name))})
+            }
+            _ => json!({}),
+        }
+    }
+
+    fn union_schema(&self, types: &[Rc<Type>]) -> Value {
+        let nullable = types.iter().any(|ty| matches!(ty.kind, TypeKind::None));
+        let types: Vec<&Rc<Type>> = types
+            .iter()
+            .filter(|ty| !matches!(ty.kind, TypeKind::None))
+            .collect();
+        let literals: Vec<(&str, Value)> =
+            types.iter().filter_map(|ty| literal_value(ty)).collect();
+        let schema = if !literals.is_empty()
+            && literals.len() == types.len()
+            && literals.iter().all(|(ty, _)| *ty == literals[0].0)
+        {
+            // The union of the literal types e.g. `"Always" | "Never"`.
+            let ty = literals[0].0;
+            let values: Vec<Value> = literals.into_iter().map(|(_, value)| value).collect();
+            json!({"type": ty, "enum": values})
+        } else if types.len() == 1 {
+            self.type_schema(types[0])
+        } else {
+            json!({"anyOf": types.iter().map(|ty| self.type_schema(ty)).collect::<Vec<_>>()})
+        };
+        match (nullable, self.format) {
+            (false, _) => schema,
+            (true, ExportFormat::JsonSchema) => json!({"anyOf": [schema, {"type": "null"}]}),
+            (true, ExportFormat::OpenAPI) => {
+                let mut annotations = Map::new();
+                annotations.insert("nullable".to_string(), json!(true));
+                self.with_annotations(schema, annotations)
+            }
+        }
+    }
+
+    /// Add the annotations to the schema, the `$ref` is wrapped with `allOf` in the OpenAPI
+    /// document because the siblings of the `$ref` are ignored.
+    fn with_annotations(&self, schema: Value, annotations: Map<String, Value>) -> Value {
+        if annotations.is_empty() {
+            return schema;
+        }
+        let mut schema = match schema {
+            Value::Object(schema)
+                if schema.contains_key("$ref") && self.format == ExportFormat::OpenAPI =>
+            {
+                let mut wrapped = Map::new();
+                wrapped.insert("allOf".to_string(), json!([schema]));
+                wrapped
+            }
+            Value::Object(schema) => schema,
+            _ => Map::new(),
+        };
+        schema.extend(annotations);
+        Value::Object(schema)
+    }
+
+    /// Returns the check expressions of the schema statement in the AST program.
+    fn schema_checks(&self, schema_ty: &SchemaTypeInfo) -> Vec<&'a ast::CheckExpr> {
+        let modules = match self.prog.pkgs.get(&schema_ty.pkgpath) {
+            Some(modules) => modules,
+            None => return vec![],
+        };
+        for module in modules.iter().filter(|m| m.filename == schema_ty.filename) {
+            for stmt in &module.body {
+                if let ast::Stmt::Schema(schema_stmt) = &stmt.node {
+                    if schema_stmt.name.node == schema_ty.name {
+                        return schema_stmt.checks.iter().map(|check| &check.node).collect();
+                    }
+                }
+            }
+        }
+        vec![]
+    }
+
+    /// Add the validation keywords of the check expression to the properties. A check guarded
+    /// by the attribute itself e.g. `age > 0 if age` is also represented because the keywords
+    /// only apply to the present properties.
+    fn check_constraints(
+        &self,
+        schema_ty: &SchemaTypeInfo,
+        check: &ast::CheckExpr,
+        properties: &mut Map<String, Value>,
+    ) {
+        let guard = match &check.if_cond {
+            None => None,
+            Some(cond) => match attr_name(&cond.node) {
+                Some(name) => Some(name),
+                None => return,
+            },
+        };
+        let mut constraints = vec![];
+        self.expr_constraints(schema_ty, &check.test.node, &mut constraints);
+        for (name, keyword, value) in constraints {
+            // The names not in the schema attributes are local variables or the
+            // attributes of other schemas, e.g., `max_len = 10` in the schema body.
+            if guard.map_or(false, |guard| guard != name) || !schema_ty.attrs.contains_key(name) {
+                continue;
+            }
+            if let Value::Object(property) = properties
+                .entry(name.to_string())
+                .or_insert_with(|| json!({}))
+            {
+                property.insert(keyword.to_string(), value);
+            }
+        }
+    }
+
+    fn expr_constraints<'e>(
+        &self,
+        schema_ty: &SchemaTypeInfo,
+        expr: &'e ast::Expr,
+        constraints: &mut Vec<(&'e str, &'static str, Value)>,
+    ) {
+        match expr {
+            ast::Expr::Paren(paren_expr) => {
+                self.expr_constraints(schema_ty, &paren_expr.expr.node, constraints)
+            }
+            ast::Expr::Binary(binary_expr)
+                if matches!(binary_expr.op, ast::BinOrCmpOp::Bin(ast::BinOp::And)) =>
+            {
+                self.expr_constraints(schema_ty, &binary_expr.left.node, constraints);
+                self.expr_constraints(schema_ty, &binary_expr.right.node, constraints);
+            }
+            ast::Expr::Compare(compare) => {
+                let mut left = &compare.left.node;
+                for (op, right) in compare.ops.iter().zip(&compare.comparators) {
+                    self.compare_constraints(schema_ty, left, op, &right.node, constraints);
+                    left = &right.node;
+                }
+            }
+            ast::Expr::Call(call_expr) => {
+                let func = match &call_expr.func.node {
+                    ast::Expr::Identifier(identifier) => identifier.get_name(),
+                    _ => return,
+                };
+                let args: Vec<&ast::Expr> = call_expr.args.iter().map(|arg| &arg.node).collect();
+                match (func.as_str(), args.as_slice()) {
+                    ("regex.match", [attr, ast::Expr::StringLit(pattern)]) => {
+                        if let Some(name) = attr_name(attr) {
+                            // `regex.match` matches from the beginning of the string.
+                            let pattern = if pattern.value.starts_with('^') {
+                                pattern.value.clone()
+                            } else {
+                                format!("^{}", pattern.value)
+                            };
+                            constraints.push((name, "pattern", json!(pattern)));
+                        }
+                    }
+                    ("multiplyof", [attr, value]) => {
+                        if let (Some(name), Some(value)) = (attr_name(attr), number_value(value)) {
+                            constraints.push((name, "multipleOf", value));
+                        }
+                    }
+                    ("isunique", [attr]) => {
+                        if let Some(name) = attr_name(attr) {
+                            constraints.push((name, "uniqueItems", json!(true)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn compare_constraints<'e>(
+        &self,
+        schema_ty: &SchemaTypeInfo,
+        left: &'e ast::Expr,
+        op: &ast::CmpOp,
+        right: &'e ast::Expr,
+        constraints: &mut Vec<(&'e str, &'static str, Value)>,
+    ) {
+        if let (ast::CmpOp::In, Some(name), ast::Expr::List(list_expr)) =
+            (op, attr_name(left), right)
+        {
+            let values: Option<Vec<Value>> = list_expr
+                .elts
+                .iter()
+                .map(|elt| expr_to_json(&elt.node))
+                .collect();
+            if let Some(values) = values {
+                constraints.push((name, "enum", Value::Array(values)));
+            }
+            return;
+        }
+        // Normalize the comparison as `<subject> <op> <number>`.
+        let (subject, op, value) = match (number_value(left), number_value(right)) {
+            (None, Some(value)) => (left, op.clone(), value),
+            (Some(value), None) => {
+                let op = match op {
+                    ast::CmpOp::Lt => ast::CmpOp::Gt,
+                    ast::CmpOp::LtE => ast::CmpOp::GtE,
+                    ast::CmpOp::Gt => ast::CmpOp::Lt,
+                    ast::CmpOp::GtE => ast::CmpOp::LtE,
+                    op => op.clone(),
+                };
+                (right, op, value)
+            }
+            _ => return,
+        };
+        if let Some(name) = attr_name(subject) {
+            let exclusive = |keyword: &'static str, inclusive: &'static str| match self.format {
+                ExportFormat::JsonSchema => vec![(name, keyword, value.clone())],
+                // The exclusive keywords are booleans in the OpenAPI v3.0.
+                ExportFormat::OpenAPI => vec![
+                    (name, inclusive, value.clone()),
+                    (name, keyword, json!(true)),
+                ],
+            };
+            let keywords = match op {
+                ast::CmpOp::Gt => exclusive("exclusiveMinimum", "minimum"),
+                ast::CmpOp::GtE => vec![(name, "minimum", value.clone())],
+                ast::CmpOp::Lt => exclusive("exclusiveMaximum", "maximum"),
+                ast::CmpOp::LtE => vec![(name, "maximum", value.clone())],
+                _ => vec![],
+            };
+            constraints.extend(keywords);
+        } else if let Some(name) = len_attr_name(subject) {
+            let (min, max) = match schema_ty.attrs.get(name).map(|attr| &attr.ty.kind) {
+                Some(TypeKind::Str) => ("minLength", "maxLength"),
+                Some(TypeKind::List(_)) => ("minItems", "maxItems"),
+                Some(TypeKind::Dict(..)) => ("minProperties", "maxProperties"),
+                _ => return,
+            };
+            let value = match value.as_i64() {
+                Some(value) => value,
+                None => return,
+            };
+            let keywords = match op {
+                ast::CmpOp::Gt => vec![(name, min, json!(value + 1))],
+                ast::CmpOp::GtE => vec![(name, min, json!(value))],
+                ast::CmpOp::Lt => vec![(name, max, json!(value - 1))],
+                ast::CmpOp::LtE => vec![(name, max, json!(value))],
+                ast::CmpOp::Eq => vec![(name, min, json!(value)), (name, max, json!(value))],
+                _ => vec![],
+            };
+            constraints.extend(keywords);
+        }
+    }
+}
+
+fn is_deprecated(decorators: &[kclvm_query::DecoratorInfo]) -> bool {
+    decorators
+        .iter()
+        .any(|decorator| decorator.name == DEPRECATED_DECORATOR)
+}
+
+/// Returns the JSON Schema type and the value of the literal type.
+fn literal_value(ty: &Type) -> Option<(&'static str, Value)> {
+    match &ty.kind {
+        TypeKind::BoolLit(v) => Some(("boolean", json!(v))),
+        TypeKind::IntLit(v) => Some(("integer", json!(v))),
+        TypeKind::FloatLit(v) => Some(("number", json!(v))),
+        TypeKind::StrLit(v) => Some(("string", json!(v))),
+        _ => None,
+    }
+}
+
+/// Returns the attribute name of the identifier expression e.g. `name`.
+fn attr_name(expr: &ast::Expr) -> Option<&str> {
+    match expr {
+        ast::Expr::Identifier(identifier) if identifier.names.len() == 1 => {
+            Some(identifier.names[0].as_str())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the attribute name of the length expression e.g. `len(name)`.
+fn len_attr_name(expr: &ast::Expr) -> Option<&str> {
+    match expr {
+        ast::Expr::Call(call_expr) if call_expr.args.len() == 1 => match &call_expr.func.node {
+            ast::Expr::Identifier(identifier) if identifier.get_name() == "len" => {
+                attr_name(&call_expr.args[0].node)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn number_value(expr: &ast::Expr) -> Option<Value> {
+    match expr_to_json(expr)? {
+        Value::Number(number) => Some(Value::Number(number)),
+        _ => None,
+    }
+}
+
+/// Convert the literal expression into the JSON value, returns [None] when the expression
+/// can't be evaluated statically.
+fn expr_to_json(expr: &ast::Expr) -> Option<Value> {
+    match expr {
+        ast::Expr::NumberLit(number_lit) => match (&number_lit.binary_suffix, &number_lit.value) {
+            (Some(suffix), ast::NumberLitValue::Int(v)) => {
+                Some(json!(format!("{}{}", v, suffix.value())))
+            }
+            (Some(suffix), ast::NumberLitValue::Float(v)) => {
+                Some(json!(format!("{}{}", v, suffix.value())))
+            }
+            (None, ast::NumberLitValue::Int(v)) => Some(json!(v)),
+            (None, ast::NumberLitValue::Float(v)) => Some(json!(v)),
+        },
+        ast::Expr::Unary(unary_expr) if matches!(unary_expr.op, ast::UnaryOp::USub) => {
+            match &unary_expr.operand.node {
+                ast::Expr::NumberLit(number_lit) if number_lit.binary_suffix.is_none() => {
+                    match number_lit.value {
+                        ast::NumberLitValue::Int(v) => Some(json!(-v)),
+                        ast::NumberLitValue::Float(v) => Some(json!(-v)),
+                    }
+                }
+                _ => None,
+            }
+        }
+        ast::Expr::StringLit(string_lit) => Some(json!(string_lit.value)),
+        ast::Expr::NameConstantLit(name_constant) => match name_constant.value {
+            ast::NameConstant::True => Some(json!(true)),
+            ast::NameConstant::False => Some(json!(false)),
+            ast::NameConstant::None => Some(Value::Null),
+            ast::NameConstant::Undefined => None,
+        },
+        ast::Expr::Paren(paren_expr) => expr_to_json(&paren_expr.expr.node),
+        ast::Expr::List(list_expr) => list_expr
+            .elts
+            .iter()
+            .map(|elt| expr_to_json(&elt.node))
+            .collect::<Option<Vec<Value>>>()
+            .map(Value::Array),
+        ast::Expr::Schema(schema_expr) => expr_to_json(&schema_expr.config.node),
+        ast::Expr::Config(config_expr) => {
+            let mut object = Map::new();
+            for entry in &config_expr.items {
+                let key = match &entry.node.key.as_ref()?.node {
+                    ast::Expr::Identifier(identifier) if identifier.names.len() == 1 => {
+                        identifier.names[0].clone()
+                    }
+                    ast::Expr::StringLit(string_lit) => string_lit.value.clone(),
+                    _ => return None,
+                };
+                object.insert(key, expr_to_json(&entry.node.value.node)?);
+            }
+            Some(Value::Object(object))
+        }
+        _ => None,
+    }
+}
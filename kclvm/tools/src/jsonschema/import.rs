@@ -0,0 +1,1110 @@
+//! Import the JSON Schema documents, the OpenAPI v3 documents and the Kubernetes
+//! CustomResourceDefinitions as KCL schemas.
+//!
+//! The definitions are mapped to the KCL AST and printed by [kclvm_ast_pretty] as follows:
+//!
+//! - The object schemas are KCL schemas, their properties are the attributes, the properties
+//!   which are not required are optional, and the first object schema referenced by `allOf`
+//!   is the parent schema. The other definitions are type aliases.
+//! - The inline object schemas are the schemas named by the parent schema and the property
+//!   names, e.g. the schema of `spec` in `CronTab` is named `CronTabSpec`.
+//! - `string`, `integer`, `number`, `boolean`, `array` and the `object` without properties
+//!   are `str`, `int`, `float`, `bool`, `[T]` and `{str:T}`, `enum` and `const` are the
+//!   literal types, `anyOf` and `oneOf` are the unions and `x-kubernetes-int-or-string`
+//!   is `int | str`.
+//! - The descriptions are the schema documents, the defaults are the attribute values and
+//!   `deprecated` is the `@deprecated` decorator.
+//! - The validation keywords of the properties are the schema checks, e.g. `pattern`,
+//!   `minLength`, `maximum`, `multipleOf` and `uniqueItems`.
+//! - The properties named by the KCL keywords are the attributes escaped by `$`, e.g. `$check`.
+//!   `additionalProperties`, `x-kubernetes-preserve-unknown-fields` and the properties which
+//!   are not valid KCL attribute names, e.g. `_id` and `x-extension`, are the index signature
+//!   `[...str]: T`.
+//!
+//! A JSON Schema document is imported as a single KCL file. The OpenAPI definitions such as
+//! `io.k8s.api.core.v1.Pod` are imported as the KCL files in the packages of their namespaces,
+//! e.g. `io_k8s_api_core_v1/pod.k`, and the definitions without namespaces are imported as a
+//! single KCL file. Each CustomResourceDefinition is imported as a KCL file in the package of
+//! its API group.
+//!
+//! The properties imported as the index signature and the references which can't be resolved,
+//! e.g. the references to the external documents, are reported as the warnings.
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::ast::{self, Node, NodeRef};
+use kclvm_ast::node_ref;
+use kclvm_ast_pretty::{print_ast_node, ASTNode};
+use serde::Deserialize;
+use serde_yaml::{Mapping, Number, Value};
+
+const CRD_KIND: &str = "CustomResourceDefinition";
+const REGEX_PKG: &str = "regex";
+const DEPRECATED_DECORATOR: &str = "deprecated";
+/// The KCL keywords which are escaped by `$` in the attribute names and the check expressions.
+const KEYWORDS: &[&str] = &[
+    "mixin",
+    "check",
+    "assert",
+    "if",
+    "not",
+    "lambda",
+    "all",
+    "any",
+    "filter",
+    "map",
+    "True",
+    "False",
+    "None",
+    "Undefined",
+];
+/// The JSON pointers of the reusable schema definitions.
+const DEFINITIONS: &[&str] = &["/$defs", "/definitions", "/components/schemas"];
+/// The max depth of the schemas composed by `allOf`, which stops the recursive references.
+const MAX_ALL_OF_DEPTH: usize = 16;
+static ANY_PROPERTIES: Value = Value::Bool(true);
+
+/// The imported document format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// The JSON Schema document, its root schema and the schemas in `$defs` and
+    /// `definitions` are imported.
+    JsonSchema,
+    /// The OpenAPI v3 (or Swagger v2) document, the schemas in `components.schemas`
+    /// and `definitions` are imported.
+    OpenAPI,
+    /// The Kubernetes CustomResourceDefinition YAML stream, the storage version schema
+    /// of each CRD is imported.
+    CRD,
+}
+
+/// ImportOptions contains one option:
+/// - format: the imported document format. When it is [None], the document with the
+///   `openapi` or `swagger` field is an OpenAPI document, the document of the kind
+///   `CustomResourceDefinition` is a CRD and the others are JSON Schema documents.
+#[derive(Debug, Default)]
+pub struct ImportOptions {
+    pub format: Option<ImportFormat>,
+}
+
+/// The KCL code imported from the definitions.
+#[derive(Debug, Default)]
+pub struct ImportResult {
+    /// The generated KCL files keyed by their paths relative to the output directory.
+    pub files: IndexMap<String, String>,
+    /// The warnings of the definitions which are not imported exactly.
+    pub warnings: IndexSet<String>,
+}
+
+/// Import the JSON Schema, OpenAPI or CRD definitions in the JSON or YAML file as KCL code.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_tools::jsonschema::import::{import_file, ImportOptions};
+///
+/// let result = import_file("crontab.crd.yaml", &ImportOptions::default()).unwrap();
+/// for (path, code) in &result.files {
+///     println!("{}:\n{}", path, code);
+/// }
+/// for warning in &result.warnings {
+///     eprintln!("Warning: {}", warning);
+/// }
+/// ```
+pub fn import_file(file: &str, opts: &ImportOptions) -> Result<ImportResult> {
+    let content =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read '{}'", file))?;
+    let name = Path::new(file)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split('.').next())
+        .unwrap_or_default();
+    import_str(name, &content, opts).with_context(|| format!("Failed to import '{}'", file))
+}
+
+/// Import the JSON Schema, OpenAPI or CRD definitions in the JSON or YAML source as KCL code.
+/// `name` is the name of the generated file and the root schema of the JSON Schema document
+/// without the title.
+pub fn import_str(name: &str, content: &str, opts: &ImportOptions) -> Result<ImportResult> {
+    let mut documents = vec![];
+    // JSON is also YAML, so the YAML deserializer loads both of them.
+    for document in serde_yaml::Deserializer::from_str(content) {
+        let value = Value::deserialize(document)?;
+        if !value.is_null() {
+            documents.push(value);
+        }
+    }
+    let first = documents
+        .first()
+        .ok_or_else(|| anyhow!("No definitions found"))?;
+    let format = opts.format.unwrap_or_else(|| detect_format(first));
+    let mut result = ImportResult::default();
+    match format {
+        ImportFormat::CRD => {
+            // The type names used in each API group package.
+            let mut package_names = HashMap::new();
+            for document in documents.iter().filter(|doc| doc["kind"] == CRD_KIND) {
+                let crd = Crd::new(document)?;
+                let pkg = package_name(&crd.group);
+                let mut generator = Generator::new(&crd.schema, package_names);
+                generator.pkg = pkg.clone();
+                let name = generator.unique_name(&pkg, &pascal_case(&crd.kind));
+                generator.pending.push_back((name, &crd.schema));
+                generator.gen_pending();
+                result.files.insert(
+                    format!("{}/{}.k", pkg, snake_case(&crd.kind)),
+                    generator.print(),
+                );
+                result.warnings.extend(generator.warnings);
+                package_names = generator.names;
+            }
+            if result.files.is_empty() {
+                return Err(anyhow!("No {} found", CRD_KIND));
+            }
+        }
+        ImportFormat::JsonSchema | ImportFormat::OpenAPI => {
+            let mut generator = Generator::new(first, HashMap::new());
+            if format == ImportFormat::JsonSchema && is_object_schema(first) {
+                let title = first["title"].as_str().unwrap_or(name);
+                let name = generator.unique_name("", &pascal_case(title));
+                // The recursive schemas reference the root schema by `#`.
+                generator
+                    .refs
+                    .insert("#".to_string(), ("".to_string(), name.clone()));
+                generator.pending.push_back((name, first));
+            }
+            let definitions = generator.register_definitions(format == ImportFormat::OpenAPI);
+            if generator.pending.is_empty() && definitions.is_empty() {
+                return Err(anyhow!("No schema definitions found"));
+            }
+            let (definitions, pkg_definitions): (Vec<_>, Vec<_>) = definitions
+                .into_iter()
+                .partition(|(pkg, _, _)| pkg.is_empty());
+            generator.pending.extend(
+                definitions
+                    .into_iter()
+                    .map(|(_, name, value)| (name, value)),
+            );
+            if !generator.pending.is_empty() {
+                generator.gen_pending();
+                result
+                    .files
+                    .insert(format!("{}.k", package_name(name)), generator.print());
+            }
+            // Each definition in a package is imported as a KCL file like the CRDs.
+            for (pkg, name, value) in pkg_definitions {
+                let path = format!("{}/{}.k", pkg, snake_case(&name));
+                generator.pkg = pkg;
+                generator.pending.push_back((name, value));
+                generator.gen_pending();
+                result.files.insert(path, generator.print());
+            }
+            result.warnings.extend(generator.warnings);
+        }
+    }
+    Ok(result)
+}
+
+fn detect_format(document: &Value) -> ImportFormat {
+    if document.get("openapi").is_some() || document.get("swagger").is_some() {
+        ImportFormat::OpenAPI
+    } else if document["kind"] == CRD_KIND {
+        ImportFormat::CRD
+    } else {
+        ImportFormat::JsonSchema
+    }
+}
+
+/// The storage version schema of the CustomResourceDefinition.
+struct Crd {
+    group: String,
+    kind: String,
+    /// The `openAPIV3Schema` with the `apiVersion` and `kind` constants.
+    schema: Value,
+}
+
+impl Crd {
+    fn new(document: &Value) -> Result<Self> {
+        let spec = &document["spec"];
+        let group = spec["group"]
+            .as_str()
+            .ok_or_else(|| anyhow!("The {} has no group", CRD_KIND))?;
+        let kind = spec["names"]["kind"]
+            .as_str()
+            .ok_or_else(|| anyhow!("The {} has no kind", CRD_KIND))?;
+        // The `apiextensions.k8s.io/v1` CRDs define the schema of each version, and the
+        // `v1beta1` CRDs may define the schema of all the versions in `validation`.
+        let versions = spec["versions"]
+            .as_sequence()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let (version, schema) = match versions
+            .iter()
+            .find(|version| version["storage"] == true)
+            .or_else(|| versions.first())
+        {
+            Some(version) => (
+                &version["name"],
+                version["schema"]
+                    .get("openAPIV3Schema")
+                    .unwrap_or(&spec["validation"]["openAPIV3Schema"]),
+            ),
+            None => (&spec["version"], &spec["validation"]["openAPIV3Schema"]),
+        };
+        let version = version
+            .as_str()
+            .ok_or_else(|| anyhow!("The {} '{}' has no version", CRD_KIND, kind))?;
+        if !schema.is_mapping() {
+            return Err(anyhow!("The {} '{}' has no schema", CRD_KIND, kind));
+        }
+        let mut schema = schema.clone();
+        let api_version = format!("{}/{}", group, version);
+        // The `apiVersion` and `kind` of the custom resources are the first attributes with
+        // the constant values.
+        let mut properties = Mapping::new();
+        for (attr, value) in [("apiVersion", api_version.as_str()), ("kind", kind)] {
+            let mut property = match schema["properties"].get(attr) {
+                Some(property) if property.is_mapping() => property.clone(),
+                _ => Value::Mapping(Mapping::new()),
+            };
+            property["type"] = Value::from("string");
+            property["const"] = Value::from(value);
+            properties.insert(Value::from(attr), property);
+            match &mut schema["required"] {
+                Value::Sequence(required) => {
+                    if !required.contains(&Value::from(attr)) {
+                        required.push(Value::from(attr));
+                    }
+                }
+                required => *required = Value::Sequence(vec![Value::from(attr)]),
+            }
+        }
+        if let Some(Value::Mapping(others)) = schema.get("properties") {
+            for (attr, property) in others {
+                if !properties.contains_key(attr) {
+                    properties.insert(attr.clone(), property.clone());
+                }
+            }
+        }
+        schema["properties"] = Value::Mapping(properties);
+        Ok(Self {
+            group: group.to_string(),
+            kind: kind.to_string(),
+            schema,
+        })
+    }
+}
+
+/// The properties of an object schema and the schemas it composes by `allOf`.
+#[derive(Default)]
+struct ObjectSchema<'a> {
+    /// The reference of the parent schema.
+    parent: Option<&'a str>,
+    properties: IndexMap<&'a str, &'a Value>,
+    required: HashSet<&'a str>,
+    /// The schema of the additional properties, which is `true` for any values.
+    additional_properties: Option<&'a Value>,
+}
+
+/// Generator generates the KCL statements of the schemas in the document.
+struct Generator<'a> {
+    document: &'a Value,
+    /// The packages and the KCL type names of the definitions keyed by their references, the
+    /// package is empty when the definition is not in a package.
+    refs: HashMap<String, (String, String)>,
+    /// The package of the generated file.
+    pkg: String,
+    /// The type names used in each package.
+    names: HashMap<String, HashSet<String>>,
+    /// The definitions and the inline object schemas to be generated.
+    pending: VecDeque<(String, &'a Value)>,
+    stmts: Vec<NodeRef<ast::Stmt>>,
+    import_regex: bool,
+    /// The packages of the definitions referenced by the generated file.
+    imports: BTreeSet<String>,
+    warnings: IndexSet<String>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(document: &'a Value, names: HashMap<String, HashSet<String>>) -> Self {
+        Self {
+            document,
+            refs: HashMap::new(),
+            pkg: "".to_string(),
+            names,
+            pending: VecDeque::new(),
+            stmts: vec![],
+            import_regex: false,
+            imports: BTreeSet::new(),
+            warnings: IndexSet::new(),
+        }
+    }
+
+    /// Name the reusable definitions and returns their packages, names and schemas in the
+    /// document order. When `split` is true, the definitions such as `io.k8s.api.core.v1.Pod`
+    /// are in the packages of their namespaces, e.g. `io_k8s_api_core_v1`.
+    fn register_definitions(&mut self, split: bool) -> Vec<(String, String, &'a Value)> {
+        let mut registered = vec![];
+        for pointer in DEFINITIONS {
+            if let Some(Value::Mapping(definitions)) = resolve_pointer(self.document, pointer) {
+                for (key, value) in definitions {
+                    let key = match key.as_str() {
+                        Some(key) => key,
+                        None => continue,
+                    };
+                    // The definitions such as `io.k8s.api.core.v1.Pod` are named by the
+                    // last part of the keys.
+                    let (namespace, name) = key.rsplit_once('.').unwrap_or(("", key));
+                    let pkg = if split {
+                        package_name(namespace)
+                    } else {
+                        "".to_string()
+                    };
+                    let name = self.unique_name(&pkg, &pascal_case(name));
+                    let reference =
+                        format!("#{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"));
+                    self.refs.insert(reference, (pkg.clone(), name.clone()));
+                    registered.push((pkg, name, value));
+                }
+            }
+        }
+        registered
+    }
+
+    /// Returns the name which is not used in the package.
+    fn unique_name(&mut self, pkg: &str, name: &str) -> String {
+        let names = self.names.entry(pkg.to_string()).or_default();
+        let mut unique_name = name.to_string();
+        let mut index = 1;
+        while names.contains(&unique_name) {
+            unique_name = format!("{}{}", name, index);
+            index += 1;
+        }
+        names.insert(unique_name.clone());
+        unique_name
+    }
+
+    fn resolve_ref(&self, reference: &str) -> Option<&'a Value> {
+        reference
+            .strip_prefix('#')
+            .and_then(|pointer| resolve_pointer(self.document, pointer))
+    }
+
+    /// Returns the type name of the referenced definition, which is qualified by the package
+    /// name and imported when the definition is in another package.
+    fn ref_type_name(&mut self, reference: &str) -> Option<String> {
+        match self.refs.get(reference) {
+            Some((pkg, name)) if *pkg == self.pkg => Some(name.clone()),
+            Some((pkg, name)) => {
+                self.imports.insert(pkg.clone());
+                Some(format!("{}.{}", pkg, name))
+            }
+            None => {
+                self.warn_ref(reference);
+                None
+            }
+        }
+    }
+
+    fn warn_ref(&mut self, reference: &str) {
+        self.warnings.insert(if reference.starts_with('#') {
+            format!("Failed to resolve the reference '{}'", reference)
+        } else {
+            format!("The external reference '{}' is not supported", reference)
+        });
+    }
+
+    fn gen_pending(&mut self) {
+        while let Some((name, value)) = self.pending.pop_front() {
+            let stmt = if is_object_schema(value) {
+                ast::Stmt::Schema(self.gen_schema(&name, value))
+            } else {
+                let ty = self.gen_type(value, &name);
+                ast::Stmt::TypeAlias(ast::TypeAliasStmt {
+                    type_name: node_ref!(identifier(&name)),
+                    type_value: node_ref!(type_str(&ty)),
+                    ty: node_ref!(ty),
+                })
+            };
+            self.stmts.push(node_ref!(stmt));
+        }
+    }
+
+    fn gen_schema(&mut self, name: &str, value: &'a Value) -> ast::SchemaStmt {
+        let mut object = ObjectSchema::default();
+        self.collect_object(value, &mut object, 0);
+        let parent = object
+            .parent
+            .and_then(|reference| self.ref_type_name(reference));
+        let mut body = vec![];
+        let mut checks = vec![];
+        let mut attr_docs = vec![];
+        let mut any_other = false;
+        for (attr, property) in &object.properties {
+            let attr_name = match attr_name(attr) {
+                Some(attr_name) => attr_name,
+                None => {
+                    self.warnings.insert(format!(
+                        "The property '{}' of '{}' is not a valid attribute name and is imported as the index signature",
+                        attr, name
+                    ));
+                    any_other = true;
+                    continue;
+                }
+            };
+            let is_optional = !object.required.contains(attr);
+            let schema_attr = self.gen_attr(&attr_name, property, name, is_optional);
+            attr_docs.push(attr_doc(attr, &schema_attr, property));
+            body.push(node_ref!(ast::Stmt::SchemaAttr(schema_attr)));
+            checks.extend(self.gen_checks(&attr_name, property, is_optional));
+        }
+        let index_value_ty = match object.additional_properties {
+            Some(Value::Bool(false)) | None if !any_other => None,
+            Some(schema @ Value::Mapping(mapping)) if !mapping.is_empty() => {
+                Some(self.gen_type(schema, &format!("{}Value", name)))
+            }
+            _ => Some(ast::Type::Any),
+        };
+        ast::SchemaStmt {
+            doc: schema_doc(value, &attr_docs),
+            name: node_ref!(name.to_string()),
+            parent_name: parent.map(|parent| node_ref!(identifier(&parent))),
+            for_host_name: None,
+            is_mixin: false,
+            is_protocol: false,
+            args: None,
+            mixins: vec![],
+            body,
+            decorators: vec![],
+            checks,
+            index_signature: index_value_ty.map(|ty| {
+                node_ref!(ast::SchemaIndexSignature {
+                    key_name: None,
+                    key_type: node_ref!("str".to_string()),
+                    value_type: node_ref!(type_str(&ty)),
+                    value: None,
+                    any_other: true,
+                    value_ty: node_ref!(ty),
+                })
+            }),
+        }
+    }
+
+    /// Collect the properties of the object schema and the schemas it composes, the first
+    /// definition composed by the top level `allOf` is the parent schema.
+    fn collect_object(&mut self, value: &'a Value, object: &mut ObjectSchema<'a>, depth: usize) {
+        if depth > MAX_ALL_OF_DEPTH {
+            return;
+        }
+        if let Some(Value::Sequence(all_of)) = value.get("allOf") {
+            for item in all_of {
+                let reference = match item["$ref"].as_str() {
+                    Some(reference) => reference,
+                    None => {
+                        self.collect_object(item, object, depth + 1);
+                        continue;
+                    }
+                };
+                match self.resolve_ref(reference) {
+                    Some(target) if is_object_schema(target) => {
+                        if depth == 0
+                            && object.parent.is_none()
+                            && self.refs.contains_key(reference)
+                        {
+                            object.parent = Some(reference);
+                        } else {
+                            self.collect_object(target, object, depth + 1);
+                        }
+                    }
+                    Some(_) => {}
+                    None => self.warn_ref(reference),
+                }
+            }
+        }
+        if let Some(Value::Mapping(properties)) = value.get("properties") {
+            for (attr, property) in properties {
+                if let Some(attr) = attr.as_str() {
+                    object.properties.insert(attr, property);
+                }
+            }
+        }
+        if let Some(Value::Sequence(required)) = value.get("required") {
+            object
+                .required
+                .extend(required.iter().filter_map(|attr| attr.as_str()));
+        }
+        if value["x-kubernetes-preserve-unknown-fields"] == true {
+            object.additional_properties = Some(&ANY_PROPERTIES);
+        } else if let Some(additional_properties) = value.get("additionalProperties") {
+            object.additional_properties = Some(additional_properties);
+        }
+    }
+
+    fn gen_attr(
+        &mut self,
+        attr: &str,
+        property: &'a Value,
+        schema_name: &str,
+        is_optional: bool,
+    ) -> ast::SchemaAttr {
+        let ty = self.gen_type(property, &format!("{}{}", schema_name, pascal_case(attr)));
+        let value = property
+            .get("const")
+            .or_else(|| property.get("default"))
+            .map(value_expr);
+        let decorators = if property["deprecated"] == true {
+            vec![node_ref!(ast::CallExpr {
+                func: node_ref!(ast::Expr::Identifier(identifier(DEPRECATED_DECORATOR))),
+                args: vec![],
+                keywords: vec![],
+            })]
+        } else {
+            vec![]
+        };
+        ast::SchemaAttr {
+            doc: "".to_string(),
+            name: node_ref!(attr.to_string()),
+            type_str: node_ref!(type_str(&ty)),
+            op: value
+                .as_ref()
+                .map(|_| ast::BinOrAugOp::Aug(ast::AugOp::Assign)),
+            value,
+            is_optional,
+            decorators,
+            ty: node_ref!(ty),
+        }
+    }
+
+    /// Generate the KCL type of the schema, the inline object schemas named `name` are
+    /// generated later.
+    fn gen_type(&mut self, value: &'a Value, name: &str) -> ast::Type {
+        if let Some(reference) = value["$ref"].as_str() {
+            return match self.ref_type_name(reference) {
+                Some(name) => ast::Type::Named(identifier(&name)),
+                None => ast::Type::Any,
+            };
+        }
+        if value["x-kubernetes-int-or-string"] == true {
+            return union_type(vec![
+                ast::Type::Basic(ast::BasicType::Int),
+                ast::Type::Basic(ast::BasicType::Str),
+            ]);
+        }
+        if let Some(literal) = value.get("const").and_then(literal_type) {
+            return literal;
+        }
+        if let Some(literals) = enum_literal_types(value) {
+            return union_type(literals);
+        }
+        for key in ["anyOf", "oneOf"] {
+            if let Some(Value::Sequence(items)) = value.get(key) {
+                let types = items
+                    .iter()
+                    .filter(|item| item["type"] != "null")
+                    .map(|item| self.gen_type(item, name))
+                    .collect();
+                return union_type(types);
+            }
+        }
+        if is_object_schema(value) {
+            let pkg = self.pkg.clone();
+            let name = self.unique_name(&pkg, name);
+            self.pending.push_back((name.clone(), value));
+            return ast::Type::Named(identifier(&name));
+        }
+        if let Some(Value::Sequence(all_of)) = value.get("allOf") {
+            if let [item] = all_of.as_slice() {
+                return self.gen_type(item, name);
+            }
+        }
+        let types: Vec<&str> = match &value["type"] {
+            Value::String(ty) => vec![ty.as_str()],
+            Value::Sequence(types) => types.iter().filter_map(|ty| ty.as_str()).collect(),
+            _ if value.get("items").is_some() => vec!["array"],
+            _ if value.get("additionalProperties").is_some() => vec!["object"],
+            _ => vec![],
+        };
+        let types = types
+            .into_iter()
+            .filter(|ty| *ty != "null")
+            .map(|ty| match ty {
+                "string" => ast::Type::Basic(ast::BasicType::Str),
+                "integer" => ast::Type::Basic(ast::BasicType::Int),
+                "number" => ast::Type::Basic(ast::BasicType::Float),
+                "boolean" => ast::Type::Basic(ast::BasicType::Bool),
+                "array" => {
+                    let item_ty = match value.get("items") {
+                        Some(items) if items.is_mapping() => self.gen_type(items, name),
+                        _ => ast::Type::Any,
+                    };
+                    ast::Type::List(ast::ListType {
+                        inner_type: Some(node_ref!(item_ty)),
+                    })
+                }
+                "object" => {
+                    let value_ty = match value.get("additionalProperties") {
+                        Some(schema @ Value::Mapping(mapping)) if !mapping.is_empty() => {
+                            self.gen_type(schema, name)
+                        }
+                        _ => ast::Type::Any,
+                    };
+                    ast::Type::Dict(ast::DictType {
+                        key_type: Some(node_ref!(ast::Type::Basic(ast::BasicType::Str))),
+                        value_type: Some(node_ref!(value_ty)),
+                    })
+                }
+                _ => ast::Type::Any,
+            })
+            .collect();
+        union_type(types)
+    }
+
+    /// Generate the checks of the validation keywords of the property, the checks of the
+    /// optional attribute are guarded by `if attr`.
+    fn gen_checks(
+        &mut self,
+        attr: &str,
+        property: &Value,
+        is_optional: bool,
+    ) -> Vec<NodeRef<ast::CheckExpr>> {
+        let mut tests = vec![];
+        if let Some(pattern) = property["pattern"].as_str() {
+            self.import_regex = true;
+            // `regex.match` matches from the beginning of the string, and the JSON Schema
+            // patterns are not anchored.
+            let func = if pattern.starts_with('^') {
+                "match"
+            } else {
+                "search"
+            };
+            tests.push(call_expr(
+                &[REGEX_PKG, func],
+                vec![name_expr(attr), value_expr(&Value::from(pattern))],
+            ));
+        }
+        for (min, max) in [
+            ("minLength", "maxLength"),
+            ("minItems", "maxItems"),
+            ("minProperties", "maxProperties"),
+        ] {
+            let lower = property[min]
+                .as_u64()
+                .map(|n| (ast::CmpOp::GtE, Number::from(n)));
+            let upper = property[max]
+                .as_u64()
+                .map(|n| (ast::CmpOp::LtE, Number::from(n)));
+            let len_expr = call_expr(&["len"], vec![name_expr(attr)]);
+            if let Some(test) = range_expr(len_expr, lower, upper) {
+                tests.push(test);
+            }
+        }
+        let lower = number_bound(property, "minimum", "exclusiveMinimum", ast::CmpOp::GtE);
+        let upper = number_bound(property, "maximum", "exclusiveMaximum", ast::CmpOp::LtE);
+        if let Some(test) = range_expr(name_expr(attr), lower, upper) {
+            tests.push(test);
+        }
+        if let Some(n) = property["multipleOf"].as_i64() {
+            tests.push(call_expr(
+                &["multiplyof"],
+                vec![name_expr(attr), value_expr(&Value::from(n))],
+            ));
+        }
+        if property["uniqueItems"] == true {
+            tests.push(call_expr(&["isunique"], vec![name_expr(attr)]));
+        }
+        // The enums which can't be the literal types are checked by the membership tests.
+        if let Some(Value::Sequence(values)) = property.get("enum") {
+            if enum_literal_types(property).is_none() {
+                tests.push(node_ref!(ast::Expr::Compare(ast::Compare {
+                    left: name_expr(attr),
+                    ops: vec![ast::CmpOp::In],
+                    comparators: vec![value_expr(&Value::Sequence(values.clone()))],
+                })));
+            }
+        }
+        tests
+            .into_iter()
+            .map(|test| {
+                node_ref!(ast::CheckExpr {
+                    test,
+                    if_cond: if is_optional {
+                        Some(name_expr(attr))
+                    } else {
+                        None
+                    },
+                    msg: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Print the generated statements and the imports as the KCL code of a file.
+    fn print(&mut self) -> String {
+        let mut imports = vec![];
+        if std::mem::take(&mut self.import_regex) {
+            imports.push(REGEX_PKG.to_string());
+        }
+        imports.extend(std::mem::take(&mut self.imports));
+        let imports: Vec<NodeRef<ast::Stmt>> = imports
+            .into_iter()
+            .map(|pkg| {
+                node_ref!(ast::Stmt::Import(ast::ImportStmt {
+                    path: pkg.clone(),
+                    rawpath: pkg.clone(),
+                    name: pkg,
+                    asname: None,
+                }))
+            })
+            .collect();
+        let print_stmts = |stmts: &[NodeRef<ast::Stmt>], sep: &str| {
+            stmts
+                .iter()
+                .map(|stmt| print_ast_node(ASTNode::Stmt(stmt)).trim_end().to_string())
+                .collect::<Vec<String>>()
+                .join(sep)
+        };
+        // Separate the imports and the statements by blank lines.
+        let mut code = vec![];
+        if !imports.is_empty() {
+            code.push(print_stmts(&imports, "\n"));
+        }
+        code.push(print_stmts(&std::mem::take(&mut self.stmts), "\n\n"));
+        code.join("\n\n") + "\n"
+    }
+}
+
+/// Returns the value at the JSON pointer, e.g. `/$defs/Port`.
+fn resolve_pointer<'v>(value: &'v Value, pointer: &str) -> Option<&'v Value> {
+    pointer.split('/').skip(1).try_fold(value, |value, token| {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        match value {
+            Value::Sequence(_) => value.get(token.parse::<usize>().ok()?),
+            _ => value.get(token.as_str()),
+        }
+    })
+}
+
+/// Whether the schema is an object schema with properties or composed of object schemas.
+fn is_object_schema(value: &Value) -> bool {
+    match (value.get("properties"), value.get("allOf")) {
+        (Some(Value::Mapping(properties)), _) if !properties.is_empty() => true,
+        (_, Some(Value::Sequence(all_of))) => {
+            all_of.iter().any(is_object_schema)
+                || (all_of.len() > 1 && all_of.iter().any(|item| item.get("$ref").is_some()))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the literal types of the enum, or [None] when some values are not literals.
+fn enum_literal_types(value: &Value) -> Option<Vec<ast::Type>> {
+    match value.get("enum") {
+        Some(Value::Sequence(values)) if values.iter().any(|value| !value.is_null()) => values
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(literal_type)
+            .collect(),
+        _ => None,
+    }
+}
+
+fn literal_type(value: &Value) -> Option<ast::Type> {
+    let literal = match value {
+        Value::Bool(value) => ast::LiteralType::Bool(*value),
+        Value::Number(n) => match n.as_i64() {
+            Some(n) => ast::LiteralType::Int(n, None),
+            None => ast::LiteralType::Float(n.as_f64()?),
+        },
+        // The literal types are printed without escapes except the quotes.
+        Value::String(s) if !s.chars().any(|c| c == '\\' || c.is_control()) => {
+            ast::LiteralType::Str(s.clone())
+        }
+        _ => return None,
+    };
+    Some(ast::Type::Literal(literal))
+}
+
+/// Returns the union of the deduplicated types, which is `any` when there are no types.
+fn union_type(types: Vec<ast::Type>) -> ast::Type {
+    let mut type_strs = HashSet::new();
+    let mut types: Vec<ast::Type> = types
+        .into_iter()
+        .filter(|ty| type_strs.insert(ty.to_string()))
+        .collect();
+    match types.len() {
+        0 => ast::Type::Any,
+        1 => types.remove(0),
+        _ => ast::Type::Union(ast::UnionType {
+            type_elements: types.into_iter().map(|ty| node_ref!(ty)).collect(),
+        }),
+    }
+}
+
+/// Returns the type string of the attribute annotation, e.g. `int | str`.
+fn type_str(ty: &ast::Type) -> String {
+    match ty {
+        ast::Type::Union(union_type) => union_type
+            .type_elements
+            .iter()
+            .map(|ty| ty.node.to_string())
+            .collect::<Vec<String>>()
+            .join(" | "),
+        _ => ty.to_string(),
+    }
+}
+
+/// Returns the bound of the `minimum` or `maximum` keyword with the comparison operator. The
+/// exclusive keywords are numbers in the JSON Schema draft 6 and later, and booleans which
+/// modify `minimum` and `maximum` in the OpenAPI v3.0.
+fn number_bound(
+    property: &Value,
+    keyword: &str,
+    exclusive_keyword: &str,
+    op: ast::CmpOp,
+) -> Option<(ast::CmpOp, Number)> {
+    let exclusive_op = match op {
+        ast::CmpOp::GtE => ast::CmpOp::Gt,
+        _ => ast::CmpOp::Lt,
+    };
+    match (&property[exclusive_keyword], &property[keyword]) {
+        (Value::Number(n), _) | (Value::Bool(true), Value::Number(n)) => {
+            Some((exclusive_op, n.clone()))
+        }
+        (_, Value::Number(n)) => Some((op, n.clone())),
+        _ => None,
+    }
+}
+
+/// Returns the comparison `subject >= lower`, `subject <= upper` or `lower <= subject <= upper`.
+fn range_expr(
+    subject: NodeRef<ast::Expr>,
+    lower: Option<(ast::CmpOp, Number)>,
+    upper: Option<(ast::CmpOp, Number)>,
+) -> Option<NodeRef<ast::Expr>> {
+    let compare = match (lower, upper) {
+        (Some((lower_op, lower)), Some((upper_op, upper))) => ast::Compare {
+            left: value_expr(&Value::Number(lower)),
+            ops: vec![
+                match lower_op {
+                    ast::CmpOp::Gt => ast::CmpOp::Lt,
+                    _ => ast::CmpOp::LtE,
+                },
+                upper_op,
+            ],
+            comparators: vec![subject, value_expr(&Value::Number(upper))],
+        },
+        (Some((op, n)), None) | (None, Some((op, n))) => ast::Compare {
+            left: subject,
+            ops: vec![op],
+            comparators: vec![value_expr(&Value::Number(n))],
+        },
+        (None, None) => return None,
+    };
+    Some(node_ref!(ast::Expr::Compare(compare)))
+}
+
+fn identifier(name: &str) -> ast::Identifier {
+    ast::Identifier {
+        names: name.split('.').map(|name| name.to_string()).collect(),
+        pkgpath: "".to_string(),
+        ctx: ast::ExprContext::Load,
+    }
+}
+
+fn name_expr(name: &str) -> NodeRef<ast::Expr> {
+    node_ref!(ast::Expr::Identifier(identifier(name)))
+}
+
+fn call_expr(func: &[&str], args: Vec<NodeRef<ast::Expr>>) -> NodeRef<ast::Expr> {
+    node_ref!(ast::Expr::Call(ast::CallExpr {
+        func: name_expr(&func.join(".")),
+        args,
+        keywords: vec![],
+    }))
+}
+
+/// Convert the YAML value to the KCL expression, the mappings are the config expressions.
+fn value_expr(value: &Value) -> NodeRef<ast::Expr> {
+    let expr = match value {
+        Value::Null => ast::Expr::NameConstantLit(ast::NameConstantLit {
+            value: ast::NameConstant::None,
+        }),
+        Value::Bool(value) => ast::Expr::NameConstantLit(ast::NameConstantLit {
+            value: if *value {
+                ast::NameConstant::True
+            } else {
+                ast::NameConstant::False
+            },
+        }),
+        Value::Number(n) => ast::Expr::NumberLit(ast::NumberLit {
+            binary_suffix: None,
+            value: match n.as_i64() {
+                Some(n) => ast::NumberLitValue::Int(n),
+                None => ast::NumberLitValue::Float(n.as_f64().unwrap_or_default()),
+            },
+        }),
+        Value::String(s) => ast::Expr::StringLit(ast::StringLit {
+            is_long_string: false,
+            // Escape `${` which begins the string interpolation.
+            raw_value: format!("{:?}", s).replace("${", "$${"),
+            value: s.clone(),
+        }),
+        Value::Sequence(values) => ast::Expr::List(ast::ListExpr {
+            elts: values.iter().map(value_expr).collect(),
+            ctx: ast::ExprContext::Load,
+        }),
+        Value::Tagged(tagged) => return value_expr(&tagged.value),
+        Value::Mapping(values) => ast::Expr::Config(ast::ConfigExpr {
+            items: values
+                .iter()
+                .map(|(key, value)| {
+                    node_ref!(ast::ConfigEntry {
+                        key: Some(value_expr(key)),
+                        value: value_expr(value),
+                        operation: ast::ConfigEntryOperation::Union,
+                        insert_index: -1,
+                    })
+                })
+                .collect(),
+        }),
+    };
+    node_ref!(expr)
+}
+
+/// Returns the attribute entry in the `Attributes` section of the schema document, e.g.
+///
+/// ```no_check
+/// name : str, default is "app", required
+///     The name of the application.
+/// ```
+fn attr_doc(attr: &str, schema_attr: &ast::SchemaAttr, property: &Value) -> String {
+    let mut doc = format!("{} : {}", attr, schema_attr.type_str.node);
+    if let Some(value) = &schema_attr.value {
+        doc.push_str(&format!(
+            ", default is {}",
+            print_ast_node(ASTNode::Expr(value))
+        ));
+    }
+    doc.push_str(if schema_attr.is_optional {
+        ", optional"
+    } else {
+        ", required"
+    });
+    for line in description(property).lines() {
+        doc.push_str("\n    ");
+        doc.push_str(line.trim_end());
+    }
+    doc
+}
+
+/// Returns the schema document with the description and the `Attributes` section.
+fn schema_doc(value: &Value, attr_docs: &[String]) -> String {
+    let mut lines: Vec<&str> = description(value).lines().collect();
+    if !attr_docs.is_empty() {
+        if !lines.is_empty() {
+            lines.push("");
+        }
+        lines.extend(["Attributes", "----------"]);
+        for attr_doc in attr_docs {
+            lines.extend(attr_doc.lines());
+        }
+    }
+    if lines.is_empty() {
+        return "".to_string();
+    }
+    // The schema body is indented by 4 spaces.
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|line| {
+            let line = line.trim_end();
+            if line.is_empty() {
+                "".to_string()
+            } else {
+                format!("    {}", line)
+            }
+        })
+        .collect();
+    let doc = lines
+        .join("\n")
+        .replace('\\', "\\\\")
+        .replace("\"\"\"", "\\\"\\\"\\\"");
+    format!("\"\"\"\n{}\n    \"\"\"", doc)
+}
+
+fn description(value: &Value) -> &str {
+    value["description"]
+        .as_str()
+        .or_else(|| value["title"].as_str())
+        .unwrap_or_default()
+        .trim()
+}
+
+/// Returns the KCL schema attribute name of the property, e.g. `$check` for `check`, or [None]
+/// when the property name can't be an attribute name. The names with the `_` prefix are not
+/// used because they are the private attributes in KCL.
+fn attr_name(name: &str) -> Option<String> {
+    let mut chars = name.chars();
+    if !matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+        || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        None
+    } else if KEYWORDS.contains(&name) {
+        Some(format!("${}", name))
+    } else {
+        Some(name.to_string())
+    }
+}
+
+/// Convert the name to the PascalCase schema name, e.g. `cron-tab` to `CronTab`.
+fn pascal_case(name: &str) -> String {
+    let mut pascal_name = String::new();
+    for part in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            pascal_name.push(first.to_ascii_uppercase());
+            pascal_name.extend(chars);
+        }
+    }
+    if !pascal_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        pascal_name.insert_str(0, "Schema");
+    }
+    pascal_name
+}
+
+/// Convert the name to the snake_case file name, e.g. `CronTab` to `cron_tab`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake_name = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let is_word_start = i > 0
+                && (!chars[i - 1].is_ascii_uppercase()
+                    || matches!(chars.get(i + 1), Some(c) if c.is_ascii_lowercase()));
+            if is_word_start && !snake_name.ends_with('_') {
+                snake_name.push('_');
+            }
+            snake_name.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            snake_name.push(*c);
+        } else {
+            snake_name.push('_');
+        }
+    }
+    snake_name
+}
+
+/// Convert the API group or the file name to the package name, e.g. `stable.example.com`
+/// to `stable_example_com`.
+fn package_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
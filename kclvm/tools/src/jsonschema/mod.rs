@@ -0,0 +1,5 @@
+pub mod export;
+pub mod import;
+
+#[cfg(test)]
+mod tests;
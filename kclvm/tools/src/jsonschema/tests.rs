@@ -0,0 +1,214 @@
+use super::export::*;
+use super::import::*;
+use kclvm_parser::load_program;
+use kclvm_sema::resolver::resolve_program;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+
+const EXPORT_TEST_DATA_PATH: &str = "./src/jsonschema/test_data/export";
+const IMPORT_TEST_DATA_PATH: &str = "./src/jsonschema/test_data/import";
+
+fn read_golden(name: &str) -> Value {
+    let path = format!("{}/{}", EXPORT_TEST_DATA_PATH, name);
+    let content = std::fs::read_to_string(&path).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+#[test]
+fn test_export_json_schema() {
+    let document = export_file(
+        &format!("{}/app.k", EXPORT_TEST_DATA_PATH),
+        &ExportOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(document, read_golden("app.json"));
+}
+
+#[test]
+fn test_export_openapi() {
+    let opts = ExportOptions {
+        format: ExportFormat::OpenAPI,
+        ..Default::default()
+    };
+    let document = export_file(&format!("{}/app.k", EXPORT_TEST_DATA_PATH), &opts).unwrap();
+    assert_eq!(document, read_golden("app.openapi.json"));
+}
+
+#[test]
+fn test_export_schema_name() {
+    let opts = ExportOptions {
+        schema_name: Some("Port".to_string()),
+        ..Default::default()
+    };
+    let document = export_file(&format!("{}/app.k", EXPORT_TEST_DATA_PATH), &opts).unwrap();
+    assert_eq!(document["$ref"], "#/$defs/Port");
+    assert_eq!(
+        document["$defs"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["Port"]
+    );
+
+    let opts = ExportOptions {
+        schema_name: Some("Deployment".to_string()),
+        ..Default::default()
+    };
+    let err = export_file(&format!("{}/app.k", EXPORT_TEST_DATA_PATH), &opts).unwrap_err();
+    assert_eq!(err.to_string(), "Schema 'Deployment' not found");
+}
+
+/// Import the test file and compare the generated files with the golden files in the
+/// directory named by the file stem, returns the import warnings.
+fn check_import_golden(file: &str, opts: &ImportOptions, expected_files: &[&str]) -> Vec<String> {
+    let result = import_file(&format!("{}/{}", IMPORT_TEST_DATA_PATH, file), opts).unwrap();
+    assert_eq!(result.files.keys().collect::<Vec<_>>(), expected_files);
+    let golden_dir = format!(
+        "{}/{}",
+        IMPORT_TEST_DATA_PATH,
+        file.split('.').next().unwrap()
+    );
+    for (path, code) in &result.files {
+        let golden = std::fs::read_to_string(format!("{}/{}", golden_dir, path)).unwrap();
+        assert_eq!(code, &golden, "{}", path);
+    }
+    result.warnings.into_iter().collect()
+}
+
+#[test]
+fn test_import_crd() {
+    let warnings = check_import_golden(
+        "crontab.crd.yaml",
+        &ImportOptions::default(),
+        &[
+            "stable_example_com/cron_tab.k",
+            "stable_example_com/backup.k",
+            "cert_manager_io/certificate.k",
+        ],
+    );
+    assert_eq!(warnings, Vec::<String>::new());
+}
+
+#[test]
+fn test_import_json_schema() {
+    let warnings = check_import_golden("app.schema.json", &ImportOptions::default(), &["app.k"]);
+    assert_eq!(
+        warnings,
+        vec![
+            "The property 'x-extension' of 'App' is not a valid attribute name and is imported as the index signature",
+            "The property '_id' of 'App' is not a valid attribute name and is imported as the index signature",
+        ]
+    );
+}
+
+#[test]
+fn test_import_openapi() {
+    let warnings = check_import_golden(
+        "petstore.openapi.yaml",
+        &ImportOptions::default(),
+        &["petstore.k"],
+    );
+    assert_eq!(
+        warnings,
+        vec!["The external reference 'common.yaml#/components/schemas/Category' is not supported"]
+    );
+}
+
+#[test]
+fn test_import_openapi_packages() {
+    let warnings = check_import_golden(
+        "kubernetes.swagger.yaml",
+        &ImportOptions::default(),
+        &[
+            "io_k8s_api_apps_v1/deployment.k",
+            "io_k8s_api_apps_v1/deployment_spec.k",
+            "io_k8s_api_core_v1/pod_template_spec.k",
+            "io_k8s_api_core_v1/container.k",
+            "io_k8s_apimachinery_pkg_apis_meta_v1/object_meta.k",
+        ],
+    );
+    assert_eq!(warnings, Vec::<String>::new());
+}
+
+#[test]
+fn test_import_unresolved_refs() {
+    let content = r#"
+components:
+  schemas:
+    Pet:
+      allOf:
+        - $ref: '#/components/schemas/Animal'
+        - type: object
+          properties:
+            owner:
+              $ref: '#/components/schemas/Owner'
+            tag:
+              $ref: 'https://example.com/schemas/tag.json'
+"#;
+    let opts = ImportOptions {
+        format: Some(ImportFormat::OpenAPI),
+    };
+    let result = import_str("pet", content, &opts).unwrap();
+    assert_eq!(
+        result.warnings.into_iter().collect::<Vec<_>>(),
+        vec![
+            "Failed to resolve the reference '#/components/schemas/Animal'",
+            "Failed to resolve the reference '#/components/schemas/Owner'",
+            "The external reference 'https://example.com/schemas/tag.json' is not supported",
+        ]
+    );
+    assert_eq!(
+        result.files["pet.k"],
+        r#"schema Pet:
+    """
+    Attributes
+    ----------
+    owner : any, optional
+    tag : any, optional
+    """
+    owner?: any
+    tag?: any
+"#
+    );
+}
+
+#[test]
+fn test_import_golden_resolve() {
+    for files in [
+        vec!["app/app.k"],
+        vec!["petstore/petstore.k"],
+        vec![
+            "crontab/stable_example_com/cron_tab.k",
+            "crontab/stable_example_com/backup.k",
+        ],
+        vec![
+            "kubernetes/io_k8s_api_apps_v1/deployment.k",
+            "kubernetes/io_k8s_api_apps_v1/deployment_spec.k",
+        ],
+    ] {
+        let files: Vec<String> = files
+            .iter()
+            .map(|file| format!("{}/{}", IMPORT_TEST_DATA_PATH, file))
+            .collect();
+        let files: Vec<&str> = files.iter().map(|file| file.as_str()).collect();
+        let mut prog = load_program(&files, None).unwrap();
+        let scope = resolve_program(&mut prog);
+        assert_eq!(scope.diagnostics.len(), 0, "{:?}", files);
+    }
+}
+
+#[test]
+fn test_import_errors() {
+    let opts = ImportOptions {
+        format: Some(ImportFormat::CRD),
+    };
+    let err = import_str("app", "type: object", &opts).unwrap_err();
+    assert_eq!(err.to_string(), "No CustomResourceDefinition found");
+
+    let err = import_str("app", "type: string", &ImportOptions::default()).unwrap_err();
+    assert_eq!(err.to_string(), "No schema definitions found");
+
+    let err = import_str("app", "", &ImportOptions::default()).unwrap_err();
+    assert_eq!(err.to_string(), "No definitions found");
+}
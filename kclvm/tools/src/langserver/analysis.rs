@@ -0,0 +1,210 @@
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+use indexmap::IndexSet;
+use kclvm_ast::MAIN_PKG;
+use kclvm_error::{Diagnostic, Handler, Level, Position};
+use kclvm_parser::{load_program_with_errors, LoadProgramOptions};
+use kclvm_runtime::PanicInfo;
+use kclvm_sema::resolver::resolve_program;
+use kclvm_sema::resolver::scope::{ProgramScope, Scope, ScopeObject};
+
+use crate::langserver::names::{collect_names, NameRef};
+use crate::langserver::to_lsp::kcl_pos;
+
+/// The semantic information of a KCL document, it holds the program scope
+/// resolved from the document text, the names in the document AST and the
+/// diagnostics of the parser and the resolver.
+pub(crate) struct Analysis {
+    pub(crate) filename: String,
+    pub(crate) text: String,
+    pub(crate) scope: Option<ProgramScope>,
+    pub(crate) names: Vec<NameRef>,
+    pub(crate) diagnostics: IndexSet<Diagnostic>,
+}
+
+impl Analysis {
+    /// Parse and resolve the document text. The document is parsed with error
+    /// recovery, so the partial program with syntax errors is still resolved.
+    /// Other compile errors abort the process with a panic, so we catch it and
+    /// convert the panic info into a diagnostic.
+    pub(crate) fn new(filename: &str, text: &str) -> Self {
+        let opts = LoadProgramOptions {
+            k_code_list: vec![text.to_string()],
+            ..Default::default()
+        };
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            load_program_with_errors(&[filename], Some(opts)).map(|(mut program, errors)| {
+                let scope = resolve_program(&mut program);
+                let names = program
+                    .pkgs
+                    .get(MAIN_PKG)
+                    .into_iter()
+                    .flatten()
+                    .filter(|module| module.filename == filename)
+                    .flat_map(|module| collect_names(module, text))
+                    .collect::<Vec<NameRef>>();
+                (scope, names, errors)
+            })
+        }));
+        let (scope, names, diagnostics) = match result {
+            Ok(Ok((scope, names, errors))) => {
+                let mut diagnostics: IndexSet<Diagnostic> = errors.into_iter().collect();
+                diagnostics.extend(scope.diagnostics.clone());
+                (Some(scope), names, diagnostics)
+            }
+            Ok(Err(err)) => (None, vec![], err_to_diagnostics(filename, &err)),
+            Err(err) => {
+                let err = match err.downcast_ref::<String>() {
+                    Some(err) => err.to_string(),
+                    None => match err.downcast_ref::<&str>() {
+                        Some(err) => err.to_string(),
+                        None => "Internal error: unknown panic".to_string(),
+                    },
+                };
+                (None, vec![], err_to_diagnostics(filename, &err))
+            }
+        };
+        Analysis {
+            filename: filename.to_string(),
+            text: text.to_string(),
+            scope,
+            names,
+            diagnostics,
+        }
+    }
+
+    /// Returns the name and the scope object it refers to at the position,
+    /// the line of the position is 1 based. The attribute names such as
+    /// config keys and selector attributes are not resolved.
+    pub(crate) fn lookup(&self, pos: &Position) -> Option<(String, ScopeObject)> {
+        let name = self.names.iter().find(|name| {
+            name.start.line == pos.line
+                && name.start.column <= pos.column
+                && pos.column < name.end.column
+        })?;
+        let obj = self.resolve(name)?;
+        Some((name.name.clone(), obj))
+    }
+
+    /// Returns the definition of the identifier at the position. Builtin
+    /// objects without a source position are ignored.
+    pub(crate) fn definition(&self, pos: &Position) -> Option<ScopeObject> {
+        let (_, obj) = self.lookup(pos)?;
+        if obj.pos_is_valid() && !obj.start.filename.is_empty() {
+            Some(obj)
+        } else {
+            None
+        }
+    }
+
+    /// Convert the lsp position to the kcl position in the document.
+    pub(crate) fn kcl_pos(&self, pos: lsp_types::Position) -> Position {
+        kcl_pos(&self.filename, &self.text, pos)
+    }
+
+    /// Returns the start and end positions of all names in the document
+    /// which refer to the same definition as the name at the position.
+    pub(crate) fn references(
+        &self,
+        pos: &Position,
+        include_declaration: bool,
+    ) -> Vec<(Position, Position)> {
+        let decl = match self.lookup(pos) {
+            Some((_, decl)) => decl,
+            None => return vec![],
+        };
+        let mut refs: Vec<(Position, Position)> = self
+            .names
+            .iter()
+            .filter(|name| include_declaration || name.decl_start != decl.start)
+            .filter(|name| self.resolve(name).map(|obj| obj.start) == Some(decl.start.clone()))
+            .map(|name| (name.start.clone(), name.end.clone()))
+            .collect();
+        refs.sort_by_key(|(start, _)| (start.line, start.column));
+        refs.dedup();
+        refs
+    }
+
+    /// Returns the top level objects defined in the document and the
+    /// attributes of the schemas.
+    pub(crate) fn symbols(&self) -> Vec<(ScopeObject, Vec<ScopeObject>)> {
+        let main_scope = match self.scope.as_ref().and_then(|scope| scope.main_scope()) {
+            Some(scope) => scope.borrow(),
+            None => return vec![],
+        };
+        let in_document = |obj: &ScopeObject| obj.start.filename == self.filename;
+        let mut symbols = vec![];
+        for obj in main_scope.elems.values() {
+            let obj = obj.borrow().clone();
+            if !in_document(&obj) {
+                continue;
+            }
+            let children = match main_scope.search_child_scope_by_name(&obj.name) {
+                Some(schema_scope) => schema_scope
+                    .borrow()
+                    .elems
+                    .values()
+                    .map(|attr| attr.borrow().clone())
+                    .filter(in_document)
+                    .collect(),
+                None => vec![],
+            };
+            symbols.push((obj, children));
+        }
+        symbols
+    }
+
+    /// Resolve the name in the inner most scope which contains it.
+    fn resolve(&self, name: &NameRef) -> Option<ScopeObject> {
+        let main_scope = self.scope.as_ref()?.main_scope()?;
+        let scope = inner_most_scope(main_scope, &name.start);
+        let obj = scope.borrow().lookup(&name.name)?;
+        let obj = obj.borrow().clone();
+        Some(obj)
+    }
+}
+
+/// Returns the inner most scope which contains the position. Unlike
+/// `Scope::inner_most`, the package scope without positions is returned
+/// when no child scope contains the position, and the scope end position
+/// is exclusive because the end of a schema scope is the start of the next
+/// statement.
+fn inner_most_scope(scope: &Rc<RefCell<Scope>>, pos: &Position) -> Rc<RefCell<Scope>> {
+    for child in scope.borrow().children.iter() {
+        let contains_pos = {
+            let child = child.borrow();
+            child.start.less_equal(pos) && pos.less(&child.end)
+        };
+        if contains_pos {
+            return inner_most_scope(child, pos);
+        }
+    }
+    Rc::clone(scope)
+}
+
+/// Convert the compile error string, which is a panic info json string
+/// in most cases, to diagnostics.
+fn err_to_diagnostics(filename: &str, err: &str) -> IndexSet<Diagnostic> {
+    match serde_json::from_str::<PanicInfo>(err) {
+        Ok(panic_info) => {
+            let mut handler = Handler::default();
+            handler.add_panic_info(&panic_info);
+            handler.diagnostics
+        }
+        Err(_) => {
+            let mut diagnostics = IndexSet::new();
+            diagnostics.insert(Diagnostic::new(
+                Level::Error,
+                err,
+                Position {
+                    filename: filename.to_string(),
+                    line: 1,
+                    column: None,
+                },
+            ));
+            diagnostics
+        }
+    }
+}
@@ -4,8 +4,12 @@ use crate::util;
 use anyhow::Result;
 use kclvm_error::Position;
 
+mod analysis;
 mod find_refs;
 mod go_to_def;
+mod names;
+pub mod server;
+mod to_lsp;
 mod word_map;
 
 #[cfg(test)]
@@ -27,25 +31,26 @@ pub fn word_at_pos(pos: Position) -> Option<String> {
         return None;
     }
     let text = text.unwrap();
+    pos.column?;
+    word_at_text_pos(&text, pos.line, pos.column.unwrap()).map(|w| w.word)
+}
+
+// Get the word at the line and column of the text, the line number is 0 based.
+pub fn word_at_text_pos(text: &str, line: u64, col: u64) -> Option<LineWord> {
     let lines: Vec<&str> = text.lines().collect();
-    if pos.line >= lines.len() as u64 {
+    if line >= lines.len() as u64 {
         return None;
     }
-    pos.column?;
-    let col = pos.column.unwrap();
-    let line_words = line_to_words(lines[pos.line as usize].to_string());
+    let line_words = line_to_words(lines[line as usize].to_string());
     if line_words.is_empty()
         || col < line_words.first().unwrap().startpos
         || col >= line_words.last().unwrap().endpos
     {
         return None;
     }
-    for line_word in line_words.into_iter() {
-        if col >= line_word.startpos && col < line_word.endpos {
-            return Some(line_word.word);
-        }
-    }
-    None
+    line_words
+        .into_iter()
+        .find(|line_word| col >= line_word.startpos && col < line_word.endpos)
 }
 
 pub fn read_file(path: &String) -> Result<String> {
@@ -0,0 +1,347 @@
+use kclvm_ast::ast;
+use kclvm_error::Position;
+use kclvm_sema::resolver::pos::GetPos;
+
+/// A name in the document which refers to a scope object, e.g., the first
+/// name `a` of the identifier `a.b.c`, the name of a schema statement or the
+/// name of a schema attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NameRef {
+    pub(crate) name: String,
+    pub(crate) start: Position,
+    pub(crate) end: Position,
+    /// The start position of the scope object if the name declares it.
+    /// The start position of schemas and rules is the statement start
+    /// instead of the name start.
+    pub(crate) decl_start: Position,
+}
+
+/// Returns all names in the module which can refer to scope objects. The
+/// attribute names of selectors, config keys and keyword argument names are
+/// not collected because they are resolved by the value types instead of
+/// the scopes, and strings and comments are not walked at all except the
+/// expressions in the string interpolation.
+pub(crate) fn collect_names(module: &ast::Module, text: &str) -> Vec<NameRef> {
+    let mut collector = NameCollector {
+        lines: text.lines().collect(),
+        names: vec![],
+    };
+    for stmt in &module.body {
+        collector.stmt(stmt);
+    }
+    collector.names
+}
+
+struct NameCollector<'a> {
+    lines: Vec<&'a str>,
+    names: Vec<NameRef>,
+}
+
+impl<'a> NameCollector<'a> {
+    fn stmt(&mut self, stmt: &ast::NodeRef<ast::Stmt>) {
+        match &stmt.node {
+            ast::Stmt::TypeAlias(type_alias_stmt) => {
+                self.identifier(&type_alias_stmt.type_name);
+                self.type_str(&type_alias_stmt.type_value);
+            }
+            ast::Stmt::Expr(expr_stmt) => self.exprs(&expr_stmt.exprs),
+            ast::Stmt::Unification(unification_stmt) => {
+                self.identifier(&unification_stmt.target);
+                self.schema_expr(&unification_stmt.value.node);
+            }
+            ast::Stmt::Assign(assign_stmt) => {
+                for target in &assign_stmt.targets {
+                    self.identifier(target);
+                }
+                if let Some(type_annotation) = &assign_stmt.type_annotation {
+                    self.type_str(type_annotation);
+                }
+                self.expr(&assign_stmt.value);
+            }
+            ast::Stmt::AugAssign(aug_assign_stmt) => {
+                self.identifier(&aug_assign_stmt.target);
+                self.expr(&aug_assign_stmt.value);
+            }
+            ast::Stmt::Assert(assert_stmt) => {
+                self.expr(&assert_stmt.test);
+                self.opt_expr(&assert_stmt.if_cond);
+                self.opt_expr(&assert_stmt.msg);
+            }
+            ast::Stmt::If(if_stmt) => {
+                self.expr(&if_stmt.cond);
+                self.stmts(&if_stmt.body);
+                self.stmts(&if_stmt.orelse);
+            }
+            ast::Stmt::Import(_) => {}
+            ast::Stmt::SchemaAttr(schema_attr) => {
+                self.name(&schema_attr.name, schema_attr.name.get_pos());
+                self.type_str(&schema_attr.type_str);
+                self.calls(&schema_attr.decorators);
+                self.opt_expr(&schema_attr.value);
+            }
+            ast::Stmt::Schema(schema_stmt) => {
+                self.name(&schema_stmt.name, stmt.get_pos());
+                if let Some(parent_name) = &schema_stmt.parent_name {
+                    self.identifier(parent_name);
+                }
+                if let Some(for_host_name) = &schema_stmt.for_host_name {
+                    self.identifier(for_host_name);
+                }
+                for mixin in &schema_stmt.mixins {
+                    self.identifier(mixin);
+                }
+                if let Some(args) = &schema_stmt.args {
+                    self.arguments(&args.node);
+                }
+                self.calls(&schema_stmt.decorators);
+                if let Some(index_signature) = &schema_stmt.index_signature {
+                    self.opt_expr(&index_signature.node.value);
+                }
+                self.stmts(&schema_stmt.body);
+                self.checks(&schema_stmt.checks);
+            }
+            ast::Stmt::Rule(rule_stmt) => {
+                self.name(&rule_stmt.name, stmt.get_pos());
+                for parent_rule in &rule_stmt.parent_rules {
+                    self.identifier(parent_rule);
+                }
+                if let Some(for_host_name) = &rule_stmt.for_host_name {
+                    self.identifier(for_host_name);
+                }
+                if let Some(args) = &rule_stmt.args {
+                    self.arguments(&args.node);
+                }
+                self.calls(&rule_stmt.decorators);
+                self.checks(&rule_stmt.checks);
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &ast::NodeRef<ast::Expr>) {
+        match &expr.node {
+            ast::Expr::Identifier(identifier) => self.names(identifier, expr),
+            ast::Expr::Unary(unary_expr) => self.expr(&unary_expr.operand),
+            ast::Expr::Binary(binary_expr) => {
+                self.expr(&binary_expr.left);
+                self.expr(&binary_expr.right);
+            }
+            ast::Expr::If(if_expr) => {
+                self.expr(&if_expr.cond);
+                self.expr(&if_expr.body);
+                self.expr(&if_expr.orelse);
+            }
+            // The attribute name of the selector is not collected.
+            ast::Expr::Selector(selector_expr) => self.expr(&selector_expr.value),
+            ast::Expr::Call(call_expr) => self.call_expr(call_expr),
+            ast::Expr::Paren(paren_expr) => self.expr(&paren_expr.expr),
+            ast::Expr::Quant(quant_expr) => {
+                self.expr(&quant_expr.target);
+                for variable in &quant_expr.variables {
+                    self.identifier(variable);
+                }
+                self.expr(&quant_expr.test);
+                self.opt_expr(&quant_expr.if_cond);
+            }
+            ast::Expr::List(list_expr) => self.exprs(&list_expr.elts),
+            ast::Expr::ListIfItem(list_if_item_expr) => {
+                self.expr(&list_if_item_expr.if_cond);
+                self.exprs(&list_if_item_expr.exprs);
+                self.opt_expr(&list_if_item_expr.orelse);
+            }
+            ast::Expr::ListComp(list_comp) => {
+                self.comp_clauses(&list_comp.generators);
+                self.expr(&list_comp.elt);
+            }
+            ast::Expr::Starred(starred_expr) => self.expr(&starred_expr.value),
+            ast::Expr::DictComp(dict_comp) => {
+                self.comp_clauses(&dict_comp.generators);
+                // The key of the dict comprehension is an expression.
+                self.opt_expr(&dict_comp.entry.key);
+                self.expr(&dict_comp.entry.value);
+            }
+            ast::Expr::ConfigIfEntry(config_if_entry_expr) => {
+                self.expr(&config_if_entry_expr.if_cond);
+                self.config_entries(&config_if_entry_expr.items);
+                self.opt_expr(&config_if_entry_expr.orelse);
+            }
+            ast::Expr::CompClause(comp_clause) => self.comp_clause(comp_clause),
+            ast::Expr::Schema(schema_expr) => self.schema_expr(schema_expr),
+            ast::Expr::Config(config_expr) => self.config_entries(&config_expr.items),
+            ast::Expr::Check(check_expr) => self.check_expr(check_expr),
+            ast::Expr::Lambda(lambda_expr) => {
+                if let Some(args) = &lambda_expr.args {
+                    self.arguments(&args.node);
+                }
+                self.stmts(&lambda_expr.body);
+            }
+            ast::Expr::Subscript(subscript) => {
+                self.expr(&subscript.value);
+                self.opt_expr(&subscript.index);
+                self.opt_expr(&subscript.lower);
+                self.opt_expr(&subscript.upper);
+                self.opt_expr(&subscript.step);
+            }
+            ast::Expr::Keyword(keyword) => self.opt_expr(&keyword.value),
+            ast::Expr::Arguments(arguments) => self.arguments(arguments),
+            ast::Expr::Compare(compare) => {
+                self.expr(&compare.left);
+                self.exprs(&compare.comparators);
+            }
+            ast::Expr::JoinedString(joined_string) => self.exprs(&joined_string.values),
+            ast::Expr::FormattedValue(formatted_value) => self.expr(&formatted_value.value),
+            ast::Expr::NumberLit(_)
+            | ast::Expr::StringLit(_)
+            | ast::Expr::NameConstantLit(_)
+            | ast::Expr::Missing(_) => {}
+        }
+    }
+
+    fn stmts(&mut self, stmts: &[ast::NodeRef<ast::Stmt>]) {
+        for stmt in stmts {
+            self.stmt(stmt);
+        }
+    }
+
+    fn exprs(&mut self, exprs: &[ast::NodeRef<ast::Expr>]) {
+        for expr in exprs {
+            self.expr(expr);
+        }
+    }
+
+    fn opt_expr(&mut self, expr: &Option<ast::NodeRef<ast::Expr>>) {
+        if let Some(expr) = expr {
+            self.expr(expr);
+        }
+    }
+
+    fn call_expr(&mut self, call_expr: &ast::CallExpr) {
+        self.expr(&call_expr.func);
+        self.exprs(&call_expr.args);
+        // The keyword argument names are not collected.
+        for keyword in &call_expr.keywords {
+            self.opt_expr(&keyword.node.value);
+        }
+    }
+
+    fn calls(&mut self, calls: &[ast::NodeRef<ast::CallExpr>]) {
+        for call in calls {
+            self.call_expr(&call.node);
+        }
+    }
+
+    fn check_expr(&mut self, check_expr: &ast::CheckExpr) {
+        self.expr(&check_expr.test);
+        self.opt_expr(&check_expr.if_cond);
+        self.opt_expr(&check_expr.msg);
+    }
+
+    fn checks(&mut self, checks: &[ast::NodeRef<ast::CheckExpr>]) {
+        for check in checks {
+            self.check_expr(&check.node);
+        }
+    }
+
+    fn schema_expr(&mut self, schema_expr: &ast::SchemaExpr) {
+        self.identifier(&schema_expr.name);
+        self.exprs(&schema_expr.args);
+        for keyword in &schema_expr.kwargs {
+            self.opt_expr(&keyword.node.value);
+        }
+        self.expr(&schema_expr.config);
+    }
+
+    fn config_entries(&mut self, entries: &[ast::NodeRef<ast::ConfigEntry>]) {
+        for entry in entries {
+            // The identifier keys e.g., `name` in `{name = "Alice"}` are
+            // attribute names instead of variable references.
+            if let Some(key) = &entry.node.key {
+                if !matches!(key.node, ast::Expr::Identifier(_)) {
+                    self.expr(key);
+                }
+            }
+            self.expr(&entry.node.value);
+        }
+    }
+
+    fn comp_clause(&mut self, comp_clause: &ast::CompClause) {
+        self.expr(&comp_clause.iter);
+        for target in &comp_clause.targets {
+            self.identifier(target);
+        }
+        self.exprs(&comp_clause.ifs);
+    }
+
+    fn comp_clauses(&mut self, comp_clauses: &[ast::NodeRef<ast::CompClause>]) {
+        for comp_clause in comp_clauses {
+            self.comp_clause(&comp_clause.node);
+        }
+    }
+
+    fn arguments(&mut self, arguments: &ast::Arguments) {
+        for arg in &arguments.args {
+            self.identifier(arg);
+        }
+        for type_annotation in arguments.type_annotation_list.iter().flatten() {
+            self.type_str(type_annotation);
+        }
+        for default in arguments.defaults.iter().flatten() {
+            self.expr(default);
+        }
+    }
+
+    fn identifier(&mut self, identifier: &ast::NodeRef<ast::Identifier>) {
+        self.names(&identifier.node, identifier);
+    }
+
+    /// Collect the first name of the identifier, the other names are the
+    /// attribute names.
+    fn names<T>(&mut self, identifier: &ast::Identifier, node: &ast::Node<T>) {
+        if let Some(name) = identifier.names.first() {
+            let start = node.get_pos();
+            self.push(name, start.clone(), start);
+        }
+    }
+
+    fn name(&mut self, name: &ast::NodeRef<String>, decl_start: Position) {
+        self.push(&name.node, name.get_pos(), decl_start);
+    }
+
+    /// Collect the schema name in the type annotation such as `Person` and
+    /// `pkg.Person`, the composite types such as `[Person]` are ignored.
+    fn type_str(&mut self, type_str: &ast::NodeRef<String>) {
+        let ty = &type_str.node;
+        if !ty.is_empty()
+            && ty
+                .split('.')
+                .all(|name| name.chars().all(rustc_lexer::is_id_continue))
+        {
+            let name = ty.split('.').next().unwrap_or_default();
+            let start = type_str.get_pos();
+            self.push(name, start.clone(), start);
+        }
+    }
+
+    fn push(&mut self, name: &str, start: Position, decl_start: Position) {
+        let column = match start.column {
+            Some(column) => column,
+            None => return,
+        };
+        // The raw identifier prefix `$` has been removed from the name by
+        // the resolver, but it is still in the document.
+        let prefix = self
+            .lines
+            .get((start.line as usize).wrapping_sub(1))
+            .and_then(|line| line.chars().nth(column as usize))
+            .map_or(0, |c| (c == '$') as u64);
+        let end = Position {
+            column: Some(column + prefix + name.chars().count() as u64),
+            ..start.clone()
+        };
+        self.names.push(NameRef {
+            name: name.to_string(),
+            start,
+            end,
+            decl_start,
+        });
+    }
+}
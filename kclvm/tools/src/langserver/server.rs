@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{
+    DocumentSymbolRequest, GotoDefinition, HoverRequest, References, Request as LspRequest,
+};
+use lsp_types::{
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DocumentSymbolParams, DocumentSymbolResponse, GotoDefinitionParams, GotoDefinitionResponse,
+    HoverParams, HoverProviderCapability, Location, OneOf, PublishDiagnosticsParams,
+    ReferenceParams, ServerCapabilities, TextDocumentPositionParams, TextDocumentSyncCapability,
+    TextDocumentSyncKind, Url,
+};
+use serde::de::DeserializeOwned;
+
+use crate::langserver::analysis::Analysis;
+use crate::langserver::to_lsp::{lsp_diags, lsp_hover, lsp_range, lsp_range_of, lsp_symbol};
+
+/// Run the KCL language server on the stdin and stdout until the client
+/// sends the exit notification.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_tools::langserver::server::run_server;
+///
+/// run_server().unwrap();
+/// ```
+pub fn run_server() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    main_loop(&connection)?;
+    io_threads.join()?;
+    Ok(())
+}
+
+/// Returns the capabilities of the KCL language server.
+pub fn server_capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Initialize the connection and serve the client requests until the
+/// shutdown request. The connection can be built in memory with
+/// `Connection::memory()` to run the server in the same process of the client.
+pub fn main_loop(connection: &Connection) -> Result<()> {
+    connection.initialize(serde_json::to_value(server_capabilities())?)?;
+    let mut state = LanguageServerState::default();
+    for msg in &connection.receiver {
+        match msg {
+            Message::Request(req) => {
+                if connection.handle_shutdown(&req)? {
+                    return Ok(());
+                }
+                connection
+                    .sender
+                    .send(Message::Response(state.handle_request(req)))?;
+            }
+            Message::Notification(not) => {
+                for params in state.handle_notification(not) {
+                    connection
+                        .sender
+                        .send(Message::Notification(Notification::new(
+                            PublishDiagnostics::METHOD.to_string(),
+                            params,
+                        )))?;
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// The language server state holds the analysis of all opened documents.
+#[derive(Default)]
+struct LanguageServerState {
+    documents: HashMap<Url, Analysis>,
+}
+
+impl LanguageServerState {
+    /// Handle the document synchronization notifications and returns the
+    /// diagnostics need to be published.
+    fn handle_notification(&mut self, not: Notification) -> Vec<PublishDiagnosticsParams> {
+        match not.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                match extract_notification::<DidOpenTextDocumentParams>(not) {
+                    Some(params) => {
+                        let doc = params.text_document;
+                        self.update_document(doc.uri, &doc.text, Some(doc.version))
+                    }
+                    None => vec![],
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                match extract_notification::<DidChangeTextDocumentParams>(not) {
+                    // The server uses the full text document sync, so the last
+                    // change holds the whole text of the document.
+                    Some(params) => match params.content_changes.last() {
+                        Some(change) => {
+                            let doc = params.text_document;
+                            self.update_document(doc.uri, &change.text, Some(doc.version))
+                        }
+                        None => vec![],
+                    },
+                    None => vec![],
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                match extract_notification::<DidCloseTextDocumentParams>(not) {
+                    Some(params) => {
+                        let uri = params.text_document.uri;
+                        self.documents.remove(&uri);
+                        vec![PublishDiagnosticsParams::new(uri, vec![], None)]
+                    }
+                    None => vec![],
+                }
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Analyze the new text of the document and returns its diagnostics.
+    fn update_document(
+        &mut self,
+        uri: Url,
+        text: &str,
+        version: Option<i32>,
+    ) -> Vec<PublishDiagnosticsParams> {
+        let filename = match uri.to_file_path() {
+            Ok(path) => path.to_string_lossy().to_string(),
+            Err(_) => return vec![],
+        };
+        let analysis = Analysis::new(&filename, text);
+        let diags = analysis
+            .diagnostics
+            .iter()
+            .filter(|diag| {
+                diag.messages
+                    .iter()
+                    .all(|msg| msg.pos.filename.is_empty() || msg.pos.filename == filename)
+            })
+            .flat_map(|diag| lsp_diags(text, diag))
+            .collect();
+        self.documents.insert(uri.clone(), analysis);
+        vec![PublishDiagnosticsParams::new(uri, diags, version)]
+    }
+
+    fn handle_request(&mut self, req: Request) -> Response {
+        let id = req.id.clone();
+        let method = req.method.clone();
+        let result = match method.as_str() {
+            GotoDefinition::METHOD => extract_request::<GotoDefinitionParams>(req).map(|params| {
+                let result = self.goto_definition(params.text_document_position_params);
+                serde_json::to_value(result)
+            }),
+            References::METHOD => extract_request::<ReferenceParams>(req)
+                .map(|params| serde_json::to_value(self.references(params))),
+            HoverRequest::METHOD => extract_request::<HoverParams>(req).map(|params| {
+                let result = self.hover(params.text_document_position_params);
+                serde_json::to_value(result)
+            }),
+            DocumentSymbolRequest::METHOD => extract_request::<DocumentSymbolParams>(req)
+                .map(|params| serde_json::to_value(self.document_symbols(params))),
+            _ => {
+                return Response::new_err(
+                    id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("unsupported request method '{}'", method),
+                )
+            }
+        };
+        response(id, result)
+    }
+
+    fn goto_definition(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Option<GotoDefinitionResponse> {
+        let uri = params.text_document.uri;
+        let analysis = self.documents.get(&uri)?;
+        let obj = analysis.definition(&analysis.kcl_pos(params.position))?;
+        // The definition may be in another file of the program.
+        let range = if obj.start.filename == analysis.filename {
+            lsp_range(&analysis.text, &obj)
+        } else {
+            let text = std::fs::read_to_string(&obj.start.filename).unwrap_or_default();
+            lsp_range(&text, &obj)
+        };
+        let uri = Url::from_file_path(&obj.start.filename).unwrap_or(uri);
+        Some(GotoDefinitionResponse::Scalar(Location::new(uri, range)))
+    }
+
+    fn references(&self, params: ReferenceParams) -> Option<Vec<Location>> {
+        let position = params.text_document_position;
+        let uri = position.text_document.uri;
+        let analysis = self.documents.get(&uri)?;
+        let refs = analysis.references(
+            &analysis.kcl_pos(position.position),
+            params.context.include_declaration,
+        );
+        Some(
+            refs.iter()
+                .map(|(start, end)| {
+                    Location::new(uri.clone(), lsp_range_of(&analysis.text, start, end))
+                })
+                .collect(),
+        )
+    }
+
+    fn hover(&self, params: TextDocumentPositionParams) -> Option<lsp_types::Hover> {
+        let analysis = self.documents.get(&params.text_document.uri)?;
+        let (_, obj) = analysis.lookup(&analysis.kcl_pos(params.position))?;
+        Some(lsp_hover(&obj))
+    }
+
+    fn document_symbols(&self, params: DocumentSymbolParams) -> Option<DocumentSymbolResponse> {
+        let analysis = self.documents.get(&params.text_document.uri)?;
+        let symbols = analysis
+            .symbols()
+            .iter()
+            .map(|(obj, children)| lsp_symbol(&analysis.text, obj, children))
+            .collect();
+        Some(DocumentSymbolResponse::Nested(symbols))
+    }
+}
+
+fn extract_notification<P: DeserializeOwned>(not: Notification) -> Option<P> {
+    serde_json::from_value(not.params).ok()
+}
+
+fn extract_request<P: DeserializeOwned>(req: Request) -> Result<P, String> {
+    serde_json::from_value(req.params).map_err(|err| err.to_string())
+}
+
+fn response(
+    id: RequestId,
+    result: Result<serde_json::Result<serde_json::Value>, String>,
+) -> Response {
+    match result {
+        Ok(Ok(value)) => Response::new_ok(id, value),
+        Ok(Err(err)) => Response::new_err(id, ErrorCode::InternalError as i32, err.to_string()),
+        Err(err) => Response::new_err(id, ErrorCode::InvalidParams as i32, err),
+    }
+}
@@ -1,11 +1,30 @@
 use crate::langserver;
+use crate::langserver::server::main_loop;
 use crate::langserver::LineWord;
 use kclvm_error::Position;
+use lsp_server::{Connection, Message, Notification, Request};
+use lsp_types::notification::Notification as LspNotification;
+use lsp_types::request::Request as LspRequest;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lsp_types::notification::{
+        DidChangeTextDocument, DidOpenTextDocument, Exit, Initialized, PublishDiagnostics,
+    };
+    use lsp_types::request::{
+        DocumentSymbolRequest, GotoDefinition, HoverRequest, Initialize, References, Shutdown,
+    };
+    use lsp_types::{
+        DidChangeTextDocumentParams, DidOpenTextDocumentParams, DocumentSymbolParams,
+        DocumentSymbolResponse, GotoDefinitionParams, GotoDefinitionResponse, HoverContents,
+        HoverParams, InitializeParams, InitializedParams, ReferenceContext, ReferenceParams,
+        TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem,
+        TextDocumentPositionParams, Url, VersionedTextDocumentIdentifier,
+    };
     use std::fs;
+    use std::thread::{self, JoinHandle};
+    use std::time::Duration;
     use std::{collections::HashMap, hash::Hash};
 
     fn check_line_to_words(code: &str, expect: Vec<LineWord>) {
@@ -198,4 +217,284 @@ mod tests {
         }];
         assert_eq!(mp.get(&String::from("Son")), Some(except));
     }
+
+    /// An in-process LSP client which talks with the language server
+    /// running in another thread through the memory connection.
+    struct LspClient {
+        conn: Connection,
+        server: Option<JoinHandle<()>>,
+        next_id: i32,
+    }
+
+    impl LspClient {
+        fn new() -> Self {
+            let (server_conn, client_conn) = Connection::memory();
+            let server = thread::spawn(move || main_loop(&server_conn).unwrap());
+            let mut client = LspClient {
+                conn: client_conn,
+                server: Some(server),
+                next_id: 0,
+            };
+            client.request::<Initialize>(InitializeParams::default());
+            client.notify::<Initialized>(InitializedParams {});
+            client
+        }
+
+        fn request<R: LspRequest>(&mut self, params: R::Params) -> R::Result {
+            self.next_id += 1;
+            let id: lsp_server::RequestId = self.next_id.into();
+            let req = Request::new(id.clone(), R::METHOD.to_string(), params);
+            self.conn.sender.send(Message::Request(req)).unwrap();
+            loop {
+                match self.recv() {
+                    Message::Response(resp) if resp.id == id => {
+                        assert!(resp.error.is_none(), "{:?}", resp.error);
+                        let result = resp.result.unwrap_or(serde_json::Value::Null);
+                        return serde_json::from_value(result).unwrap();
+                    }
+                    _ => continue,
+                }
+            }
+        }
+
+        fn notify<N: LspNotification>(&self, params: N::Params) {
+            let not = Notification::new(N::METHOD.to_string(), params);
+            self.conn.sender.send(Message::Notification(not)).unwrap();
+        }
+
+        fn wait_notification<N: LspNotification>(&self) -> N::Params {
+            loop {
+                match self.recv() {
+                    Message::Notification(not) if not.method == N::METHOD => {
+                        return serde_json::from_value(not.params).unwrap();
+                    }
+                    _ => continue,
+                }
+            }
+        }
+
+        fn recv(&self) -> Message {
+            self.conn
+                .receiver
+                .recv_timeout(Duration::from_secs(30))
+                .unwrap()
+        }
+    }
+
+    impl Drop for LspClient {
+        fn drop(&mut self) {
+            self.request::<Shutdown>(());
+            self.notify::<Exit>(());
+            if let Some(server) = self.server.take() {
+                server.join().unwrap();
+            }
+        }
+    }
+
+    fn position_params(uri: &Url, line: u32, character: u32) -> TextDocumentPositionParams {
+        TextDocumentPositionParams::new(
+            TextDocumentIdentifier::new(uri.clone()),
+            lsp_types::Position::new(line, character),
+        )
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> lsp_types::Range {
+        lsp_types::Range::new(
+            lsp_types::Position::new(start.0, start.1),
+            lsp_types::Position::new(end.0, end.1),
+        )
+    }
+
+    #[test]
+    fn test_language_server() {
+        let path = fs::canonicalize("./src/langserver/test_data/lsp/schema.k").unwrap();
+        let uri = Url::from_file_path(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let mut client = LspClient::new();
+
+        client.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri.clone(), "kcl".to_string(), 1, text),
+        });
+        let diags = client.wait_notification::<PublishDiagnostics>();
+        assert_eq!(diags.uri, uri);
+        assert!(diags.diagnostics.is_empty(), "{:?}", diags.diagnostics);
+
+        // Go to the definition of `Person` in `person = Person {`.
+        let def = client.request::<GotoDefinition>(GotoDefinitionParams {
+            text_document_position_params: position_params(&uri, 4, 10),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        });
+        match def {
+            Some(GotoDefinitionResponse::Scalar(loc)) => {
+                assert_eq!(loc.uri, uri);
+                assert_eq!(loc.range.start, lsp_types::Position::new(0, 0));
+            }
+            _ => panic!("unexpected definition response {:?}", def),
+        }
+
+        // Hover on `person` in `name = person.name`.
+        let hover = client
+            .request::<HoverRequest>(HoverParams {
+                text_document_position_params: position_params(&uri, 8, 8),
+                work_done_progress_params: Default::default(),
+            })
+            .unwrap();
+        match hover.contents {
+            HoverContents::Markup(content) => assert!(content.value.contains("person: Person")),
+            _ => panic!("unexpected hover contents {:?}", hover.contents),
+        }
+
+        // Find the references of `Person`.
+        let refs = |client: &mut LspClient, include_declaration| {
+            client
+                .request::<References>(ReferenceParams {
+                    text_document_position: position_params(&uri, 4, 10),
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                    context: ReferenceContext {
+                        include_declaration,
+                    },
+                })
+                .unwrap()
+                .iter()
+                .map(|loc| loc.range)
+                .collect::<Vec<lsp_types::Range>>()
+        };
+        assert_eq!(
+            refs(&mut client, true),
+            vec![range((0, 7), (0, 13)), range((4, 9), (4, 15))]
+        );
+        assert_eq!(refs(&mut client, false), vec![range((4, 9), (4, 15))]);
+
+        // Document symbols.
+        let symbols = client.request::<DocumentSymbolRequest>(DocumentSymbolParams {
+            text_document: TextDocumentIdentifier::new(uri.clone()),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        });
+        match symbols {
+            Some(DocumentSymbolResponse::Nested(symbols)) => {
+                let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+                assert!(names.contains(&"Person"));
+                assert!(names.contains(&"person"));
+                let schema = symbols.iter().find(|s| s.name == "Person").unwrap();
+                let attrs: Vec<&str> = schema
+                    .children
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .map(|s| s.name.as_str())
+                    .collect();
+                assert!(attrs.contains(&"name"));
+                assert!(attrs.contains(&"age"));
+            }
+            _ => panic!("unexpected document symbol response {:?}", symbols),
+        }
+
+        // Change the document with a type error.
+        client.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier::new(uri.clone(), 2),
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "a: int = \"1\"\n".to_string(),
+            }],
+        });
+        let diags = client.wait_notification::<PublishDiagnostics>();
+        assert_eq!(diags.version, Some(2));
+        assert!(!diags.diagnostics.is_empty());
+        let diag_range = diags.diagnostics[0].range;
+        assert_eq!(diag_range.start.line, 0);
+        assert!(diag_range.start < diag_range.end, "{:?}", diag_range);
+    }
+
+    #[test]
+    fn test_language_server_references() {
+        let path = fs::canonicalize("./src/langserver/test_data/lsp/refs.k").unwrap();
+        let uri = Url::from_file_path(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let mut client = LspClient::new();
+
+        client.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri.clone(), "kcl".to_string(), 1, text),
+        });
+        let diags = client.wait_notification::<PublishDiagnostics>();
+        assert!(diags.diagnostics.is_empty(), "{:?}", diags.diagnostics);
+
+        let refs = |client: &mut LspClient, line, character| {
+            client
+                .request::<References>(ReferenceParams {
+                    text_document_position: position_params(&uri, line, character),
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                    context: ReferenceContext {
+                        include_declaration: true,
+                    },
+                })
+                .unwrap()
+                .iter()
+                .map(|loc| loc.range)
+                .collect::<Vec<lsp_types::Range>>()
+        };
+        // The config key `name` in `Person {}`, the selector attribute
+        // `person.name`, the strings and the comment are not the references
+        // of the top level variable `name`, while the interpolated `${name}` is.
+        let expected = vec![
+            range((4, 0), (4, 4)),
+            range((6, 11), (6, 15)),
+            range((9, 15), (9, 19)),
+        ];
+        assert_eq!(refs(&mut client, 4, 0), expected);
+        assert_eq!(refs(&mut client, 6, 12), expected);
+        assert_eq!(refs(&mut client, 6, 4), vec![]);
+        assert_eq!(refs(&mut client, 10, 16), vec![]);
+        assert_eq!(refs(&mut client, 4, 20), vec![]);
+
+        // The schema attribute `name` is not referred by the top level variable.
+        assert_eq!(refs(&mut client, 1, 4), vec![range((1, 4), (1, 8))]);
+    }
+
+    #[test]
+    fn test_language_server_utf16_positions() {
+        let path = fs::canonicalize("./src/langserver/test_data/lsp/unicode.k").unwrap();
+        let uri = Url::from_file_path(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let mut client = LspClient::new();
+
+        client.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri.clone(), "kcl".to_string(), 1, text),
+        });
+        let diags = client.wait_notification::<PublishDiagnostics>();
+        assert!(diags.diagnostics.is_empty(), "{:?}", diags.diagnostics);
+
+        // `person` in `msg = "😀" + person.name` starts at the char column 12,
+        // and the emoji takes two UTF-16 code units.
+        let refs = client
+            .request::<References>(ReferenceParams {
+                text_document_position: position_params(&uri, 4, 14),
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+                context: ReferenceContext {
+                    include_declaration: true,
+                },
+            })
+            .unwrap()
+            .iter()
+            .map(|loc| loc.range)
+            .collect::<Vec<lsp_types::Range>>();
+        assert_eq!(refs, vec![range((3, 0), (3, 6)), range((4, 13), (4, 19))]);
+
+        // Hover on the end of `person` after the emoji.
+        let hover = client
+            .request::<HoverRequest>(HoverParams {
+                text_document_position_params: position_params(&uri, 4, 18),
+                work_done_progress_params: Default::default(),
+            })
+            .unwrap();
+        match hover.contents {
+            HoverContents::Markup(content) => assert!(content.value.contains("person: Person")),
+            _ => panic!("unexpected hover contents {:?}", hover.contents),
+        }
+    }
 }
@@ -0,0 +1,171 @@
+use kclvm_error::{Diagnostic as KCLDiagnostic, Level, Position as KCLPos};
+use kclvm_sema::resolver::scope::{ScopeObject, ScopeObjectKind};
+use kclvm_sema::ty::TypeKind;
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, DocumentSymbol, Hover, HoverContents, MarkupContent,
+    MarkupKind, Position, Range, SymbolKind,
+};
+
+use crate::langserver::word_at_text_pos;
+
+/// Convert the kcl position to the lsp position in the document text. The line
+/// number of the kcl position is 1 based and the column counts the chars, the
+/// lsp one is 0 based and the character counts the UTF-16 code units.
+pub(crate) fn lsp_pos(text: &str, pos: &KCLPos) -> Position {
+    let line = pos.line.saturating_sub(1);
+    let column = pos.column.unwrap_or(0);
+    let character = match text.lines().nth(line as usize) {
+        Some(line_text) => utf16_column(line_text, column),
+        None => column as u32,
+    };
+    Position {
+        line: line as u32,
+        character,
+    }
+}
+
+/// Convert the lsp position to the kcl position of the file with the document text.
+pub(crate) fn kcl_pos(filename: &str, text: &str, pos: Position) -> KCLPos {
+    let column = match text.lines().nth(pos.line as usize) {
+        Some(line_text) => char_column(line_text, pos.character),
+        None => pos.character as u64,
+    };
+    KCLPos {
+        filename: filename.to_string(),
+        line: pos.line as u64 + 1,
+        column: Some(column),
+    }
+}
+
+/// Returns the UTF-16 offset of the char column in the line.
+fn utf16_column(line: &str, column: u64) -> u32 {
+    line.chars()
+        .take(column as usize)
+        .map(|c| c.len_utf16() as u32)
+        .sum()
+}
+
+/// Returns the char column of the UTF-16 offset in the line.
+fn char_column(line: &str, character: u32) -> u64 {
+    let mut offset = 0;
+    for (column, c) in line.chars().enumerate() {
+        if offset >= character {
+            return column as u64;
+        }
+        offset += c.len_utf16() as u32;
+    }
+    line.chars().count() as u64
+}
+
+/// Convert the kcl diagnostic to lsp diagnostics, one for each message. The
+/// message only holds the start position, so the range covers the word at the
+/// position, or the whole line when the column is unknown.
+pub(crate) fn lsp_diags(text: &str, diag: &KCLDiagnostic) -> Vec<Diagnostic> {
+    let severity = match diag.level {
+        Level::Error => DiagnosticSeverity::ERROR,
+        Level::Warning => DiagnosticSeverity::WARNING,
+        Level::Note => DiagnosticSeverity::HINT,
+    };
+    diag.messages
+        .iter()
+        .map(|msg| {
+            let message = match &msg.note {
+                Some(note) => format!("{}\n{}", msg.message, note),
+                None => msg.message.clone(),
+            };
+            Diagnostic {
+                range: word_range(text, &msg.pos),
+                severity: Some(severity),
+                source: Some("kcl".to_string()),
+                message,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+/// Returns the range of the word at the position, or the range of the rest
+/// of the line when there is no word at the position.
+fn word_range(text: &str, pos: &KCLPos) -> Range {
+    let line = pos.line.saturating_sub(1);
+    let line_text = text.lines().nth(line as usize).unwrap_or_default();
+    let line_end = line_text.chars().count() as u64;
+    let (start, end) = match pos.column {
+        Some(column) => match word_at_text_pos(text, line, column) {
+            Some(word) => (word.startpos, word.endpos),
+            None => (column, line_end.max(column)),
+        },
+        None => (0, line_end),
+    };
+    Range::new(
+        Position::new(line as u32, utf16_column(line_text, start)),
+        Position::new(line as u32, utf16_column(line_text, end)),
+    )
+}
+
+/// Returns the range from the start to the end position in the document text.
+pub(crate) fn lsp_range_of(text: &str, start: &KCLPos, end: &KCLPos) -> Range {
+    Range::new(lsp_pos(text, start), lsp_pos(text, end))
+}
+
+/// Returns the range of the scope object in the document text, the end position
+/// of some scope objects such as schema attributes is unknown, use the start instead.
+pub(crate) fn lsp_range(text: &str, obj: &ScopeObject) -> Range {
+    let start = lsp_pos(text, &obj.start);
+    if obj.end.filename == obj.start.filename && obj.start.less_equal(&obj.end) {
+        Range::new(start, lsp_pos(text, &obj.end))
+    } else {
+        Range::new(start, start)
+    }
+}
+
+/// Returns the hover content of the scope object, which displays its
+/// name and type.
+pub(crate) fn lsp_hover(obj: &ScopeObject) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```kcl\n{}: {}\n```", obj.name, obj.ty.ty_str()),
+        }),
+        range: None,
+    }
+}
+
+/// Convert the scope object and its children to a document symbol.
+#[allow(deprecated)]
+pub(crate) fn lsp_symbol(
+    text: &str,
+    obj: &ScopeObject,
+    children: &[ScopeObject],
+) -> DocumentSymbol {
+    let kind = match obj.kind {
+        ScopeObjectKind::Variable | ScopeObjectKind::Parameter => SymbolKind::VARIABLE,
+        ScopeObjectKind::Attribute => SymbolKind::PROPERTY,
+        ScopeObjectKind::Definition => match obj.ty.kind {
+            TypeKind::Schema(_) => SymbolKind::STRUCT,
+            _ => SymbolKind::FUNCTION,
+        },
+        ScopeObjectKind::TypeAlias => SymbolKind::TYPE_PARAMETER,
+        ScopeObjectKind::Module => SymbolKind::MODULE,
+    };
+    let range = lsp_range(text, obj);
+    DocumentSymbol {
+        name: obj.name.clone(),
+        detail: Some(obj.ty.ty_str()),
+        kind,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range: range,
+        children: if children.is_empty() {
+            None
+        } else {
+            Some(
+                children
+                    .iter()
+                    .map(|child| lsp_symbol(text, child, &[]))
+                    .collect(),
+            )
+        },
+    }
+}
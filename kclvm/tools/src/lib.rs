@@ -1,5 +1,6 @@
 pub mod format;
-mod langserver;
+pub mod jsonschema;
+pub mod langserver;
 pub mod lint;
-pub(crate) mod util;
+pub mod util;
 pub mod vet;
@@ -0,0 +1,220 @@
+//! The lint fixer applies the machine-applicable suggestions of the lint diagnostics
+//! such as `UnusedImport`, `ReImport` and `ImportPosition` on the source code. The
+//! suggestions are text edits at the positions of the fixed statements, so the rest
+//! of the source including the comments and the blank lines is kept as it is.
+//!
+//! The fixes of one round may make others possible e.g., the duplicate import
+//! statement becomes unused after the first one is removed, so the files are
+//! linted and fixed repeatedly until no suggestion can be applied.
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use kclvm_error::{Level, Position, Suggestion};
+use kclvm_parser::{load_program, LoadProgramOptions};
+use kclvm_sema::resolver::resolve_program;
+
+use crate::format::unified_diff;
+use crate::util::get_kcl_files;
+
+/// The max rounds of linting and fixing the files.
+const MAX_FIX_ROUNDS: usize = 8;
+
+/// Fixes the lint warnings of the files and returns the fixed file paths. The
+/// fixed sources are written back into the files, or printed as unified diffs
+/// without modifying the files in the dry run mode.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_tools::lint::fix;
+///
+/// // Print the diff of the fixes.
+/// fix(&["main.k"], None, true).unwrap();
+/// // Fix the file in place.
+/// fix(&["main.k"], None, false).unwrap();
+/// ```
+pub fn fix(
+    files: &[&str],
+    opts: Option<LoadProgramOptions>,
+    is_dry_run: bool,
+) -> Result<Vec<String>> {
+    let fixed_sources = fix_files(files, opts)?;
+    for (file, (src, fixed_src)) in &fixed_sources {
+        if is_dry_run {
+            print!("{}", unified_diff(file, src, fixed_src));
+        } else {
+            std::fs::write(file, fixed_src)?;
+        }
+    }
+    let n = fixed_sources.len();
+    println!(
+        "KCL lint fix done and {} {}:",
+        n,
+        match (n <= 1, is_dry_run) {
+            (true, true) => "file would be fixed",
+            (false, true) => "files would be fixed",
+            (true, false) => "file was fixed",
+            (false, false) => "files were fixed",
+        }
+    );
+    for file in fixed_sources.keys() {
+        println!("{}", file);
+    }
+    Ok(fixed_sources.into_keys().collect())
+}
+
+/// Fixes the lint warnings of the files without modifying them, and returns the
+/// original sources and the fixed sources of the changed files. The directories
+/// in `files` are expanded into the KCL files in them.
+pub fn fix_files(
+    files: &[&str],
+    opts: Option<LoadProgramOptions>,
+) -> Result<IndexMap<String, (String, String)>> {
+    let mut paths = vec![];
+    for file in files {
+        if Path::new(file).is_dir() {
+            paths.extend(get_kcl_files(file, false)?);
+        } else {
+            paths.push(file.to_string());
+        }
+    }
+    let mut sources = vec![];
+    for path in &paths {
+        sources.push(std::fs::read_to_string(path)?);
+    }
+    let origin_sources = sources.clone();
+    let canonical_paths: Vec<Option<std::path::PathBuf>> = paths
+        .iter()
+        .map(|path| Path::new(path).canonicalize().ok())
+        .collect();
+    let mut opts = opts.unwrap_or_default();
+    for _ in 0..MAX_FIX_ROUNDS {
+        opts.k_code_list = sources.clone();
+        let path_refs: Vec<&str> = paths.iter().map(|path| path.as_str()).collect();
+        let mut program =
+            load_program(&path_refs, Some(opts.clone())).map_err(|err| anyhow!(err))?;
+        let scope = resolve_program(&mut program);
+        // Suggestions of each file.
+        let mut suggestions: IndexMap<usize, Vec<&Suggestion>> = IndexMap::new();
+        for diag in &scope.diagnostics {
+            if diag.level != Level::Warning {
+                continue;
+            }
+            for suggestion in &diag.suggestions {
+                let filename = match suggestion.replacements.first() {
+                    Some(replacement) => &replacement.start.filename,
+                    None => continue,
+                };
+                let canonical_path = Path::new(filename).canonicalize().ok();
+                if let Some(index) = canonical_paths
+                    .iter()
+                    .position(|path| path.is_some() && *path == canonical_path)
+                {
+                    suggestions.entry(index).or_default().push(suggestion);
+                }
+            }
+        }
+        let mut is_fixed = false;
+        for (index, suggestions) in suggestions {
+            let src = apply_suggestions(&sources[index], &suggestions);
+            if src != sources[index] {
+                sources[index] = src;
+                is_fixed = true;
+            }
+        }
+        if !is_fixed {
+            break;
+        }
+    }
+    let mut fixed_sources = IndexMap::new();
+    for ((path, src), origin_src) in paths.iter().zip(sources).zip(origin_sources) {
+        if src != origin_src {
+            fixed_sources.insert(path.clone(), (origin_src, src));
+        }
+    }
+    Ok(fixed_sources)
+}
+
+/// Applies the suggestions on the source and returns the fixed source. The
+/// suggestions which overlap the applied ones are skipped, and the removals are
+/// applied first because other fixes on the removed code are useless.
+pub fn apply_suggestions(src: &str, suggestions: &[&Suggestion]) -> String {
+    let mut suggestions = suggestions.to_vec();
+    suggestions.sort_by_key(|suggestion| !is_removal(suggestion));
+    // The applied replacements in the form of byte ranges of the source.
+    let mut ranges: Vec<(usize, usize, &str)> = vec![];
+    for suggestion in suggestions {
+        let new_ranges: Vec<(usize, usize, &str)> = suggestion
+            .replacements
+            .iter()
+            .map(|replacement| {
+                let start = byte_offset(src, &replacement.start);
+                let mut end = byte_offset(src, &replacement.end);
+                if replacement.text.is_empty() {
+                    end = removal_end(src, start, end);
+                }
+                (start, end, replacement.text.as_str())
+            })
+            .collect();
+        let is_overlapped = new_ranges.iter().any(|(start, end, _)| {
+            ranges
+                .iter()
+                .any(|(other_start, other_end, _)| start < other_end && other_start < end)
+        });
+        if !is_overlapped && new_ranges.iter().all(|(start, end, _)| start <= end) {
+            ranges.extend(new_ranges);
+        }
+    }
+    // Keep the insertion order of the replacements at the same position.
+    ranges.sort_by_key(|(start, _, _)| *start);
+    let mut fixed_src = String::new();
+    let mut cursor = 0;
+    for (start, end, text) in ranges {
+        if start > cursor {
+            fixed_src.push_str(&src[cursor..start]);
+        }
+        fixed_src.push_str(text);
+        cursor = cursor.max(end);
+    }
+    fixed_src.push_str(&src[cursor..]);
+    fixed_src
+}
+
+#[inline]
+fn is_removal(suggestion: &Suggestion) -> bool {
+    suggestion
+        .replacements
+        .iter()
+        .all(|replacement| replacement.text.is_empty())
+}
+
+/// Returns the end of the removal which is extended to the following blank line
+/// when the removed lines are between two blank lines, so that no redundant blank
+/// line is left e.g., the import statement moved to the top of the module.
+fn removal_end(src: &str, start: usize, end: usize) -> usize {
+    if start < end && src[..start].ends_with("\n\n") && src[end..].starts_with('\n') {
+        end + 1
+    } else {
+        end
+    }
+}
+
+/// Returns the byte offset of the position in the source, the positions out of
+/// the source are clamped into it.
+fn byte_offset(src: &str, pos: &Position) -> usize {
+    let mut offset = 0;
+    for _ in 1..pos.line {
+        match src[offset..].find('\n') {
+            Some(index) => offset += index + 1,
+            None => return src.len(),
+        }
+    }
+    let line = src[offset..].split('\n').next().unwrap_or_default();
+    let column = pos.column.unwrap_or_default() as usize;
+    offset
+        + line
+            .char_indices()
+            .nth(column)
+            .map_or(line.len(), |(index, _)| index)
+}
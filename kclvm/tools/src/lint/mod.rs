@@ -1,10 +1,14 @@
 use indexmap::IndexSet;
 use kclvm_error::{Diagnostic, Level};
 use kclvm_parser::{load_program, LoadProgramOptions};
-use kclvm_sema::resolver::resolve_program;
+use kclvm_sema::resolver::{resolve_program_with_opts, Options};
+
+mod fix;
 #[cfg(test)]
 mod tests;
 
+pub use fix::{apply_suggestions, fix, fix_files};
+
 /// KCL Lint tools API, check a set of files, skips execute, divides and returns diagnostics into error and warning
 ///
 /// # Parameters
@@ -51,6 +55,14 @@ mod tests;
 ///            note: Some("Consider removing this statement".to_string()),
 ///        }],
 ///        code: Some<WarningKind::UnusedImportWarning>,
+///        suggestions: [Suggestion {
+///            message: "Remove the unused import statement",
+///            replacements: [Replacement {
+///                start: Position { filename: test.k, line: 1, column: None },
+///                end: Position { filename: test.k, line: 2, column: None },
+///                text: "",
+///            }],
+///        }],
 ///     }
 /// ]
 pub fn lint_files(
@@ -59,7 +71,17 @@ pub fn lint_files(
 ) -> (IndexSet<Diagnostic>, IndexSet<Diagnostic>) {
     // Parse AST program.
     let mut program = load_program(files, opts).unwrap();
-    let scope = resolve_program(&mut program);
+    // The lints configured with the `error` level are only reported as errors by the lint tool.
+    let scope = resolve_program_with_opts(
+        &mut program,
+        Options {
+            raise_err: true,
+            config_auto_fix: false,
+            lint_check: true,
+            lint_error_level: true,
+            ..Default::default()
+        },
+    );
     let (mut errs, mut warnings) = (IndexSet::new(), IndexSet::new());
     for diag in &scope.diagnostics {
         if diag.level == Level::Error {
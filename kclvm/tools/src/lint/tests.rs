@@ -1,4 +1,5 @@
-use super::lint_files;
+use super::{apply_suggestions, fix_files, lint_files};
+use kclvm_error::{Replacement, Suggestion};
 
 #[test]
 fn test_lint() {
@@ -12,3 +13,37 @@ fn test_lint() {
         assert_eq!(diag.messages[0].message, m.to_string());
     }
 }
+
+#[test]
+fn test_fix_files() {
+    let file = "./src/lint/test_data/fix.k";
+    let fixed_sources = fix_files(&[file], None).unwrap();
+    let (src, fixed_src) = fixed_sources.get(file).unwrap();
+    assert_eq!(src, &std::fs::read_to_string(file).unwrap());
+    assert_eq!(
+        fixed_src,
+        &std::fs::read_to_string("./src/lint/test_data/fix.golden").unwrap()
+    );
+}
+
+#[test]
+fn test_apply_suggestions() {
+    let src = "import a\nimport b\nx = 1\n";
+    let remove = Suggestion {
+        message: "".to_string(),
+        replacements: vec![Replacement::remove_lines("main.k", 2, 2)],
+    };
+    let insert = Suggestion {
+        message: "".to_string(),
+        replacements: vec![Replacement::insert_before_line("main.k", 2, "import c\n")],
+    };
+    let overlapped = Suggestion {
+        message: "".to_string(),
+        replacements: vec![Replacement::remove_lines("main.k", 1, 2)],
+    };
+    assert_eq!(
+        apply_suggestions(src, &[&insert, &remove, &overlapped]),
+        "import a\nimport c\nx = 1\n"
+    );
+    assert_eq!(apply_suggestions(src, &[]), src);
+}
@@ -1,17 +1,38 @@
 use std::fs;
 
 use anyhow::{bail, Context, Result};
+use indexmap::IndexMap;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::fmt;
+use toml::Spanned;
+use yaml_rust::{
+    parser::{Event, MarkedEventReceiver, Parser},
+    scanner::Marker,
+};
 
 pub(crate) trait Loader<T> {
     fn load(&self) -> Result<T>;
 }
 
 /// Types of verifiable files currently supported by KCL-Vet,
-/// currently only YAML files and Json files are supported.
+/// currently YAML files, Json files and TOML files are supported.
+/// A YAML file may be a stream of multiple documents.
 #[derive(Clone, Copy)]
 pub enum LoaderKind {
     YAML,
     JSON,
+    TOML,
+}
+
+impl LoaderKind {
+    /// The file extensions of the data files of the kind.
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            LoaderKind::YAML => &["yaml", "yml"],
+            LoaderKind::JSON => &["json"],
+            LoaderKind::TOML => &["toml"],
+        }
+    }
 }
 
 /// DataLoader for Json or Yaml
@@ -35,7 +56,6 @@ impl DataLoader {
     }
 
     /// If `DataLoader` is constructed using a Json/Yaml string, then `content` is the string
-    #[allow(dead_code)]
     pub(crate) fn new_with_str(loader_kind: LoaderKind, content: &str) -> Result<Self> {
         Ok(Self {
             kind: loader_kind,
@@ -67,6 +87,38 @@ impl Loader<serde_json::Value> for DataLoader {
     }
 }
 
+impl Loader<Vec<serde_yaml::Value>> for DataLoader {
+    /// Load each document of the Yaml stream into a Yaml value.
+    fn load(&self) -> Result<Vec<serde_yaml::Value>> {
+        let v = match self.kind {
+            LoaderKind::YAML => serde_yaml::Deserializer::from_str(self.get_data())
+                .map(serde_yaml::Value::deserialize)
+                .collect::<Result<Vec<_>, _>>()
+                .with_context(|| format!("Failed to String '{}' to Yaml", self.get_data()))?,
+            _ => {
+                bail!("Failed to String to Yaml Value")
+            }
+        };
+
+        Ok(v)
+    }
+}
+
+impl Loader<toml::Value> for DataLoader {
+    /// Load data into TOML value.
+    fn load(&self) -> Result<toml::Value> {
+        let v = match self.kind {
+            LoaderKind::TOML => toml::from_str(self.get_data())
+                .with_context(|| format!("Failed to String '{}' to TOML", self.get_data()))?,
+            _ => {
+                bail!("Failed to String to TOML Value")
+            }
+        };
+
+        Ok(v)
+    }
+}
+
 impl Loader<serde_yaml::Value> for DataLoader {
     /// Load data into Yaml value.
     fn load(&self) -> Result<serde_yaml::Value> {
@@ -81,3 +133,282 @@ impl Loader<serde_yaml::Value> for DataLoader {
         Ok(v)
     }
 }
+
+/// The positions of the nodes in a Json/Yaml document indexed by the JSON
+/// paths of the nodes, e.g., `$.spec.containers[0].image`. The lines are
+/// 1-based and the columns are 0-based, the same as the KCL AST.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct DataPositions {
+    /// The positions of the values.
+    pub(crate) values: IndexMap<String, (u64, u64)>,
+    /// The positions of the mapping keys, indexed by the paths of their values.
+    pub(crate) keys: IndexMap<String, (u64, u64)>,
+}
+
+/// The JSON path of the root node of a Json/Yaml document.
+pub(crate) const ROOT_PATH: &str = "$";
+
+/// Returns the JSON path of the value of `key` in the mapping at `path`.
+pub(crate) fn key_path(path: &str, key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        format!("{}.{}", path, key)
+    } else {
+        format!(
+            "{}['{}']",
+            path,
+            key.replace('\\', "\\\\").replace('\'', "\\'")
+        )
+    }
+}
+
+/// Returns the JSON path of the `index`-th item in the sequence at `path`.
+pub(crate) fn index_path(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+impl DataLoader {
+    /// Load the positions of the nodes in each Json/Yaml/TOML document of the data.
+    /// A Json document is parsed as Yaml, which is a superset of Json.
+    pub(crate) fn load_positions(&self) -> Result<Vec<DataPositions>> {
+        if let LoaderKind::TOML = self.kind {
+            return Ok(vec![load_toml_positions(self.get_data())?]);
+        }
+        let mut receiver = PositionReceiver::default();
+        Parser::new(self.get_data().chars())
+            .load(&mut receiver, true)
+            .with_context(|| "Failed to Load the Positions of the Data".to_string())?;
+        Ok(receiver.documents)
+    }
+}
+
+/// The sequence or mapping node being parsed.
+enum Collection {
+    /// The index of the next item.
+    Sequence(usize),
+    /// The key of the next value, `None` when expecting a key.
+    Mapping(Option<String>),
+}
+
+#[derive(Default)]
+struct PositionReceiver {
+    documents: Vec<DataPositions>,
+    /// The paths of the sequences and mappings being parsed.
+    stack: Vec<(String, Collection)>,
+}
+
+impl MarkedEventReceiver for PositionReceiver {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        let pos = (mark.line() as u64, mark.col() as u64);
+        let scalar = match &event {
+            Event::DocumentStart => {
+                self.documents.push(DataPositions::default());
+                self.stack.clear();
+                return;
+            }
+            Event::SequenceEnd | Event::MappingEnd => {
+                self.stack.pop();
+                return;
+            }
+            Event::Scalar(value, ..) => Some(value.clone()),
+            Event::Alias(_) | Event::SequenceStart(_) | Event::MappingStart(_) => None,
+            _ => return,
+        };
+        let document = match self.documents.last_mut() {
+            Some(document) => document,
+            None => return,
+        };
+        let path = match self.stack.last_mut() {
+            None => ROOT_PATH.to_string(),
+            Some((path, Collection::Sequence(index))) => {
+                *index += 1;
+                lower_pos(document, path, pos);
+                index_path(path, *index - 1)
+            }
+            Some((path, Collection::Mapping(key))) => match key.take() {
+                Some(key) => key_path(path, &key),
+                None => {
+                    // A mapping key, complex keys are not supported in the JSON paths.
+                    let name = scalar.unwrap_or_default();
+                    document.keys.insert(key_path(path, &name), pos);
+                    lower_pos(document, path, pos);
+                    *key = Some(name);
+                    if matches!(event, Event::SequenceStart(_) | Event::MappingStart(_)) {
+                        // The nodes in the complex key are recorded out of the document root.
+                        self.stack.push((String::new(), collection_of(&event)));
+                    }
+                    return;
+                }
+            },
+        };
+        document.values.entry(path.clone()).or_insert(pos);
+        if matches!(event, Event::SequenceStart(_) | Event::MappingStart(_)) {
+            self.stack.push((path, collection_of(&event)));
+        }
+    }
+}
+
+/// The start marks of the block sequences and mappings may be after their first
+/// items, so the position of the collection at `path` is the earliest one.
+fn lower_pos(document: &mut DataPositions, path: &str, pos: (u64, u64)) {
+    if let Some(collection_pos) = document.values.get_mut(path) {
+        if pos < *collection_pos {
+            *collection_pos = pos;
+        }
+    }
+}
+
+fn collection_of(event: &Event) -> Collection {
+    match event {
+        Event::SequenceStart(_) => Collection::Sequence(0),
+        _ => Collection::Mapping(None),
+    }
+}
+
+/// A TOML node with the spans of its keys and items. The spans of the tables
+/// defined by the headers and the arrays of tables are empty.
+enum TomlNode {
+    Table(Vec<(Spanned<String>, Spanned<TomlNode>)>),
+    Array(Vec<Spanned<TomlNode>>),
+    Value,
+}
+
+impl<'de> Deserialize<'de> for TomlNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TomlNodeVisitor)
+    }
+}
+
+struct TomlNodeVisitor;
+
+impl<'de> Visitor<'de> for TomlNodeVisitor {
+    type Value = TomlNode;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a TOML value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<TomlNode, E> {
+        Ok(TomlNode::Value)
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<TomlNode, E> {
+        Ok(TomlNode::Value)
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<TomlNode, E> {
+        Ok(TomlNode::Value)
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<TomlNode, E> {
+        Ok(TomlNode::Value)
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<TomlNode, E> {
+        Ok(TomlNode::Value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<TomlNode, A::Error> {
+        let mut items = vec![];
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(TomlNode::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<TomlNode, A::Error> {
+        let mut items = vec![];
+        loop {
+            match map.next_key::<Spanned<String>>() {
+                Ok(Some(key)) => items.push((key, map.next_value()?)),
+                Ok(None) => return Ok(TomlNode::Table(items)),
+                // A datetime is visited as a map with a private key without span.
+                Err(_) if items.is_empty() => {
+                    map.next_value::<String>()?;
+                    return Ok(TomlNode::Value);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// Load the positions of the keys and values in the TOML document from their
+/// spans. The tables defined by the headers are located at their keys and the
+/// tables in the arrays of tables are located at their headers.
+fn load_toml_positions(content: &str) -> Result<DataPositions> {
+    let root: Spanned<TomlNode> = toml::from_str(content)
+        .with_context(|| "Failed to Load the Positions of the Data".to_string())?;
+    let mut locator = TomlLocator {
+        content,
+        line_starts: std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect(),
+        document: DataPositions::default(),
+    };
+    locator.locate(ROOT_PATH, &root, (1, 0));
+    Ok(locator.document)
+}
+
+struct TomlLocator<'a> {
+    content: &'a str,
+    /// The byte offsets of the line starts.
+    line_starts: Vec<usize>,
+    document: DataPositions,
+}
+
+impl TomlLocator<'_> {
+    /// Records the positions of the `node` at `path` and its items, the node is
+    /// located at `pos` when its span is empty.
+    fn locate(&mut self, path: &str, node: &Spanned<TomlNode>, pos: (u64, u64)) {
+        let (start, end) = node.span();
+        let pos = if start < end { self.pos(start) } else { pos };
+        self.document.values.insert(path.to_string(), pos);
+        match node.get_ref() {
+            TomlNode::Table(items) => {
+                for (key, value) in items {
+                    let path = key_path(path, key.get_ref());
+                    let key_pos = self.pos(key.start());
+                    self.document.keys.insert(path.clone(), key_pos);
+                    self.locate(&path, value, key_pos);
+                }
+            }
+            TomlNode::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    let item_pos = self.header_pos(item.get_ref()).unwrap_or(pos);
+                    self.locate(&index_path(path, index), item, item_pos);
+                }
+            }
+            TomlNode::Value => {}
+        }
+    }
+
+    /// Returns the 1-based line and the 0-based column of the byte `offset`.
+    fn pos(&self, offset: usize) -> (u64, u64) {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        let column = self.content[self.line_starts[line]..offset].chars().count();
+        (line as u64 + 1, column as u64)
+    }
+
+    /// Returns the position of the `[[...]]` header of the table in an array of
+    /// tables, which is the nearest header before the first key of the table.
+    fn header_pos(&self, table: &TomlNode) -> Option<(u64, u64)> {
+        let first_key = match table {
+            TomlNode::Table(items) => items.first()?.0.start(),
+            _ => return None,
+        };
+        let (line, _) = self.pos(first_key);
+        (0..line as usize - 1).rev().find_map(|index| {
+            let text = &self.content[self.line_starts[index]..self.line_starts[index + 1]];
+            let trimmed = text.trim_start();
+            trimmed
+                .starts_with("[[")
+                .then(|| (index as u64 + 1, (text.len() - trimmed.len()) as u64))
+        })
+    }
+}
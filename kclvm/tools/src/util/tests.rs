@@ -184,6 +184,59 @@ websites:
             }
         }
 
+        #[test]
+        fn test_load_positions() {
+            let json_loader = data_loader_from_str(LoaderKind::JSON, JSON_STR_TEST_CASES[0]);
+            let positions = json_loader.load_positions().unwrap();
+            assert_eq!(positions.len(), 1);
+            assert_eq!(positions[0].values.get("$"), Some(&(1, 0)));
+            assert_eq!(positions[0].values.get("$.address.city"), Some(&(6, 16)));
+            assert_eq!(positions[0].keys.get("$.address.city"), Some(&(6, 8)));
+            assert_eq!(positions[0].values.get("$.phones[1]"), Some(&(10, 8)));
+
+            let yaml_loader = data_loader_from_str(
+                LoaderKind::YAML,
+                "a: 1\n'b.c':\n  - x\n  - y: 2\n---\n- 3\n",
+            );
+            let positions = yaml_loader.load_positions().unwrap();
+            assert_eq!(positions.len(), 2);
+            assert_eq!(positions[0].values.get("$"), Some(&(1, 0)));
+            assert_eq!(positions[0].values.get("$['b.c'][1].y"), Some(&(4, 7)));
+            assert_eq!(positions[0].keys.get("$['b.c']"), Some(&(2, 0)));
+            assert_eq!(positions[1].values.get("$[0]"), Some(&(6, 2)));
+        }
+
+        #[test]
+        fn test_load_yaml_stream() {
+            let yaml_loader =
+                data_loader_from_str(LoaderKind::YAML, "name: Alice\n---\nname: Bob\n");
+            let documents: Vec<serde_yaml::Value> = yaml_loader.load().unwrap();
+            assert_eq!(documents.len(), 2);
+            assert_eq!(documents[1]["name"], serde_yaml::Value::from("Bob"));
+        }
+
+        #[test]
+        fn test_load_toml_positions() {
+            let toml_loader = data_loader_from_str(
+                LoaderKind::TOML,
+                "name = \"Alice\"\nlabels.\"app.kubernetes.io/name\" = \"web\"\n\n[[friends]]\nname = \"Bob\"\n\n[[friends]]\nname = \"Tom\"\n\n[owner]\nborn = 1979-05-27T07:32:00Z\nports = [80,\n  443]\n",
+            );
+            let value: toml::Value = toml_loader.load().unwrap();
+            assert_eq!(value["friends"][1]["name"].as_str(), Some("Tom"));
+            let positions = toml_loader.load_positions().unwrap();
+            assert_eq!(positions.len(), 1);
+            assert_eq!(positions[0].values.get("$.name"), Some(&(1, 7)));
+            assert_eq!(
+                positions[0].keys.get("$.labels['app.kubernetes.io/name']"),
+                Some(&(2, 7))
+            );
+            assert_eq!(positions[0].values.get("$.friends[1]"), Some(&(7, 0)));
+            assert_eq!(positions[0].values.get("$.friends[1].name"), Some(&(8, 7)));
+            assert_eq!(positions[0].values.get("$.owner"), Some(&(10, 1)));
+            assert_eq!(positions[0].values.get("$.owner.born"), Some(&(11, 7)));
+            assert_eq!(positions[0].values.get("$.owner.ports[1]"), Some(&(13, 2)));
+        }
+
         #[test]
         fn new_with_file_path_invalid() {
             match DataLoader::new_with_file_path(LoaderKind::JSON, "invalid file path") {
@@ -7,7 +7,7 @@ use kclvm_ast::{
     node_ref,
 };
 
-use crate::util::loader::{DataLoader, Loader, LoaderKind};
+use crate::util::loader::{DataLoader, DataPositions, Loader, LoaderKind};
 use anyhow::{bail, Context, Result};
 
 trait ExprGenerator<T> {
@@ -29,7 +29,6 @@ impl ExprBuilder {
         Ok(Self { loader })
     }
 
-    #[allow(dead_code)]
     pub(crate) fn new_with_str(kind: LoaderKind, content: String) -> Result<Self> {
         let loader = DataLoader::new_with_str(kind, &content)
             .with_context(|| format!("Failed to Parse String '{}'", content))?;
@@ -37,7 +36,12 @@ impl ExprBuilder {
         Ok(Self { loader })
     }
 
-    /// Generate ast expr from Json/Yaml depends on `LoaderKind`.
+    /// Load the positions of the nodes in each Json/Yaml/TOML document.
+    pub(crate) fn load_positions(&self) -> Result<Vec<DataPositions>> {
+        self.loader.load_positions()
+    }
+
+    /// Generate ast expr from Json/Yaml/TOML depends on `LoaderKind`.
     pub(crate) fn build(&self, schema_name: Option<String>) -> Result<NodeRef<Expr>> {
         match self.loader.get_kind() {
             LoaderKind::JSON => {
@@ -54,6 +58,35 @@ impl ExprBuilder {
                     .generate(&value, &schema_name)
                     .with_context(|| "Failed to Load YAML".to_string())?)
             }
+            LoaderKind::TOML => {
+                let value = <DataLoader as Loader<toml::Value>>::load(&self.loader)
+                    .with_context(|| "Failed to Load TOML".to_string())?;
+                Ok(self
+                    .generate(&value, &schema_name)
+                    .with_context(|| "Failed to Load TOML".to_string())?)
+            }
+        }
+    }
+
+    /// Generate an ast expr for each document of the data, a Yaml stream may
+    /// contain multiple documents and a Json/TOML file is a single document.
+    pub(crate) fn build_documents(
+        &self,
+        schema_name: Option<String>,
+    ) -> Result<Vec<NodeRef<Expr>>> {
+        match self.loader.get_kind() {
+            LoaderKind::YAML => {
+                let values = <DataLoader as Loader<Vec<serde_yaml::Value>>>::load(&self.loader)
+                    .with_context(|| "Failed to Load YAML".to_string())?;
+                values
+                    .iter()
+                    .map(|value| {
+                        self.generate(value, &schema_name)
+                            .with_context(|| "Failed to Load YAML".to_string())
+                    })
+                    .collect()
+            }
+            _ => Ok(vec![self.build(schema_name)?]),
         }
     }
 }
@@ -301,3 +334,102 @@ impl ExprGenerator<serde_json::Value> for ExprBuilder {
         }
     }
 }
+
+impl ExprGenerator<toml::Value> for ExprBuilder {
+    fn generate(&self, value: &toml::Value, schema_name: &Option<String>) -> Result<NodeRef<Expr>> {
+        match value {
+            toml::Value::Boolean(t_bool) => {
+                let name_const = match NameConstant::try_from(*t_bool) {
+                    Ok(nc) => nc,
+                    Err(_) => {
+                        bail!("Failed to Load Validated File")
+                    }
+                };
+
+                Ok(node_ref!(Expr::NameConstantLit(NameConstantLit {
+                    value: name_const
+                })))
+            }
+            toml::Value::Integer(t_int) => Ok(node_ref!(Expr::NumberLit(NumberLit {
+                binary_suffix: None,
+                value: NumberLitValue::Int(*t_int)
+            }))),
+            toml::Value::Float(t_float) => Ok(node_ref!(Expr::NumberLit(NumberLit {
+                binary_suffix: None,
+                value: NumberLitValue::Float(*t_float)
+            }))),
+            // The TOML datetime is validated as its string representation.
+            toml::Value::String(_) | toml::Value::Datetime(_) => {
+                let t_string = match value.as_str() {
+                    Some(t_string) => t_string.to_string(),
+                    None => value.to_string(),
+                };
+                let str_lit = match StringLit::try_from(t_string) {
+                    Ok(s) => s,
+                    Err(_) => {
+                        bail!("Failed to Load Validated File")
+                    }
+                };
+
+                Ok(node_ref!(Expr::StringLit(str_lit)))
+            }
+            toml::Value::Array(t_arr) => {
+                let mut t_arr_ast_nodes: Vec<NodeRef<Expr>> = Vec::new();
+                for t_arr_item in t_arr {
+                    t_arr_ast_nodes.push(
+                        self.generate(t_arr_item, schema_name)
+                            .with_context(|| "Failed to Load Validated File".to_string())?,
+                    );
+                }
+                Ok(node_ref!(Expr::List(ListExpr {
+                    ctx: ExprContext::Load,
+                    elts: t_arr_ast_nodes
+                })))
+            }
+            toml::Value::Table(t_map) => {
+                let mut config_entries: Vec<NodeRef<ConfigEntry>> = Vec::new();
+
+                for (k, v) in t_map.iter() {
+                    let k = match StringLit::try_from(k.to_string()) {
+                        Ok(s) => s,
+                        Err(_) => {
+                            bail!("Failed to Load Validated File")
+                        }
+                    };
+                    let v = self
+                        .generate(v, &None)
+                        .with_context(|| "Failed to Load Validated File".to_string())?;
+
+                    let config_entry = node_ref!(ConfigEntry {
+                        key: Some(node_ref!(Expr::StringLit(k))),
+                        value: v,
+                        operation: ConfigEntryOperation::Union,
+                        insert_index: -1
+                    });
+                    config_entries.push(config_entry);
+                }
+
+                let config_expr = node_ref!(Expr::Config(ConfigExpr {
+                    items: config_entries
+                }));
+
+                match schema_name {
+                    Some(s_name) => {
+                        let iden = node_ref!(Identifier {
+                            names: vec![s_name.to_string()],
+                            pkgpath: String::new(),
+                            ctx: ExprContext::Load
+                        });
+                        Ok(node_ref!(Expr::Schema(SchemaExpr {
+                            name: iden,
+                            config: config_expr,
+                            args: vec![],
+                            kwargs: vec![]
+                        })))
+                    }
+                    None => Ok(config_expr),
+                }
+            }
+        }
+    }
+}
@@ -301,26 +301,142 @@ mod test_expr_builder {
 }
 
 mod test_validater {
-    use std::{fs, panic};
+    use std::fs;
 
     use crate::{
         util::loader::LoaderKind,
-        vet::validator::{validate, ValidateOption},
+        vet::validator::{validate, validate_documents, ValidateOption, ValidationError},
     };
 
     use super::{construct_full_path, LOADER_KIND};
 
     const KCL_TEST_CASES: &[&str] = &["test.k", "simple.k", "list.k", "plain_value.k", "complex.k"];
+    const INVALID_KCL_TEST_CASES: &[&str] = &[
+        "test.k",
+        "simple.k",
+        "list.k",
+        "plain_value.k",
+        "complex.k",
+        "multi_failures.k",
+    ];
     const VALIDATED_FILE_TYPE: &[&str] = &["json", "yaml"];
 
     #[test]
     fn test_validator() {
         test_validate();
-        // TOOD: Fix me on ubuntu platform. @zongzhe
-        // test_invalid_validate();
+        test_invalid_validate();
         test_validate_with_invalid_kcl_path();
         test_validate_with_invalid_file_path();
         test_validate_with_invalid_file_type();
+        test_validate_yaml_stream();
+        test_validate_toml();
+        test_validate_directory();
+        test_validate_directory_with_invalid_file();
+        test_validate_glob();
+        test_validate_with_no_data_files();
+    }
+
+    fn documents_option(data_path: &str, kind: LoaderKind) -> ValidateOption {
+        ValidateOption::new(
+            None,
+            "value".to_string(),
+            construct_full_path(&format!("documents_cases/{}", data_path)).unwrap(),
+            kind,
+            Some(construct_full_path("documents_cases/users.k").unwrap()),
+            None,
+        )
+    }
+
+    fn error_paths(errors: &[ValidationError]) -> Vec<(&str, u64, &str)> {
+        errors
+            .iter()
+            .map(|e| (e.path.as_str(), e.line, e.message.as_str()))
+            .collect()
+    }
+
+    fn test_validate_yaml_stream() {
+        let reports =
+            validate_documents(documents_option("users/stream.yaml", LoaderKind::YAML)).unwrap();
+        assert_eq!(reports.len(), 3);
+        assert_eq!(
+            reports.iter().map(|r| r.index).collect::<Vec<usize>>(),
+            vec![0, 1, 2]
+        );
+        assert!(reports[0].errors.is_empty());
+        assert_eq!(
+            error_paths(&reports[1].errors),
+            vec![
+                ("$", 4, "name must not be empty"),
+                ("$", 4, "age must not be negative"),
+                ("$.friends[0]", 7, "age must not be negative"),
+            ]
+        );
+        assert!(reports[2].errors.is_empty());
+    }
+
+    fn test_validate_toml() {
+        let reports =
+            validate_documents(documents_option("users/users.toml", LoaderKind::TOML)).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(
+            error_paths(&reports[0].errors),
+            vec![
+                ("$.labels.app", 4, "label is too long"),
+                ("$.friends[1]", 11, "age must not be negative"),
+            ]
+        );
+    }
+
+    fn test_validate_directory() {
+        let reports = validate_documents(documents_option("users", LoaderKind::YAML)).unwrap();
+        let documents: Vec<(String, usize, usize)> = reports
+            .iter()
+            .map(|r| {
+                let filename = std::path::Path::new(&r.filename)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string();
+                (filename, r.index, r.errors.len())
+            })
+            .collect();
+        assert_eq!(
+            documents,
+            vec![
+                ("stream.yaml".to_string(), 0, 0),
+                ("stream.yaml".to_string(), 1, 3),
+                ("stream.yaml".to_string(), 2, 0),
+                ("valid.yml".to_string(), 0, 0),
+            ]
+        );
+    }
+
+    fn test_validate_directory_with_invalid_file() {
+        let reports = validate_documents(documents_option("partial", LoaderKind::YAML)).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(reports[0].filename.ends_with("broken.yaml"));
+        assert_eq!(reports[0].errors.len(), 1);
+        let (path, line, message) = error_paths(&reports[0].errors)[0];
+        assert_eq!((path, line), ("$", 1));
+        assert!(message.starts_with("Failed to load validated file"));
+        assert!(reports[1].filename.ends_with("valid.yaml"));
+        assert!(reports[1].errors.is_empty());
+    }
+
+    fn test_validate_glob() {
+        let reports =
+            validate_documents(documents_option("users/*.toml", LoaderKind::TOML)).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].filename.ends_with("users.toml"));
+        assert_eq!(reports[0].errors.len(), 2);
+    }
+
+    fn test_validate_with_no_data_files() {
+        let path = construct_full_path("documents_cases/users/*.json").unwrap();
+        match validate_documents(documents_option("users/*.json", LoaderKind::JSON)) {
+            Ok(_) => panic!("unreachable"),
+            Err(err) => assert_eq!(err, format!("No data files found in '{}'", path)),
+        }
     }
 
     fn test_validate() {
@@ -343,7 +459,7 @@ mod test_validater {
                 );
 
                 match validate(opt) {
-                    Ok(res) => assert!(res),
+                    Ok(errors) => assert!(errors.is_empty(), "{:?}", errors),
                     Err(_) => panic!("Unreachable"),
                 }
             }
@@ -351,12 +467,8 @@ mod test_validater {
     }
 
     fn test_invalid_validate() {
-        let prev_hook = std::panic::take_hook();
-        // disable print panic info
-        std::panic::set_hook(Box::new(|_| {}));
-
         for (i, file_suffix) in VALIDATED_FILE_TYPE.iter().enumerate() {
-            for case in KCL_TEST_CASES {
+            for case in INVALID_KCL_TEST_CASES {
                 let validated_file_path = construct_full_path(&format!(
                     "{}/{}.{}",
                     "invalid_validate_cases", case, file_suffix
@@ -368,10 +480,10 @@ mod test_validater {
                 )
                 .expect("Something went wrong reading the file");
 
-                let expected_err_msg = fs::read_to_string(
+                let expected_errors = fs::read_to_string(
                     construct_full_path(&format!(
-                        "{}/{}.{}",
-                        "invalid_validate_cases", case, "stderr.json"
+                        "{}/{}.{}.{}",
+                        "invalid_validate_cases", case, file_suffix, "stderr.json"
                     ))
                     .unwrap(),
                 )
@@ -386,31 +498,13 @@ mod test_validater {
                     Some(kcl_code),
                 );
 
-                let result = panic::catch_unwind(|| validate(opt));
-
-                let expect: serde_json::Value = serde_json::from_str(&expected_err_msg).unwrap();
-                match result {
-                    Ok(result) => match result {
-                        Ok(_) => {
-                            panic!("Unreachable.")
-                        }
-                        Err(err) => {
-                            let got: serde_json::Value = serde_json::from_str(&err).unwrap();
-                            assert_eq!(got, expect);
-                        }
-                    },
-                    Err(panic_err) => {
-                        if let Some(result) = panic_err.downcast_ref::<String>() {
-                            let got: serde_json::Value = serde_json::from_str(result).unwrap();
-                            assert_eq!(got, expect);
-                        } else {
-                            panic!("Unreachable.")
-                        };
-                    }
+                let expect: Vec<ValidationError> = serde_json::from_str(&expected_errors).unwrap();
+                match validate(opt) {
+                    Ok(errors) => assert_eq!(errors, expect, "{}", validated_file_path),
+                    Err(err) => panic!("{}", err),
                 }
             }
         }
-        std::panic::set_hook(prev_hook);
     }
 
     fn test_validate_with_invalid_kcl_path() {
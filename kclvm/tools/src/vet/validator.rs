@@ -64,24 +64,80 @@
 //!         name == "Alice"
 //!         age > 10
 //! ```
+use std::{collections::HashMap, path::Path, rc::Rc};
+
 use super::expr_builder::ExprBuilder;
-use crate::util::loader::LoaderKind;
+use crate::util::loader::{index_path, key_path, DataPositions, LoaderKind, ROOT_PATH};
 use kclvm_ast::{
-    ast::{AssignStmt, Expr, ExprContext, Identifier, Module, Node, NodeRef, SchemaStmt, Stmt},
-    node_ref,
+    ast::{
+        AssignStmt, ConfigExpr, Expr, ExprContext, Identifier, Module, Node, NodeRef,
+        NumberLitValue, Program, SchemaExpr, SchemaStmt, Stmt,
+    },
+    node_ref, MAIN_PKG,
+};
+use kclvm_error::{Diagnostic, DiagnosticId, ErrorKind, Position};
+use kclvm_runner::{exec_module, ExecProgramArgs};
+use kclvm_sema::{
+    resolver::resolve_program,
+    ty::{Type, TypeKind},
 };
-use kclvm_runner::execute_module;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
 
 const TMP_FILE: &str = "validationTempKCLCode.k";
+/// The file name of the validated data in the positions of the validation
+/// errors when the data is not loaded from a file.
+const TMP_DATA_FILE: &str = "validationTempData";
+
+/// ValidationError is a failure of the validated data, e.g., a failed check
+/// condition or a mismatched type, located at the data node where it occurs.
+///
+/// The failures not located at a data node, e.g., a failed `assert` statement
+/// in the KCL code, are located at the root node of the data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationError {
+    /// The JSON path of the data node, e.g., `$.spec.containers[0].image`.
+    pub path: String,
+    /// The 1-based line of the data node in the validated file.
+    pub line: u64,
+    /// The 1-based column of the data node in the validated file.
+    pub column: u64,
+    pub message: String,
+    /// The position of the failed check condition or the mismatched type in
+    /// the KCL code.
+    pub kcl_pos: Option<Position>,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (line {}, column {}): {}",
+            self.path, self.line, self.column, self.message
+        )
+    }
+}
+
+/// DocumentReport is the validation result of a document in a data file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentReport {
+    /// The path of the data file, or `validationTempData` for the data string.
+    pub filename: String,
+    /// The 0-based index of the document in the data file, e.g., a Yaml stream.
+    pub index: usize,
+    /// The validation failures of the document, empty when it is valid.
+    pub errors: Vec<ValidationError>,
+}
 
 /// Validate the data string using the schema code string, when the parameter
 /// `schema` is omitted, use the first schema appeared in the kcl code.
 ///
-/// Returns a bool result denoting whether validating success, raise an error
-/// when validating failed because of the file not found error, schema not found
-/// error, syntax error, check error, etc.
-///
-/// When the content of the json file conforms to the rules, a normal kcl expression will be returned.
+/// Returns all the validation failures of all the documents of the data, see
+/// method `validate_documents`, and an empty list denotes that the data is
+/// valid. Each failed check condition of each schema instance is reported
+/// with the JSON path and the position of the data node instead of stopping at
+/// the first one. Raise an error when validating failed because of
+/// the file not found error, schema not found error, syntax error, etc.
 ///
 /// # Examples
 ///
@@ -110,7 +166,7 @@ const TMP_FILE: &str = "validationTempKCLCode.k";
 ///
 /// 3. Second, you can call this method as follows to validate the content of the json file with the kcl file.
 /// ```rust
-/// # use kclvm_tools::vet::validator::validate;
+/// # use kclvm_tools::vet::validator::{validate, ValidateOption};
 /// # use std::path::PathBuf;
 /// # use kclvm_tools::util::loader::LoaderKind;
 ///
@@ -122,62 +178,61 @@ const TMP_FILE: &str = "validationTempKCLCode.k";
 /// // Then get the path to the KCL file.
 /// let mut kcl_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
 /// kcl_file_path.push("src/vet/test_datas/validate_cases/test.k");
-/// let kcl_file_path = Some(kcl_file_path.to_str().unwrap());
+/// let kcl_file_path = Some(kcl_file_path.to_str().unwrap().to_string());
 ///
 /// // Get the name of the schema defined in the kcl file
 /// let schema_name = Some("User".to_string());
 ///
 /// // Define the name of an attribute.
 /// // The name of this property is related to the rules in the KCL file.
-/// let attr_name = "value";
+/// let attr_name = "value".to_string();
 ///
 /// // Define the kind of file you want to validate.
 /// let kind = LoaderKind::JSON;
 ///
 /// // One of the KCL file path or the content of the KCL file is enough.
-/// let result = validate(schema_name, attr_name, validated_file_path.to_string(), kind, kcl_file_path, None);
-/// ```
-///
-/// The json file used above conforms to the schema rules, so the content of `result` you get is :
+/// let opt = ValidateOption::new(schema_name, attr_name, validated_file_path.to_string(), kind, kcl_file_path, None);
+/// let errors = validate(opt).unwrap();
+/// assert!(errors.is_empty());
 /// ```
 ///
 /// If you change the content of the above json file to :
 /// ```ignore
 /// {
 ///     "name": "Tom",
-///     "age": 18,
+///     "age": 8,
 ///     "message": "This is Alice"
 /// }
 /// ```
 ///
-/// You will get an error message like this:
+/// You will get both the failures like this:
 /// ```ignore
-/// {
-///     "__kcl_PanicInfo__": true,
-///     "rust_file": "runtime/src/value/api.rs",
-///     "rust_line": 2203,
-///     "rust_col": 9,
-///     "kcl_pkgpath": "__main__",
-///     "kcl_file": "kclvm/tools/src/vet/test_datas/invalid_validate_cases/test.json",
-///     "kcl_line": 7,
-///     "kcl_col": 0,
-///     "kcl_arg_msg": "Check failed on the condition",
-///     "kcl_config_meta_file": "",
-///     "kcl_config_meta_line": 1,
-///     "kcl_config_meta_col": 1,
-///     "kcl_config_meta_arg_msg": "Instance check failed",
-///     "message": "",
-///     "err_type_code": 17,
-///     "is_warning": false
-/// }
+/// $ (line 1, column 1): Check failed on the condition
+/// $ (line 1, column 1): Check failed on the condition
 /// ```
-pub fn validate(val_opt: ValidateOption) -> Result<bool, String> {
+pub fn validate(val_opt: ValidateOption) -> Result<Vec<ValidationError>, String> {
+    Ok(validate_documents(val_opt)?
+        .into_iter()
+        .flat_map(|report| report.errors)
+        .collect())
+}
+
+/// Validate each document of the data like method `validate`, and returns the
+/// validation report of each document.
+///
+/// The validated file path in `val_opt` may be a data file, a directory of the
+/// data files with the extensions of the data kind, e.g., `.yaml` and `.yml`,
+/// or a glob pattern of the data files, e.g., `deploy/*.yaml`. A Yaml file may
+/// be a stream of multiple documents separated by `---`. When a data file of a
+/// directory or a glob pattern can't be loaded, it is reported as a document
+/// with the load error at the root node instead of failing the other files.
+pub fn validate_documents(val_opt: ValidateOption) -> Result<Vec<DocumentReport>, String> {
     let k_path = match val_opt.kcl_path {
         Some(path) => path,
         None => TMP_FILE.to_string(),
     };
 
-    let mut module: Module = match kclvm_parser::parse_file(&k_path, val_opt.kcl_code) {
+    let module: Module = match kclvm_parser::parse_file(&k_path, val_opt.kcl_code) {
         Ok(ast_m) => ast_m,
         Err(err_msg) => return Err(err_msg),
     };
@@ -188,26 +243,327 @@ pub fn validate(val_opt: ValidateOption) -> Result<bool, String> {
         None => schemas.get(0).map(|schema| schema.name.node.clone()),
     };
 
-    let expr_builder = match ExprBuilder::new_with_file_path(
-        val_opt.validated_file_kind,
-        val_opt.validated_file_path,
-    ) {
-        Ok(builder) => builder,
-        Err(_) => return Err("Failed to load validated file.".to_string()),
+    let expr_builders = match val_opt.validated_data {
+        Some(data) => vec![(
+            TMP_DATA_FILE.to_string(),
+            ExprBuilder::new_with_str(val_opt.validated_file_kind, data),
+        )],
+        None => data_files(&val_opt.validated_file_path, val_opt.validated_file_kind)?
+            .into_iter()
+            .map(|file| {
+                let expr_builder =
+                    ExprBuilder::new_with_file_path(val_opt.validated_file_kind, file.clone());
+                (file, expr_builder)
+            })
+            .collect(),
     };
+    let is_data_set = !matches!(
+        expr_builders.as_slice(),
+        [(file, _)] if *file == val_opt.validated_file_path || *file == TMP_DATA_FILE
+    );
+
+    // The schema types don't depend on the data, so they are resolved only once.
+    let schema_types = resolve_schema_types(module.clone());
+    let mut reports = vec![];
+    for (filename, expr_builder) in expr_builders {
+        let built = expr_builder.and_then(|builder| {
+            let exprs = builder.build_documents(schema_name.clone())?;
+            Ok((builder, exprs))
+        });
+        let (expr_builder, validated_exprs) = match built {
+            Ok(built) => built,
+            Err(err) if is_data_set => {
+                reports.push(DocumentReport {
+                    errors: vec![ValidationError {
+                        path: ROOT_PATH.to_string(),
+                        line: 1,
+                        column: 1,
+                        message: format!("Failed to load validated file: {:#}", err),
+                        kcl_pos: None,
+                    }],
+                    filename,
+                    index: 0,
+                });
+                continue;
+            }
+            Err(_) => return Err("Failed to load validated file.".to_string()),
+        };
 
-    let validated_expr = match expr_builder.build(schema_name) {
-        Ok(expr) => expr,
-        Err(_) => return Err("Failed to load validated file.".to_string()),
+        // The positions are only used to locate the failures.
+        let mut positions = expr_builder.load_positions().unwrap_or_default();
+        positions.resize(validated_exprs.len(), DataPositions::default());
+
+        for (index, (validated_expr, positions)) in
+            validated_exprs.into_iter().zip(positions).enumerate()
+        {
+            reports.push(DocumentReport {
+                errors: validate_document(
+                    module.clone(),
+                    &schema_types,
+                    &val_opt.attribute_name,
+                    validated_expr,
+                    &filename,
+                    positions,
+                ),
+                filename: filename.clone(),
+                index,
+            });
+        }
+    }
+    Ok(reports)
+}
+
+/// Validate the document expression `validated_expr` with the KCL `module` and
+/// its `schema_types`, and returns the validation failures located with the
+/// `positions` in `filename`.
+fn validate_document(
+    mut module: Module,
+    schema_types: &HashMap<String, Rc<Type>>,
+    attribute_name: &str,
+    mut validated_expr: NodeRef<Expr>,
+    filename: &str,
+    positions: DataPositions,
+) -> Vec<ValidationError> {
+    let mut locator = DataLocator {
+        filename: filename.to_string(),
+        schema_types,
+        positions,
+        instances: HashMap::new(),
     };
+    locator.locate_expr(&mut validated_expr, ROOT_PATH, None);
 
-    let assign_stmt = build_assign(&val_opt.attribute_name, validated_expr);
+    let assign_stmt = build_assign(attribute_name, validated_expr);
 
     module.body.insert(0, assign_stmt);
 
-    match execute_module(module) {
-        Ok(_) => Ok(true),
-        Err(err) => Err(err),
+    let args = ExecProgramArgs {
+        collect_check_failures: true,
+        ..Default::default()
+    };
+    match exec_module(module, &args) {
+        Ok(_) => vec![],
+        Err(err) => {
+            let mut errors: Vec<ValidationError> = err
+                .diagnostics
+                .iter()
+                .filter(|diag| diag.is_error())
+                .map(|diag| locator.validation_error(diag))
+                .collect();
+            errors.sort_by_key(|err| (err.line, err.column));
+            errors
+        }
+    }
+}
+
+/// Returns the data files of `path`, which is a data file, a directory of the data
+/// files with the extensions of `kind`, or a glob pattern of the data files.
+fn data_files(path: &str, kind: LoaderKind) -> Result<Vec<String>, String> {
+    let files: Vec<String> = if Path::new(path).is_dir() {
+        WalkDir::new(path)
+            .sort_by(|a, b| a.file_name().cmp(b.file_name()))
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                let ext = entry.path().extension().and_then(|ext| ext.to_str());
+                entry.path().is_file()
+                    && matches!(ext, Some(ext) if kind.extensions().contains(&ext))
+            })
+            .map(|entry| entry.path().display().to_string())
+            .collect()
+    } else if path.contains(['*', '?', '[']) && !Path::new(path).exists() {
+        glob::glob(path)
+            .map_err(|err| format!("Invalid data file pattern '{}': {}", path, err))?
+            .filter_map(|entry| entry.ok())
+            .filter(|path| path.is_file())
+            .map(|path| path.display().to_string())
+            .collect()
+    } else {
+        return Ok(vec![path.to_string()]);
+    };
+    if files.is_empty() {
+        Err(format!("No data files found in '{}'", path))
+    } else {
+        Ok(files)
+    }
+}
+
+/// Returns the types of the schemas in the main package of the module.
+fn resolve_schema_types(mut module: Module) -> HashMap<String, Rc<Type>> {
+    module.pkg = MAIN_PKG.to_string();
+    let mut program = Program {
+        root: MAIN_PKG.to_string(),
+        main: MAIN_PKG.to_string(),
+        pkgs: HashMap::from([(MAIN_PKG.to_string(), vec![module])]),
+        cmd_args: vec![],
+        cmd_overrides: vec![],
+    };
+    let scope = resolve_program(&mut program);
+    let mut schema_types = HashMap::new();
+    if let Some(main_scope) = scope.scope_map.get(MAIN_PKG) {
+        for (name, obj) in &main_scope.borrow().elems {
+            let ty = obj.borrow().ty.clone();
+            if let TypeKind::Schema(_) = &ty.kind {
+                schema_types.insert(name.clone(), ty);
+            }
+        }
+    }
+    schema_types
+}
+
+/// DataLocator locates the expression built from the validated data with the
+/// positions of the data nodes, and locates the validation failures with the
+/// JSON paths of the data nodes.
+struct DataLocator<'a> {
+    filename: String,
+    schema_types: &'a HashMap<String, Rc<Type>>,
+    positions: DataPositions,
+    /// The JSON paths of the schema instances indexed by their positions.
+    instances: HashMap<(u64, u64), String>,
+}
+
+impl DataLocator<'_> {
+    /// Sets the positions of the data nodes to the expression `expr` of the data at
+    /// `path`, and converts the nested configs into the schema expressions of their
+    /// attribute types `ty`, so that the failures of the nested schema instances
+    /// are also located at the data nodes.
+    fn locate_expr(&mut self, expr: &mut NodeRef<Expr>, path: &str, ty: Option<&Type>) {
+        self.locate(expr, self.positions.values.get(path));
+        if let (Expr::Config(_), Some(TypeKind::Schema(schema_ty))) =
+            (&expr.node, ty.map(|ty| &ty.kind))
+        {
+            if schema_ty.pkgpath == MAIN_PKG && !schema_ty.is_mixin && !schema_ty.is_protocol {
+                let mut name = Box::new(Node::dummy_node(Identifier {
+                    names: vec![schema_ty.name.clone()],
+                    pkgpath: String::new(),
+                    ctx: ExprContext::Load,
+                }));
+                self.locate(&mut name, self.positions.values.get(path));
+                let mut config = Box::new(Node::dummy_node(expr.node.clone()));
+                self.locate(&mut config, self.positions.values.get(path));
+                expr.node = Expr::Schema(SchemaExpr {
+                    name,
+                    config,
+                    args: vec![],
+                    kwargs: vec![],
+                });
+            }
+        }
+        match &mut expr.node {
+            Expr::Schema(schema_expr) => {
+                self.locate(&mut schema_expr.name, self.positions.values.get(path));
+                if let Some(pos) = self.positions.values.get(path) {
+                    self.instances.insert(*pos, path.to_string());
+                }
+                let schema_ty = self
+                    .schema_types
+                    .get(&schema_expr.name.node.get_name())
+                    .cloned();
+                self.locate_config(&mut schema_expr.config, path, schema_ty.as_deref());
+            }
+            Expr::Config(_) => self.locate_config(expr, path, ty),
+            Expr::List(list_expr) => {
+                let item_ty = match ty.map(|ty| &ty.kind) {
+                    Some(TypeKind::List(item_ty)) => Some(item_ty.as_ref()),
+                    _ => None,
+                };
+                for (i, elt) in list_expr.elts.iter_mut().enumerate() {
+                    self.locate_expr(elt, &index_path(path, i), item_ty);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Locates the config `expr` of the data at `path` and its entries, the entry
+    /// values are located with the attribute types of `ty`.
+    fn locate_config(&mut self, expr: &mut NodeRef<Expr>, path: &str, ty: Option<&Type>) {
+        self.locate(expr, self.positions.values.get(path));
+        if let Expr::Config(ConfigExpr { items }) = &mut expr.node {
+            for entry in items {
+                let key = match entry.node.key.as_ref().map(|key| &key.node) {
+                    Some(Expr::StringLit(string_lit)) => string_lit.value.clone(),
+                    Some(Expr::NumberLit(number_lit)) => match number_lit.value {
+                        NumberLitValue::Int(value) => value.to_string(),
+                        NumberLitValue::Float(value) => value.to_string(),
+                    },
+                    _ => continue,
+                };
+                let value_path = key_path(path, &key);
+                let key_pos = self.positions.keys.get(&value_path);
+                self.locate(entry, key_pos);
+                if let Some(key) = &mut entry.node.key {
+                    self.locate(key, key_pos);
+                }
+                let value_ty = ty.and_then(|ty| match &ty.kind {
+                    TypeKind::Schema(schema_ty) => schema_ty.get_type_of_attr(&key).or_else(|| {
+                        schema_ty
+                            .index_signature
+                            .as_ref()
+                            .map(|index_signature| index_signature.val_ty.clone())
+                    }),
+                    TypeKind::Dict(_, value_ty) => Some(value_ty.clone()),
+                    _ => None,
+                });
+                self.locate_expr(&mut entry.node.value, &value_path, value_ty.as_deref());
+            }
+        }
+    }
+
+    /// Sets the position `pos` in the data file to the `node`.
+    fn locate<T>(&self, node: &mut Node<T>, pos: Option<&(u64, u64)>) {
+        if let Some((line, column)) = pos {
+            node.filename = self.filename.clone();
+            node.line = *line;
+            node.column = *column;
+            node.end_line = *line;
+            node.end_column = *column;
+        }
+    }
+
+    /// Converts the diagnostic into the validation error at the data node where it occurs,
+    /// which is the schema instance of a failed check, or the nearest data node before the
+    /// position of the diagnostic in the data file.
+    fn validation_error(&self, diag: &Diagnostic) -> ValidationError {
+        let data_pos = diag
+            .messages
+            .iter()
+            .find(|msg| msg.pos.filename == self.filename)
+            .map(|msg| (msg.pos.line, msg.pos.column.unwrap_or_default()));
+        let is_check_failure = matches!(
+            diag.code,
+            Some(DiagnosticId::Error(ErrorKind::SchemaCheckFailure))
+        );
+        let (path, (line, column)) = data_pos
+            .and_then(|data_pos| match self.instances.get_key_value(&data_pos) {
+                Some((pos, path)) if is_check_failure => Some((path, pos)),
+                _ => self
+                    .positions
+                    .values
+                    .iter()
+                    .chain(self.positions.keys.iter())
+                    .filter(|(_, pos)| **pos <= data_pos)
+                    .max_by_key(|(_, pos)| **pos),
+            })
+            .map(|(path, pos)| (path.clone(), *pos))
+            .unwrap_or_else(|| {
+                let pos = self.positions.values.get(ROOT_PATH).copied();
+                (ROOT_PATH.to_string(), pos.unwrap_or((1, 0)))
+            });
+        ValidationError {
+            path,
+            line,
+            column: column + 1,
+            message: match (diag.messages.first(), &diag.code) {
+                (Some(msg), _) if !msg.message.is_empty() => msg.message.clone(),
+                (_, Some(DiagnosticId::Error(kind))) => kind.to_string(),
+                _ => String::new(),
+            },
+            kcl_pos: diag
+                .messages
+                .iter()
+                .find(|msg| msg.pos.filename != self.filename && !msg.pos.filename.is_empty())
+                .map(|msg| msg.pos.clone()),
+        }
     }
 }
 
@@ -239,6 +595,7 @@ pub struct ValidateOption {
     attribute_name: String,
     validated_file_path: String,
     validated_file_kind: LoaderKind,
+    validated_data: Option<String>,
     kcl_path: Option<String>,
     kcl_code: Option<String>,
 }
@@ -257,6 +614,28 @@ impl ValidateOption {
             attribute_name,
             validated_file_path,
             validated_file_kind,
+            validated_data: None,
+            kcl_path,
+            kcl_code,
+        }
+    }
+
+    /// Create a validate option that validates the Json/Yaml string `data`
+    /// instead of the content of a file.
+    pub fn new_with_data(
+        schema_name: Option<String>,
+        attribute_name: String,
+        data: String,
+        validated_file_kind: LoaderKind,
+        kcl_path: Option<String>,
+        kcl_code: Option<String>,
+    ) -> Self {
+        Self {
+            schema_name,
+            attribute_name,
+            validated_file_path: String::new(),
+            validated_file_kind,
+            validated_data: Some(data),
             kcl_path,
             kcl_code,
         }